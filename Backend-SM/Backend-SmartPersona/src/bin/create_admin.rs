@@ -0,0 +1,97 @@
+//! Bootstrap CLI: สร้างบัญชี Admin คนแรกของระบบ
+//!
+//! Usage: cargo run --bin create_admin -- <username> <email>
+//! Password อ่านจาก ADMIN_BOOTSTRAP_PASSWORD หรือจาก stdin
+//! Admin คนต่อไปต้องสร้างผ่านคำเชิญ (POST /admin/invites)
+
+use std::{io::BufRead, sync::Arc};
+
+use rust_api::{
+    config::config_loader,
+    domain::{
+        entities::audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+        usecase::{audit_log::AuditLogUseCase, user::UserUseCase},
+        value_object::user::{AdminAccountModel, normalize_email},
+    },
+    infrastructure::postgres::{
+        postgres_connection,
+        repositories::{audit_event::AuditEventPostgres, user::UserPostgres},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt().with_max_level(tracing::Level::INFO).init();
+
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: create_admin <username> <email>");
+        std::process::exit(2);
+    }
+
+    let password = match std::env::var("ADMIN_BOOTSTRAP_PASSWORD") {
+        Ok(password) => password,
+        Err(_) => {
+            eprintln!("Enter password for {}:", args[1]);
+            let mut line = String::new();
+            if let Err(e) = std::io::stdin().lock().read_line(&mut line) {
+                eprintln!("Failed to read password: {}", e);
+                std::process::exit(1);
+            }
+            line.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    let dot_env = match config_loader::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load config: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let postgres_pool = match postgres_connection::create_pool(&dot_env.database.url) {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Failed to establish postgres connection: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let postgres_pool = Arc::new(postgres_pool);
+    let user_use_case = UserUseCase::new(Arc::new(UserPostgres::new(Arc::clone(&postgres_pool))));
+    let audit_log_use_case = AuditLogUseCase::new(Arc::new(AuditEventPostgres::new(postgres_pool)));
+
+    let admin_account_model = AdminAccountModel {
+        username: args[1].clone(),
+        email: args[2].clone(),
+        password,
+    };
+
+    match user_use_case.bootstrap_admin(admin_account_model).await {
+        Ok(user_id) => {
+            // ไม่มี admin ผู้กระทำ - บันทึกเป็น system พร้อม OS user ที่รันคำสั่ง
+            let new_event = NewAuditEvent {
+                actor_role: Some("system".to_string()),
+                user_agent: Some(format!(
+                    "create_admin CLI ({})",
+                    std::env::var("USER").unwrap_or_else(|_| "unknown".to_string())
+                )),
+                ..NewAuditEvent::new(AuditAction::AdminBootstrap, AuditTarget::User, user_id)
+                    .after(serde_json::json!({
+                        "username": args[1],
+                        "email": normalize_email(&args[2]),
+                    }))
+            };
+            if let Err(e) = audit_log_use_case.record(new_event).await {
+                eprintln!("Warning: failed to write audit event: {}", e);
+            }
+
+            println!("Created admin account {} ({})", args[1], user_id);
+        }
+        Err(e) => {
+            eprintln!("Failed to create admin account: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use super::{
     config_model::{
-        Application, Config, Cors, Database, Jwt, JwtAdminSecret, JwtSecret, Server, Services,
-        Supabase,
+        AccountDeletion, Application, ApplicationPipeline, Config, Cors, Database, Jwt,
+        OidcProvider, RateLimit, Server, Services, Supabase,
     },
     stage::Stage,
 };
+use crate::infrastructure::jwt_authentication::jwt_keys::JwtKeySet;
 use anyhow::Result;
+use ipnet::IpNet;
+use std::{net::IpAddr, sync::Arc};
 
 pub fn load() -> Result<Config> {
     // Try to load .env file from current directory first, then parent directories
@@ -63,6 +66,7 @@ pub fn load() -> Result<Config> {
         timeout: std::env::var("SERVER_TIMEOUT")
             .expect("timeout not set")
             .parse()?,
+        trusted_proxies: load_trusted_proxies()?,
     };
 
     let database = Database {
@@ -70,16 +74,7 @@ pub fn load() -> Result<Config> {
     };
 
     let jwt = Jwt {
-        user: JwtSecret {
-            user_secret: std::env::var("JWT_USER_SECRET").expect("JWT_USER_SECRET not set"),
-            user_refresh_secret: std::env::var("JWT_USER_REFRESH_SECRET")
-                .expect("JWT_USER_REFRESH_SECRET not set"),
-        },
-        admin: JwtAdminSecret {
-            admin_secret: std::env::var("JWT_ADMIN_SECRET").expect("JWT_ADMIN_SECRET not set"),
-            admin_refresh_secret: std::env::var("JWT_ADMIN_REFRESH_SECRET")
-                .expect("JWT_ADMIN_REFRESH_SECRET not set"),
-        },
+        keys: Arc::new(JwtKeySet::from_env(app.backend_url.clone())?),
         access_token_expiration: std::env::var("JWT_ACCESS_TOKEN_EXPIRATION")
             .expect("JWT_ACCESS_TOKEN_EXPIRATION not set"),
         refresh_token_expiration: std::env::var("JWT_REFRESH_TOKEN_EXPIRATION")
@@ -103,6 +98,34 @@ pub fn load() -> Result<Config> {
         allowed_origins: std::env::var("CORS_ALLOWED_ORIGINS").unwrap(),
     };
 
+    let rate_limit = RateLimit {
+        burst_size: env_or("RATE_LIMIT_BURST_SIZE", 10)?,
+        burst_replenish_ms: env_or("RATE_LIMIT_BURST_REPLENISH_MS", 2000)?,
+        ip_max_attempts: env_or("RATE_LIMIT_IP_MAX_ATTEMPTS", 20)?,
+        ip_window_secs: env_or("RATE_LIMIT_IP_WINDOW_SECS", 300)?,
+        username_max_attempts: env_or("RATE_LIMIT_USERNAME_MAX_ATTEMPTS", 10)?,
+        username_window_secs: env_or("RATE_LIMIT_USERNAME_WINDOW_SECS", 300)?,
+        lockout_threshold: env_or("LOGIN_LOCKOUT_THRESHOLD", 5)?,
+        lockout_base_secs: env_or("LOGIN_LOCKOUT_BASE_SECS", 60)?,
+        lockout_max_secs: env_or("LOGIN_LOCKOUT_MAX_SECS", 3600)?,
+    };
+
+    let account_deletion = AccountDeletion {
+        grace_period_days: env_or("ACCOUNT_DELETION_GRACE_DAYS", 30)?,
+    };
+
+    let application_pipeline = ApplicationPipeline {
+        stages: std::env::var("APPLICATION_PIPELINE_STAGES")
+            .unwrap_or_else(|_| "screening,interview,offer".to_string())
+            .split(',')
+            .map(|stage| stage.trim().to_lowercase())
+            .filter(|stage| !stage.is_empty())
+            .collect(),
+        allow_skip: env_or("APPLICATION_PIPELINE_ALLOW_SKIP", false)?,
+    };
+
+    let oidc_providers = load_oidc_providers(&app.backend_url)?;
+
     Ok(Config {
         app,
         server,
@@ -111,27 +134,81 @@ pub fn load() -> Result<Config> {
         services,
         cors,
         supabase,
+        rate_limit,
+        account_deletion,
+        application_pipeline,
+        oidc_providers,
     })
 }
 
+/// `OIDC_PROVIDERS=google,mock` แล้วตั้งค่าแต่ละตัวด้วย `OIDC_<NAME>_*`
+fn load_oidc_providers(backend_url: &str) -> Result<Vec<OidcProvider>> {
+    let names = std::env::var("OIDC_PROVIDERS").unwrap_or_default();
+
+    names
+        .split(',')
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let prefix = format!("OIDC_{}_", name.to_uppercase().replace('-', "_"));
+            let required = |key: &str| {
+                std::env::var(format!("{}{}", prefix, key))
+                    .map_err(|_| anyhow::anyhow!("{}{} not set", prefix, key))
+            };
+
+            Ok(OidcProvider {
+                issuer: required("ISSUER")?,
+                client_id: required("CLIENT_ID")?,
+                client_secret: std::env::var(format!("{}CLIENT_SECRET", prefix))
+                    .ok()
+                    .filter(|secret| !secret.is_empty()),
+                authorization_endpoint: required("AUTHORIZATION_ENDPOINT")?,
+                token_endpoint: required("TOKEN_ENDPOINT")?,
+                jwks_uri: required("JWKS_URI")?,
+                scopes: std::env::var(format!("{}SCOPES", prefix))
+                    .unwrap_or_else(|_| "openid email profile".to_string()),
+                redirect_uri: format!(
+                    "{}/authentication/oidc/{}/callback",
+                    backend_url.trim_end_matches('/'),
+                    name
+                ),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// TRUSTED_PROXIES=10.0.0.0/8,172.16.0.1 - IP เดี่ยวถือเป็น /32 หรือ /128
+fn load_trusted_proxies() -> Result<Vec<IpNet>> {
+    let value = std::env::var("TRUSTED_PROXIES").unwrap_or_default();
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .parse::<IpNet>()
+                .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+                .map_err(|_| anyhow::anyhow!("Invalid TRUSTED_PROXIES entry '{}'", entry))
+        })
+        .collect()
+}
+
+/// อ่านค่าตัวเลขจาก env ถ้าไม่ได้ตั้งไว้ใช้ค่าเริ่มต้น
+fn env_or<T>(key: &str, default: T) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    match std::env::var(key) {
+        Ok(value) => Ok(value.trim().parse()?),
+        Err(_) => Ok(default),
+    }
+}
+
 pub fn get_stage() -> Stage {
     dotenvy::dotenv().ok();
     let stage_str = std::env::var("STAGE").unwrap_or("".to_string());
     Stage::try_from(&stage_str).unwrap_or_default()
 }
-pub fn get_user_secret() -> Result<JwtSecret> {
-    dotenvy::dotenv().ok();
-    Ok(JwtSecret {
-        user_secret: std::env::var("JWT_USER_SECRET").expect("JWT_USER_SECRET not set"),
-        user_refresh_secret: std::env::var("JWT_USER_REFRESH_SECRET")
-            .expect("JWT_USER_REFRESH_SECRET not set"),
-    })
-}
-pub fn get_admin_secret() -> Result<JwtAdminSecret> {
-    dotenvy::dotenv().ok();
-    Ok(JwtAdminSecret {
-        admin_secret: std::env::var("JWT_ADMIN_SECRET").expect("JWT_ADMIN_SECRET not set"),
-        admin_refresh_secret: std::env::var("JWT_ADMIN_REFRESH_SECRET")
-            .expect("JWT_ADMIN_REFRESH_SECRET not set"),
-    })
-}
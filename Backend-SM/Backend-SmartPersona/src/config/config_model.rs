@@ -1,6 +1,11 @@
+use std::sync::Arc;
+
+use ipnet::IpNet;
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Deserialize)]
+use crate::infrastructure::jwt_authentication::jwt_keys::JwtKeySet;
+
+#[derive(Debug, Clone)]
 pub struct Config {
     pub app: Application,
     pub server: Server,
@@ -9,6 +14,10 @@ pub struct Config {
     pub services: Services,
     pub cors: Cors,
     pub supabase: Supabase,
+    pub rate_limit: RateLimit,
+    pub account_deletion: AccountDeletion,
+    pub application_pipeline: ApplicationPipeline,
+    pub oidc_providers: Vec<OidcProvider>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -24,6 +33,9 @@ pub struct Server {
     pub port: u16,
     pub body_limit: u64,
     pub timeout: u64,
+    /// Reverse proxies / load balancers ที่เชื่อ X-Forwarded-For ได้ (IP หรือ CIDR)
+    /// ว่าง = ใช้ peer address เสมอ
+    pub trusted_proxies: Vec<IpNet>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -31,30 +43,15 @@ pub struct Database {
     pub url: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct Jwt {
-    #[serde(flatten)]
-    pub user: JwtSecret,
-    #[serde(flatten)]
-    pub admin: JwtAdminSecret,
+    /// Ed25519 signing keys (user / admin ใช้ชุดเดียวกัน แยกด้วย aud)
+    pub keys: Arc<JwtKeySet>,
 
     pub access_token_expiration: String,
     pub refresh_token_expiration: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct JwtSecret {
-    pub user_secret: String,
-    pub user_refresh_secret: String,
-}
-
-// แยก struct สำหรับ Admin
-#[derive(Debug, Clone, Deserialize)]
-pub struct JwtAdminSecret {
-    pub admin_secret: String,
-    pub admin_refresh_secret: String,
-}
-
 #[derive(Debug, Clone, Deserialize)]
 pub struct Services {
     pub ai_service_url: String,
@@ -72,3 +69,58 @@ pub struct Supabase {
 pub struct Cors {
     pub allowed_origins: String,
 }
+
+/// Rate limit / lockout ของ endpoint login, MFA และ register
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimit {
+    /// Burst limit ต่อ IP ใน process (tower_governor) - กันไม่ให้ยิงถึง database
+    pub burst_size: u32,
+    pub burst_replenish_ms: u64,
+
+    /// จำนวนครั้งที่ลองได้ต่อ IP ต่อ window (เก็บใน database)
+    pub ip_max_attempts: i32,
+    pub ip_window_secs: i64,
+
+    /// จำนวนครั้งที่ลองได้ต่อ username ต่อ window (เก็บใน database)
+    pub username_max_attempts: i32,
+    pub username_window_secs: i64,
+
+    /// login ผิดติดกันกี่ครั้งถึงล็อกบัญชี - ระยะเวลาล็อกเพิ่มเป็นสองเท่าทุกครั้งจนถึง max
+    pub lockout_threshold: i32,
+    pub lockout_base_secs: i64,
+    pub lockout_max_secs: i64,
+}
+
+/// การปิดบัญชีโดยผู้ใช้ (PDPA right to erasure)
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountDeletion {
+    /// จำนวนวันก่อนลบข้อมูลจริง - ผู้ใช้ยกเลิกคำขอได้ภายในช่วงนี้
+    pub grace_period_days: i64,
+}
+
+/// ขั้นตอนของใบสมัครงานระหว่าง applied กับ hired
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApplicationPipeline {
+    /// ตามลำดับ เช่น ["screening", "interview", "offer"] - ขั้นที่ไม่ได้ใส่จะถูกข้าม
+    pub stages: Vec<String>,
+    /// ให้บริษัทเลื่อนผู้สมัครข้ามหลายขั้นในครั้งเดียวได้
+    pub allow_skip: bool,
+}
+
+/// OpenID Connect provider สำหรับ sign-in (authorization code + PKCE)
+/// ใส่ endpoint ตรง ๆ (ไม่ใช้ discovery) เพื่อให้ชี้ไปที่ mock identity provider ได้
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcProvider {
+    /// ชื่อที่ใช้ใน URL เช่น /authentication/oidc/google/start
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    /// ไม่ตั้งค่าสำหรับ public client (PKCE อย่างเดียว)
+    pub client_secret: Option<String>,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+    pub scopes: String,
+    /// {BACKEND_URL}/authentication/oidc/{name}/callback
+    pub redirect_uri: String,
+}
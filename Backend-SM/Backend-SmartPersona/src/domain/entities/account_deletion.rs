@@ -0,0 +1,116 @@
+use crate::infrastructure::postgres::schema::account_deletions;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+// =================================================================
+// 🎯 Status
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDeletionStatus {
+    Scheduled,
+    Cancelled,
+    Completed,
+}
+
+impl AccountDeletionStatus {
+    /// ค่าที่เก็บในคอลัมน์ `status`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountDeletionStatus::Scheduled => "scheduled",
+            AccountDeletionStatus::Cancelled => "cancelled",
+            AccountDeletionStatus::Completed => "completed",
+        }
+    }
+}
+
+// =================================================================
+// 📊 AccountDeletion Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = account_deletions)]
+pub struct AccountDeletion {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: String,
+    pub reason: Option<String>,
+    pub requested_at: DateTime<Utc>,
+
+    /// เวลาที่ job จะลบข้อมูลจริง (requested_at + grace period)
+    pub scheduled_for: DateTime<Utc>,
+
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// URL ของไฟล์ที่ลบจาก storage ไม่สำเร็จ
+    pub storage_failures: Vec<String>,
+}
+
+// =================================================================
+// ➕ NewAccountDeletion (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = account_deletions)]
+pub struct NewAccountDeletion {
+    pub user_id: Uuid,
+    pub status: String,
+    pub reason: Option<String>,
+    pub scheduled_for: DateTime<Utc>,
+}
+
+impl NewAccountDeletion {
+    pub fn new(user_id: Uuid, reason: Option<String>, grace_period: Duration) -> Self {
+        Self {
+            user_id,
+            status: AccountDeletionStatus::Scheduled.as_str().to_string(),
+            reason: reason
+                .map(|reason| reason.trim().to_string())
+                .filter(|reason| !reason.is_empty()),
+            scheduled_for: Utc::now() + grace_period,
+        }
+    }
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+/// POST /api/user/account/deletion
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct RequestAccountDeletionRequest {
+    /// ต้องพิมพ์ username ของตัวเองเพื่อยืนยัน
+    #[validate(length(min = 1, max = 255))]
+    pub confirm_username: String,
+
+    #[validate(length(max = 1000))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountDeletionResponse {
+    pub id: Uuid,
+    pub status: String,
+    pub requested_at: DateTime<Utc>,
+    pub scheduled_for: DateTime<Utc>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub can_cancel: bool,
+}
+
+impl From<AccountDeletion> for AccountDeletionResponse {
+    fn from(deletion: AccountDeletion) -> Self {
+        Self {
+            can_cancel: deletion.status == AccountDeletionStatus::Scheduled.as_str()
+                && deletion.scheduled_for > Utc::now(),
+            id: deletion.id,
+            status: deletion.status,
+            requested_at: deletion.requested_at,
+            scheduled_for: deletion.scheduled_for,
+            cancelled_at: deletion.cancelled_at,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::infrastructure::postgres::schema::account_tokens;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use validator::Validate;
+
+// =================================================================
+// 🎯 Token purpose
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountTokenPurpose {
+    EmailVerification,
+    PasswordReset,
+}
+
+impl AccountTokenPurpose {
+    /// ค่าที่เก็บในคอลัมน์ `purpose`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountTokenPurpose::EmailVerification => "email_verification",
+            AccountTokenPurpose::PasswordReset => "password_reset",
+        }
+    }
+
+    /// อายุของ token แต่ละประเภท
+    pub fn ttl(&self) -> Duration {
+        match self {
+            AccountTokenPurpose::EmailVerification => Duration::hours(24),
+            AccountTokenPurpose::PasswordReset => Duration::hours(1),
+        }
+    }
+}
+
+// =================================================================
+// 📊 AccountToken Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = account_tokens)]
+pub struct AccountToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+
+    /// SHA-256 ของ token (ไม่เก็บ token จริงใน database)
+    pub token_hash: String,
+
+    /// email_verification | password_reset
+    pub purpose: String,
+
+    pub expires_at: DateTime<Utc>,
+
+    /// วันเวลาที่ใช้ token ไปแล้ว (token ใช้ได้ครั้งเดียว)
+    pub used_at: Option<DateTime<Utc>>,
+
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccountToken {
+    /// ตรวจสอบว่า token ยังใช้งานได้ (ยังไม่ถูกใช้และยังไม่หมดอายุ)
+    pub fn is_usable(&self) -> bool {
+        self.used_at.is_none() && self.expires_at > Utc::now()
+    }
+}
+
+// =================================================================
+// ➕ NewAccountToken (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = account_tokens)]
+pub struct NewAccountToken {
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub purpose: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl NewAccountToken {
+    /// สร้าง token ใหม่ คืนค่า (record สำหรับบันทึก, token จริงสำหรับส่งทางอีเมล)
+    pub fn generate(user_id: Uuid, purpose: AccountTokenPurpose) -> (Self, String) {
+        let raw_token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(64)
+            .map(char::from)
+            .collect();
+
+        let new_token = Self {
+            user_id,
+            token_hash: hash_token(&raw_token),
+            purpose: purpose.as_str().to_string(),
+            expires_at: Utc::now() + purpose.ttl(),
+        };
+
+        (new_token, raw_token)
+    }
+}
+
+/// Hash token ด้วย SHA-256 (hex)
+pub fn hash_token(raw_token: &str) -> String {
+    format!("{:x}", Sha256::digest(raw_token.as_bytes()))
+}
+
+// =================================================================
+// 📝 Request DTOs
+// =================================================================
+
+/// ขอส่งอีเมลยืนยันบัญชีใหม่
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct EmailVerificationRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+/// ขอรีเซ็ตรหัสผ่าน
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct PasswordResetRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+/// ยืนยันอีเมลด้วย token
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfirmEmailRequest {
+    pub token: String,
+}
+
+/// ตั้งรหัสผ่านใหม่ด้วย token
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ConfirmPasswordResetRequest {
+    pub token: String,
+    #[validate(length(min = 8, max = 128))]
+    pub new_password: String,
+}
+
+/// Response กลางสำหรับ endpoint ที่ไม่ต้องการเปิดเผยว่ามีบัญชีอยู่หรือไม่
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountTokenMessage {
+    pub message: String,
+}
@@ -0,0 +1,110 @@
+use crate::infrastructure::postgres::schema::admin_invites;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::{
+    entities::account_token::hash_token,
+    value_object::user::{validate_password_strength, validate_username},
+};
+
+/// อายุของคำเชิญ Admin
+pub fn admin_invite_ttl() -> Duration {
+    Duration::days(3)
+}
+
+// =================================================================
+// 📊 AdminInvite Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = admin_invites)]
+pub struct AdminInvite {
+    pub id: Uuid,
+
+    /// อีเมลที่ได้รับเชิญ (ตัวพิมพ์เล็ก) - บัญชีที่สร้างจะใช้อีเมลนี้
+    pub email: String,
+
+    /// SHA-256 ของ token (ไม่เก็บ token จริงใน database)
+    pub token_hash: String,
+
+    /// Admin ที่ส่งคำเชิญ
+    pub invited_by: Uuid,
+
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub accepted_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AdminInvite {
+    /// ตรวจสอบว่าคำเชิญยังใช้งานได้ (ยังไม่ถูกตอบรับและยังไม่หมดอายุ)
+    pub fn is_usable(&self) -> bool {
+        self.accepted_at.is_none() && self.expires_at > Utc::now()
+    }
+}
+
+// =================================================================
+// ➕ NewAdminInvite (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = admin_invites)]
+pub struct NewAdminInvite {
+    pub email: String,
+    pub token_hash: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl NewAdminInvite {
+    /// สร้างคำเชิญใหม่ คืนค่า (record สำหรับบันทึก, token จริงสำหรับส่งทางอีเมล)
+    pub fn generate(email: String, invited_by: Uuid) -> (Self, String) {
+        let raw_token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(64)
+            .map(char::from)
+            .collect();
+
+        let new_invite = Self {
+            email,
+            token_hash: hash_token(&raw_token),
+            invited_by,
+            expires_at: Utc::now() + admin_invite_ttl(),
+        };
+
+        (new_invite, raw_token)
+    }
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+/// Admin เชิญผู้ดูแลระบบคนใหม่
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CreateAdminInviteRequest {
+    #[validate(email, length(max = 255))]
+    pub email: String,
+}
+
+/// ตอบรับคำเชิญและตั้ง username / password
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct AcceptAdminInviteRequest {
+    pub token: String,
+    #[validate(length(min = 3, max = 50), custom(function = "validate_username"))]
+    pub username: String,
+    #[validate(length(min = 8, max = 128), custom(function = "validate_password_strength"))]
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AdminInviteResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+    pub email_sent: bool,
+}
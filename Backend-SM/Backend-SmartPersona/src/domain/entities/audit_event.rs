@@ -0,0 +1,186 @@
+use crate::infrastructure::postgres::schema::audit_events;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+pub const DEFAULT_AUDIT_PAGE_SIZE: i64 = 50;
+pub const MAX_AUDIT_PAGE_SIZE: i64 = 200;
+
+// =================================================================
+// 🎯 Actions / targets
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    CompanyApprove,
+    CompanyReject,
+    CompanyBan,
+    CompanyUnban,
+    UserBan,
+    UserUnban,
+    AdminInviteCreate,
+    AdminBootstrap,
+    AdminRecoveryCodesRegenerate,
+    LoginLockoutClear,
+    ImpersonationStart,
+    ImpersonationRequest,
+}
+
+impl AuditAction {
+    /// ค่าที่เก็บในคอลัมน์ `action` (ใช้เป็น filter ได้)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::CompanyApprove => "company.approve",
+            AuditAction::CompanyReject => "company.reject",
+            AuditAction::CompanyBan => "company.ban",
+            AuditAction::CompanyUnban => "company.unban",
+            AuditAction::UserBan => "user.ban",
+            AuditAction::UserUnban => "user.unban",
+            AuditAction::AdminInviteCreate => "admin_invite.create",
+            AuditAction::AdminBootstrap => "admin.bootstrap",
+            AuditAction::AdminRecoveryCodesRegenerate => "admin.mfa_recovery_codes.regenerate",
+            AuditAction::LoginLockoutClear => "login_lockout.clear",
+            AuditAction::ImpersonationStart => "impersonation.start",
+            AuditAction::ImpersonationRequest => "impersonation.request",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditTarget {
+    User,
+    Company,
+    AdminInvite,
+    LoginLockout,
+}
+
+impl AuditTarget {
+    /// ค่าที่เก็บในคอลัมน์ `target_type`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditTarget::User => "user",
+            AuditTarget::Company => "company",
+            AuditTarget::AdminInvite => "admin_invite",
+            AuditTarget::LoginLockout => "login_lockout",
+        }
+    }
+}
+
+// =================================================================
+// 📊 AuditEvent Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = audit_events)]
+pub struct AuditEvent {
+    pub id: Uuid,
+
+    /// users.id ของผู้กระทำ (None = ระบบ เช่น bootstrap CLI)
+    pub actor_id: Option<Uuid>,
+    pub actor_role: Option<String>,
+
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub reason: Option<String>,
+
+    /// ข้อมูลที่เกี่ยวข้องก่อน / หลังการกระทำ (เฉพาะ field ที่เปลี่ยน ไม่เก็บข้อมูลลับ)
+    pub before_state: Option<Value>,
+    pub after_state: Option<Value>,
+
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// =================================================================
+// ➕ NewAuditEvent (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = audit_events)]
+pub struct NewAuditEvent {
+    pub actor_id: Option<Uuid>,
+    pub actor_role: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    pub reason: Option<String>,
+    pub before_state: Option<Value>,
+    pub after_state: Option<Value>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl NewAuditEvent {
+    pub fn new(action: AuditAction, target: AuditTarget, target_id: impl ToString) -> Self {
+        Self {
+            actor_id: None,
+            actor_role: None,
+            action: action.as_str().to_string(),
+            target_type: target.as_str().to_string(),
+            target_id: target_id.to_string(),
+            reason: None,
+            before_state: None,
+            after_state: None,
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    pub fn reason(mut self, reason: Option<String>) -> Self {
+        self.reason = reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        self
+    }
+
+    pub fn before(mut self, state: Value) -> Self {
+        self.before_state = Some(state);
+        self
+    }
+
+    pub fn after(mut self, state: Value) -> Self {
+        self.after_state = Some(state);
+        self
+    }
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+/// GET /admin/audit-log query string (ทุก filter ไม่บังคับ)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+}
+
+impl AuditLogQuery {
+    /// (page เริ่มที่ 1, per_page ที่ถูกจำกัดแล้ว)
+    pub fn pagination(&self) -> (i64, i64) {
+        let page = self.page.unwrap_or(1).max(1);
+        let per_page = self
+            .per_page
+            .unwrap_or(DEFAULT_AUDIT_PAGE_SIZE)
+            .clamp(1, MAX_AUDIT_PAGE_SIZE);
+        (page, per_page)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditLogPage {
+    pub items: Vec<AuditEvent>,
+    pub page: i64,
+    pub per_page: i64,
+    pub total: i64,
+}
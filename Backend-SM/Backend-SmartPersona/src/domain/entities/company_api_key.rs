@@ -0,0 +1,212 @@
+use crate::infrastructure::postgres::schema::company_api_keys;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::entities::account_token::hash_token;
+
+/// ทุก key ขึ้นต้นด้วย prefix นี้ - ใช้แยก API key ออกจาก JWT ใน Authorization header
+pub const API_KEY_PREFIX: &str = "spk_";
+
+/// จำนวนตัวอักษรต้น key ที่เก็บไว้แสดงในรายการ (รวม prefix)
+const DISPLAY_PREFIX_LENGTH: usize = 12;
+
+/// จำนวน key ที่ยังใช้งานได้ต่อบริษัท
+pub const MAX_ACTIVE_API_KEYS: usize = 20;
+
+pub fn is_api_key(token: &str) -> bool {
+    token.starts_with(API_KEY_PREFIX)
+}
+
+// =================================================================
+// 🎯 Scopes
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyScope {
+    JobsRead,
+    JobsWrite,
+    ApplicationsRead,
+    ApplicationsWrite,
+}
+
+impl ApiKeyScope {
+    pub const ALL: [ApiKeyScope; 4] = [
+        ApiKeyScope::JobsRead,
+        ApiKeyScope::JobsWrite,
+        ApiKeyScope::ApplicationsRead,
+        ApiKeyScope::ApplicationsWrite,
+    ];
+
+    /// ค่าที่เก็บในคอลัมน์ `scopes`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::JobsRead => "jobs:read",
+            ApiKeyScope::JobsWrite => "jobs:write",
+            ApiKeyScope::ApplicationsRead => "applications:read",
+            ApiKeyScope::ApplicationsWrite => "applications:write",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.as_str() == value)
+    }
+}
+
+// =================================================================
+// 📊 CompanyApiKey Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = company_api_keys)]
+pub struct CompanyApiKey {
+    pub id: Uuid,
+    pub company_id: Uuid,
+
+    /// user ที่สร้าง key (เจ้าของบริษัท ณ ตอนนั้น)
+    pub created_by: Option<Uuid>,
+
+    pub name: String,
+
+    /// ต้น key สำหรับให้ผู้ใช้จำได้ว่าเป็น key ไหน
+    pub key_prefix: String,
+
+    /// SHA-256 ของ key (ไม่เก็บ key จริงใน database)
+    pub key_hash: String,
+
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub last_used_ip: Option<String>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CompanyApiKey {
+    pub fn is_usable(&self) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > Utc::now())
+    }
+
+    /// scope ที่รู้จัก (ค่าที่ไม่รู้จักถูกข้ามไป)
+    pub fn parsed_scopes(&self) -> Vec<ApiKeyScope> {
+        self.scopes
+            .iter()
+            .filter_map(|scope| ApiKeyScope::parse(scope))
+            .collect()
+    }
+}
+
+// =================================================================
+// ➕ NewCompanyApiKey (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = company_api_keys)]
+pub struct NewCompanyApiKey {
+    pub company_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl NewCompanyApiKey {
+    /// สร้าง key ใหม่ คืนค่า (record สำหรับบันทึก, key จริงที่แสดงให้ผู้ใช้ครั้งเดียว)
+    pub fn generate(
+        company_id: Uuid,
+        created_by: Uuid,
+        name: String,
+        scopes: &[ApiKeyScope],
+        expires_in_days: Option<i64>,
+    ) -> (Self, String) {
+        let secret: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(40)
+            .map(char::from)
+            .collect();
+        let raw_key = format!("{}{}", API_KEY_PREFIX, secret);
+
+        let new_key = Self {
+            company_id,
+            created_by: Some(created_by),
+            name,
+            key_prefix: raw_key[..DISPLAY_PREFIX_LENGTH].to_string(),
+            key_hash: hash_token(&raw_key),
+            scopes: scopes.iter().map(|scope| scope.as_str().to_string()).collect(),
+            expires_at: expires_in_days.map(|days| Utc::now() + Duration::days(days)),
+        };
+
+        (new_key, raw_key)
+    }
+}
+
+/// ผู้เรียกที่ยืนยันตัวตนด้วย API key - middleware ใส่ไว้ใน request extensions
+#[derive(Debug, Clone)]
+pub struct ApiKeyPrincipal {
+    pub key_id: Uuid,
+    pub company_id: Uuid,
+
+    /// users.id ของเจ้าของบริษัท - handler เดิมตรวจสิทธิ์ด้วย user นี้
+    pub owner_user_id: Uuid,
+
+    pub scopes: Vec<ApiKeyScope>,
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CreateApiKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    #[validate(length(min = 1))]
+    pub scopes: Vec<String>,
+
+    /// ไม่ระบุ = ไม่มีวันหมดอายุ (จนกว่าจะ revoke)
+    #[validate(range(min = 1, max = 730))]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub last_used_ip: Option<String>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<CompanyApiKey> for ApiKeyResponse {
+    fn from(key: CompanyApiKey) -> Self {
+        Self {
+            id: key.id,
+            name: key.name,
+            key_prefix: key.key_prefix,
+            scopes: key.scopes,
+            expires_at: key.expires_at,
+            last_used_at: key.last_used_at,
+            last_used_ip: key.last_used_ip,
+            revoked_at: key.revoked_at,
+            created_at: key.created_at,
+        }
+    }
+}
+
+/// ตอบกลับตอนสร้าง key - `api_key` แสดงครั้งเดียวเท่านั้น
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedApiKeyResponse {
+    #[serde(flatten)]
+    pub key: ApiKeyResponse,
+    pub api_key: String,
+}
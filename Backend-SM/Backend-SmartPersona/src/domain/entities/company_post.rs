@@ -1,8 +1,16 @@
-use crate::infrastructure::postgres::schema::company_posts;
+use crate::{
+    domain::value_object::{
+        geo::GeoPoint,
+        salary::{DEFAULT_SALARY_CURRENCY, Salary, SalaryPeriod},
+    },
+    infrastructure::postgres::schema::{company_posts, post_screening_questions},
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::Validate;
 
 // Struct สำหรับ "อ่าน" (Queryable)
 #[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize, Deserialize)]
@@ -25,6 +33,189 @@ pub struct CompanyPostEntity {
     pub benefits: Option<String>,
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: String,
+    /// hourly / daily / monthly / yearly
+    pub salary_period: String,
+    pub salary_negotiable: bool,
+    /// เวลาที่ตั้งให้เผยแพร่ (scheduled) / ปิดรับอัตโนมัติ (published → expired)
+    pub publish_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    /// เวลาที่เผยแพร่ / ปิดรับจริงครั้งล่าสุด
+    pub published_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// ประกาศที่เปิดรับสมัคร (เงื่อนไขเดียวกับที่ใช้กรองผลค้นหา)
+/// เลย closes_at แล้วถือว่าปิด แม้ scheduler ยังไม่ได้เปลี่ยนสถานะเป็น expired
+pub fn is_open_post(status: &str, closes_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    PostStatus::parse(status) == Some(PostStatus::Published)
+        && closes_at.is_none_or(|closes_at| closes_at > now)
+}
+
+// =================================================================
+// 🗓️ Lifecycle (draft → scheduled → published → closed / expired)
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostStatus {
+    /// บริษัทเห็นคนเดียว
+    Draft,
+    /// รอ scheduler เผยแพร่เมื่อถึง publish_at
+    Scheduled,
+    /// เปิดรับสมัคร
+    Published,
+    /// บริษัทปิดรับเอง
+    Closed,
+    /// ถึง closes_at แล้ว (scheduler ตั้งให้เท่านั้น)
+    Expired,
+}
+
+impl PostStatus {
+    /// ค่าที่เก็บในคอลัมน์ `status`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Published => "published",
+            PostStatus::Closed => "closed",
+            PostStatus::Expired => "expired",
+        }
+    }
+
+    /// "active" คือค่าเดิมก่อนมี lifecycle - client เก่ายังส่งมา
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "draft" => Some(PostStatus::Draft),
+            "scheduled" => Some(PostStatus::Scheduled),
+            "published" | "active" => Some(PostStatus::Published),
+            "closed" => Some(PostStatus::Closed),
+            "expired" => Some(PostStatus::Expired),
+            _ => None,
+        }
+    }
+
+    /// คนทั่วไปเห็นประกาศได้ (draft / scheduled เห็นเฉพาะบริษัทเจ้าของ)
+    pub fn is_public(&self) -> bool {
+        !matches!(self, PostStatus::Draft | PostStatus::Scheduled)
+    }
+
+    /// การเปลี่ยนสถานะที่บริษัททำได้ - ประกาศที่เผยแพร่แล้วกลับเป็น draft ไม่ได้เพราะอาจมีผู้สมัครแล้ว
+    pub fn can_transition_to(&self, next: PostStatus) -> bool {
+        if *self == next {
+            return true;
+        }
+        match self {
+            PostStatus::Draft | PostStatus::Scheduled => matches!(
+                next,
+                PostStatus::Draft | PostStatus::Scheduled | PostStatus::Published
+            ),
+            PostStatus::Published => next == PostStatus::Closed,
+            PostStatus::Closed | PostStatus::Expired => matches!(
+                next,
+                PostStatus::Scheduled | PostStatus::Published | PostStatus::Closed
+            ),
+        }
+    }
+}
+
+/// สถานะและเวลาของประกาศหลังเปลี่ยนสถานะ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostSchedule {
+    pub status: PostStatus,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl PostSchedule {
+    /// คำนวณสถานะ / เวลาใหม่ - `current` เป็น None ตอนสร้างประกาศ
+    /// ขอ published พร้อม publish_at ในอนาคตจะได้ scheduled
+    /// (ยังไม่ตรวจว่าเปลี่ยนจากสถานะเดิมได้ - ดู `PostStatus::can_transition_to`)
+    pub fn resolve(
+        current: Option<&CompanyPostEntity>,
+        requested: PostStatus,
+        publish_at: Option<DateTime<Utc>>,
+        closes_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, String> {
+        let previous = current.and_then(|post| PostStatus::parse(&post.status));
+        let published_at = current.and_then(|post| post.published_at);
+        let closed_at = current.and_then(|post| post.closed_at);
+
+        match requested {
+            PostStatus::Expired if previous == Some(PostStatus::Expired) => Ok(Self {
+                status: PostStatus::Expired,
+                publish_at,
+                closes_at,
+                published_at,
+                closed_at,
+            }),
+            PostStatus::Expired => Err("expired is set automatically when closes_at passes".to_string()),
+            PostStatus::Draft => Ok(Self {
+                status: PostStatus::Draft,
+                publish_at,
+                closes_at,
+                published_at,
+                closed_at: None,
+            }),
+            PostStatus::Closed => Ok(Self {
+                status: PostStatus::Closed,
+                publish_at,
+                closes_at,
+                published_at,
+                closed_at: closed_at.filter(|_| previous == Some(PostStatus::Closed)).or(Some(now)),
+            }),
+            PostStatus::Scheduled | PostStatus::Published => {
+                let publish_later = publish_at.filter(|publish_at| *publish_at > now);
+                if requested == PostStatus::Scheduled && publish_later.is_none() {
+                    return Err("publish_at must be in the future to schedule a post".to_string());
+                }
+                if let Some(closes_at) = closes_at
+                    && closes_at <= publish_later.unwrap_or(now)
+                {
+                    return Err("closes_at must be after the publish time".to_string());
+                }
+
+                let status = if publish_later.is_some() {
+                    PostStatus::Scheduled
+                } else {
+                    PostStatus::Published
+                };
+                let published_at = match status {
+                    PostStatus::Published if previous == Some(PostStatus::Published) => published_at,
+                    PostStatus::Published => Some(now),
+                    _ => None,
+                };
+
+                Ok(Self {
+                    status,
+                    publish_at,
+                    closes_at,
+                    published_at,
+                    closed_at: None,
+                })
+            }
+        }
+    }
+}
+
+/// ผู้สมัครที่ใบสมัครยังไม่จบ - แจ้งเมื่อประกาศปิดรับ
+#[derive(Debug, Clone)]
+pub struct OpenApplicant {
+    pub application_id: Uuid,
+    pub name: String,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PostClosingNotice {
+    pub job_title: String,
+    pub company_name: String,
+    pub applicants: Vec<OpenApplicant>,
 }
 
 // Struct สำหรับ "สร้างใหม่" (Insertable)
@@ -44,6 +235,15 @@ pub struct NewCompanyPost {
     pub responsibilities: Option<String>,
     pub qualifications: Option<String>,
     pub benefits: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: String,
+    pub salary_period: String,
+    pub salary_negotiable: bool,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
 }
 
 // Struct สำหรับ "อัปเดต" (AsChangeset)
@@ -63,9 +263,23 @@ pub struct UpdateCompanyPost {
     pub responsibilities: Option<String>,
     pub qualifications: Option<String>,
     pub benefits: Option<String>,
+    /// Some(None) = ล้างค่า (เมื่อแก้ข้อมูลเงินเดือนทั้งชุด)
+    pub salary_min: Option<Option<i64>>,
+    pub salary_max: Option<Option<i64>>,
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<String>,
+    pub salary_negotiable: Option<bool>,
+    /// Some(None) = ล้างค่า (ตั้งพร้อมกันทั้งชุดเมื่อเปลี่ยนสถานะ)
+    pub publish_at: Option<Option<DateTime<Utc>>>,
+    pub closes_at: Option<Option<DateTime<Utc>>>,
+    pub published_at: Option<Option<DateTime<Utc>>>,
+    pub closed_at: Option<Option<DateTime<Utc>>>,
 }
 
 /// DTO สำหรับรับข้อมูลจาก API request
+/// ส่ง salary_min / salary_max / salary_currency / salary_period / salary_negotiable
+/// หรือส่งแค่ salary_range แล้วระบบจะแยกค่าให้
+/// status: draft / scheduled / published (ไม่ส่ง = published หรือ scheduled ถ้า publish_at อยู่ในอนาคต)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePostRequest {
     pub title: String,
@@ -80,10 +294,83 @@ pub struct CreatePostRequest {
     pub responsibilities: Option<String>,
     pub qualifications: Option<String>,
     pub benefits: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<SalaryPeriod>,
+    pub salary_negotiable: Option<bool>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+}
+
+/// สถานะที่ขอ - None ถ้าไม่ได้ส่งมา
+fn requested_status(status: Option<&str>) -> Result<Option<PostStatus>, String> {
+    status
+        .map(|status| {
+            PostStatus::parse(status).ok_or_else(|| {
+                format!(
+                    "invalid status '{}': must be one of draft, scheduled, published, closed",
+                    status
+                )
+            })
+        })
+        .transpose()
+}
+
+/// ฟิลด์เงินเดือนของ request - None ถ้าไม่ได้ส่งข้อมูลเงินเดือนมาเลย
+/// ฟิลด์แบบมีโครงสร้างนับเป็นชุดเดียวกัน (ค่าที่ไม่ได้ส่งใช้ค่าเริ่มต้น) และมาก่อน salary_range
+fn requested_salary(
+    salary_range: Option<&str>,
+    salary_min: Option<i64>,
+    salary_max: Option<i64>,
+    salary_currency: Option<&str>,
+    salary_period: Option<SalaryPeriod>,
+    salary_negotiable: Option<bool>,
+) -> Option<Salary> {
+    let structured = salary_min.is_some()
+        || salary_max.is_some()
+        || salary_currency.is_some()
+        || salary_period.is_some()
+        || salary_negotiable.is_some();
+
+    if !structured {
+        return salary_range.map(Salary::parse);
+    }
+
+    Some(Salary {
+        min: salary_min,
+        max: salary_max,
+        currency: salary_currency
+            .map(|currency| currency.trim().to_uppercase())
+            .unwrap_or_else(|| DEFAULT_SALARY_CURRENCY.to_string()),
+        period: salary_period.unwrap_or_default(),
+        negotiable: salary_negotiable.unwrap_or(false),
+    })
 }
 
 impl CreatePostRequest {
-    pub fn into_new_post(self, company_id: Uuid) -> NewCompanyPost {
+    pub fn salary(&self) -> Option<Salary> {
+        requested_salary(
+            self.salary_range.as_deref(),
+            self.salary_min,
+            self.salary_max,
+            self.salary_currency.as_deref(),
+            self.salary_period,
+            self.salary_negotiable,
+        )
+    }
+
+    pub fn requested_status(&self) -> Result<PostStatus, String> {
+        Ok(requested_status(self.status.as_deref())?.unwrap_or(PostStatus::Published))
+    }
+
+    pub fn into_new_post(self, company_id: Uuid, schedule: PostSchedule) -> NewCompanyPost {
+        let salary = self.salary();
+        let salary_range = self
+            .salary_range
+            .or_else(|| salary.as_ref().map(Salary::display));
+        let salary = salary.unwrap_or_default();
+
         NewCompanyPost {
             company_id,
             title: self.title,
@@ -91,13 +378,22 @@ impl CreatePostRequest {
             latitude: self.latitude,
             longitude: self.longitude,
             job_type: self.job_type,
-            salary_range: self.salary_range,
+            salary_range,
             tags: self.tags.map(|t| t.into_iter().map(Some).collect()),
             description: self.description,
-            status: self.status.unwrap_or_else(|| "active".to_string()),
+            status: schedule.status.as_str().to_string(),
             responsibilities: self.responsibilities,
             qualifications: self.qualifications,
             benefits: self.benefits,
+            salary_min: salary.min,
+            salary_max: salary.max,
+            salary_currency: salary.currency,
+            salary_period: salary.period.as_str().to_string(),
+            salary_negotiable: salary.negotiable,
+            publish_at: schedule.publish_at,
+            closes_at: schedule.closes_at,
+            published_at: schedule.published_at,
+            closed_at: schedule.closed_at,
         }
     }
 }
@@ -117,27 +413,84 @@ pub struct UpdatePostRequest {
     pub responsibilities: Option<String>,
     pub qualifications: Option<String>,
     pub benefits: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<SalaryPeriod>,
+    pub salary_negotiable: Option<bool>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
 }
 
 impl UpdatePostRequest {
-    pub fn into_update_post(self) -> UpdateCompanyPost {
+    /// None = ไม่แก้ข้อมูลเงินเดือน
+    pub fn salary(&self) -> Option<Salary> {
+        requested_salary(
+            self.salary_range.as_deref(),
+            self.salary_min,
+            self.salary_max,
+            self.salary_currency.as_deref(),
+            self.salary_period,
+            self.salary_negotiable,
+        )
+    }
+
+    /// ส่ง status / publish_at / closes_at มาอย่างน้อยหนึ่งอย่าง
+    pub fn changes_schedule(&self) -> bool {
+        self.status.is_some() || self.publish_at.is_some() || self.closes_at.is_some()
+    }
+
+    /// สถานะที่ขอ - ไม่ส่งมาใช้สถานะเดิม
+    pub fn requested_status(&self, current: PostStatus) -> Result<PostStatus, String> {
+        Ok(requested_status(self.status.as_deref())?.unwrap_or(current))
+    }
+
+    /// `schedule` = None ไม่แก้สถานะ / เวลาของประกาศ
+    pub fn into_update_post(self, schedule: Option<PostSchedule>) -> UpdateCompanyPost {
+        let salary = self.salary();
+        let salary_range = self
+            .salary_range
+            .or_else(|| salary.as_ref().map(Salary::display));
+
         UpdateCompanyPost {
             title: self.title,
             location: self.location,
             latitude: self.latitude,
             longitude: self.longitude,
             job_type: self.job_type,
-            salary_range: self.salary_range,
+            salary_range,
             tags: self.tags.map(|t| t.into_iter().map(Some).collect()),
             description: self.description,
-            status: self.status,
+            status: schedule.map(|schedule| schedule.status.as_str().to_string()),
             updated_at: None, // Will be set by DB or Repo logic if needed, or default to now()
             responsibilities: self.responsibilities,
             qualifications: self.qualifications,
             benefits: self.benefits,
+            salary_min: salary.as_ref().map(|salary| salary.min),
+            salary_max: salary.as_ref().map(|salary| salary.max),
+            salary_currency: salary.as_ref().map(|salary| salary.currency.clone()),
+            salary_period: salary.as_ref().map(|salary| salary.period.as_str().to_string()),
+            salary_negotiable: salary.as_ref().map(|salary| salary.negotiable),
+            publish_at: schedule.map(|schedule| schedule.publish_at),
+            closes_at: schedule.map(|schedule| schedule.closes_at),
+            published_at: schedule.map(|schedule| schedule.published_at),
+            closed_at: schedule.map(|schedule| schedule.closed_at),
         }
     }
 }
+/// ข้อมูลเงินเดือนของประกาศ สำหรับผลลัพธ์ที่ไม่ได้คืนประกาศทั้งก้อน (เช่นผล matching)
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = company_posts)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct JobSalary {
+    pub salary_range: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: String,
+    pub salary_period: String,
+    pub salary_negotiable: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyPostWithCompany {
     #[serde(flatten)]
@@ -145,3 +498,411 @@ pub struct CompanyPostWithCompany {
     pub company_name: String,
     pub company_logo: Option<String>,
 }
+
+// =================================================================
+// ❓ Screening questions (คำถามคัดกรองผู้สมัคร)
+// =================================================================
+
+pub const MAX_SCREENING_OPTIONS: usize = 20;
+pub const MAX_SCREENING_ANSWER_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningQuestionKind {
+    /// ตอบเป็นข้อความ - ใช้เป็น knockout ไม่ได้
+    Text,
+    /// ตอบ "yes" / "no"
+    YesNo,
+    /// เลือกหนึ่งข้อจาก options
+    MultipleChoice,
+}
+
+impl ScreeningQuestionKind {
+    /// ค่าที่เก็บในคอลัมน์ `kind`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreeningQuestionKind::Text => "text",
+            ScreeningQuestionKind::YesNo => "yes_no",
+            ScreeningQuestionKind::MultipleChoice => "multiple_choice",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(ScreeningQuestionKind::Text),
+            "yes_no" => Some(ScreeningQuestionKind::YesNo),
+            "multiple_choice" => Some(ScreeningQuestionKind::MultipleChoice),
+            _ => None,
+        }
+    }
+}
+
+const YES_NO_OPTIONS: [&str; 2] = ["yes", "no"];
+
+#[derive(Debug, Clone, Serialize, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = post_screening_questions)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct ScreeningQuestion {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub position: i32,
+    pub kind: String,
+    pub prompt: String,
+    pub required: bool,
+    /// ตัวเลือก (multiple_choice) หรือ ["yes", "no"] (yes_no)
+    pub options: Vec<String>,
+    /// คำตอบที่ไม่ผ่านเกณฑ์ - แสดงเฉพาะบริษัทเจ้าของประกาศ
+    pub knockout_answers: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// คำตอบที่ตรวจแล้ว
+#[derive(Debug, Clone)]
+pub struct ScreeningOutcome {
+    /// None = ไม่ได้ตอบ (คำถามไม่บังคับ)
+    pub answer: Option<String>,
+    pub knocked_out: bool,
+}
+
+impl ScreeningQuestion {
+    /// ตรวจคำตอบของผู้สมัคร - คืนข้อความสำหรับ "Validation error"
+    pub fn evaluate(&self, answer: Option<&str>) -> Result<ScreeningOutcome, String> {
+        let answer = answer.map(str::trim).filter(|answer| !answer.is_empty());
+
+        let Some(answer) = answer else {
+            if self.required {
+                return Err(format!("question {} is required", self.position + 1));
+            }
+            return Ok(ScreeningOutcome {
+                answer: None,
+                knocked_out: false,
+            });
+        };
+
+        let answer = match ScreeningQuestionKind::parse(&self.kind) {
+            Some(ScreeningQuestionKind::Text) => {
+                if answer.chars().count() > MAX_SCREENING_ANSWER_LENGTH {
+                    return Err(format!(
+                        "answer to question {} must be at most {} characters",
+                        self.position + 1,
+                        MAX_SCREENING_ANSWER_LENGTH
+                    ));
+                }
+                answer.to_string()
+            }
+            // ตัวเลือกเทียบแบบไม่สนตัวพิมพ์ แล้วเก็บตามที่บริษัทตั้งไว้
+            Some(ScreeningQuestionKind::YesNo | ScreeningQuestionKind::MultipleChoice) => self
+                .options
+                .iter()
+                .find(|option| option.to_lowercase() == answer.to_lowercase())
+                .cloned()
+                .ok_or_else(|| {
+                    format!(
+                        "answer to question {} must be one of: {}",
+                        self.position + 1,
+                        self.options.join(", ")
+                    )
+                })?,
+            None => return Err(format!("question {} has unknown kind '{}'", self.position + 1, self.kind)),
+        };
+
+        Ok(ScreeningOutcome {
+            knocked_out: self.knockout_answers.contains(&answer),
+            answer: Some(answer),
+        })
+    }
+}
+
+/// คำถามที่ผู้สมัครเห็น (ไม่มี knockout_answers)
+#[derive(Debug, Clone, Serialize)]
+pub struct CandidateScreeningQuestion {
+    pub id: Uuid,
+    pub position: i32,
+    pub kind: String,
+    pub prompt: String,
+    pub required: bool,
+    pub options: Vec<String>,
+}
+
+impl From<ScreeningQuestion> for CandidateScreeningQuestion {
+    fn from(question: ScreeningQuestion) -> Self {
+        Self {
+            id: question.id,
+            position: question.position,
+            kind: question.kind,
+            prompt: question.prompt,
+            required: question.required,
+            options: question.options,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = post_screening_questions)]
+pub struct NewScreeningQuestion {
+    pub post_id: Uuid,
+    pub position: i32,
+    pub kind: String,
+    pub prompt: String,
+    pub required: bool,
+    pub options: Vec<String>,
+    pub knockout_answers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ScreeningQuestionRequest {
+    pub kind: ScreeningQuestionKind,
+
+    #[validate(length(min = 1, max = 500))]
+    pub prompt: String,
+
+    /// ค่าเริ่มต้น: true
+    pub required: Option<bool>,
+
+    /// เฉพาะ multiple_choice (2-20 ข้อ)
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub options: Vec<String>,
+
+    /// คำตอบที่ทำให้ไม่ผ่านเกณฑ์ (yes_no / multiple_choice)
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub knockout_answers: Vec<String>,
+}
+
+/// PUT /posts/:id/screening-questions (แทนที่คำถามเดิมทั้งหมด, ส่ง [] เพื่อลบ)
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SetScreeningQuestionsRequest {
+    #[validate(length(max = 20), nested)]
+    pub questions: Vec<ScreeningQuestionRequest>,
+}
+
+impl SetScreeningQuestionsRequest {
+    /// ตรวจตัวเลือก / knockout ตามชนิดคำถาม - คืนข้อความสำหรับ "Validation error"
+    pub fn into_new_questions(self, post_id: Uuid) -> Result<Vec<NewScreeningQuestion>, String> {
+        let mut questions = Vec::with_capacity(self.questions.len());
+
+        for (index, question) in self.questions.into_iter().enumerate() {
+            let number = index + 1;
+            let prompt = question.prompt.trim().to_string();
+            if prompt.is_empty() {
+                return Err(format!("question {} must have a prompt", number));
+            }
+
+            let options: Vec<String> = match question.kind {
+                ScreeningQuestionKind::Text => {
+                    if !question.options.is_empty() || !question.knockout_answers.is_empty() {
+                        return Err(format!(
+                            "question {}: text questions cannot have options or knockout answers",
+                            number
+                        ));
+                    }
+                    Vec::new()
+                }
+                ScreeningQuestionKind::YesNo => YES_NO_OPTIONS.iter().map(|option| option.to_string()).collect(),
+                ScreeningQuestionKind::MultipleChoice => {
+                    let options: Vec<String> = question
+                        .options
+                        .iter()
+                        .map(|option| option.trim().to_string())
+                        .filter(|option| !option.is_empty())
+                        .collect();
+                    if options.len() < 2 || options.len() > MAX_SCREENING_OPTIONS {
+                        return Err(format!(
+                            "question {}: multiple_choice needs 2-{} options",
+                            number, MAX_SCREENING_OPTIONS
+                        ));
+                    }
+                    if options.iter().any(|option| option.chars().count() > 200) {
+                        return Err(format!("question {}: options must be at most 200 characters", number));
+                    }
+                    let mut lowered: Vec<String> = options.iter().map(|option| option.to_lowercase()).collect();
+                    lowered.sort();
+                    lowered.dedup();
+                    if lowered.len() != options.len() {
+                        return Err(format!("question {}: options must be unique", number));
+                    }
+                    options
+                }
+            };
+
+            let mut knockout_answers = Vec::new();
+            for answer in &question.knockout_answers {
+                let option = options
+                    .iter()
+                    .find(|option| option.to_lowercase() == answer.trim().to_lowercase())
+                    .ok_or_else(|| {
+                        format!(
+                            "question {}: knockout answer '{}' is not one of the options",
+                            number, answer
+                        )
+                    })?;
+                if !knockout_answers.contains(option) {
+                    knockout_answers.push(option.clone());
+                }
+            }
+            if !options.is_empty() && knockout_answers.len() == options.len() {
+                return Err(format!(
+                    "question {}: at least one answer must pass the knockout",
+                    number
+                ));
+            }
+
+            questions.push(NewScreeningQuestion {
+                post_id,
+                position: index as i32,
+                kind: question.kind.as_str().to_string(),
+                prompt,
+                required: question.required.unwrap_or(true),
+                options,
+                knockout_answers,
+            });
+        }
+
+        Ok(questions)
+    }
+}
+
+// =================================================================
+// 🔍 Search
+// =================================================================
+
+pub const DEFAULT_JOB_SEARCH_LIMIT: i64 = 20;
+pub const MAX_JOB_SEARCH_LIMIT: i64 = 50;
+pub const DEFAULT_JOB_SEARCH_RADIUS_KM: f64 = 25.0;
+pub const MAX_JOB_SEARCH_RADIUS_KM: f64 = 500.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobSearchSort {
+    /// คะแนนจาก full-text search (ต้องมี q)
+    Relevance,
+    /// ประกาศใหม่ก่อน
+    Recent,
+    /// ใกล้จุดที่ค้นก่อน (ต้องมีพิกัด)
+    Distance,
+    /// เงินเดือนสูงก่อน (เฉพาะประกาศที่ระบุตัวเลข)
+    Salary,
+}
+
+/// GET /api/user/posts/search, GET /api/user/posts/nearby
+/// ค่าที่เลือกได้หลายค่า (job_type, tags) คั่นด้วย comma
+/// ส่ง lat + lng เพื่อค้นในรัศมี radius_km รอบจุดนั้น
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct JobSearchQuery {
+    #[validate(length(max = 200))]
+    pub q: Option<String>,
+    pub job_type: Option<String>,
+    #[validate(length(max = 255))]
+    pub location: Option<String>,
+    #[validate(range(min = 0))]
+    pub salary_min: Option<i64>,
+    #[validate(range(min = 0))]
+    pub salary_max: Option<i64>,
+    /// ค่าเริ่มต้นเมื่อกรอง / เรียงตามเงินเดือน: THB
+    #[validate(length(equal = 3))]
+    pub salary_currency: Option<String>,
+    /// ค่าเริ่มต้นเมื่อกรอง / เรียงตามเงินเดือน: monthly
+    pub salary_period: Option<SalaryPeriod>,
+    pub tags: Option<String>,
+    pub company_id: Option<Uuid>,
+    pub posted_after: Option<DateTime<Utc>>,
+    pub posted_before: Option<DateTime<Utc>>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    /// ค่าเริ่มต้น 25 km
+    #[validate(range(min = 0.1, max = 500.0))]
+    pub radius_km: Option<f64>,
+    /// ค่าเริ่มต้น: relevance ถ้ามี q, distance ถ้ามีพิกัด, ไม่อย่างนั้น recent
+    pub sort: Option<JobSearchSort>,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+/// ตำแหน่งของแถวสุดท้ายในหน้าก่อน (keyset pagination)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobSearchCursor {
+    pub sort: JobSearchSort,
+    pub relevance: Option<f32>,
+    #[serde(default)]
+    pub distance_km: Option<f64>,
+    #[serde(default)]
+    pub salary: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl JobSearchCursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+/// วงกลมรอบจุดค้นหา
+#[derive(Debug, Clone, Copy)]
+pub struct JobSearchRadius {
+    pub origin: GeoPoint,
+    pub radius_km: f64,
+}
+
+/// เงื่อนไขที่ตรวจและแปลงแล้ว สำหรับ repository
+#[derive(Debug, Clone)]
+pub struct JobSearchFilter {
+    /// tsquery literal ของคำค้นที่ตัดคำแล้ว
+    pub ts_query: Option<String>,
+    pub job_types: Vec<String>,
+    pub location: Option<String>,
+    pub salary_min: Option<i64>,
+    pub salary_max: Option<i64>,
+    pub salary_currency: Option<String>,
+    pub salary_period: Option<SalaryPeriod>,
+    pub tags: Vec<String>,
+    pub company_id: Option<Uuid>,
+    pub posted_after: Option<DateTime<Utc>>,
+    pub posted_before: Option<DateTime<Utc>>,
+    /// ช่วงเวลาที่เผยแพร่ (published_at) - ใช้โดยตัวจับคู่การค้นหาที่บันทึกไว้
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
+    /// ประกาศที่ไม่มีพิกัดจะไม่ติดผลเมื่อค้นแบบรัศมี
+    pub near: Option<JobSearchRadius>,
+    pub sort: JobSearchSort,
+    pub after: Option<JobSearchCursor>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobSearchHit {
+    #[serde(flatten)]
+    pub post: CompanyPostWithCompany,
+    /// มีเฉพาะเมื่อค้นด้วย q
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relevance: Option<f32>,
+    /// ระยะทางจากจุดค้นหา (km) - มีเฉพาะเมื่อค้นด้วยพิกัด
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_km: Option<f64>,
+}
+
+impl JobSearchHit {
+    pub fn cursor(&self, sort: JobSearchSort) -> JobSearchCursor {
+        JobSearchCursor {
+            sort,
+            relevance: self.relevance,
+            distance_km: self.distance_km,
+            salary: self.post.post.salary_max.or(self.post.post.salary_min),
+            created_at: self.post.post.created_at,
+            id: self.post.post.id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobSearchPage {
+    pub items: Vec<JobSearchHit>,
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,213 @@
+use crate::infrastructure::postgres::schema::data_exports;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// รุ่นของโครงสร้างไฟล์ใน ZIP (เขียนไว้ใน manifest.json)
+pub const DATA_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// อายุของลิงก์ดาวน์โหลดหลังไฟล์พร้อม - หมดอายุแล้วไฟล์ถูกลบออกจาก database
+pub const DATA_EXPORT_LINK_TTL_HOURS: i64 = 24;
+
+/// จำนวนคำขอ export ต่อ user ภายใน 24 ชั่วโมง
+pub const MAX_DATA_EXPORTS_PER_DAY: i64 = 3;
+
+/// งานที่ค้างสถานะ processing นานกว่านี้ (เช่น server restart ระหว่างทำ) จะถูกนำกลับมาทำใหม่
+pub const STALE_DATA_EXPORT_MINUTES: i64 = 15;
+
+// =================================================================
+// 🎯 Status
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataExportStatus {
+    Pending,
+    Processing,
+    Ready,
+    Failed,
+    Expired,
+}
+
+impl DataExportStatus {
+    pub const ALL: [DataExportStatus; 5] = [
+        DataExportStatus::Pending,
+        DataExportStatus::Processing,
+        DataExportStatus::Ready,
+        DataExportStatus::Failed,
+        DataExportStatus::Expired,
+    ];
+
+    /// ค่าที่เก็บในคอลัมน์ `status`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataExportStatus::Pending => "pending",
+            DataExportStatus::Processing => "processing",
+            DataExportStatus::Ready => "ready",
+            DataExportStatus::Failed => "failed",
+            DataExportStatus::Expired => "expired",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|status| status.as_str() == value)
+    }
+}
+
+// =================================================================
+// 📊 DataExport Entity (สำหรับอ่านข้อมูลจาก database - ไม่รวมตัวไฟล์)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = data_exports)]
+pub struct DataExport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: String,
+    pub archive_size: Option<i64>,
+    pub archive_sha256: Option<String>,
+
+    /// SHA-256 ของ token ในลิงก์ดาวน์โหลดล่าสุด - token จริงแสดงครั้งเดียวตอนออกลิงก์
+    pub download_token_hash: Option<String>,
+
+    pub error_message: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub downloaded_at: Option<DateTime<Utc>>,
+    pub download_count: i32,
+}
+
+impl DataExport {
+    pub fn parsed_status(&self) -> Option<DataExportStatus> {
+        DataExportStatus::parse(&self.status)
+    }
+
+    pub fn is_downloadable(&self) -> bool {
+        self.parsed_status() == Some(DataExportStatus::Ready)
+            && self.expires_at.is_some_and(|expires_at| expires_at > Utc::now())
+    }
+}
+
+// =================================================================
+// ➕ NewDataExport (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = data_exports)]
+pub struct NewDataExport {
+    pub user_id: Uuid,
+    pub status: String,
+}
+
+impl NewDataExport {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            status: DataExportStatus::Pending.as_str().to_string(),
+        }
+    }
+}
+
+/// ผลลัพธ์ของงานที่ทำเสร็จ - บันทึกพร้อมกันในครั้งเดียว
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = data_exports)]
+pub struct CompletedDataExport {
+    pub status: String,
+    pub archive: Option<Vec<u8>>,
+    pub archive_size: Option<i64>,
+    pub archive_sha256: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl CompletedDataExport {
+    pub fn new(archive: Vec<u8>, archive_sha256: String) -> Self {
+        let completed_at = Utc::now();
+
+        Self {
+            status: DataExportStatus::Ready.as_str().to_string(),
+            archive_size: Some(archive.len() as i64),
+            archive: Some(archive),
+            archive_sha256: Some(archive_sha256),
+            completed_at: Some(completed_at),
+            expires_at: Some(completed_at + Duration::hours(DATA_EXPORT_LINK_TTL_HOURS)),
+        }
+    }
+}
+
+/// token ของลิงก์ดาวน์โหลด - เก็บแค่ `hash_token` ของค่านี้
+pub fn generate_download_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(48)
+        .map(char::from)
+        .collect()
+}
+
+// =================================================================
+// 📦 Export content
+// =================================================================
+
+/// ข้อมูลหนึ่งหมวด (หนึ่งตาราง) - แต่ละ record เป็น JSON object ของหนึ่งแถว
+#[derive(Debug, Clone)]
+pub struct DataExportSection {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub records: Vec<Value>,
+}
+
+/// ไฟล์ ZIP ที่พร้อมส่งให้ผู้ใช้
+#[derive(Debug, Clone)]
+pub struct DataExportDownload {
+    pub file_name: String,
+    pub archive: Vec<u8>,
+}
+
+// =================================================================
+// 📝 Response DTOs
+// =================================================================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataExportResponse {
+    pub id: Uuid,
+    pub status: String,
+    pub requested_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+
+    /// ready และยังไม่หมดอายุ - ขอลิงก์ได้ที่ POST /data-exports/:id/download-link
+    pub downloadable: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub file_size: Option<i64>,
+    pub sha256: Option<String>,
+    pub download_count: i32,
+    pub error: Option<String>,
+}
+
+impl From<DataExport> for DataExportResponse {
+    fn from(export: DataExport) -> Self {
+        Self {
+            id: export.id,
+            downloadable: export.is_downloadable(),
+            status: export.status,
+            requested_at: export.requested_at,
+            completed_at: export.completed_at,
+            expires_at: export.expires_at,
+            file_size: export.archive_size,
+            sha256: export.archive_sha256,
+            download_count: export.download_count,
+            error: export.error_message,
+        }
+    }
+}
+
+/// ลิงก์ดาวน์โหลดที่เพิ่งออก - token อยู่ใน URL และไม่ถูกเก็บไว้ที่ใด
+#[derive(Debug, Clone, Serialize)]
+pub struct DataExportLinkResponse {
+    pub download_url: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
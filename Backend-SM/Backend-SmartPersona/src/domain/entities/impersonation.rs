@@ -0,0 +1,48 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::entities::user::Role;
+
+/// อายุของ token impersonation เมื่อไม่ระบุ (ไม่มี refresh token ต้องขอใหม่)
+pub fn default_impersonation_lifetime() -> Duration {
+    Duration::minutes(15)
+}
+
+/// Admin ขอ token สำหรับดูระบบในมุมมองของ user
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct StartImpersonationRequest {
+    /// เหตุผล (เช่นเลข ticket) - บันทึกใน audit log
+    #[validate(length(min = 3, max = 500))]
+    pub reason: String,
+
+    /// อนุญาตให้แก้ไขข้อมูลแทน user (ค่าเริ่มต้นอ่านอย่างเดียว)
+    #[serde(default)]
+    pub allow_write: bool,
+
+    #[validate(range(min = 1, max = 30))]
+    pub duration_minutes: Option<i64>,
+}
+
+impl StartImpersonationRequest {
+    pub fn lifetime(&self) -> Duration {
+        self.duration_minutes
+            .map(Duration::minutes)
+            .unwrap_or_else(default_impersonation_lifetime)
+    }
+}
+
+/// Token ใช้เป็น `Authorization: Bearer` เท่านั้น (ไม่ตั้ง cookie ทับ session ของ admin)
+#[derive(Debug, Clone, Serialize)]
+pub struct ImpersonationTokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub jti: String,
+    pub expires_at: DateTime<Utc>,
+    pub expires_in: i64,
+    pub user_id: Uuid,
+    pub username: String,
+    pub role: Role,
+    pub read_only: bool,
+}
@@ -0,0 +1,310 @@
+use crate::domain::entities::account_token::hash_token;
+use crate::infrastructure::postgres::schema::{calendar_feeds, interview_slots, interviews};
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// ความยาวสูงสุดของการสัมภาษณ์หนึ่งครั้ง
+pub const MAX_INTERVIEW_HOURS: i64 = 8;
+
+// =================================================================
+// 🎯 Status
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterviewStatus {
+    /// บริษัทเสนอช่วงเวลาแล้ว รอผู้สมัครเลือก
+    Proposed,
+    /// ผู้สมัครเลือกเวลาแล้ว
+    Scheduled,
+    Cancelled,
+}
+
+impl InterviewStatus {
+    /// ค่าที่เก็บในคอลัมน์ `status`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterviewStatus::Proposed => "proposed",
+            InterviewStatus::Scheduled => "scheduled",
+            InterviewStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+// =================================================================
+// 📊 Interview Entity (สำหรับอ่านข้อมูลจาก database)
+// =================================================================
+
+#[derive(Debug, Clone, Serialize, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = interviews)]
+pub struct Interview {
+    pub id: Uuid,
+    pub application_id: Uuid,
+    pub status: String,
+    /// สถานที่ (onsite) - ต้องมี location หรือ video_url อย่างน้อยหนึ่งอย่าง
+    pub location: Option<String>,
+    pub video_url: Option<String>,
+    pub note: Option<String>,
+
+    /// ช่วงเวลาที่ผู้สมัครเลือก (None จนกว่าจะ scheduled)
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+
+    /// SEQUENCE ของ VEVENT - เพิ่มทุกครั้งที่นัดเปลี่ยน
+    pub sequence: i32,
+
+    pub cancel_reason: Option<String>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Interview {
+    pub fn is(&self, status: InterviewStatus) -> bool {
+        self.status == status.as_str()
+    }
+
+    /// UID ของ VEVENT - คงเดิมตลอดอายุนัดเพื่อให้ calendar app อัปเดตแทนการสร้างนัดใหม่
+    pub fn calendar_uid(&self) -> String {
+        format!("interview-{}@smartpersona", self.id)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Queryable, Selectable, Identifiable, Associations)]
+#[diesel(table_name = interview_slots, belongs_to(Interview))]
+pub struct InterviewSlot {
+    pub id: Uuid,
+    pub interview_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InterviewWithSlots {
+    #[serde(flatten)]
+    pub interview: Interview,
+    /// ช่วงเวลาที่เสนอ (ของการเสนอครั้งล่าสุด)
+    pub slots: Vec<InterviewSlot>,
+}
+
+/// ผู้เกี่ยวข้องกับใบสมัคร - ใช้ตรวจสิทธิ์และส่งอีเมล / ICS
+#[derive(Debug, Clone)]
+pub struct InterviewParticipants {
+    pub application_id: Uuid,
+    pub application_status: String,
+    pub job_title: String,
+    pub candidate_id: Uuid,
+    pub candidate_name: String,
+    pub candidate_email: Option<String>,
+    pub company_user_id: Uuid,
+    pub company_name: String,
+    pub company_email: Option<String>,
+}
+
+// =================================================================
+// ➕ New records (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = interviews)]
+pub struct NewInterview {
+    pub application_id: Uuid,
+    pub status: String,
+    pub location: Option<String>,
+    pub video_url: Option<String>,
+    pub note: Option<String>,
+    pub created_by: Option<Uuid>,
+}
+
+/// สถานที่ / ลิงก์ / หมายเหตุ ที่เปลี่ยนได้ตอนเลื่อนนัด
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = interviews, treat_none_as_null = true)]
+pub struct InterviewDetails {
+    pub location: Option<String>,
+    pub video_url: Option<String>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = interview_slots)]
+pub struct NewInterviewSlot {
+    pub interview_id: Uuid,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+// =================================================================
+// 📝 Request DTOs
+// =================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterviewSlotRequest {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// POST /applications/:application_id/interviews
+/// POST /interviews/:interview_id/reschedule (แทนที่ช่วงเวลาเดิมทั้งหมด)
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ProposeInterviewRequest {
+    /// เสนอได้ครั้งละไม่เกิน 10 ช่วง
+    #[validate(length(min = 1, max = 10))]
+    pub slots: Vec<InterviewSlotRequest>,
+
+    #[validate(length(max = 500))]
+    pub location: Option<String>,
+
+    #[validate(url, length(max = 2048))]
+    pub video_url: Option<String>,
+
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+impl ProposeInterviewRequest {
+    /// ตรวจช่วงเวลาและสถานที่ - คืนข้อความสำหรับ "Validation error"
+    pub fn validate_schedule(&self) -> Result<(), String> {
+        let now = Utc::now();
+        for slot in &self.slots {
+            if slot.ends_at <= slot.starts_at {
+                return Err("each slot must end after it starts".to_string());
+            }
+            if slot.starts_at <= now {
+                return Err("slots must be in the future".to_string());
+            }
+            if slot.ends_at - slot.starts_at > Duration::hours(MAX_INTERVIEW_HOURS) {
+                return Err(format!("a slot cannot be longer than {} hours", MAX_INTERVIEW_HOURS));
+            }
+        }
+        let details = self.details();
+        if details.location.is_none() && details.video_url.is_none() {
+            return Err("location or video_url is required".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn details(&self) -> InterviewDetails {
+        let trimmed = |value: &Option<String>| {
+            value
+                .as_ref()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        // Url::parse ข้าม tab / CR / LF ในข้อความ (validate ผ่าน) - เก็บรูปที่ parse แล้วแทนข้อความดิบ
+        let video_url = trimmed(&self.video_url).map(|value| match Url::parse(&value) {
+            Ok(url) => url.to_string(),
+            Err(_) => value,
+        });
+
+        InterviewDetails {
+            location: trimmed(&self.location),
+            video_url,
+            note: trimmed(&self.note),
+        }
+    }
+
+    /// ช่วงเวลาเรียงตามเวลาเริ่ม ตัดช่วงที่ซ้ำกันออก
+    pub fn slot_times(&self) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut times: Vec<_> = self
+            .slots
+            .iter()
+            .map(|slot| (slot.starts_at, slot.ends_at))
+            .collect();
+        times.sort();
+        times.dedup();
+        times
+    }
+}
+
+/// POST /interviews/:interview_id/confirm
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfirmInterviewRequest {
+    pub slot_id: Uuid,
+}
+
+/// POST /interviews/:interview_id/cancel
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CancelInterviewRequest {
+    #[validate(length(max = 1000))]
+    pub reason: Option<String>,
+}
+
+// =================================================================
+// 📅 ICS feed
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Insertable)]
+#[diesel(table_name = calendar_feeds)]
+pub struct CalendarFeed {
+    pub user_id: Uuid,
+    /// SHA-256 ของ token ใน URL - token จริงแสดงครั้งเดียวตอนสร้าง / เปลี่ยน
+    pub feed_token_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeed {
+    /// คืนค่า (feed สำหรับบันทึก, token จริงสำหรับใส่ใน URL)
+    pub fn generate(user_id: Uuid) -> (Self, String) {
+        let feed_token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(48)
+            .map(char::from)
+            .collect();
+
+        let feed = Self {
+            user_id,
+            feed_token_hash: hash_token(&feed_token),
+            created_at: Utc::now(),
+        };
+        (feed, feed_token)
+    }
+}
+
+/// GET /calendar/feed
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarFeedResponse {
+    /// ใส่ใน calendar app (Google Calendar: "From URL")
+    /// มีเฉพาะตอนสร้าง / เปลี่ยน token - ทำ URL หายต้อง rotate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_url: Option<String>,
+    /// เปิดใน Apple Calendar / Outlook ได้โดยตรง
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webcal_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeedResponse {
+    /// feed ที่มีอยู่แล้ว - เก็บแค่ hash จึงแสดง URL ไม่ได้
+    pub fn existing(feed: &CalendarFeed) -> Self {
+        Self {
+            feed_url: None,
+            webcal_url: None,
+            created_at: feed.created_at,
+        }
+    }
+
+    pub fn new(backend_url: &str, feed: &CalendarFeed, feed_token: &str) -> Self {
+        let feed_url = format!(
+            "{}/api/calendar/{}.ics",
+            backend_url.trim_end_matches('/'),
+            feed_token
+        );
+        let webcal_url = match feed_url.split_once("://") {
+            Some((_, rest)) => format!("webcal://{}", rest),
+            None => feed_url.clone(),
+        };
+
+        Self {
+            feed_url: Some(feed_url),
+            webcal_url: Some(webcal_url),
+            created_at: feed.created_at,
+        }
+    }
+}
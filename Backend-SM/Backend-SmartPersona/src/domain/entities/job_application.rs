@@ -1,8 +1,206 @@
-use crate::infrastructure::postgres::schema::job_applications;
+use crate::{
+    domain::entities::user::{Role, UserStatusEnum},
+    infrastructure::postgres::schema::{
+        application_screening_answers, application_status_history, job_applications,
+    },
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::Validate;
+
+// =================================================================
+// 🎯 Status
+// =================================================================
+
+/// สถานะของใบสมัคร: applied → screening → interview → offer → hired (+ rejected / withdrawn)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationStatus {
+    Applied,
+    Screening,
+    Interview,
+    Offer,
+    Hired,
+    Rejected,
+    Withdrawn,
+}
+
+impl ApplicationStatus {
+    /// ค่าที่เก็บในคอลัมน์ `status`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationStatus::Applied => "applied",
+            ApplicationStatus::Screening => "screening",
+            ApplicationStatus::Interview => "interview",
+            ApplicationStatus::Offer => "offer",
+            ApplicationStatus::Hired => "hired",
+            ApplicationStatus::Rejected => "rejected",
+            ApplicationStatus::Withdrawn => "withdrawn",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "applied" => Some(ApplicationStatus::Applied),
+            "screening" => Some(ApplicationStatus::Screening),
+            "interview" => Some(ApplicationStatus::Interview),
+            "offer" => Some(ApplicationStatus::Offer),
+            "hired" => Some(ApplicationStatus::Hired),
+            "rejected" => Some(ApplicationStatus::Rejected),
+            "withdrawn" => Some(ApplicationStatus::Withdrawn),
+            _ => None,
+        }
+    }
+
+    /// จบแล้ว - เปลี่ยนสถานะต่อไม่ได้
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ApplicationStatus::Hired | ApplicationStatus::Rejected | ApplicationStatus::Withdrawn
+        )
+    }
+
+    /// ลำดับในขั้นตอน (rejected / withdrawn ไม่อยู่ในลำดับ)
+    fn rank(&self) -> Option<u8> {
+        match self {
+            ApplicationStatus::Applied => Some(0),
+            ApplicationStatus::Screening => Some(1),
+            ApplicationStatus::Interview => Some(2),
+            ApplicationStatus::Offer => Some(3),
+            ApplicationStatus::Hired => Some(4),
+            ApplicationStatus::Rejected | ApplicationStatus::Withdrawn => None,
+        }
+    }
+}
+
+/// ผู้เปลี่ยนสถานะ - เก็บในคอลัมน์ `actor_role` ของ history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplicationActorRole {
+    Candidate,
+    Company,
+    System,
+}
+
+impl ApplicationActorRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationActorRole::Candidate => "candidate",
+            ApplicationActorRole::Company => "company",
+            ApplicationActorRole::System => "system",
+        }
+    }
+}
+
+// =================================================================
+// 🔀 Pipeline (การเปลี่ยนสถานะที่อนุญาต)
+// =================================================================
+
+/// ขั้นตอนระหว่าง applied กับ hired ที่ตั้งค่าได้ - ขั้นที่ไม่ได้ตั้งค่าจะถูกข้ามเสมอ
+#[derive(Debug, Clone)]
+pub struct ApplicationPipeline {
+    stages: Vec<ApplicationStatus>,
+    allow_skip: bool,
+}
+
+impl Default for ApplicationPipeline {
+    fn default() -> Self {
+        Self {
+            stages: vec![
+                ApplicationStatus::Screening,
+                ApplicationStatus::Interview,
+                ApplicationStatus::Offer,
+            ],
+            allow_skip: false,
+        }
+    }
+}
+
+impl ApplicationPipeline {
+    /// stages เรียงตามลำดับ เช่น ["screening", "interview", "offer"] (ว่างได้ = applied → hired)
+    pub fn new(stages: &[String], allow_skip: bool) -> Result<Self, String> {
+        let mut parsed: Vec<ApplicationStatus> = Vec::new();
+
+        for stage in stages {
+            let status = ApplicationStatus::parse(stage)
+                .filter(|status| {
+                    matches!(
+                        status,
+                        ApplicationStatus::Screening
+                            | ApplicationStatus::Interview
+                            | ApplicationStatus::Offer
+                    )
+                })
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid pipeline stage '{}': must be one of screening, interview, offer",
+                        stage
+                    )
+                })?;
+
+            if parsed.last().is_some_and(|last| last.rank() >= status.rank()) {
+                return Err(
+                    "Pipeline stages must be unique and in the order screening, interview, offer"
+                        .to_string(),
+                );
+            }
+            parsed.push(status);
+        }
+
+        Ok(Self {
+            stages: parsed,
+            allow_skip,
+        })
+    }
+
+    /// สถานะที่ actor เปลี่ยนไปได้จากสถานะปัจจุบัน
+    /// - บริษัท: ขั้นถัดไป (หรือขั้นใดก็ได้ที่อยู่ถัดไปถ้า allow_skip) หรือ rejected
+    /// - ผู้สมัคร: withdrawn
+    pub fn next_statuses(
+        &self,
+        from: ApplicationStatus,
+        role: ApplicationActorRole,
+    ) -> Vec<ApplicationStatus> {
+        if from.is_terminal() {
+            return Vec::new();
+        }
+
+        match role {
+            ApplicationActorRole::Company => {
+                let forward = self
+                    .stages
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(ApplicationStatus::Hired))
+                    .filter(|stage| stage.rank() > from.rank());
+
+                let mut next: Vec<ApplicationStatus> = if self.allow_skip {
+                    forward.collect()
+                } else {
+                    forward.take(1).collect()
+                };
+                next.push(ApplicationStatus::Rejected);
+                next
+            }
+            ApplicationActorRole::Candidate => vec![ApplicationStatus::Withdrawn],
+            ApplicationActorRole::System => Vec::new(),
+        }
+    }
+
+    pub fn can_transition(
+        &self,
+        from: ApplicationStatus,
+        to: ApplicationStatus,
+        role: ApplicationActorRole,
+    ) -> bool {
+        self.next_statuses(from, role).contains(&to)
+    }
+}
+
+// =================================================================
+// 📊 JobApplication Entity
+// =================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize, Queryable, Insertable)]
 #[diesel(table_name = job_applications)]
@@ -13,6 +211,12 @@ pub struct JobApplicationEntity {
     pub status: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub cover_letter: Option<String>,
+    /// ไฟล์ที่ upload ผ่าน /api/storage/upload/resume
+    pub resume_url: Option<String>,
+    /// ตอบคำถามคัดกรองตรงกับ knockout answer - ผู้สมัครไม่เห็นค่านี้
+    #[serde(skip_serializing, default)]
+    pub knocked_out: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Insertable)]
@@ -21,6 +225,9 @@ pub struct NewJobApplication {
     pub job_id: Uuid,
     pub user_id: Uuid,
     pub status: String,
+    pub cover_letter: Option<String>,
+    pub resume_url: Option<String>,
+    pub knocked_out: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +238,10 @@ pub struct JobApplicationWithUser {
     pub status: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub cover_letter: Option<String>,
+    pub resume_url: Option<String>,
+    pub knocked_out: bool,
+    pub screening_answers: Vec<ApplicationScreeningAnswer>,
     // User details
     pub first_name: Option<String>,
     pub last_name: Option<String>,
@@ -38,3 +249,220 @@ pub struct JobApplicationWithUser {
     pub phone: Option<String>,
     pub profile_image_url: Option<String>,
 }
+
+// =================================================================
+// ❓ Screening answers
+// =================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, Queryable, Selectable)]
+#[diesel(table_name = application_screening_answers)]
+pub struct ApplicationScreeningAnswer {
+    pub id: Uuid,
+    pub application_id: Uuid,
+    /// None เมื่อบริษัทลบ / แทนที่คำถามไปแล้ว (prompt ยังอยู่)
+    pub question_id: Option<Uuid>,
+    pub position: i32,
+    pub kind: String,
+    pub prompt: String,
+    pub answer: Option<String>,
+    pub knocked_out: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// คำตอบที่ตรวจแล้ว - application_id ใส่ตอนสร้างใบสมัครใน repository
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = application_screening_answers)]
+pub struct NewApplicationScreeningAnswer {
+    pub application_id: Uuid,
+    pub question_id: Option<Uuid>,
+    pub position: i32,
+    pub kind: String,
+    pub prompt: String,
+    pub answer: Option<String>,
+    pub knocked_out: bool,
+}
+
+/// ผลคัดกรองที่ใช้กรองรายชื่อผู้สมัคร
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KnockoutFilter {
+    /// ไม่ติด knockout (รวมใบสมัครของประกาศที่ไม่มีคำถาม)
+    Passed,
+    Failed,
+}
+
+impl KnockoutFilter {
+    /// ค่า `knocked_out` ที่ต้องการ
+    pub fn knocked_out(&self) -> bool {
+        matches!(self, KnockoutFilter::Failed)
+    }
+}
+
+// =================================================================
+// 🚦 Apply rules / contacts
+// =================================================================
+
+/// ข้อมูลที่ใช้ตรวจว่ายื่นใบสมัครประกาศนี้ได้หรือไม่
+#[derive(Debug, Clone)]
+pub struct ApplyEligibility {
+    pub post_status: String,
+    pub post_closes_at: Option<DateTime<Utc>>,
+    pub company_user_id: Uuid,
+    pub company_status: String,
+    /// สถานะบัญชีของบริษัท (แบนบริษัท = ระงับบัญชี)
+    pub company_account_status: UserStatusEnum,
+    pub applicant_role: Role,
+    pub applicant_first_name: Option<String>,
+    pub applicant_last_name: Option<String>,
+    /// อีเมลจากโปรไฟล์ หรือของบัญชี
+    pub applicant_email: Option<String>,
+}
+
+impl ApplyEligibility {
+    /// อนุมัติแล้วและไม่ถูกแบน (แบน = status "banned" และระงับบัญชีของบริษัท)
+    pub fn company_approved(&self) -> bool {
+        self.company_status.eq_ignore_ascii_case("approved")
+            && self.company_account_status == UserStatusEnum::Active
+    }
+
+    /// ข้อมูลขั้นต่ำที่บริษัทต้องใช้ติดต่อผู้สมัคร
+    pub fn missing_profile_fields(&self) -> Vec<&'static str> {
+        let present = |value: &Option<String>| value.as_deref().is_some_and(|value| !value.trim().is_empty());
+
+        let mut missing = Vec::new();
+        if !present(&self.applicant_first_name) {
+            missing.push("first name");
+        }
+        if !present(&self.applicant_last_name) {
+            missing.push("last name");
+        }
+        if !present(&self.applicant_email) {
+            missing.push("email");
+        }
+        missing
+    }
+}
+
+/// ผู้เกี่ยวข้องกับใบสมัคร สำหรับส่งอีเมลแจ้งเตือน
+#[derive(Debug, Clone)]
+pub struct ApplicationContacts {
+    pub job_title: String,
+    pub company_name: String,
+    pub company_email: Option<String>,
+    pub applicant_name: String,
+    pub applicant_email: Option<String>,
+}
+
+// =================================================================
+// 🕒 Status history (timeline)
+// =================================================================
+
+#[derive(Debug, Clone, Serialize, Queryable, Selectable)]
+#[diesel(table_name = application_status_history)]
+pub struct ApplicationStatusHistoryEntity {
+    pub id: Uuid,
+    pub application_id: Uuid,
+    /// None = ตอนยื่นใบสมัคร
+    pub from_status: Option<String>,
+    pub to_status: String,
+    /// None เมื่อเป็น system หรือบัญชีผู้เปลี่ยนถูกลบไปแล้ว
+    pub actor_id: Option<Uuid>,
+    pub actor_role: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = application_status_history)]
+pub struct NewApplicationStatusHistory {
+    pub application_id: Uuid,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub actor_id: Option<Uuid>,
+    pub actor_role: String,
+    pub note: Option<String>,
+}
+
+impl NewApplicationStatusHistory {
+    pub fn new(
+        application_id: Uuid,
+        from_status: Option<ApplicationStatus>,
+        to_status: ApplicationStatus,
+        actor_id: Option<Uuid>,
+        actor_role: ApplicationActorRole,
+        note: Option<String>,
+    ) -> Self {
+        Self {
+            application_id,
+            from_status: from_status.map(|status| status.as_str().to_string()),
+            to_status: to_status.as_str().to_string(),
+            actor_id,
+            actor_role: actor_role.as_str().to_string(),
+            note: note
+                .map(|note| note.trim().to_string())
+                .filter(|note| !note.is_empty()),
+        }
+    }
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreeningAnswerRequest {
+    pub question_id: Uuid,
+    pub answer: Option<String>,
+}
+
+/// POST /apply
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ApplyForJobRequest {
+    pub job_id: Uuid,
+
+    #[validate(length(max = 5000))]
+    pub cover_letter: Option<String>,
+
+    #[validate(url, length(max = 2048))]
+    pub resume_url: Option<String>,
+
+    /// คำตอบของคำถามคัดกรองของประกาศ (ส่งทุกข้อที่บังคับ)
+    #[serde(default)]
+    #[validate(length(max = 20))]
+    pub answers: Vec<ScreeningAnswerRequest>,
+}
+
+/// POST /applications/:application_id/withdraw
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct WithdrawApplicationRequest {
+    /// แสดงใน timeline และอีเมลถึงบริษัท
+    #[validate(length(max = 1000))]
+    pub reason: Option<String>,
+}
+
+/// GET /candidates/:job_id?knockout=passed|failed
+#[derive(Debug, Clone, Deserialize)]
+pub struct CandidateListQuery {
+    pub knockout: Option<KnockoutFilter>,
+}
+
+/// PATCH /applications/:application_id/status
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateApplicationStatusRequest {
+    #[validate(length(min = 1, max = 20))]
+    pub status: String,
+
+    /// เหตุผล / ข้อความถึงอีกฝ่าย - แสดงใน timeline
+    #[validate(length(max = 1000))]
+    pub note: Option<String>,
+}
+
+/// GET /applications/:application_id/history
+#[derive(Debug, Clone, Serialize)]
+pub struct ApplicationTimeline {
+    pub application_id: Uuid,
+    pub status: String,
+    /// สถานะที่ผู้เรียกเปลี่ยนไปได้ต่อ
+    pub next_statuses: Vec<ApplicationStatus>,
+    pub history: Vec<ApplicationStatusHistoryEntity>,
+}
@@ -0,0 +1,96 @@
+use crate::infrastructure::postgres::schema::{login_lockout_events, login_lockouts};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// =================================================================
+// 🎯 Throttle scope (แยก bucket ตาม endpoint)
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleScope {
+    UserLogin,
+    AdminLogin,
+    Mfa,
+    Register,
+    Oidc,
+}
+
+impl ThrottleScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThrottleScope::UserLogin => "login",
+            ThrottleScope::AdminLogin => "admin_login",
+            ThrottleScope::Mfa => "mfa",
+            ThrottleScope::Register => "register",
+            ThrottleScope::Oidc => "oidc",
+        }
+    }
+}
+
+/// username ใช้เป็น key ของ bucket / lockout แบบไม่สนตัวพิมพ์
+pub fn normalize_username(username: &str) -> String {
+    username.trim().to_lowercase()
+}
+
+// =================================================================
+// 🔒 LoginLockout Entity (หนึ่งแถวต่อ username)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = login_lockouts)]
+pub struct LoginLockout {
+    pub username: String,
+
+    /// login ผิดติดกันนับจากการล็อกครั้งล่าสุด
+    pub failed_attempts: i32,
+
+    /// จำนวนครั้งที่ถูกล็อกติดกัน (ใช้คำนวณระยะเวลาล็อกครั้งถัดไป)
+    pub lockout_level: i32,
+
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_failed_at: DateTime<Utc>,
+    pub last_ip_address: Option<String>,
+}
+
+impl LoginLockout {
+    pub fn is_locked(&self) -> bool {
+        self.locked_until.is_some_and(|locked_until| locked_until > Utc::now())
+    }
+}
+
+// =================================================================
+// 📜 LoginLockoutEvent (ประวัติการล็อกให้ Admin ตรวจสอบ)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = login_lockout_events)]
+pub struct LoginLockoutEvent {
+    pub id: Uuid,
+    pub username: String,
+    pub ip_address: Option<String>,
+    pub lockout_level: i32,
+    pub failed_attempts: i32,
+    pub locked_until: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = login_lockout_events)]
+pub struct NewLoginLockoutEvent {
+    pub username: String,
+    pub ip_address: Option<String>,
+    pub lockout_level: i32,
+    pub failed_attempts: i32,
+    pub locked_until: DateTime<Utc>,
+}
+
+// =================================================================
+// 📥 Query DTO
+// =================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LockoutEventsQuery {
+    pub limit: Option<i64>,
+}
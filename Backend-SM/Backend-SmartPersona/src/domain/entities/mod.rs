@@ -1,10 +1,21 @@
+pub mod account_deletion;
+pub mod account_token;
 pub mod admin_data;
+pub mod admin_invite;
+pub mod audit_event;
 pub mod ads;
 pub mod ai_analysis;
 pub mod company;
+pub mod company_api_key;
 pub mod company_gallery;
 pub mod company_post;
+pub mod data_export;
+pub mod impersonation;
+pub mod interview;
+pub mod login_throttle;
+pub mod oidc;
 pub mod saved_job;
+pub mod saved_search;
 pub mod social_analysis;
 pub mod social_connection;
 pub mod social_post;
@@ -15,9 +26,11 @@ pub mod user_education;
 pub mod user_experience;
 pub mod user_job_match;
 pub mod user_job_preference;
+pub mod user_mfa;
 pub mod user_portfolio;
 pub mod user_privacy_settings;
 pub mod user_profile;
+pub mod user_session;
 pub mod user_share;
 pub mod user_skill;
 pub mod job_application;
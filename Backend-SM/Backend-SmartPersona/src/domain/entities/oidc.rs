@@ -0,0 +1,186 @@
+use crate::infrastructure::postgres::schema::{oidc_auth_requests, user_identities};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::entities::{account_token::hash_token, user::UserEntity};
+
+/// เวลาที่ผู้ใช้มีในการ login ที่ provider ก่อน state หมดอายุ
+pub fn oidc_auth_request_ttl() -> Duration {
+    Duration::minutes(10)
+}
+
+fn random_string(length: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
+
+/// PKCE S256: BASE64URL(SHA256(code_verifier))
+pub fn pkce_code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// username สำหรับบัญชีที่สร้างจาก OIDC: ชื่อจาก provider + suffix สุ่ม (ตามกฎ validate_username)
+pub fn generate_username(hint: Option<&str>) -> String {
+    let base: String = hint
+        .and_then(|hint| hint.split('@').next())
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        .take(30)
+        .collect();
+    let base = if base.len() < 3 { "user".to_string() } else { base };
+
+    format!("{}_{}", base, random_string(6).to_lowercase())
+}
+
+/// return_to ต้องเป็น path ภายใน frontend เท่านั้น (กัน open redirect)
+pub fn sanitize_return_to(return_to: Option<String>) -> Option<String> {
+    return_to.filter(|path| {
+        path.starts_with('/')
+            && !path.starts_with("//")
+            && !path.contains('\\')
+            && path.len() <= 512
+    })
+}
+
+// =================================================================
+// 🔗 UserIdentity Entity (บัญชี OIDC ที่ผูกกับ user)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable, Serialize)]
+#[diesel(table_name = user_identities)]
+pub struct UserIdentity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+
+    /// ชื่อ provider ตาม config
+    pub provider: String,
+
+    /// `sub` claim ของ ID token (ไม่ซ้ำภายใน provider)
+    pub subject: String,
+
+    /// อีเมลที่ provider แจ้งตอนผูกบัญชี
+    pub email: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = user_identities)]
+pub struct NewUserIdentity {
+    pub user_id: Uuid,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+// =================================================================
+// 🔐 OidcAuthRequest (state ระหว่าง redirect ไป provider และ callback)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = oidc_auth_requests)]
+pub struct OidcAuthRequest {
+    pub id: Uuid,
+
+    /// SHA-256 ของ state (ไม่เก็บ state จริงใน database)
+    pub state_hash: String,
+
+    pub provider: String,
+    pub nonce: String,
+    pub code_verifier: String,
+
+    /// มีค่าเมื่อ user ที่ login อยู่แล้วขอผูกบัญชี (ไม่ใช่การ login)
+    pub link_user_id: Option<Uuid>,
+
+    pub return_to: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = oidc_auth_requests)]
+pub struct NewOidcAuthRequest {
+    pub state_hash: String,
+    pub provider: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub link_user_id: Option<Uuid>,
+    pub return_to: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl NewOidcAuthRequest {
+    /// สร้าง state / nonce / code_verifier ใหม่ คืนค่า (record สำหรับบันทึก, state จริงสำหรับ redirect)
+    pub fn generate(
+        provider: String,
+        link_user_id: Option<Uuid>,
+        return_to: Option<String>,
+    ) -> (Self, String) {
+        let raw_state = random_string(43);
+
+        let new_request = Self {
+            state_hash: hash_token(&raw_state),
+            provider,
+            nonce: random_string(43),
+            code_verifier: random_string(64),
+            link_user_id,
+            return_to: sanitize_return_to(return_to),
+            expires_at: Utc::now() + oidc_auth_request_ttl(),
+        };
+
+        (new_request, raw_state)
+    }
+}
+
+/// ผลของ callback: login ด้วยบัญชีที่ผูกไว้ หรือผูกบัญชีให้ user ที่ login อยู่แล้ว
+#[derive(Debug, Clone)]
+pub enum OidcCallbackOutcome {
+    SignedIn {
+        user: UserEntity,
+        return_to: Option<String>,
+    },
+    Linked {
+        return_to: Option<String>,
+    },
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcStartQuery {
+    /// path ใน frontend ที่จะกลับไปหลัง login สำเร็จ
+    pub return_to: Option<String>,
+}
+
+/// Query string ที่ provider ส่งกลับมาที่ callback
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OidcAuthorizationResponse {
+    pub authorization_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OidcProviderResponse {
+    pub name: String,
+    pub login_url: String,
+}
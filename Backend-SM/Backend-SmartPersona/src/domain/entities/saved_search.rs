@@ -0,0 +1,307 @@
+use crate::{
+    domain::{
+        entities::company_post::JobSearchQuery,
+        value_object::salary::SalaryPeriod,
+    },
+    infrastructure::postgres::schema::saved_searches,
+};
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+/// จำนวนการค้นหาที่บันทึกได้ต่อผู้ใช้
+pub const MAX_SAVED_SEARCHES_PER_USER: i64 = 20;
+
+/// จำนวนประกาศสูงสุดที่จับคู่ได้ต่อการค้นหาในแต่ละรอบ
+pub const MAX_ALERT_MATCHES_PER_RUN: i64 = 50;
+
+/// ลิงก์ยกเลิกในอีเมลที่เก่ากว่านี้ใช้ไม่ได้ (digest ทุกฉบับมีลิงก์ใหม่)
+pub const UNSUBSCRIBE_LINK_TTL_DAYS: i64 = 365;
+
+// =================================================================
+// 🔔 Frequency
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertFrequency {
+    /// ส่งทันทีที่ตัวจับคู่รอบถัดไปเจอประกาศใหม่
+    Instant,
+    Daily,
+    Weekly,
+}
+
+impl AlertFrequency {
+    /// ค่าที่เก็บในคอลัมน์ `frequency`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertFrequency::Instant => "instant",
+            AlertFrequency::Daily => "daily",
+            AlertFrequency::Weekly => "weekly",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "instant" => Some(AlertFrequency::Instant),
+            "daily" => Some(AlertFrequency::Daily),
+            "weekly" => Some(AlertFrequency::Weekly),
+            _ => None,
+        }
+    }
+
+    /// ระยะห่างขั้นต่ำระหว่าง digest สองฉบับ
+    pub fn period(&self) -> Duration {
+        match self {
+            AlertFrequency::Instant => Duration::zero(),
+            AlertFrequency::Daily => Duration::days(1),
+            AlertFrequency::Weekly => Duration::weeks(1),
+        }
+    }
+}
+
+// =================================================================
+// 🔍 Criteria
+// =================================================================
+
+/// เงื่อนไขที่บันทึกไว้ - ชุดเดียวกับ GET /posts/search (ไม่รวม sort / limit / cursor)
+/// ตรวจค่าด้วย `job_search_filter` ผ่าน `to_query()`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedSearchCriteria {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// คั่นด้วย comma
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salary_min: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salary_max: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salary_currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salary_period: Option<SalaryPeriod>,
+    /// คั่นด้วย comma
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lng: Option<f64>,
+    /// ค่าเริ่มต้น 25 km
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius_km: Option<f64>,
+}
+
+impl SavedSearchCriteria {
+    /// ไม่มีเงื่อนไขเลย (จะตรงกับทุกประกาศ)
+    pub fn is_empty(&self) -> bool {
+        let blank = |value: &Option<String>| value.as_deref().is_none_or(|v| v.trim().is_empty());
+
+        blank(&self.q)
+            && blank(&self.job_type)
+            && blank(&self.location)
+            && blank(&self.tags)
+            && self.salary_min.is_none()
+            && self.salary_max.is_none()
+            && self.company_id.is_none()
+            && self.lat.is_none()
+            && self.lng.is_none()
+    }
+
+    pub fn to_query(&self) -> JobSearchQuery {
+        JobSearchQuery {
+            q: self.q.clone(),
+            job_type: self.job_type.clone(),
+            location: self.location.clone(),
+            salary_min: self.salary_min,
+            salary_max: self.salary_max,
+            salary_currency: self.salary_currency.clone(),
+            salary_period: self.salary_period,
+            tags: self.tags.clone(),
+            company_id: self.company_id,
+            posted_after: None,
+            posted_before: None,
+            lat: self.lat,
+            lng: self.lng,
+            radius_km: self.radius_km,
+            sort: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+// =================================================================
+// 💾 Saved search
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Serialize)]
+#[diesel(table_name = saved_searches)]
+pub struct SavedSearch {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub criteria: serde_json::Value,
+    pub frequency: String,
+    pub alerts_enabled: bool,
+    #[serde(skip_serializing)]
+    pub matched_until: DateTime<Utc>,
+    pub last_sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SavedSearch {
+    /// แถวที่ frequency ผิดรูปแบบถือเป็น daily
+    pub fn frequency(&self) -> AlertFrequency {
+        AlertFrequency::parse(&self.frequency).unwrap_or(AlertFrequency::Daily)
+    }
+
+    pub fn criteria(&self) -> Result<SavedSearchCriteria, serde_json::Error> {
+        serde_json::from_value(self.criteria.clone())
+    }
+
+    /// ถึงรอบส่ง digest แล้วหรือยัง (นับจาก digest ล่าสุด หรือเวลาที่สร้าง)
+    pub fn is_digest_due(&self, now: DateTime<Utc>) -> bool {
+        self.last_sent_at.unwrap_or(self.created_at) + self.frequency().period() <= now
+    }
+}
+
+/// ลิงก์ยกเลิกในอีเมล - token ออกใหม่ทุก digest และเก็บแค่ `hash_token`
+pub fn unsubscribe_url(backend_url: &str, unsubscribe_token: &str) -> String {
+    format!(
+        "{}/api/saved-searches/unsubscribe/{}",
+        backend_url.trim_end_matches('/'),
+        unsubscribe_token
+    )
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = saved_searches)]
+pub struct NewSavedSearch {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub criteria: serde_json::Value,
+    pub frequency: String,
+    pub alerts_enabled: bool,
+    pub matched_until: DateTime<Utc>,
+}
+
+pub fn generate_unsubscribe_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(48)
+        .map(char::from)
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, AsChangeset)]
+#[diesel(table_name = saved_searches)]
+pub struct UpdateSavedSearch {
+    pub name: Option<String>,
+    pub criteria: Option<serde_json::Value>,
+    pub frequency: Option<String>,
+    pub alerts_enabled: Option<bool>,
+    /// เริ่มจับคู่ใหม่จากเวลานี้ (เมื่อแก้เงื่อนไขหรือเปิดแจ้งเตือนอีกครั้ง)
+    pub matched_until: Option<DateTime<Utc>>,
+}
+
+impl UpdateSavedSearch {
+    pub fn has_changes(&self) -> bool {
+        self.name.is_some()
+            || self.criteria.is_some()
+            || self.frequency.is_some()
+            || self.alerts_enabled.is_some()
+    }
+
+    /// ประกาศที่จับคู่ไว้แต่ยังไม่ได้ส่งใช้ไม่ได้แล้ว
+    pub fn discards_pending_matches(&self) -> bool {
+        self.criteria.is_some() || self.alerts_enabled == Some(false)
+    }
+}
+
+// =================================================================
+// 📝 Requests
+// =================================================================
+
+/// POST /saved-searches
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CreateSavedSearchRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub criteria: SavedSearchCriteria,
+    /// ค่าเริ่มต้น: daily
+    pub frequency: Option<AlertFrequency>,
+    /// ค่าเริ่มต้น: true
+    pub alerts_enabled: Option<bool>,
+}
+
+impl CreateSavedSearchRequest {
+    pub fn into_new_saved_search(self, user_id: Uuid, now: DateTime<Utc>) -> NewSavedSearch {
+        NewSavedSearch {
+            id: Uuid::new_v4(),
+            user_id,
+            name: self.name.trim().to_string(),
+            criteria: serde_json::to_value(&self.criteria).unwrap_or_default(),
+            frequency: self
+                .frequency
+                .unwrap_or(AlertFrequency::Daily)
+                .as_str()
+                .to_string(),
+            alerts_enabled: self.alerts_enabled.unwrap_or(true),
+            // แจ้งเฉพาะประกาศที่เผยแพร่หลังบันทึกการค้นหา
+            matched_until: now,
+        }
+    }
+}
+
+/// PATCH /saved-searches/:id
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateSavedSearchRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    pub criteria: Option<SavedSearchCriteria>,
+    pub frequency: Option<AlertFrequency>,
+    pub alerts_enabled: Option<bool>,
+}
+
+impl UpdateSavedSearchRequest {
+    pub fn into_update(self, current: &SavedSearch, now: DateTime<Utc>) -> UpdateSavedSearch {
+        let enables_alerts = self.alerts_enabled == Some(true) && !current.alerts_enabled;
+        let criteria = self
+            .criteria
+            .map(|criteria| serde_json::to_value(&criteria).unwrap_or_default());
+
+        UpdateSavedSearch {
+            name: self.name.map(|name| name.trim().to_string()),
+            matched_until: (criteria.is_some() || enables_alerts).then_some(now),
+            criteria,
+            frequency: self.frequency.map(|frequency| frequency.as_str().to_string()),
+            alerts_enabled: self.alerts_enabled,
+        }
+    }
+}
+
+// =================================================================
+// 📬 Digest
+// =================================================================
+
+/// ประกาศที่รอส่งใน digest
+#[derive(Debug, Clone, Queryable)]
+pub struct PendingAlertPost {
+    pub saved_search_id: Uuid,
+    pub post_id: Uuid,
+    pub title: String,
+    pub company_name: String,
+    pub location: String,
+    pub job_type: String,
+}
@@ -19,6 +19,8 @@ pub enum UserStatusEnum {
     Pending,
     Active,
     Suspended,
+    /// ปิดบัญชีแล้ว - แถวนี้เหลือเป็น tombstone ที่ไม่มีข้อมูลส่วนบุคคล
+    Deleted,
 }
 
 #[derive(Debug, Clone, Queryable, Selectable, Serialize, Deserialize)]
@@ -32,6 +34,7 @@ pub struct UserEntity {
     pub status: UserStatusEnum,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub email: Option<String>,
 }
 
 #[derive(Debug, Clone, Insertable, Queryable)]
@@ -39,6 +42,7 @@ pub struct UserEntity {
 pub struct RegisterUserEntity {
     pub username: String,
     pub password_hash: String,
+    pub email: Option<String>,
     pub role: Option<Role>,
     pub status: Option<UserStatusEnum>,
     pub created_at: NaiveDateTime,
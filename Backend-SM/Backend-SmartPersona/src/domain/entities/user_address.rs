@@ -1,4 +1,6 @@
-use crate::infrastructure::postgres::schema::user_addresses;
+use crate::{
+    domain::value_object::geo::GeoPoint, infrastructure::postgres::schema::user_addresses,
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,24 @@ pub struct UserAddressEntity {
     pub address_detail: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    /// พิกัดของที่อยู่ - ใช้เป็นจุดเริ่มต้นของการค้นหางานใกล้บ้าน
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl UserAddressEntity {
+    pub fn coordinates(&self) -> Option<GeoPoint> {
+        GeoPoint::new(self.latitude?, self.longitude?)
+    }
+
+    /// ที่อยู่สำหรับผู้ใช้อื่น - พิกัดระบุตำแหน่งบ้านได้แม่นเกินกว่าที่ privacy setting ตั้งใจเปิด
+    pub fn without_coordinates(self) -> Self {
+        Self {
+            latitude: None,
+            longitude: None,
+            ..self
+        }
+    }
 }
 
 // Custom Serialize implementation for JSON response
@@ -25,7 +45,7 @@ impl serde::Serialize for UserAddressEntity {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("UserAddressEntity", 9)?;
+        let mut state = serializer.serialize_struct("UserAddressEntity", 11)?;
         state.serialize_field("id", &self.id)?;
         state.serialize_field("user_id", &self.user_id)?;
         state.serialize_field("province", &self.province)?;
@@ -41,6 +61,8 @@ impl serde::Serialize for UserAddressEntity {
             "updated_at",
             &self.updated_at.map(|dt| dt.to_rfc3339()),
         )?;
+        state.serialize_field("latitude", &self.latitude)?;
+        state.serialize_field("longitude", &self.longitude)?;
         state.end()
     }
 }
@@ -55,6 +77,8 @@ pub struct NewUserAddress {
     pub subdistrict: Option<String>,
     pub postal_code: Option<String>,
     pub address_detail: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 impl NewUserAddress {
@@ -74,6 +98,8 @@ impl NewUserAddress {
             subdistrict,
             postal_code,
             address_detail,
+            latitude: None,
+            longitude: None,
         }
     }
 }
@@ -86,6 +112,8 @@ pub struct UpdateUserAddress {
     pub subdistrict: Option<String>,
     pub postal_code: Option<String>,
     pub address_detail: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -97,9 +125,29 @@ pub struct UserAddressRequest {
     pub subdistrict: Option<String>,
     pub postal_code: Option<String>,
     pub address_detail: Option<String>,
+    /// ต้องส่งมาคู่กัน (latitude + longitude)
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 impl UserAddressRequest {
+    /// ตรวจพิกัด - ต้องมีทั้งคู่หรือไม่มีเลย และอยู่ในช่วงที่ถูกต้อง
+    pub fn validate_coordinates(&self) -> anyhow::Result<()> {
+        match (self.latitude, self.longitude) {
+            (None, None) => Ok(()),
+            (Some(latitude), Some(longitude)) => GeoPoint::new(latitude, longitude)
+                .map(|_| ())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Validation error: latitude must be within -90..90 and longitude within -180..180"
+                    )
+                }),
+            _ => Err(anyhow::anyhow!(
+                "Validation error: latitude and longitude must be provided together"
+            )),
+        }
+    }
+
     /// แปลง Request DTO เป็น NewUserAddress พร้อม user_id
     pub fn into_new_address(self, user_id: Uuid) -> NewUserAddress {
         NewUserAddress {
@@ -110,6 +158,8 @@ impl UserAddressRequest {
             subdistrict: self.subdistrict,
             postal_code: self.postal_code,
             address_detail: self.address_detail,
+            latitude: self.latitude,
+            longitude: self.longitude,
         }
     }
 
@@ -121,6 +171,8 @@ impl UserAddressRequest {
             subdistrict: self.subdistrict,
             postal_code: self.postal_code,
             address_detail: self.address_detail,
+            latitude: self.latitude,
+            longitude: self.longitude,
             updated_at: Some(Utc::now()),
         }
     }
@@ -1,4 +1,7 @@
-use crate::infrastructure::postgres::schema::user_job_matches;
+use crate::{
+    domain::entities::company_post::JobSalary,
+    infrastructure::postgres::schema::user_job_matches,
+};
 use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -17,6 +20,16 @@ pub struct UserJobMatchEntity {
     pub updated_at: DateTime<Utc>,
 }
 
+/// ผลจับคู่พร้อมชื่อตำแหน่งและเงินเดือนของงาน
+#[derive(Debug, Clone, Serialize)]
+pub struct UserJobMatchWithJob {
+    #[serde(flatten)]
+    pub job_match: UserJobMatchEntity,
+    pub job_title: String,
+    #[serde(flatten)]
+    pub salary: JobSalary,
+}
+
 #[derive(Debug, Clone, Insertable, Serialize, Deserialize)]
 #[diesel(table_name = user_job_matches)]
 pub struct NewUserJobMatch {
@@ -0,0 +1,104 @@
+use crate::infrastructure::postgres::schema::{mfa_recovery_codes, user_mfa};
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// จำนวน recovery codes ที่ออกให้ต่อครั้ง
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+// =================================================================
+// 📊 UserMfa Entity
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = user_mfa)]
+#[diesel(primary_key(user_id))]
+pub struct UserMfa {
+    pub user_id: Uuid,
+
+    /// TOTP secret (base32)
+    pub totp_secret: String,
+
+    /// None = ลงทะเบียนแล้วแต่ยังไม่ยืนยันรหัสแรก
+    pub enabled_at: Option<DateTime<Utc>>,
+
+    /// time step ล่าสุดที่ใช้แล้ว
+    pub last_used_step: Option<i64>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserMfa {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+}
+
+// =================================================================
+// 🔑 Recovery codes
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = mfa_recovery_codes)]
+pub struct MfaRecoveryCode {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mfa_recovery_codes)]
+pub struct NewMfaRecoveryCode {
+    pub user_id: Uuid,
+    pub code_hash: String,
+}
+
+/// สร้าง recovery code ในรูป xxxxx-xxxxx
+pub fn generate_recovery_code() -> String {
+    let raw: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(10)
+        .map(|b| char::from(b).to_ascii_lowercase())
+        .collect();
+    format!("{}-{}", &raw[..5], &raw[5..])
+}
+
+/// ตัดช่องว่างและทำเป็นตัวพิมพ์เล็กก่อน hash
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.trim().to_lowercase()
+}
+
+// =================================================================
+// 📝 Request / Response DTOs
+// =================================================================
+
+/// ข้อมูลสำหรับตั้งค่าแอป authenticator
+#[derive(Debug, Clone, Serialize)]
+pub struct MfaEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+/// Recovery codes (แสดงครั้งเดียว)
+#[derive(Debug, Clone, Serialize)]
+pub struct MfaRecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MfaStatusResponse {
+    pub enabled: bool,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub recovery_codes_remaining: i64,
+}
+
+/// รหัส TOTP หรือ recovery code
+#[derive(Debug, Clone, Deserialize)]
+pub struct MfaCodeRequest {
+    pub code: String,
+}
@@ -0,0 +1,164 @@
+use crate::infrastructure::postgres::schema::user_sessions;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+// =================================================================
+// 🎯 Session scope (user / admin cookies ใช้ secret คนละชุด)
+// =================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionScope {
+    User,
+    Admin,
+}
+
+impl SessionScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionScope::User => "user",
+            SessionScope::Admin => "admin",
+        }
+    }
+}
+
+/// อายุของ session นับจาก login (rotate แล้วไม่ต่ออายุ)
+pub fn session_lifetime() -> Duration {
+    Duration::days(7)
+}
+
+// =================================================================
+// 💻 Device information ที่ได้จาก request ตอน login
+// =================================================================
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionDevice {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+// =================================================================
+// 📊 UserSession Entity (หนึ่งแถวต่อ refresh token)
+// =================================================================
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = user_sessions)]
+#[diesel(primary_key(jti))]
+pub struct UserSession {
+    /// Token id ของ refresh token
+    pub jti: Uuid,
+
+    /// Session family - token ทุกตัวที่ rotate ต่อกันมาจาก login เดียวกัน
+    pub family_id: Uuid,
+
+    pub user_id: Uuid,
+
+    /// user | admin
+    pub scope: String,
+
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+
+    /// เวลาที่ login (เหมือนกันทั้ง family)
+    pub session_started_at: DateTime<Utc>,
+
+    /// เวลาที่ออก token นี้ (ใช้เป็น last active ของ session)
+    pub created_at: DateTime<Utc>,
+
+    pub expires_at: DateTime<Utc>,
+
+    /// เวลาที่ token นี้ถูกแลกเป็น token ใหม่แล้ว
+    pub rotated_at: Option<DateTime<Utc>>,
+
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revoked_reason: Option<String>,
+}
+
+impl UserSession {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
+
+// =================================================================
+// ➕ NewUserSession (สำหรับสร้างข้อมูลใหม่)
+// =================================================================
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = user_sessions)]
+pub struct NewUserSession {
+    pub jti: Uuid,
+    pub family_id: Uuid,
+    pub user_id: Uuid,
+    pub scope: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub session_started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl NewUserSession {
+    /// เริ่ม session family ใหม่ตอน login
+    pub fn start(user_id: Uuid, scope: SessionScope, device: SessionDevice) -> Self {
+        let now = Utc::now();
+
+        Self {
+            jti: Uuid::new_v4(),
+            family_id: Uuid::new_v4(),
+            user_id,
+            scope: scope.as_str().to_string(),
+            user_agent: device.user_agent,
+            ip_address: device.ip_address,
+            session_started_at: now,
+            expires_at: now + session_lifetime(),
+        }
+    }
+
+    /// token ถัดไปใน family เดิม (อายุ session ไม่ถูกต่อ)
+    pub fn rotate_from(session: &UserSession) -> Self {
+        Self {
+            jti: Uuid::new_v4(),
+            family_id: session.family_id,
+            user_id: session.user_id,
+            scope: session.scope.clone(),
+            user_agent: session.user_agent.clone(),
+            ip_address: session.ip_address.clone(),
+            session_started_at: session.session_started_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
+// =================================================================
+// 📤 Response DTO
+// =================================================================
+
+/// Session ที่ยังใช้งานได้ของ user (หนึ่งรายการต่อ device/login)
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionResponse {
+    /// family_id - ใช้เป็น id สำหรับ revoke
+    pub id: Uuid,
+    pub scope: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub last_active_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub current: bool,
+}
+
+impl SessionResponse {
+    pub fn from_session(session: UserSession, current_family_id: Option<Uuid>) -> Self {
+        Self {
+            id: session.family_id,
+            current: current_family_id == Some(session.family_id),
+            scope: session.scope,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            started_at: session.session_started_at,
+            last_active_at: session.created_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::domain::entities::account_deletion::{AccountDeletion, NewAccountDeletion};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountDeletionRepository: Send + Sync {
+    async fn create(&self, new_deletion: &NewAccountDeletion) -> Result<AccountDeletion>;
+
+    /// คำขอล่าสุดของ user (ทุกสถานะ)
+    async fn find_latest(&self, user_id: Uuid) -> Result<Option<AccountDeletion>>;
+
+    async fn find_scheduled(&self, user_id: Uuid) -> Result<Option<AccountDeletion>>;
+
+    /// ยกเลิกคำขอที่ยังไม่พ้น grace period - None ถ้าไม่มีคำขอที่ยกเลิกได้
+    async fn cancel(&self, user_id: Uuid) -> Result<Option<AccountDeletion>>;
+
+    /// คำขอที่พ้น grace period แล้ว
+    async fn list_due(&self) -> Result<Vec<AccountDeletion>>;
+
+    /// URL ของไฟล์ที่ user อัปโหลดไว้ (profile, portfolio, โลโก้และแกลเลอรีบริษัท)
+    async fn stored_file_urls(&self, user_id: Uuid) -> Result<Vec<String>>;
+
+    /// ลบ / anonymize ข้อมูลทั้งหมดของ user ใน transaction เดียว
+    /// คืนค่า false ถ้าคำขอถูกยกเลิกไปก่อน (ไม่มีอะไรถูกลบ)
+    async fn erase_user(
+        &self,
+        deletion: &AccountDeletion,
+        password_hash: String,
+        storage_failures: Vec<String>,
+    ) -> Result<bool>;
+}
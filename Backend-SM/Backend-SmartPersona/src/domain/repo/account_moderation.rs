@@ -0,0 +1,32 @@
+use crate::domain::entities::{audit_event::NewAuditEvent, company::CompanyEntity, user::UserEntity};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+/// การกระทำของ admin ต่อบัญชี / บริษัท - บันทึก audit event ใน transaction เดียวกับการกระทำ
+/// (after_state ของ event กรอกจากผลลัพธ์ภายใน transaction)
+#[async_trait]
+pub trait AccountModerationRepository: Send + Sync {
+    /// ระงับบัญชี - บัญชีที่ลบแล้วระงับไม่ได้
+    async fn suspend_user(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity>;
+
+    /// คืนสถานะก่อนถูกระงับ - ใช้ได้กับบัญชีที่ถูกระงับอยู่เท่านั้น
+    async fn reactivate_user(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity>;
+
+    /// เปลี่ยนสถานะบริษัท (เช่น approved)
+    async fn set_company_status(
+        &self,
+        company_id: Uuid,
+        status: &str,
+        audit_event: NewAuditEvent,
+    ) -> Result<CompanyEntity>;
+
+    async fn delete_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<()>;
+
+    /// สถานะบริษัทเป็น banned และระงับบัญชีเจ้าของ
+    async fn ban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity>;
+
+    /// สถานะบริษัทกลับเป็น approved และคืนสถานะบัญชีเจ้าของ
+    /// บัญชีที่ไม่ได้ถูกระงับ (แบนก่อนมีการระงับบัญชีพร้อมบริษัท หรือลบแล้ว) คงไว้ตามเดิม
+    async fn unban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity>;
+}
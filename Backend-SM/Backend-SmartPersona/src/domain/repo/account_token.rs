@@ -0,0 +1,22 @@
+use crate::domain::entities::account_token::{AccountToken, NewAccountToken};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountTokenRepository: Send + Sync {
+    /// บันทึก token ใหม่
+    async fn create(&self, new_token: &NewAccountToken) -> Result<AccountToken>;
+
+    /// ค้นหา token จาก hash และประเภท
+    async fn find_by_hash(&self, token_hash: &str, purpose: &str) -> Result<Option<AccountToken>>;
+
+    /// ใช้ token (set used_at) - คืนค่า false ถ้า token ถูกใช้ไปแล้ว
+    async fn mark_used(&self, token_id: Uuid) -> Result<bool>;
+
+    /// ยกเลิก token ที่ยังไม่ถูกใช้ทั้งหมดของ user สำหรับประเภทนั้น
+    async fn invalidate_for_user(&self, user_id: Uuid, purpose: &str) -> Result<usize>;
+
+    /// ลบ token ที่หมดอายุแล้ว
+    async fn delete_expired(&self) -> Result<usize>;
+}
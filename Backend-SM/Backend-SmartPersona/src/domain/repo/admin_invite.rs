@@ -0,0 +1,23 @@
+use crate::domain::entities::{
+    admin_invite::{AdminInvite, NewAdminInvite},
+    user::RegisterUserEntity,
+};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AdminInviteRepository: Send + Sync {
+    /// บันทึกคำเชิญใหม่
+    async fn create(&self, new_invite: &NewAdminInvite) -> Result<AdminInvite>;
+
+    /// ค้นหาคำเชิญจาก hash ของ token
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<AdminInvite>>;
+
+    /// ตอบรับคำเชิญและสร้างบัญชี Admin ใน transaction เดียวกัน
+    /// คืนค่า None ถ้าคำเชิญถูกใช้ไปแล้วหรือหมดอายุ
+    async fn accept(&self, invite_id: Uuid, admin: RegisterUserEntity) -> Result<Option<Uuid>>;
+
+    /// ลบคำเชิญที่หมดอายุแล้วและยังไม่ถูกตอบรับ
+    async fn delete_expired(&self) -> Result<usize>;
+}
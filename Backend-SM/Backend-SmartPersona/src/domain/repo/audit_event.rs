@@ -0,0 +1,21 @@
+use crate::domain::entities::audit_event::{AuditEvent, AuditLogQuery, NewAuditEvent};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    /// เพิ่ม event (ตารางแก้ไข / ลบไม่ได้)
+    async fn append(&self, new_event: &NewAuditEvent) -> Result<AuditEvent>;
+
+    /// เติม status ของ response ให้ event ที่บันทึกไว้ก่อนทำ request (เติมได้ครั้งเดียว)
+    async fn set_response_status(&self, event_id: Uuid, status: u16) -> Result<AuditEvent>;
+
+    /// events ตาม filter ใหม่สุดก่อน คืนค่า (รายการในหน้านี้, จำนวนทั้งหมด)
+    async fn search(
+        &self,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<AuditEvent>, i64)>;
+}
@@ -0,0 +1,25 @@
+use crate::domain::entities::company_api_key::{CompanyApiKey, NewCompanyApiKey};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CompanyApiKeyRepository: Send + Sync {
+    /// companies.id ของบริษัทที่ user เป็นเจ้าของ
+    async fn company_id_for_owner(&self, user_id: Uuid) -> Result<Option<Uuid>>;
+
+    async fn create(&self, new_key: &NewCompanyApiKey) -> Result<CompanyApiKey>;
+
+    /// หา key ด้วย hash พร้อม users.id ของเจ้าของบริษัท
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<(CompanyApiKey, Uuid)>>;
+
+    async fn list_by_company(&self, company_id: Uuid) -> Result<Vec<CompanyApiKey>>;
+
+    async fn count_active(&self, company_id: Uuid) -> Result<usize>;
+
+    /// เพิกถอน key คืนค่า false ถ้าไม่พบหรือถูกเพิกถอนไปแล้ว
+    async fn revoke(&self, company_id: Uuid, key_id: Uuid) -> Result<bool>;
+
+    /// บันทึกการใช้งานล่าสุด (อัปเดตไม่เกินนาทีละครั้งต่อ key)
+    async fn touch(&self, key_id: Uuid, ip_address: Option<String>) -> Result<()>;
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+/// หาเจ้าของ (users.id ของ companies) ของ resource ฝั่งบริษัท
+/// คืนค่า None ถ้าไม่พบ resource
+#[async_trait]
+pub trait CompanyOwnershipRepository: Send + Sync {
+    async fn company_owner(&self, company_id: Uuid) -> Result<Option<Uuid>>;
+    async fn post_owner(&self, post_id: Uuid) -> Result<Option<Uuid>>;
+    async fn gallery_owner(&self, gallery_id: Uuid) -> Result<Option<Uuid>>;
+    async fn application_owner(&self, application_id: Uuid) -> Result<Option<Uuid>>;
+    /// ผู้สมัครเคยสมัครประกาศงานของบริษัทที่ owner_id เป็นเจ้าของหรือไม่
+    async fn has_applied_to_owner(&self, applicant_id: Uuid, owner_id: Uuid) -> Result<bool>;
+}
@@ -1,15 +1,43 @@
 use anyhow::Result;
 use axum::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::company_post::{CompanyPostEntity, NewCompanyPost, UpdateCompanyPost};
+use crate::domain::entities::company_post::{
+    CompanyPostEntity, JobSearchFilter, JobSearchHit, NewCompanyPost, NewScreeningQuestion,
+    PostClosingNotice, ScreeningQuestion, UpdateCompanyPost,
+};
 
 #[async_trait]
 pub trait CompanyPostRepository: Send + Sync {
     async fn create(&self, new_post: &NewCompanyPost) -> Result<CompanyPostEntity>;
-    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyPostEntity>>;
+    /// `public_only` = เฉพาะประกาศที่คนทั่วไปเห็นได้ (ไม่รวม draft / scheduled)
+    async fn get_by_company_id(&self, company_id: Uuid, public_only: bool) -> Result<Vec<CompanyPostEntity>>;
     async fn get_by_id(&self, id: Uuid) -> Result<Option<CompanyPostEntity>>;
-    async fn get_all(&self) -> Result<Vec<CompanyPostEntity>>;
+    /// ประกาศที่เผยแพร่อยู่ทั้งหมด
+    async fn get_published(&self) -> Result<Vec<CompanyPostEntity>>;
     async fn update(&self, id: Uuid, update_data: &UpdateCompanyPost) -> Result<CompanyPostEntity>;
     async fn delete(&self, id: Uuid) -> Result<()>;
+
+    /// ค้นหาประกาศที่เปิดรับสมัคร (ได้ไม่เกิน `filter.limit` แถว)
+    async fn search(&self, filter: &JobSearchFilter) -> Result<Vec<JobSearchHit>>;
+
+    /// scheduled ที่ถึง publish_at แล้ว → published - คืนประกาศที่เปลี่ยน
+    async fn publish_due(&self, now: DateTime<Utc>) -> Result<Vec<CompanyPostEntity>>;
+    /// published ที่ถึง closes_at แล้ว → expired - คืนประกาศที่เปลี่ยน
+    async fn expire_due(&self, now: DateTime<Utc>) -> Result<Vec<CompanyPostEntity>>;
+    /// ชื่อประกาศ / บริษัท และผู้สมัครที่ใบสมัครยังไม่จบ
+    async fn find_closing_notice(&self, post_id: Uuid) -> Result<Option<PostClosingNotice>>;
+
+    /// สร้าง search_vector ให้ประกาศที่ยังไม่ถูก index - คืนค่าจำนวนที่ทำ
+    async fn index_pending(&self, batch_size: i64) -> Result<usize>;
+
+    /// คำถามคัดกรองของประกาศ เรียงตาม position
+    async fn find_screening_questions(&self, post_id: Uuid) -> Result<Vec<ScreeningQuestion>>;
+    /// แทนที่คำถามเดิมทั้งชุดใน transaction เดียวกัน
+    async fn replace_screening_questions(
+        &self,
+        post_id: Uuid,
+        questions: &[NewScreeningQuestion],
+    ) -> Result<Vec<ScreeningQuestion>>;
 }
@@ -0,0 +1,46 @@
+use crate::domain::entities::data_export::{
+    CompletedDataExport, DataExport, DataExportSection, NewDataExport,
+};
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait DataExportRepository: Send + Sync {
+    async fn create(&self, new_export: &NewDataExport) -> Result<DataExport>;
+
+    async fn find_for_user(&self, export_id: Uuid, user_id: Uuid) -> Result<Option<DataExport>>;
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<DataExport>>;
+
+    /// จำนวนคำขอของ user ตั้งแต่เวลาที่กำหนด
+    async fn count_requested_since(&self, user_id: Uuid, since: DateTime<Utc>) -> Result<i64>;
+
+    /// มีงานที่ยัง pending / processing อยู่หรือไม่
+    async fn has_in_progress(&self, user_id: Uuid) -> Result<bool>;
+
+    /// งานที่รอทำ รวมถึงงาน processing ที่เริ่มก่อน `stale_before` (ถูกทิ้งค้าง)
+    async fn list_claimable(&self, stale_before: DateTime<Utc>) -> Result<Vec<Uuid>>;
+
+    /// เปลี่ยนสถานะเป็น processing คืนค่า user_id - None ถ้ามี worker อื่นรับงานไปแล้ว
+    async fn claim(&self, export_id: Uuid, stale_before: DateTime<Utc>) -> Result<Option<Uuid>>;
+
+    /// รวบรวมข้อมูลส่วนบุคคลทุกหมวดของ user (ไม่รวม password hash / token / secret)
+    async fn collect_sections(&self, user_id: Uuid) -> Result<Vec<DataExportSection>>;
+
+    async fn complete(&self, export_id: Uuid, completed: &CompletedDataExport) -> Result<()>;
+
+    async fn fail(&self, export_id: Uuid, error_message: &str) -> Result<()>;
+
+    /// แทนที่ hash ของ token ลิงก์ดาวน์โหลด - false ถ้า export ไม่พร้อมดาวน์โหลดแล้ว
+    async fn set_download_token_hash(&self, export_id: Uuid, download_token_hash: &str) -> Result<bool>;
+
+    /// หา export ที่ดาวน์โหลดได้จาก hash ของ token พร้อมตัวไฟล์
+    async fn find_downloadable(&self, download_token_hash: &str) -> Result<Option<(DataExport, Vec<u8>)>>;
+
+    async fn record_download(&self, export_id: Uuid) -> Result<()>;
+
+    /// ลบไฟล์และ token ของ export ที่หมดอายุ คืนค่าจำนวนที่เปลี่ยนสถานะ
+    async fn expire_old(&self) -> Result<u64>;
+}
@@ -0,0 +1,53 @@
+use crate::domain::entities::interview::{
+    CalendarFeed, Interview, InterviewDetails, InterviewParticipants, InterviewSlot,
+    InterviewWithSlots, NewInterview,
+};
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait InterviewRepository: Send + Sync {
+    /// ผู้สมัคร / บริษัท / ตำแหน่งงานของใบสมัคร - None ถ้าไม่พบใบสมัคร
+    async fn find_participants(&self, application_id: Uuid) -> Result<Option<InterviewParticipants>>;
+
+    /// สร้างนัดพร้อมช่วงเวลาที่เสนอใน transaction เดียวกัน
+    async fn create(
+        &self,
+        new_interview: &NewInterview,
+        slots: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Result<InterviewWithSlots>;
+
+    async fn find_by_id(&self, interview_id: Uuid) -> Result<Option<InterviewWithSlots>>;
+
+    async fn find_by_application(&self, application_id: Uuid) -> Result<Vec<InterviewWithSlots>>;
+
+    /// ผู้สมัครเลือกช่วงเวลา - None ถ้านัดไม่ได้อยู่ในสถานะ proposed แล้ว
+    async fn confirm(&self, interview_id: Uuid, slot: &InterviewSlot) -> Result<Option<Interview>>;
+
+    /// แทนที่ช่วงเวลาทั้งหมดและกลับไปรอผู้สมัครเลือก - None ถ้านัดถูกยกเลิกไปแล้ว
+    async fn reschedule(
+        &self,
+        interview_id: Uuid,
+        details: &InterviewDetails,
+        slots: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Result<Option<InterviewWithSlots>>;
+
+    /// None ถ้านัดถูกยกเลิกไปแล้ว
+    async fn cancel(&self, interview_id: Uuid, reason: Option<String>) -> Result<Option<Interview>>;
+
+    /// นัดที่เลือกเวลาแล้ว (รวมที่ยกเลิกทีหลัง) ของ user ทั้งในฐานะผู้สมัครและบริษัท
+    async fn find_calendar_entries(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(Interview, InterviewParticipants)>>;
+
+    async fn find_feed(&self, user_id: Uuid) -> Result<Option<CalendarFeed>>;
+
+    async fn find_feed_by_token_hash(&self, feed_token_hash: &str) -> Result<Option<CalendarFeed>>;
+
+    /// สร้างหรือแทนที่ token ของ feed (URL เดิมใช้ไม่ได้อีก)
+    async fn save_feed(&self, feed: &CalendarFeed) -> Result<CalendarFeed>;
+}
@@ -1,13 +1,43 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use uuid::Uuid;
-use crate::domain::entities::job_application::{JobApplicationEntity, NewJobApplication, JobApplicationWithUser};
+use crate::domain::entities::{
+    company_post::ScreeningQuestion,
+    job_application::{
+        ApplicationContacts, ApplicationStatusHistoryEntity, ApplyEligibility,
+        JobApplicationEntity, JobApplicationWithUser,
+        NewApplicationScreeningAnswer, NewApplicationStatusHistory, NewJobApplication,
+    },
+};
 
 #[async_trait]
 pub trait JobApplicationRepository: Send + Sync {
-    async fn create(&self, application: NewJobApplication) -> Result<JobApplicationEntity>;
-    async fn find_by_job_id(&self, job_id: Uuid) -> Result<Vec<JobApplicationWithUser>>;
+    /// สร้างใบสมัครพร้อมคำตอบคัดกรองและ history แรก (ยื่นใบสมัคร) ใน transaction เดียวกัน
+    async fn create(
+        &self,
+        application: NewJobApplication,
+        answers: Vec<NewApplicationScreeningAnswer>,
+    ) -> Result<JobApplicationEntity>;
+    async fn find_by_id(&self, application_id: Uuid) -> Result<Option<JobApplicationEntity>>;
+    /// ผู้สมัครของประกาศพร้อมคำตอบคัดกรอง - `knocked_out` = Some(..) เพื่อกรองตามผลคัดกรอง
+    async fn find_by_job_id(
+        &self,
+        job_id: Uuid,
+        knocked_out: Option<bool>,
+    ) -> Result<Vec<JobApplicationWithUser>>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<JobApplicationEntity>>;
     async fn check_existing(&self, user_id: Uuid, job_id: Uuid) -> Result<bool>;
-    async fn update_status(&self, application_id: Uuid, status: String) -> Result<JobApplicationEntity>;
+    /// ข้อมูลประกาศ / บริษัท / ผู้สมัคร สำหรับตรวจเงื่อนไขการสมัคร - None ถ้าไม่มีประกาศนี้
+    async fn find_apply_eligibility(&self, user_id: Uuid, job_id: Uuid) -> Result<Option<ApplyEligibility>>;
+    async fn find_contacts(&self, application_id: Uuid) -> Result<Option<ApplicationContacts>>;
+    /// เปลี่ยนสถานะเมื่อสถานะปัจจุบันยังเป็น `from_status` และบันทึก history
+    /// - None ถ้ามีคนเปลี่ยนไปก่อนแล้ว
+    async fn transition_status(
+        &self,
+        from_status: &str,
+        history: NewApplicationStatusHistory,
+    ) -> Result<Option<JobApplicationEntity>>;
+    /// คำถามคัดกรองของประกาศที่ผู้สมัครต้องตอบ
+    async fn find_screening_questions(&self, job_id: Uuid) -> Result<Vec<ScreeningQuestion>>;
+    async fn find_status_history(&self, application_id: Uuid) -> Result<Vec<ApplicationStatusHistoryEntity>>;
 }
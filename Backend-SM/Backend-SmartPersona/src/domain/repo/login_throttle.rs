@@ -0,0 +1,28 @@
+use crate::domain::entities::login_throttle::{LoginLockout, LoginLockoutEvent, NewLoginLockoutEvent};
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+#[async_trait]
+pub trait LoginThrottleRepository: Send + Sync {
+    /// นับ request เข้า bucket (fixed window) คืนค่าจำนวนใน window ปัจจุบันและเวลาเริ่ม window
+    async fn hit(&self, bucket_key: &str, window: Duration) -> Result<(i32, DateTime<Utc>)>;
+
+    async fn find_lockout(&self, username: &str) -> Result<Option<LoginLockout>>;
+
+    /// เพิ่มจำนวน login ผิดของ username และคืนค่าสถานะล่าสุด
+    async fn record_failure(&self, username: &str, ip_address: Option<String>) -> Result<LoginLockout>;
+
+    /// ล็อกบัญชี (รีเซ็ตตัวนับ) และบันทึกประวัติใน transaction เดียวกัน
+    async fn lock(&self, event: &NewLoginLockoutEvent) -> Result<()>;
+
+    /// ล้างสถานะการล็อกของ username (login สำเร็จ หรือ Admin ปลดล็อก)
+    async fn clear(&self, username: &str) -> Result<bool>;
+
+    async fn list_active_lockouts(&self) -> Result<Vec<LoginLockout>>;
+
+    async fn list_events(&self, limit: i64) -> Result<Vec<LoginLockoutEvent>>;
+
+    /// ลบ bucket ที่ window หมดไปแล้ว
+    async fn delete_stale_buckets(&self, before: DateTime<Utc>) -> Result<usize>;
+}
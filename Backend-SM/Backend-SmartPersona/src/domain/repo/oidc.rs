@@ -0,0 +1,38 @@
+use crate::domain::entities::{
+    oidc::{NewOidcAuthRequest, NewUserIdentity, OidcAuthRequest, UserIdentity},
+    user::RegisterUserEntity,
+};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait OidcRepository: Send + Sync {
+    async fn create_auth_request(&self, new_request: &NewOidcAuthRequest) -> Result<()>;
+
+    /// ดึงคำขอด้วย hash ของ state และลบทิ้งทันที (ใช้ได้ครั้งเดียว)
+    async fn take_auth_request(&self, state_hash: &str) -> Result<Option<OidcAuthRequest>>;
+
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<UserIdentity>>;
+
+    /// ผูกบัญชี OIDC กับ user ที่มีอยู่แล้ว
+    async fn create_identity(&self, new_identity: &NewUserIdentity) -> Result<UserIdentity>;
+
+    /// สร้าง user ใหม่พร้อมผูกบัญชี OIDC ใน transaction เดียวกัน
+    async fn create_user_with_identity(
+        &self,
+        user: RegisterUserEntity,
+        provider: String,
+        subject: String,
+    ) -> Result<Uuid>;
+
+    async fn touch_identity(&self, identity_id: Uuid) -> Result<()>;
+
+    async fn list_identities(&self, user_id: Uuid) -> Result<Vec<UserIdentity>>;
+
+    /// ยกเลิกการผูกบัญชี คืนค่า false ถ้าไม่พบ
+    async fn delete_identity(&self, user_id: Uuid, provider: &str) -> Result<bool>;
+
+    /// ลบคำขอที่หมดอายุแล้ว
+    async fn delete_expired_auth_requests(&self) -> Result<usize>;
+}
@@ -0,0 +1,70 @@
+use crate::domain::entities::saved_search::{
+    NewSavedSearch, PendingAlertPost, SavedSearch, UpdateSavedSearch,
+};
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SavedSearchRepository: Send + Sync {
+    async fn create(&self, new_saved_search: &NewSavedSearch) -> Result<SavedSearch>;
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<SavedSearch>>;
+
+    async fn count_by_user(&self, user_id: Uuid) -> Result<i64>;
+
+    /// None ถ้าไม่พบหรือเป็นของ user อื่น
+    async fn find_by_id(&self, id: Uuid, user_id: Uuid) -> Result<Option<SavedSearch>>;
+
+    /// ลบประกาศที่รอส่งทิ้งด้วยถ้าเปลี่ยนเงื่อนไขหรือปิดแจ้งเตือน
+    async fn update(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        changes: &UpdateSavedSearch,
+    ) -> Result<Option<SavedSearch>>;
+
+    /// false ถ้าไม่พบ
+    async fn delete(&self, id: Uuid, user_id: Uuid) -> Result<bool>;
+
+    /// เก็บ hash ของ token ลิงก์ยกเลิกใน digest ฉบับใหม่ และลบ token ที่เก่ากว่า `expired_before`
+    /// token ของ digest ทั้งฉบับถูกเพิ่มให้ทุกการค้นหาใน digest ด้วย hash เดียวกัน
+    async fn add_unsubscribe_token(
+        &self,
+        saved_search_id: Uuid,
+        token_hash: &str,
+        expired_before: DateTime<Utc>,
+    ) -> Result<()>;
+
+    /// ปิดแจ้งเตือนทุกการค้นหาของ token จากลิงก์ในอีเมล - ว่างถ้า token ไม่ถูกต้อง
+    async fn unsubscribe(&self, token_hash: &str) -> Result<Vec<SavedSearch>>;
+
+    /// การค้นหาที่เปิดแจ้งเตือนอยู่ (ตัวจับคู่)
+    async fn find_alerting(&self) -> Result<Vec<SavedSearch>>;
+
+    /// บันทึกประกาศที่ตรงเงื่อนไข (ข้ามคู่ที่มีอยู่แล้ว) และเลื่อน matched_until
+    async fn record_matches(
+        &self,
+        saved_search_id: Uuid,
+        post_ids: &[Uuid],
+        matched_until: DateTime<Utc>,
+    ) -> Result<usize>;
+
+    /// การค้นหาที่เปิดแจ้งเตือนและมีประกาศรอส่ง
+    async fn find_with_pending_matches(&self) -> Result<Vec<SavedSearch>>;
+
+    /// ประกาศที่รอส่งของการค้นหาเหล่านี้ - เฉพาะที่ยังเปิดรับสมัคร
+    /// และยังไม่เคยส่งให้ user ผ่านการค้นหาอื่นมาก่อน
+    async fn find_pending_posts(
+        &self,
+        user_id: Uuid,
+        saved_search_ids: &[Uuid],
+    ) -> Result<Vec<PendingAlertPost>>;
+
+    /// ชื่อและอีเมลผู้รับ digest - อีเมลจากโปรไฟล์ก่อน แล้วจึงใช้ของบัญชี
+    async fn find_recipient(&self, user_id: Uuid) -> Result<(String, Option<String>)>;
+
+    /// ปิดรอบ digest - ประกาศที่รอส่งทั้งหมดถือว่าส่งแล้ว
+    async fn mark_sent(&self, saved_search_ids: &[Uuid], sent_at: DateTime<Utc>) -> Result<()>;
+}
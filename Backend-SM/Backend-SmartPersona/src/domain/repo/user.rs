@@ -1,4 +1,4 @@
-use crate::domain::{entities::user::{UserEntity,RegisterUserEntity}};
+use crate::domain::{entities::user::{UserEntity,RegisterUserEntity,Role}};
 use anyhow::Result;
 use axum::async_trait;
 use uuid::Uuid;
@@ -8,4 +8,11 @@ pub trait UserRepository {
     async fn find_by_username(&self,username:String) -> Result<UserEntity>;
     async fn find_by_id(&self,user_id:Uuid) -> Result<UserEntity>;
     async fn update_status(&self, user_id: Uuid, status: String) -> Result<UserEntity>;
+    /// ระงับบัญชีโดยจำสถานะเดิมไว้ - บัญชีที่ลบแล้วระงับไม่ได้
+    async fn suspend(&self, user_id: Uuid) -> Result<UserEntity>;
+    /// คืนสถานะก่อนถูกระงับ - ใช้ได้กับบัญชีที่ถูกระงับอยู่เท่านั้น
+    async fn reactivate(&self, user_id: Uuid) -> Result<UserEntity>;
+    async fn find_by_email(&self, email: String) -> Result<Option<UserEntity>>;
+    async fn update_password(&self, user_id: Uuid, password_hash: String) -> Result<()>;
+    async fn exists_with_role(&self, role: Role) -> Result<bool>;
 }
\ No newline at end of file
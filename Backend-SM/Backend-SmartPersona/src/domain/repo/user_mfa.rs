@@ -0,0 +1,30 @@
+use crate::domain::entities::user_mfa::UserMfa;
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UserMfaRepository: Send + Sync {
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserMfa>>;
+
+    /// บันทึก secret ใหม่ที่ยังไม่ยืนยัน (แทนที่ secret เดิมที่ยังไม่ได้เปิดใช้งาน)
+    async fn save_pending_secret(&self, user_id: Uuid, totp_secret: &str) -> Result<UserMfa>;
+
+    /// เปิดใช้งาน MFA และบันทึก recovery codes ใน transaction เดียวกัน
+    /// คืนค่า false ถ้าเปิดใช้งานไปแล้ว
+    async fn enable(&self, user_id: Uuid, used_step: i64, recovery_code_hashes: Vec<String>) -> Result<bool>;
+
+    /// บันทึก time step ที่ใช้แล้ว - คืนค่า false ถ้า step นี้ (หรือใหม่กว่า) ถูกใช้ไปแล้ว
+    async fn record_used_step(&self, user_id: Uuid, step: i64) -> Result<bool>;
+
+    /// ออก recovery codes ชุดใหม่ (ชุดเดิมใช้ไม่ได้อีก)
+    async fn replace_recovery_codes(&self, user_id: Uuid, recovery_code_hashes: Vec<String>) -> Result<()>;
+
+    /// ใช้ recovery code - คืนค่า false ถ้าไม่พบหรือถูกใช้ไปแล้ว
+    async fn use_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool>;
+
+    async fn count_unused_recovery_codes(&self, user_id: Uuid) -> Result<i64>;
+
+    /// ปิด MFA - ลบ secret และ recovery codes ทั้งหมด
+    async fn delete(&self, user_id: Uuid) -> Result<()>;
+}
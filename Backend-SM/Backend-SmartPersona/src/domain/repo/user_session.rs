@@ -0,0 +1,38 @@
+use crate::domain::entities::user_session::{NewUserSession, UserSession};
+use anyhow::Result;
+use axum::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UserSessionRepository: Send + Sync {
+    /// บันทึก refresh token ของ session ใหม่
+    async fn create(&self, new_session: &NewUserSession) -> Result<UserSession>;
+
+    /// ค้นหา refresh token จาก jti
+    async fn find_by_jti(&self, jti: Uuid) -> Result<Option<UserSession>>;
+
+    /// Mark token เดิมว่า rotate แล้ว และบันทึก token ใหม่ใน transaction เดียวกัน
+    /// คืนค่า None ถ้า token เดิมถูก rotate หรือ revoke ไปแล้ว (ถูกใช้ซ้ำ)
+    async fn rotate(&self, old_jti: Uuid, new_session: &NewUserSession) -> Result<Option<UserSession>>;
+
+    /// Revoke ทุก token ใน session family ของ user
+    async fn revoke_family(&self, user_id: Uuid, family_id: Uuid, reason: &str) -> Result<usize>;
+
+    /// Revoke ทุก session ของ user ยกเว้น family ที่ระบุ (ถ้ามี)
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        except_family_id: Option<Uuid>,
+        reason: &str,
+    ) -> Result<usize>;
+
+    /// session family ยังใช้งานได้ (ยังไม่ถูก revoke และ refresh token ยังไม่หมดอายุ)
+    /// ใช้ตรวจ `sid` ของ access token ใน middleware
+    async fn is_family_active(&self, user_id: Uuid, family_id: Uuid) -> Result<bool>;
+
+    /// ดึง token ล่าสุดของแต่ละ session ที่ยังใช้งานได้
+    async fn list_active_by_user(&self, user_id: Uuid) -> Result<Vec<UserSession>>;
+
+    /// ลบ token ที่หมดอายุแล้ว
+    async fn delete_expired(&self) -> Result<usize>;
+}
@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Duration;
+use rand::{Rng, distributions::Alphanumeric};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            account_deletion::{AccountDeletion, NewAccountDeletion, RequestAccountDeletionRequest},
+            user::{Role, UserStatusEnum},
+        },
+        repo::{account_deletion::AccountDeletionRepository, user::UserRepository},
+    },
+    infrastructure::{hashingpassword, supabase::storage::StorageService},
+};
+
+/// ไฟล์ที่ upload ผ่าน `StorageService` - URL ภายนอก (เช่นรูปจาก OIDC provider) ไม่ต้องลบ
+const STORAGE_PUBLIC_PATH: &str = "/storage/v1/object/public/";
+
+pub struct AccountDeletionUseCase<T, U>
+where
+    T: AccountDeletionRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+{
+    account_deletion_repository: Arc<T>,
+    user_repository: Arc<U>,
+    storage_service: Arc<StorageService>,
+    grace_period: Duration,
+}
+
+impl<T, U> AccountDeletionUseCase<T, U>
+where
+    T: AccountDeletionRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+{
+    pub fn new(
+        account_deletion_repository: Arc<T>,
+        user_repository: Arc<U>,
+        storage_service: Arc<StorageService>,
+        grace_period: Duration,
+    ) -> Self {
+        Self {
+            account_deletion_repository,
+            user_repository,
+            storage_service,
+            grace_period,
+        }
+    }
+
+    // =================================================================
+    // 📥 คำขอของผู้ใช้
+    // =================================================================
+
+    /// ตั้งเวลาลบบัญชี - ข้อมูลยังอยู่ครบจนพ้น grace period และยกเลิกได้ตลอดช่วงนั้น
+    pub async fn request_deletion(
+        &self,
+        user_id: Uuid,
+        request: RequestAccountDeletionRequest,
+    ) -> Result<AccountDeletion> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let user = self.user_repository.find_by_id(user_id).await?;
+
+        if user.status == UserStatusEnum::Deleted {
+            return Err(anyhow::anyhow!("User not found"));
+        }
+        // admin ต้องถูกถอดสิทธิ์ก่อน - กันระบบไม่เหลือ admin
+        if user.role == Role::Admin {
+            return Err(anyhow::anyhow!("Forbidden: admin accounts cannot be self-deleted"));
+        }
+        if request.confirm_username.trim() != user.username {
+            return Err(anyhow::anyhow!(
+                "Validation error: confirm_username does not match your username"
+            ));
+        }
+
+        if self
+            .account_deletion_repository
+            .find_scheduled(user_id)
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Account deletion is already scheduled"));
+        }
+
+        self.account_deletion_repository
+            .create(&NewAccountDeletion::new(
+                user_id,
+                request.reason,
+                self.grace_period,
+            ))
+            .await
+    }
+
+    pub async fn get_status(&self, user_id: Uuid) -> Result<AccountDeletion> {
+        self.account_deletion_repository
+            .find_latest(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Account deletion request not found"))
+    }
+
+    pub async fn cancel(&self, user_id: Uuid) -> Result<AccountDeletion> {
+        self.account_deletion_repository
+            .cancel(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Pending account deletion request not found"))
+    }
+
+    // =================================================================
+    // ⚙️ Worker
+    // =================================================================
+
+    /// ลบบัญชีที่พ้น grace period แล้ว คืนค่าจำนวนบัญชีที่ลบ
+    pub async fn process_due(&self) -> Result<usize> {
+        let due = self.account_deletion_repository.list_due().await?;
+
+        let mut erased = 0;
+        for deletion in due {
+            match self.erase(&deletion).await {
+                Ok(true) => erased += 1,
+                Ok(false) => {}
+                // บัญชีอื่นยังทำต่อได้ - รอบหน้าจะลองใหม่
+                Err(e) => tracing::error!(
+                    "Account deletion {} for user {} failed: {:?}",
+                    deletion.id,
+                    deletion.user_id,
+                    e
+                ),
+            }
+        }
+
+        Ok(erased)
+    }
+
+    async fn erase(&self, deletion: &AccountDeletion) -> Result<bool> {
+        // ลบไฟล์ก่อน เพราะหลังลบข้อมูลใน database จะไม่เหลือ URL ให้ตามลบ
+        let file_urls = self
+            .account_deletion_repository
+            .stored_file_urls(deletion.user_id)
+            .await?;
+
+        let mut storage_failures = Vec::new();
+        for url in file_urls
+            .into_iter()
+            .filter(|url| url.contains(STORAGE_PUBLIC_PATH))
+        {
+            if let Err(e) = self.storage_service.delete_file(url.clone()).await {
+                tracing::warn!("Failed to delete stored file {}: {}", url, e);
+                storage_failures.push(url);
+            }
+        }
+
+        // tombstone ไม่มีรหัสผ่านที่ใช้ได้
+        let unusable_password: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(64)
+            .map(char::from)
+            .collect();
+        let password_hash = hashingpassword::hash(unusable_password)?;
+
+        let failed_files = storage_failures.len();
+        let erased = self
+            .account_deletion_repository
+            .erase_user(deletion, password_hash, storage_failures)
+            .await?;
+
+        if erased {
+            tracing::info!(
+                "Erased account {} (deletion {}, {} file(s) left in storage)",
+                deletion.user_id,
+                deletion.id,
+                failed_files
+            );
+        }
+
+        Ok(erased)
+    }
+}
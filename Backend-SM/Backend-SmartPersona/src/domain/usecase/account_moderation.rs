@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{audit_event::NewAuditEvent, company::CompanyEntity, user::UserEntity},
+    repo::{
+        account_moderation::AccountModerationRepository, user_session::UserSessionRepository,
+        user_share::ProfileShareRepository,
+    },
+};
+
+/// ระงับ / เปิดใช้งานบัญชีและบริษัท พร้อมเพิกถอนสิทธิ์การเข้าถึงที่ยังค้างอยู่
+/// ทุกการกระทำบันทึก audit event ใน transaction เดียวกัน (บันทึกไม่ได้ = ไม่มีการเปลี่ยนแปลง)
+pub struct AccountModerationUseCase<TModeration, TSession, TShare>
+where
+    TModeration: AccountModerationRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TShare: ProfileShareRepository + Send + Sync,
+{
+    account_moderation_repository: Arc<TModeration>,
+    user_session_repository: Arc<TSession>,
+    profile_share_repository: Arc<TShare>,
+}
+
+impl<TModeration, TSession, TShare> AccountModerationUseCase<TModeration, TSession, TShare>
+where
+    TModeration: AccountModerationRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TShare: ProfileShareRepository + Send + Sync,
+{
+    pub fn new(
+        account_moderation_repository: Arc<TModeration>,
+        user_session_repository: Arc<TSession>,
+        profile_share_repository: Arc<TShare>,
+    ) -> Self {
+        Self {
+            account_moderation_repository,
+            user_session_repository,
+            profile_share_repository,
+        }
+    }
+
+    /// ระงับบัญชี: เปลี่ยนสถานะเป็น suspended, revoke refresh token ทุก session
+    /// และปิด share link ทั้งหมดของ user
+    pub async fn suspend_account(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity> {
+        let user = self
+            .account_moderation_repository
+            .suspend_user(user_id, audit_event)
+            .await?;
+
+        self.revoke_access(user_id).await?;
+        Ok(user)
+    }
+
+    /// เปิดใช้งานบัญชีอีกครั้ง (session และ share link เดิมไม่ถูกคืน ต้อง login / สร้างใหม่)
+    /// คืนสถานะก่อนถูกระงับ - บัญชีที่ยังไม่ยืนยันอีเมลกลับเป็น pending
+    pub async fn reactivate_account(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity> {
+        self.account_moderation_repository
+            .reactivate_user(user_id, audit_event)
+            .await
+    }
+
+    pub async fn approve_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity> {
+        self.account_moderation_repository
+            .set_company_status(company_id, "approved", audit_event)
+            .await
+    }
+
+    pub async fn reject_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<()> {
+        self.account_moderation_repository
+            .delete_company(company_id, audit_event)
+            .await
+    }
+
+    /// แบนบริษัทและระงับบัญชีเจ้าของ ให้มีผลทันที
+    pub async fn ban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity> {
+        let company = self
+            .account_moderation_repository
+            .ban_company(company_id, audit_event)
+            .await?;
+
+        self.revoke_access(company.user_id).await?;
+        Ok(company)
+    }
+
+    pub async fn unban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity> {
+        self.account_moderation_repository
+            .unban_company(company_id, audit_event)
+            .await
+    }
+
+    /// revoke refresh token ทุก session และปิด share link ทั้งหมดของบัญชีที่ถูกระงับ
+    async fn revoke_access(&self, user_id: Uuid) -> Result<()> {
+        let revoked_sessions = self
+            .user_session_repository
+            .revoke_all_for_user(user_id, None, "account_suspended")
+            .await?;
+
+        let deactivated_shares = self
+            .profile_share_repository
+            .deactivate_all_user_shares(user_id)
+            .await?;
+
+        tracing::info!(
+            "Account suspended: user_id={}, revoked_sessions={}, deactivated_shares={}",
+            user_id,
+            revoked_sessions,
+            deactivated_shares
+        );
+
+        Ok(())
+    }
+}
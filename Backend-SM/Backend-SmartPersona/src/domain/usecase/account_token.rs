@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::{AccountTokenPurpose, NewAccountToken, hash_token},
+            user::{UserEntity, UserStatusEnum},
+        },
+        repo::{
+            account_token::AccountTokenRepository, user::UserRepository,
+            user_session::UserSessionRepository,
+        },
+    },
+    infrastructure::{email::EmailService, hashingpassword},
+};
+
+pub struct AccountTokenUseCase<TUser, TToken, TSession>
+where
+    TUser: UserRepository + Send + Sync,
+    TToken: AccountTokenRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+{
+    user_repository: Arc<TUser>,
+    account_token_repository: Arc<TToken>,
+    user_session_repository: Arc<TSession>,
+    email_service: Arc<EmailService>,
+}
+
+impl<TUser, TToken, TSession> AccountTokenUseCase<TUser, TToken, TSession>
+where
+    TUser: UserRepository + Send + Sync,
+    TToken: AccountTokenRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+{
+    pub fn new(
+        user_repository: Arc<TUser>,
+        account_token_repository: Arc<TToken>,
+        user_session_repository: Arc<TSession>,
+        email_service: Arc<EmailService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            account_token_repository,
+            user_session_repository,
+            email_service,
+        }
+    }
+
+    // =================================================================
+    // ✉️ Email verification
+    // =================================================================
+
+    /// ส่งอีเมลยืนยันให้ user ที่เพิ่งสมัคร (เรียกหลัง register)
+    pub async fn send_verification_for_user(&self, user_id: Uuid) -> Result<()> {
+        let user = self.user_repository.find_by_id(user_id).await?;
+        self.issue_and_send(&user, AccountTokenPurpose::EmailVerification)
+            .await
+    }
+
+    /// ขอส่งอีเมลยืนยันใหม่ - ไม่ error ถ้าไม่พบบัญชี เพื่อไม่เปิดเผยว่ามีอีเมลนี้ในระบบ
+    pub async fn request_email_verification(&self, email: String) -> Result<()> {
+        match self.user_repository.find_by_email(email).await? {
+            Some(user) if user.status == UserStatusEnum::Pending => {
+                self.issue_and_send(&user, AccountTokenPurpose::EmailVerification)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// ยืนยันอีเมลด้วย token แล้วเปลี่ยนสถานะบัญชีเป็น Active
+    pub async fn confirm_email(&self, token: &str) -> Result<()> {
+        let user_id = self
+            .consume_token(token, AccountTokenPurpose::EmailVerification)
+            .await?;
+
+        let user = self.user_repository.find_by_id(user_id).await?;
+
+        // ไม่ปลดแบนบัญชีที่ถูกระงับผ่านลิงก์ยืนยันอีเมล
+        match user.status {
+            UserStatusEnum::Pending => {
+                self.user_repository
+                    .update_status(user_id, "active".to_string())
+                    .await?;
+                Ok(())
+            }
+            UserStatusEnum::Active => Ok(()),
+            UserStatusEnum::Suspended => Err(anyhow::anyhow!("Account suspended")),
+            UserStatusEnum::Deleted => Err(anyhow::anyhow!("Invalid or expired token")),
+        }
+    }
+
+    // =================================================================
+    // 🔑 Password reset
+    // =================================================================
+
+    /// ขอรีเซ็ตรหัสผ่าน - ไม่ error ถ้าไม่พบบัญชี เพื่อไม่เปิดเผยว่ามีอีเมลนี้ในระบบ
+    pub async fn request_password_reset(&self, email: String) -> Result<()> {
+        match self.user_repository.find_by_email(email).await? {
+            Some(user)
+                if matches!(user.status, UserStatusEnum::Pending | UserStatusEnum::Active) =>
+            {
+                self.issue_and_send(&user, AccountTokenPurpose::PasswordReset)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// ตั้งรหัสผ่านใหม่ด้วย token แล้ว revoke ทุก session (กันคนที่ขโมย token ไปใช้ต่อ)
+    /// คืนค่า user_id เพื่อให้ล้าง cache ของ session ใน middleware
+    pub async fn reset_password(&self, token: &str, new_password: String) -> Result<Uuid> {
+        let user_id = self
+            .consume_token(token, AccountTokenPurpose::PasswordReset)
+            .await?;
+
+        let password_hash = hashingpassword::hash(new_password)?;
+        self.user_repository
+            .update_password(user_id, password_hash)
+            .await?;
+
+        // token รีเซ็ตอื่นที่ยังค้างอยู่ใช้ไม่ได้อีก
+        self.account_token_repository
+            .invalidate_for_user(user_id, AccountTokenPurpose::PasswordReset.as_str())
+            .await?;
+
+        let revoked_sessions = self
+            .user_session_repository
+            .revoke_all_for_user(user_id, None, "password_reset")
+            .await?;
+
+        tracing::info!(
+            "Password reset: user_id={}, revoked_sessions={}",
+            user_id,
+            revoked_sessions
+        );
+
+        Ok(user_id)
+    }
+
+    // =================================================================
+    // 🧹 Maintenance
+    // =================================================================
+
+    /// ลบ token ที่หมดอายุแล้ว
+    pub async fn cleanup_expired_tokens(&self) -> Result<usize> {
+        self.account_token_repository.delete_expired().await
+    }
+
+    // =================================================================
+    // 🛠️ Helpers
+    // =================================================================
+
+    /// ยกเลิก token เดิม สร้าง token ใหม่ และส่งอีเมล
+    async fn issue_and_send(&self, user: &UserEntity, purpose: AccountTokenPurpose) -> Result<()> {
+        let Some(email) = user.email.as_deref() else {
+            tracing::warn!("User {} has no email address, skipping {}", user.id, purpose.as_str());
+            return Ok(());
+        };
+
+        self.account_token_repository
+            .invalidate_for_user(user.id, purpose.as_str())
+            .await?;
+
+        let (new_token, raw_token) = NewAccountToken::generate(user.id, purpose);
+        self.account_token_repository.create(&new_token).await?;
+
+        if !self.email_service.is_configured() {
+            tracing::warn!("Email service not configured, {} email not sent", purpose.as_str());
+            return Ok(());
+        }
+
+        let result = match purpose {
+            AccountTokenPurpose::EmailVerification => {
+                self.email_service
+                    .send_email_verification_email(email, &user.username, &raw_token)
+                    .await
+            }
+            AccountTokenPurpose::PasswordReset => {
+                self.email_service
+                    .send_password_reset_email(email, &user.username, &raw_token)
+                    .await
+            }
+        };
+
+        if let Err(e) = result {
+            tracing::error!("Failed to send {} email: {}", purpose.as_str(), e);
+        }
+
+        Ok(())
+    }
+
+    /// ตรวจสอบ token และ mark ว่าใช้แล้ว คืนค่า user_id ของเจ้าของ token
+    async fn consume_token(&self, token: &str, purpose: AccountTokenPurpose) -> Result<Uuid> {
+        let account_token = self
+            .account_token_repository
+            .find_by_hash(&hash_token(token), purpose.as_str())
+            .await?
+            .filter(|account_token| account_token.is_usable())
+            .ok_or_else(|| anyhow::anyhow!("Invalid or expired token"))?;
+
+        if !self
+            .account_token_repository
+            .mark_used(account_token.id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("Invalid or expired token"));
+        }
+
+        Ok(account_token.user_id)
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            admin_invite::{
+                AcceptAdminInviteRequest, AdminInviteResponse, CreateAdminInviteRequest,
+                NewAdminInvite,
+            },
+            user::{RegisterUserEntity, Role, UserStatusEnum},
+        },
+        repo::{admin_invite::AdminInviteRepository, user::UserRepository},
+        value_object::user::normalize_email,
+    },
+    infrastructure::{email::EmailService, hashingpassword},
+};
+
+pub struct AdminInviteUseCase<TUser, TInvite>
+where
+    TUser: UserRepository + Send + Sync,
+    TInvite: AdminInviteRepository + Send + Sync,
+{
+    user_repository: Arc<TUser>,
+    admin_invite_repository: Arc<TInvite>,
+    email_service: Arc<EmailService>,
+}
+
+impl<TUser, TInvite> AdminInviteUseCase<TUser, TInvite>
+where
+    TUser: UserRepository + Send + Sync,
+    TInvite: AdminInviteRepository + Send + Sync,
+{
+    pub fn new(
+        user_repository: Arc<TUser>,
+        admin_invite_repository: Arc<TInvite>,
+        email_service: Arc<EmailService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            admin_invite_repository,
+            email_service,
+        }
+    }
+
+    /// Admin เชิญผู้ดูแลระบบคนใหม่ทางอีเมล
+    pub async fn create_invite(
+        &self,
+        invited_by: Uuid,
+        request: CreateAdminInviteRequest,
+    ) -> Result<AdminInviteResponse> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let email = normalize_email(&request.email);
+        if self
+            .user_repository
+            .find_by_email(email.clone())
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Email already registered"));
+        }
+
+        let (new_invite, raw_token) = NewAdminInvite::generate(email, invited_by);
+        let invite = self.admin_invite_repository.create(&new_invite).await?;
+
+        let email_sent = if self.email_service.is_configured() {
+            match self
+                .email_service
+                .send_admin_invite_email(&invite.email, &raw_token)
+                .await
+            {
+                Ok(_) => true,
+                Err(e) => {
+                    tracing::error!("Failed to send admin invite email: {}", e);
+                    false
+                }
+            }
+        } else {
+            tracing::warn!("Email service not configured, admin invite email not sent");
+            false
+        };
+
+        tracing::info!("Admin {} invited {} as admin", invited_by, invite.email);
+
+        Ok(AdminInviteResponse {
+            id: invite.id,
+            email: invite.email,
+            expires_at: invite.expires_at,
+            email_sent,
+        })
+    }
+
+    /// ตอบรับคำเชิญ - สร้างบัญชี Admin ที่เปิดใช้งานทันที
+    pub async fn accept_invite(&self, request: AcceptAdminInviteRequest) -> Result<Uuid> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let invite = self
+            .admin_invite_repository
+            .find_by_hash(&hash_token(&request.token))
+            .await?
+            .filter(|invite| invite.is_usable())
+            .ok_or_else(|| anyhow::anyhow!("Invalid or expired token"))?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let admin = RegisterUserEntity {
+            username: request.username,
+            password_hash: hashingpassword::hash(request.password)?,
+            email: Some(invite.email),
+            role: Some(Role::Admin),
+            status: Some(UserStatusEnum::Active),
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.admin_invite_repository
+            .accept(invite.id, admin)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Invalid or expired token"))
+    }
+
+    /// ลบคำเชิญที่หมดอายุแล้ว
+    pub async fn cleanup_expired_invites(&self) -> Result<usize> {
+        self.admin_invite_repository.delete_expired().await
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::audit_event::{AuditEvent, AuditLogPage, AuditLogQuery, NewAuditEvent},
+    repo::audit_event::AuditEventRepository,
+};
+
+pub struct AuditLogUseCase<T>
+where
+    T: AuditEventRepository + Send + Sync,
+{
+    audit_event_repository: Arc<T>,
+}
+
+impl<T> AuditLogUseCase<T>
+where
+    T: AuditEventRepository + Send + Sync,
+{
+    pub fn new(audit_event_repository: Arc<T>) -> Self {
+        Self {
+            audit_event_repository,
+        }
+    }
+
+    pub async fn record(&self, new_event: NewAuditEvent) -> Result<AuditEvent> {
+        self.audit_event_repository.append(&new_event).await
+    }
+
+    /// บันทึก status ที่ตอบกลับของ request ที่ถูก record ไว้ก่อนทำงาน
+    pub async fn complete(&self, event_id: Uuid, status: u16) -> Result<AuditEvent> {
+        self.audit_event_repository
+            .set_response_status(event_id, status)
+            .await
+    }
+
+    /// ค้นหา events ตาม filter แบบแบ่งหน้า (ใหม่สุดก่อน)
+    pub async fn search(&self, query: AuditLogQuery) -> Result<AuditLogPage> {
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from >= to
+        {
+            return Err(anyhow::anyhow!("Invalid date range: from must be before to"));
+        }
+
+        let (page, per_page) = query.pagination();
+        let (items, total) = self
+            .audit_event_repository
+            .search(&query, per_page, (page - 1) * per_page)
+            .await?;
+
+        Ok(AuditLogPage {
+            items,
+            page,
+            per_page,
+            total,
+        })
+    }
+}
@@ -2,128 +2,192 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use chrono::{Duration, Utc};
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
-    config::config_loader::{get_admin_secret, get_user_secret},
-    domain::{entities::user, repo::user::UserRepository},
+    domain::{
+        entities::{
+            user::{self, UserEntity},
+            user_mfa::MfaEnrollmentResponse,
+            user_session::{NewUserSession, SessionDevice, SessionScope, UserSession},
+        },
+        repo::{user::UserRepository, user_mfa::UserMfaRepository, user_session::UserSessionRepository},
+        usecase::user_mfa::UserMfaUseCase,
+    },
     infrastructure::{
         hashingpassword,
         jwt_authentication::{
             self,
-            authentication_model::LoginModel,
-            jwt_model::{Claims, Passport, Roles},
+            authentication_model::{
+                LoginModel, LoginOutcome, MfaChallenge, MfaLoginModel, MfaLoginResult,
+            },
+            jwt_keys::JwtKeySet,
+            jwt_model::{Claims, Passport, TokenKind},
         },
     },
 };
 
-pub struct AuthenticationUseCase<T>
+/// อายุของ token ระหว่างรอรหัส MFA
+fn mfa_pending_lifetime() -> Duration {
+    Duration::minutes(5)
+}
+
+pub struct AuthenticationUseCase<T, TSession, TMfa>
 where
     T: UserRepository + Send + Sync,
-    // T2: GuildCommandersRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
     User_repository: Arc<T>,
-    // guild_commanders_repository: Arc<T2>,
+    user_session_repository: Arc<TSession>,
+    user_mfa_use_case: Arc<UserMfaUseCase<TMfa>>,
+    jwt_keys: Arc<JwtKeySet>,
 }
 
-impl<T> AuthenticationUseCase<T>
+impl<T, TSession, TMfa> AuthenticationUseCase<T, TSession, TMfa>
 where
     T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
-    pub fn new(User_repository: Arc<T>) -> Self {
-        Self { User_repository }
+    pub fn new(
+        User_repository: Arc<T>,
+        user_session_repository: Arc<TSession>,
+        user_mfa_use_case: Arc<UserMfaUseCase<TMfa>>,
+        jwt_keys: Arc<JwtKeySet>,
+    ) -> Self {
+        Self {
+            User_repository,
+            user_session_repository,
+            user_mfa_use_case,
+            jwt_keys,
+        }
     }
 
-    pub async fn user_login(&self, login_model: LoginModel) -> Result<Passport> {
+    pub async fn user_login(
+        &self,
+        login_model: LoginModel,
+        device: SessionDevice,
+    ) -> Result<LoginOutcome> {
         if let Err(errors) = login_model.validate() {
             return Err(anyhow::anyhow!("Validation error: {:?}", errors));
         }
 
-        let secret_env = get_user_secret()?;
-
         let user = self
             .User_repository
             .find_by_username(login_model.username.clone())
             .await?;
 
-        let original_password = user.password_hash;
+        let original_password = user.password_hash.clone();
         let login_password = login_model.password;
 
         if !hashingpassword::verify(login_password, original_password)? {
             return Err(anyhow::anyhow!("Invalid password"));
         }
 
-        let access_token_claims = Claims {
-            sub: user.id.to_string(),
-            role: Roles::UserAndCompany,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        self.user_login_verified(user, device).await
+    }
 
-        let refresh_token_claims = Claims {
-            sub: user.id.to_string(),
-            role: Roles::UserAndCompany,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+    /// Login ของบัญชีที่ยืนยันตัวตนแล้ว (รหัสผ่าน หรือ OIDC provider)
+    /// บัญชีที่เปิด MFA ไว้ยังต้องยืนยันรหัสอีกขั้น
+    pub async fn user_login_verified(
+        &self,
+        user: UserEntity,
+        device: SessionDevice,
+    ) -> Result<LoginOutcome> {
+        ensure_account_active(&user.status)?;
+
+        // Admin ต้องเข้าผ่าน /admin/login ที่บังคับ MFA เสมอ
+        if user.role == user::Role::Admin {
+            return Err(anyhow::anyhow!(
+                "Forbidden: Admin accounts must sign in through the admin login"
+            ));
+        }
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.user_secret, &access_token_claims)?;
+        // บัญชีที่เปิด MFA ไว้ (company users opt-in) ต้องยืนยันรหัสอีกขั้น
+        if self.user_mfa_use_case.is_enabled(user.id).await? {
+            return Ok(LoginOutcome::MfaRequired(issue_mfa_challenge(
+                &self.jwt_keys,
+                &user,
+                false,
+            )?));
+        }
 
-        let refresh_token = jwt_authentication::generate_token(
-            secret_env.user_refresh_secret,
-            &refresh_token_claims,
-        )?;
+        let session = self
+            .user_session_repository
+            .create(&NewUserSession::start(user.id, SessionScope::User, device))
+            .await?;
 
-        Ok(Passport {
-            refresh_token,
-            access_token,
-        })
+        Ok(LoginOutcome::Authenticated(sign_passport(
+            &session,
+            user.role,
+            &self.jwt_keys,
+            SessionScope::User,
+        )?))
     }
 
-    pub async fn user_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_user_secret()?;
+    /// ขั้นที่สองของการ login: ตรวจรหัส TOTP / recovery code แล้วออก Passport
+    pub async fn user_complete_mfa_login(
+        &self,
+        mfa_login_model: MfaLoginModel,
+        device: SessionDevice,
+    ) -> Result<Passport> {
 
-        let claims = jwt_authentication::verify_token(
-            secret_env.user_refresh_secret.clone(),
-            refresh_token.clone(),
-        )?;
+        let user = self
+            .pending_mfa_user(SessionScope::User, mfa_login_model.mfa_token)
+            .await?;
 
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::UserAndCompany,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        self.user_mfa_use_case
+            .verify(user.id, &mfa_login_model.code)
+            .await?;
 
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::UserAndCompany,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let session = self
+            .user_session_repository
+            .create(&NewUserSession::start(user.id, SessionScope::User, device))
+            .await?;
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.user_secret, &access_token_claims)?;
+        sign_passport(
+            &session,
+            user.role,
+            &self.jwt_keys,
+            SessionScope::User,
+        )
+    }
 
-        let refresh_token = jwt_authentication::generate_token(
-            secret_env.user_refresh_secret,
-            &refresh_token_claims,
-        )?;
+    pub async fn user_refresh_token(&self, refresh_token: String) -> Result<Passport> {
 
-        Ok(Passport {
-            refresh_token,
-            access_token,
-        })
+        let claims =
+            jwt_authentication::verify_token(&self.jwt_keys, TokenKind::UserRefresh, &refresh_token)?;
+
+        let session = self.rotate_session(&claims, SessionScope::User).await?;
+
+        // Role is read again so the new access token reflects the current account
+        let user = self.User_repository.find_by_id(session.user_id).await?;
+        ensure_account_active(&user.status)?;
+
+        sign_passport(
+            &session,
+            user.role,
+            &self.jwt_keys,
+            SessionScope::User,
+        )
+    }
+
+    pub async fn user_logout(&self, refresh_token: String) -> Result<()> {
+        self.revoke_by_refresh_token(TokenKind::UserRefresh, refresh_token)
+            .await
     }
 
-    pub async fn admin_login(&self, login_model: LoginModel) -> Result<Passport> {
+    /// Admin ต้องผ่าน MFA เสมอ - ถ้ายังไม่ได้ลงทะเบียนจะต้องลงทะเบียนก่อนเข้าสู่ระบบ
+    pub async fn admin_login(
+        &self,
+        login_model: LoginModel,
+    ) -> Result<LoginOutcome> {
         if let Err(errors) = login_model.validate() {
             return Err(anyhow::anyhow!("Validation error: {:?}", errors));
         }
 
-        let secret_env = get_admin_secret()?;
-
         let user = self
             .User_repository
             .find_by_username(login_model.username.clone())
@@ -134,71 +198,269 @@ where
             return Err(anyhow::anyhow!("Unauthorized: Not an admin"));
         }
 
-        if !hashingpassword::verify(login_model.password, user.password_hash)? {
+        if !hashingpassword::verify(login_model.password, user.password_hash.clone())? {
             return Err(anyhow::anyhow!("Invalid password"));
         }
 
-        let access_token_claims = Claims {
-            sub: user.id.to_string(),
-            role: Roles::Admin,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        ensure_account_active(&user.status)?;
+
+        let enrollment_required = !self.user_mfa_use_case.is_enabled(user.id).await?;
+
+        Ok(LoginOutcome::MfaRequired(issue_mfa_challenge(
+            &self.jwt_keys,
+            &user,
+            enrollment_required,
+        )?))
+    }
 
-        let refresh_token_claims = Claims {
-            sub: user.id.to_string(),
-            role: Roles::Admin,
-            exp: (Utc::now() + Duration::days(7)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
+    /// Admin ที่ยังไม่มี MFA: สร้าง secret สำหรับแอป authenticator ด้วย token ขั้นแรก
+    pub async fn admin_begin_mfa_enrollment(&self, mfa_token: String) -> Result<MfaEnrollmentResponse> {
+        let user = self
+            .pending_mfa_user(SessionScope::Admin, mfa_token)
+            .await?;
+
+        let account_name = user.email.as_deref().unwrap_or(&user.username);
+        self.user_mfa_use_case
+            .begin_enrollment(user.id, account_name)
+            .await
+    }
+
+    /// ขั้นที่สองของ admin login - ถ้ายังไม่ได้เปิด MFA รหัสนี้จะใช้ยืนยันการลงทะเบียน
+    pub async fn admin_complete_mfa_login(
+        &self,
+        mfa_login_model: MfaLoginModel,
+        device: SessionDevice,
+    ) -> Result<MfaLoginResult> {
+        let user = self
+            .pending_mfa_user(SessionScope::Admin, mfa_login_model.mfa_token)
+            .await?;
+
+        let recovery_codes = if self.user_mfa_use_case.is_enabled(user.id).await? {
+            self.user_mfa_use_case
+                .verify(user.id, &mfa_login_model.code)
+                .await?;
+            None
+        } else {
+            Some(
+                self.user_mfa_use_case
+                    .confirm_enrollment(user.id, &mfa_login_model.code)
+                    .await?,
+            )
         };
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.admin_secret, &access_token_claims)?;
+        let session = self
+            .user_session_repository
+            .create(&NewUserSession::start(user.id, SessionScope::Admin, device))
+            .await?;
 
-        let refresh_token = jwt_authentication::generate_token(
-            secret_env.admin_refresh_secret,
-            &refresh_token_claims,
+        let passport = sign_passport(
+            &session,
+            user::Role::Admin,
+            &self.jwt_keys,
+            SessionScope::Admin,
         )?;
 
-        Ok(Passport {
-            refresh_token,
-            access_token,
+        Ok(MfaLoginResult {
+            passport,
+            recovery_codes,
         })
     }
 
     pub async fn admin_refresh_token(&self, refresh_token: String) -> Result<Passport> {
-        let secret_env = get_admin_secret()?;
 
-        let claims = jwt_authentication::verify_token(
-            secret_env.admin_refresh_secret.clone(),
-            refresh_token.clone(),
-        )?;
+        let claims =
+            jwt_authentication::verify_token(&self.jwt_keys, TokenKind::AdminRefresh, &refresh_token)?;
 
-        let access_token_claims = Claims {
-            sub: claims.sub.clone(),
-            role: Roles::Admin,
-            exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
-            iat: Utc::now().timestamp() as usize,
-        };
+        let session = self.rotate_session(&claims, SessionScope::Admin).await?;
 
-        let refresh_token_claims = Claims {
-            sub: claims.sub,
-            role: Roles::UserAndCompany,
-            exp: claims.exp,
-            iat: Utc::now().timestamp() as usize,
-        };
+        sign_passport(
+            &session,
+            user::Role::Admin,
+            &self.jwt_keys,
+            SessionScope::Admin,
+        )
+    }
+
+    pub async fn admin_logout(&self, refresh_token: String) -> Result<()> {
+        self.revoke_by_refresh_token(TokenKind::AdminRefresh, refresh_token)
+            .await
+    }
 
-        let access_token =
-            jwt_authentication::generate_token(secret_env.admin_secret, &access_token_claims)?;
+    /// username ของ token ขั้นแรก - ใช้นับรหัส MFA ที่ผิดเข้า lockout ของบัญชี
+    pub async fn pending_mfa_username(&self, scope: SessionScope, mfa_token: &str) -> Result<String> {
+        self.pending_mfa_user(scope, mfa_token.to_string())
+            .await
+            .map(|user| user.username)
+    }
 
-        let refresh_token = jwt_authentication::generate_token(
-            secret_env.admin_refresh_secret,
-            &refresh_token_claims,
-        )?;
+    /// ตรวจ token ขั้นแรก (mfa pending) และโหลดบัญชีอีกครั้ง
+    /// token ของ admin ใช้ได้เฉพาะ admin login และ token ของ user ใช้กับ admin login ไม่ได้
+    async fn pending_mfa_user(&self, scope: SessionScope, mfa_token: String) -> Result<UserEntity> {
+        let claims = jwt_authentication::verify_token(&self.jwt_keys, TokenKind::MfaPending, &mfa_token)
+            .ok()
+            .filter(|claims| claims.mfa_pending)
+            .ok_or_else(|| anyhow::anyhow!("Invalid or expired MFA token"))?;
+
+        let user_id = Uuid::parse_str(&claims.sub)
+            .map_err(|_| anyhow::anyhow!("Invalid or expired MFA token"))?;
+
+        let user = self.User_repository.find_by_id(user_id).await?;
+        ensure_account_active(&user.status)?;
+
+        match (scope, user.role) {
+            (SessionScope::Admin, user::Role::Admin) => Ok(user),
+            (SessionScope::Admin, _) => Err(anyhow::anyhow!("Unauthorized: Not an admin")),
+            (SessionScope::User, user::Role::Admin) => Err(anyhow::anyhow!("Invalid or expired MFA token")),
+            (SessionScope::User, _) => Ok(user),
+        }
+    }
 
-        Ok(Passport {
-            refresh_token,
-            access_token,
-        })
+    /// แลก refresh token เดิมเป็น token ใหม่ใน family เดียวกัน
+    /// ถ้า token เดิมเคยถูกแลกไปแล้ว ถือว่าถูกขโมย และ revoke ทั้ง family
+    async fn rotate_session(&self, claims: &Claims, scope: SessionScope) -> Result<UserSession> {
+        let jti = Uuid::parse_str(&claims.jti)
+            .map_err(|_| anyhow::anyhow!("Invalid refresh token"))?;
+
+        let session = self
+            .user_session_repository
+            .find_by_jti(jti)
+            .await?
+            .filter(|session| session.scope == scope.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Invalid refresh token"))?;
+
+        if session.revoked_at.is_some() {
+            return Err(anyhow::anyhow!("Session has been revoked"));
+        }
+
+        if session.rotated_at.is_some() {
+            return Err(self.revoke_reused_family(&session).await);
+        }
+
+        if session.is_expired() {
+            return Err(anyhow::anyhow!("Session has expired"));
+        }
+
+        match self
+            .user_session_repository
+            .rotate(session.jti, &NewUserSession::rotate_from(&session))
+            .await?
+        {
+            Some(new_session) => Ok(new_session),
+            // อีก request แลก token นี้ไปก่อนแล้ว
+            None => Err(self.revoke_reused_family(&session).await),
+        }
+    }
+
+    async fn revoke_reused_family(&self, session: &UserSession) -> anyhow::Error {
+        tracing::warn!(
+            "Refresh token reuse detected for user {} (session {}), revoking session",
+            session.user_id,
+            session.family_id
+        );
+
+        if let Err(e) = self
+            .user_session_repository
+            .revoke_family(session.user_id, session.family_id, "refresh_token_reuse")
+            .await
+        {
+            tracing::error!("Failed to revoke session {}: {}", session.family_id, e);
+        }
+
+        anyhow::anyhow!("Refresh token reuse detected")
+    }
+
+    async fn revoke_by_refresh_token(&self, kind: TokenKind, refresh_token: String) -> Result<()> {
+        let claims = jwt_authentication::verify_token(&self.jwt_keys, kind, &refresh_token)?;
+        let jti = Uuid::parse_str(&claims.jti)
+            .map_err(|_| anyhow::anyhow!("Invalid refresh token"))?;
+
+        if let Some(session) = self.user_session_repository.find_by_jti(jti).await? {
+            self.user_session_repository
+                .revoke_family(session.user_id, session.family_id, "logout")
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// ออก access token + refresh token สำหรับ session
+fn sign_passport(
+    session: &UserSession,
+    role: user::Role,
+    keys: &JwtKeySet,
+    scope: SessionScope,
+) -> Result<Passport> {
+    let (access_kind, refresh_kind) = match scope {
+        SessionScope::User => (TokenKind::UserAccess, TokenKind::UserRefresh),
+        SessionScope::Admin => (TokenKind::AdminAccess, TokenKind::AdminRefresh),
+    };
+
+    let access_token_claims = Claims {
+        sub: session.user_id.to_string(),
+        role,
+        exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+        iat: Utc::now().timestamp() as usize,
+        jti: Uuid::new_v4().to_string(),
+        sid: Some(session.family_id.to_string()),
+        mfa_pending: false,
+        act: None,
+    };
+
+    let refresh_token_claims = Claims {
+        sub: session.user_id.to_string(),
+        role,
+        exp: session.expires_at.timestamp() as usize,
+        iat: Utc::now().timestamp() as usize,
+        jti: session.jti.to_string(),
+        sid: Some(session.family_id.to_string()),
+        mfa_pending: false,
+        act: None,
+    };
+
+    let access_token = jwt_authentication::generate_token(keys, access_kind, &access_token_claims)?;
+
+    let refresh_token = jwt_authentication::generate_token(keys, refresh_kind, &refresh_token_claims)?;
+
+    Ok(Passport {
+        refresh_token,
+        access_token,
+    })
+}
+
+/// ออก token อายุสั้นสำหรับขั้นยืนยันรหัส MFA
+/// aud แยกจาก access token - service อื่นที่ตรวจด้วย JWKS จะไม่รับเป็น access token
+fn issue_mfa_challenge(
+    keys: &JwtKeySet,
+    user: &UserEntity,
+    enrollment_required: bool,
+) -> Result<MfaChallenge> {
+    let claims = Claims {
+        sub: user.id.to_string(),
+        role: user.role,
+        exp: (Utc::now() + mfa_pending_lifetime()).timestamp() as usize,
+        iat: Utc::now().timestamp() as usize,
+        jti: Uuid::new_v4().to_string(),
+        sid: None,
+        mfa_pending: true,
+        act: None,
+    };
+
+    Ok(MfaChallenge {
+        mfa_required: true,
+        enrollment_required,
+        mfa_token: jwt_authentication::generate_token(keys, TokenKind::MfaPending, &claims)?,
+        expires_in: mfa_pending_lifetime().num_seconds(),
+    })
+}
+
+/// บัญชีที่ยังไม่ยืนยันอีเมล ถูกระงับ หรือถูกลบแล้ว ไม่สามารถเข้าสู่ระบบได้
+fn ensure_account_active(status: &user::UserStatusEnum) -> Result<()> {
+    match status {
+        user::UserStatusEnum::Active => Ok(()),
+        user::UserStatusEnum::Pending => Err(anyhow::anyhow!("Email not verified")),
+        user::UserStatusEnum::Suspended => Err(anyhow::anyhow!("Account suspended")),
+        // ตอบเหมือนไม่มีบัญชี
+        user::UserStatusEnum::Deleted => Err(anyhow::anyhow!("User not found")),
     }
 }
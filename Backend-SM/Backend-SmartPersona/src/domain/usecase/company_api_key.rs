@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        account_token::hash_token,
+        company_api_key::{
+            ApiKeyPrincipal, ApiKeyResponse, ApiKeyScope, CreateApiKeyRequest,
+            CreatedApiKeyResponse, MAX_ACTIVE_API_KEYS, NewCompanyApiKey, is_api_key,
+        },
+    },
+    repo::company_api_key::CompanyApiKeyRepository,
+};
+
+pub struct CompanyApiKeyUseCase<T>
+where
+    T: CompanyApiKeyRepository + Send + Sync,
+{
+    company_api_key_repository: Arc<T>,
+}
+
+impl<T> CompanyApiKeyUseCase<T>
+where
+    T: CompanyApiKeyRepository + Send + Sync,
+{
+    pub fn new(company_api_key_repository: Arc<T>) -> Self {
+        Self {
+            company_api_key_repository,
+        }
+    }
+
+    // =================================================================
+    // 🔑 Key management (เจ้าของบริษัท)
+    // =================================================================
+
+    /// สร้าง key ใหม่ให้บริษัทของ user - key จริงคืนค่าครั้งเดียวเท่านั้น
+    pub async fn create_key(
+        &self,
+        user_id: Uuid,
+        request: CreateApiKeyRequest,
+    ) -> Result<CreatedApiKeyResponse> {
+        let company_id = self.owned_company_id(user_id).await?;
+
+        let mut scopes = Vec::new();
+        for value in &request.scopes {
+            let scope = ApiKeyScope::parse(value.trim())
+                .ok_or_else(|| anyhow::anyhow!("Invalid scope: {}", value))?;
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+
+        if self
+            .company_api_key_repository
+            .count_active(company_id)
+            .await?
+            >= MAX_ACTIVE_API_KEYS
+        {
+            return Err(anyhow::anyhow!(
+                "Too many active API keys (maximum {})",
+                MAX_ACTIVE_API_KEYS
+            ));
+        }
+
+        let (new_key, raw_key) = NewCompanyApiKey::generate(
+            company_id,
+            user_id,
+            request.name.trim().to_string(),
+            &scopes,
+            request.expires_in_days,
+        );
+        let key = self.company_api_key_repository.create(&new_key).await?;
+
+        Ok(CreatedApiKeyResponse {
+            key: key.into(),
+            api_key: raw_key,
+        })
+    }
+
+    pub async fn list_keys(&self, user_id: Uuid) -> Result<Vec<ApiKeyResponse>> {
+        let company_id = self.owned_company_id(user_id).await?;
+
+        let keys = self
+            .company_api_key_repository
+            .list_by_company(company_id)
+            .await?;
+
+        Ok(keys.into_iter().map(ApiKeyResponse::from).collect())
+    }
+
+    pub async fn revoke_key(&self, user_id: Uuid, key_id: Uuid) -> Result<()> {
+        let company_id = self.owned_company_id(user_id).await?;
+
+        if !self
+            .company_api_key_repository
+            .revoke(company_id, key_id)
+            .await?
+        {
+            return Err(anyhow::anyhow!("API key not found"));
+        }
+
+        Ok(())
+    }
+
+    // =================================================================
+    // 🛡️ Authentication (เรียกจาก middleware)
+    // =================================================================
+
+    /// ตรวจสอบ key ที่ส่งมากับ request แล้วบันทึกการใช้งาน
+    pub async fn authenticate(
+        &self,
+        raw_key: &str,
+        ip_address: Option<String>,
+    ) -> Result<ApiKeyPrincipal> {
+        if !is_api_key(raw_key) {
+            return Err(anyhow::anyhow!("Invalid API key"));
+        }
+
+        let (key, owner_user_id) = self
+            .company_api_key_repository
+            .find_by_hash(&hash_token(raw_key))
+            .await?
+            .filter(|(key, _)| key.is_usable())
+            .ok_or_else(|| anyhow::anyhow!("Invalid API key"))?;
+
+        // บันทึก last used ไม่สำเร็จไม่ควรทำให้ request ล้ม
+        if let Err(e) = self
+            .company_api_key_repository
+            .touch(key.id, ip_address)
+            .await
+        {
+            tracing::warn!("Failed to record API key usage for {}: {}", key.id, e);
+        }
+
+        Ok(ApiKeyPrincipal {
+            key_id: key.id,
+            company_id: key.company_id,
+            owner_user_id,
+            scopes: key.parsed_scopes(),
+        })
+    }
+
+    // =================================================================
+    // 🛠️ Helpers
+    // =================================================================
+
+    async fn owned_company_id(&self, user_id: Uuid) -> Result<Uuid> {
+        self.company_api_key_repository
+            .company_id_for_owner(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Company not found"))
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::repo::company_ownership::CompanyOwnershipRepository;
+
+/// Policy: ผู้เรียกต้องเป็นเจ้าของ `companies` ที่อยู่เบื้องหลัง post / gallery / application
+pub struct CompanyOwnershipPolicy {
+    repo: Arc<dyn CompanyOwnershipRepository>,
+}
+
+impl CompanyOwnershipPolicy {
+    pub fn new(repo: Arc<dyn CompanyOwnershipRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn ensure_company_owner(&self, user_id: Uuid, company_id: Uuid) -> Result<()> {
+        let owner = self.repo.company_owner(company_id).await?;
+        check_owner(user_id, owner, "Company")
+    }
+
+    /// ใช้กับ job ด้วย (job_id คือ company_posts.id)
+    pub async fn ensure_post_owner(&self, user_id: Uuid, post_id: Uuid) -> Result<()> {
+        let owner = self.repo.post_owner(post_id).await?;
+        check_owner(user_id, owner, "Post")
+    }
+
+    pub async fn ensure_gallery_owner(&self, user_id: Uuid, gallery_id: Uuid) -> Result<()> {
+        let owner = self.repo.gallery_owner(gallery_id).await?;
+        check_owner(user_id, owner, "Gallery")
+    }
+
+    pub async fn ensure_application_owner(&self, user_id: Uuid, application_id: Uuid) -> Result<()> {
+        let owner = self.repo.application_owner(application_id).await?;
+        check_owner(user_id, owner, "Application")
+    }
+
+    /// ดูข้อมูลผู้สมัครได้เฉพาะบริษัทที่ผู้สมัครเคยสมัครงานด้วย
+    pub async fn ensure_applicant_of(&self, user_id: Uuid, applicant_id: Uuid) -> Result<()> {
+        if !self.repo.has_applied_to_owner(applicant_id, user_id).await? {
+            return Err(anyhow::anyhow!("Not authorized: this user has not applied to your company"));
+        }
+        Ok(())
+    }
+}
+
+fn check_owner(user_id: Uuid, owner: Option<Uuid>, resource: &str) -> Result<()> {
+    match owner {
+        None => Err(anyhow::anyhow!("{} not found", resource)),
+        Some(owner_id) if owner_id == user_id => Ok(()),
+        Some(_) => Err(anyhow::anyhow!("Not authorized: you do not own this {}", resource.to_lowercase())),
+    }
+}
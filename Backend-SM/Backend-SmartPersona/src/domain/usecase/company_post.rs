@@ -1,19 +1,165 @@
 use anyhow::Result;
+use chrono::Utc;
 use std::sync::Arc;
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::domain::{
-    entities::company_post::{CompanyPostEntity, CreatePostRequest, UpdatePostRequest},
-    repo::company_post::CompanyPostRepository,
+use crate::{
+    domain::{
+        entities::company_post::{
+            CompanyPostEntity, CreatePostRequest, DEFAULT_JOB_SEARCH_LIMIT,
+            DEFAULT_JOB_SEARCH_RADIUS_KM, JobSearchCursor, JobSearchFilter, JobSearchPage,
+            JobSearchQuery, JobSearchRadius, JobSearchSort, PostSchedule, PostStatus,
+            ScreeningQuestion, SetScreeningQuestionsRequest, UpdatePostRequest,
+        },
+        repo::{company_post::CompanyPostRepository, user_address::UserAddressRepository},
+        value_object::{
+            geo::GeoPoint,
+            salary::{DEFAULT_SALARY_CURRENCY, SalaryPeriod},
+        },
+    },
+    infrastructure::{email::EmailService, search::tsquery_literal},
 };
 
+/// แยกค่าที่คั่นด้วย comma เป็นรายการ (ตัดช่องว่างและค่าว่างทิ้ง)
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// ตรวจพารามิเตอร์ค้นหาและแปลงเป็นเงื่อนไขสำหรับ repository
+/// (ใช้ร่วมกับการค้นหาที่บันทึกไว้ - ผู้เรียกปรับ limit / ช่วง published_* เอง)
+pub fn job_search_filter(query: JobSearchQuery) -> Result<JobSearchFilter> {
+    if let Err(errors) = query.validate() {
+        return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+    }
+    if let (Some(min), Some(max)) = (query.salary_min, query.salary_max)
+        && min > max
+    {
+        return Err(anyhow::anyhow!(
+            "Validation error: salary_min must not be greater than salary_max"
+        ));
+    }
+
+    let near = match (query.lat, query.lng) {
+        (Some(lat), Some(lng)) => {
+            let origin = GeoPoint::new(lat, lng).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Validation error: lat must be within -90..90 and lng within -180..180"
+                )
+            })?;
+            Some(JobSearchRadius {
+                origin,
+                radius_km: query.radius_km.unwrap_or(DEFAULT_JOB_SEARCH_RADIUS_KM),
+            })
+        }
+        (None, None) if query.radius_km.is_some() => {
+            return Err(anyhow::anyhow!(
+                "Validation error: radius_km requires lat and lng"
+            ));
+        }
+        (None, None) => None,
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Validation error: lat and lng must be provided together"
+            ));
+        }
+    };
+
+    let ts_query = query.q.as_deref().and_then(tsquery_literal);
+    let sort = match (query.sort, &ts_query) {
+        (Some(JobSearchSort::Relevance), None) => {
+            return Err(anyhow::anyhow!(
+                "Validation error: sort=relevance requires a search query (q)"
+            ));
+        }
+        (Some(JobSearchSort::Distance), _) if near.is_none() => {
+            return Err(anyhow::anyhow!(
+                "Validation error: sort=distance requires lat and lng"
+            ));
+        }
+        (Some(sort), _) => sort,
+        (None, Some(_)) => JobSearchSort::Relevance,
+        (None, None) if near.is_some() => JobSearchSort::Distance,
+        (None, None) => JobSearchSort::Recent,
+    };
+
+    let after = match query.cursor.as_deref() {
+        Some(cursor) => match JobSearchCursor::decode(cursor) {
+            Some(cursor) if cursor.sort == sort => Some(cursor),
+            _ => return Err(anyhow::anyhow!("Validation error: invalid cursor")),
+        },
+        None => None,
+    };
+
+    // เทียบตัวเลขเงินเดือนได้เฉพาะหน่วยเวลาและสกุลเงินเดียวกัน
+    let compares_salary =
+        query.salary_min.is_some() || query.salary_max.is_some() || sort == JobSearchSort::Salary;
+    let salary_currency = query
+        .salary_currency
+        .map(|currency| currency.trim().to_uppercase())
+        .or_else(|| compares_salary.then(|| DEFAULT_SALARY_CURRENCY.to_string()));
+    let salary_period = query
+        .salary_period
+        .or_else(|| compares_salary.then_some(SalaryPeriod::Monthly));
+
+    Ok(JobSearchFilter {
+        ts_query,
+        job_types: split_list(query.job_type.as_deref())
+            .into_iter()
+            .map(|job_type| job_type.to_lowercase())
+            .collect(),
+        location: query
+            .location
+            .map(|location| location.trim().to_string())
+            .filter(|location| !location.is_empty()),
+        salary_min: query.salary_min,
+        salary_max: query.salary_max,
+        salary_currency,
+        salary_period,
+        tags: split_list(query.tags.as_deref()),
+        company_id: query.company_id,
+        posted_after: query.posted_after,
+        posted_before: query.posted_before,
+        published_after: None,
+        published_before: None,
+        near,
+        sort,
+        after,
+        limit: query.limit.unwrap_or(DEFAULT_JOB_SEARCH_LIMIT),
+    })
+}
+
 pub struct CompanyPostUsecase {
     repo: Arc<dyn CompanyPostRepository>,
+    /// จุดเริ่มต้นของการค้นหางานใกล้ผู้ใช้ (ไม่จำเป็นถ้าไม่เปิด /posts/nearby)
+    user_address_repo: Option<Arc<dyn UserAddressRepository>>,
+    /// แจ้งผู้สมัครเมื่อประกาศปิดรับ (ไม่ส่งอีเมลถ้าไม่ได้ตั้งค่า)
+    email_service: Option<Arc<EmailService>>,
 }
 
 impl CompanyPostUsecase {
     pub fn new(repo: Arc<dyn CompanyPostRepository>) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            user_address_repo: None,
+            email_service: None,
+        }
+    }
+
+    pub fn with_user_addresses(mut self, user_address_repo: Arc<dyn UserAddressRepository>) -> Self {
+        self.user_address_repo = Some(user_address_repo);
+        self
+    }
+
+    pub fn with_email_service(mut self, email_service: Arc<EmailService>) -> Self {
+        self.email_service = Some(email_service);
+        self
     }
 
     pub async fn create_post(
@@ -21,12 +167,34 @@ impl CompanyPostUsecase {
         company_id: Uuid,
         req: CreatePostRequest,
     ) -> Result<CompanyPostEntity> {
-        let new_post = req.into_new_post(company_id);
+        if let Some(salary) = req.salary() {
+            salary
+                .validate()
+                .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+        }
+
+        let requested = req
+            .requested_status()
+            .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+        if !PostStatus::Draft.can_transition_to(requested) {
+            return Err(anyhow::anyhow!(
+                "Validation error: a new post must be draft, scheduled or published"
+            ));
+        }
+        let schedule = PostSchedule::resolve(None, requested, req.publish_at, req.closes_at, Utc::now())
+            .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+
+        let new_post = req.into_new_post(company_id, schedule);
         self.repo.create(&new_post).await
     }
 
-    pub async fn get_posts(&self, company_id: Uuid) -> Result<Vec<CompanyPostEntity>> {
-        self.repo.get_by_company_id(company_id).await
+    /// `include_unpublished` สำหรับบริษัทเจ้าของเท่านั้น (เห็น draft / scheduled ด้วย)
+    pub async fn get_posts(
+        &self,
+        company_id: Uuid,
+        include_unpublished: bool,
+    ) -> Result<Vec<CompanyPostEntity>> {
+        self.repo.get_by_company_id(company_id, !include_unpublished).await
     }
 
     pub async fn get_post(&self, id: Uuid) -> Result<Option<CompanyPostEntity>> {
@@ -38,15 +206,192 @@ impl CompanyPostUsecase {
         id: Uuid,
         req: UpdatePostRequest,
     ) -> Result<CompanyPostEntity> {
-        let update_data = req.into_update_post();
-        self.repo.update(id, &update_data).await
+        if let Some(salary) = req.salary() {
+            salary
+                .validate()
+                .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+        }
+
+        let current = self
+            .repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Post not found"))?;
+        let from = PostStatus::parse(&current.status).unwrap_or(PostStatus::Draft);
+
+        let schedule = if req.changes_schedule() {
+            let requested = req
+                .requested_status(from)
+                .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+            // ไม่ส่งเวลามา = ใช้เวลาเดิม
+            let schedule = PostSchedule::resolve(
+                Some(&current),
+                requested,
+                req.publish_at.or(current.publish_at),
+                req.closes_at.or(current.closes_at),
+                Utc::now(),
+            )
+            .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+
+            if !from.can_transition_to(schedule.status) {
+                return Err(anyhow::anyhow!(
+                    "Conflict: cannot change post status from '{}' to '{}'",
+                    from.as_str(),
+                    schedule.status.as_str()
+                ));
+            }
+            Some(schedule)
+        } else {
+            None
+        };
+
+        let update_data = req.into_update_post(schedule);
+        let post = self.repo.update(id, &update_data).await?;
+
+        if from == PostStatus::Published && PostStatus::parse(&post.status) == Some(PostStatus::Closed) {
+            self.notify_post_closed(post.id, false).await;
+        }
+
+        Ok(post)
     }
 
     pub async fn delete_post(&self, id: Uuid) -> Result<()> {
         self.repo.delete(id).await
     }
 
+    /// ประกาศที่เผยแพร่อยู่ (หน้า /posts สาธารณะ)
     pub async fn get_all_posts(&self) -> Result<Vec<CompanyPostEntity>> {
-        self.repo.get_all().await
+        self.repo.get_published().await
+    }
+
+    /// ค้นหาประกาศงานด้วยคำค้น + ตัวกรอง แบ่งหน้าด้วย cursor
+    pub async fn search_posts(&self, query: JobSearchQuery) -> Result<JobSearchPage> {
+        let mut filter = job_search_filter(query)?;
+        let limit = filter.limit;
+        let sort = filter.sort;
+        // ดึงเกิน 1 แถวเพื่อรู้ว่ายังมีหน้าถัดไปหรือไม่
+        filter.limit = limit + 1;
+
+        let mut items = self.repo.search(&filter).await?;
+
+        let next_cursor = if items.len() as i64 > limit {
+            items.truncate(limit as usize);
+            items.last().map(|hit| hit.cursor(sort).encode())
+        } else {
+            None
+        };
+
+        Ok(JobSearchPage { items, next_cursor })
+    }
+
+    /// ค้นหางานรอบตัวผู้ใช้ - ถ้าไม่ส่ง lat/lng มาจะใช้พิกัดจากที่อยู่ของผู้ใช้
+    pub async fn search_posts_near_user(
+        &self,
+        user_id: Uuid,
+        mut query: JobSearchQuery,
+    ) -> Result<JobSearchPage> {
+        if query.lat.is_none() && query.lng.is_none() {
+            let user_address_repo = self
+                .user_address_repo
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("User address lookup is not configured"))?;
+
+            let origin = user_address_repo
+                .get_by_user_id(user_id)
+                .await?
+                .and_then(|address| address.coordinates())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Validation error: lat and lng are required because your address has no coordinates"
+                    )
+                })?;
+
+            query.lat = Some(origin.latitude);
+            query.lng = Some(origin.longitude);
+        }
+
+        self.search_posts(query).await
+    }
+
+    pub async fn get_screening_questions(&self, post_id: Uuid) -> Result<Vec<ScreeningQuestion>> {
+        if self.repo.get_by_id(post_id).await?.is_none() {
+            return Err(anyhow::anyhow!("Post not found"));
+        }
+        self.repo.find_screening_questions(post_id).await
+    }
+
+    /// แทนที่คำถามคัดกรองทั้งชุด - สิทธิ์เจ้าของประกาศต้องตรวจมาก่อนแล้ว
+    pub async fn set_screening_questions(
+        &self,
+        post_id: Uuid,
+        req: SetScreeningQuestionsRequest,
+    ) -> Result<Vec<ScreeningQuestion>> {
+        if let Err(errors) = req.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let questions = req
+            .into_new_questions(post_id)
+            .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+        self.repo.replace_screening_questions(post_id, &questions).await
+    }
+
+    // =================================================================
+    // 🗓️ Scheduler
+    // =================================================================
+
+    /// เผยแพร่ประกาศที่ถึง publish_at และปิดประกาศที่ถึง closes_at - คืน (จำนวนที่เผยแพร่, จำนวนที่ปิด)
+    pub async fn process_due_posts(&self) -> Result<(usize, usize)> {
+        let now = Utc::now();
+        let published = self.repo.publish_due(now).await?;
+        let expired = self.repo.expire_due(now).await?;
+
+        for post in &expired {
+            self.notify_post_closed(post.id, true).await;
+        }
+
+        Ok((published.len(), expired.len()))
+    }
+
+    /// แจ้งผู้สมัครที่ใบสมัครยังไม่จบว่าประกาศปิดรับแล้ว - ส่งไม่สำเร็จแค่ log ไว้
+    async fn notify_post_closed(&self, post_id: Uuid, expired: bool) {
+        let Some(email_service) = self.email_service.as_ref().filter(|service| service.is_configured())
+        else {
+            tracing::warn!("Email service not configured, post closed notifications not sent");
+            return;
+        };
+
+        let notice = match self.repo.find_closing_notice(post_id).await {
+            Ok(Some(notice)) => notice,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!("Failed to load applicants of closed post {}: {}", post_id, e);
+                return;
+            }
+        };
+
+        for applicant in &notice.applicants {
+            let Some(email) = applicant.email.as_deref() else {
+                tracing::warn!("Applicant of application {} has no email address", applicant.application_id);
+                continue;
+            };
+            if let Err(e) = email_service
+                .send_post_closed_notification(
+                    email,
+                    &applicant.name,
+                    &notice.job_title,
+                    &notice.company_name,
+                    expired,
+                )
+                .await
+            {
+                tracing::error!("Failed to send post closed email: {}", e);
+            }
+        }
+    }
+
+    /// index ประกาศที่ยังไม่มี / มี search_vector เก่า
+    pub async fn index_pending_posts(&self, batch_size: i64) -> Result<usize> {
+        self.repo.index_pending(batch_size).await
     }
 }
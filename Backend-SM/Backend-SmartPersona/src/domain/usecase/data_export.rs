@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            data_export::{
+                CompletedDataExport, DataExport, DataExportDownload, DataExportLinkResponse,
+                MAX_DATA_EXPORTS_PER_DAY, NewDataExport, STALE_DATA_EXPORT_MINUTES,
+                generate_download_token,
+            },
+        },
+        repo::data_export::DataExportRepository,
+    },
+    infrastructure::data_export::build_archive,
+};
+
+pub struct DataExportUseCase<T>
+where
+    T: DataExportRepository + Send + Sync,
+{
+    data_export_repository: Arc<T>,
+}
+
+impl<T> DataExportUseCase<T>
+where
+    T: DataExportRepository + Send + Sync,
+{
+    pub fn new(data_export_repository: Arc<T>) -> Self {
+        Self {
+            data_export_repository,
+        }
+    }
+
+    // =================================================================
+    // 📥 คำขอของผู้ใช้
+    // =================================================================
+
+    /// สร้างคำขอใหม่ (สถานะ pending) - ตัวงานทำโดย `process`
+    pub async fn request_export(&self, user_id: Uuid) -> Result<DataExport> {
+        if self.data_export_repository.has_in_progress(user_id).await? {
+            return Err(anyhow::anyhow!("An export is already in progress"));
+        }
+
+        let requested_today = self
+            .data_export_repository
+            .count_requested_since(user_id, Utc::now() - Duration::hours(24))
+            .await?;
+        if requested_today >= MAX_DATA_EXPORTS_PER_DAY {
+            return Err(anyhow::anyhow!(
+                "Too many export requests (maximum {} per 24 hours)",
+                MAX_DATA_EXPORTS_PER_DAY
+            ));
+        }
+
+        self.data_export_repository
+            .create(&NewDataExport::new(user_id))
+            .await
+    }
+
+    pub async fn list_exports(&self, user_id: Uuid) -> Result<Vec<DataExport>> {
+        self.data_export_repository.list_by_user(user_id).await
+    }
+
+    pub async fn get_export(&self, user_id: Uuid, export_id: Uuid) -> Result<DataExport> {
+        self.data_export_repository
+            .find_for_user(export_id, user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Data export not found"))
+    }
+
+    /// ออกลิงก์ดาวน์โหลดใหม่ให้เจ้าของ - ลิงก์ที่ออกก่อนหน้าใช้ไม่ได้อีก
+    /// (เก็บแค่ hash ของ token จึงแสดงลิงก์เดิมซ้ำไม่ได้)
+    pub async fn issue_download_link(
+        &self,
+        user_id: Uuid,
+        export_id: Uuid,
+    ) -> Result<DataExportLinkResponse> {
+        let export = self.get_export(user_id, export_id).await?;
+        if !export.is_downloadable() {
+            return Err(anyhow::anyhow!("Conflict: Data export is not ready for download"));
+        }
+
+        let download_token = generate_download_token();
+        if !self
+            .data_export_repository
+            .set_download_token_hash(export.id, &hash_token(&download_token))
+            .await?
+        {
+            // หมดอายุระหว่างทาง
+            return Err(anyhow::anyhow!("Conflict: Data export is not ready for download"));
+        }
+
+        Ok(DataExportLinkResponse {
+            download_url: format!("/api/data-exports/download/{}", download_token),
+            expires_at: export.expires_at,
+        })
+    }
+
+    /// ไฟล์ ZIP จาก token ในลิงก์ดาวน์โหลด
+    pub async fn download(&self, download_token: &str) -> Result<DataExportDownload> {
+        let (export, archive) = self
+            .data_export_repository
+            .find_downloadable(&hash_token(download_token))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Download link not found or expired"))?;
+
+        self.data_export_repository.record_download(export.id).await?;
+
+        let generated_on = export
+            .completed_at
+            .unwrap_or(export.requested_at)
+            .format("%Y%m%d");
+        Ok(DataExportDownload {
+            file_name: format!("smartpersona-data-{}.zip", generated_on),
+            archive,
+        })
+    }
+
+    // =================================================================
+    // ⚙️ Worker
+    // =================================================================
+
+    /// รวบรวมข้อมูลและสร้าง ZIP คืนค่า false ถ้างานถูก worker อื่นรับไปแล้ว
+    pub async fn process(&self, export_id: Uuid) -> Result<bool> {
+        let stale_before = Utc::now() - Duration::minutes(STALE_DATA_EXPORT_MINUTES);
+        let Some(user_id) = self
+            .data_export_repository
+            .claim(export_id, stale_before)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        match self.build(export_id, user_id).await {
+            Ok(completed) => {
+                self.data_export_repository
+                    .complete(export_id, &completed)
+                    .await?;
+                tracing::info!(
+                    "Data export {} ready for user {} ({} bytes)",
+                    export_id,
+                    user_id,
+                    completed.archive_size.unwrap_or_default()
+                );
+            }
+            Err(e) => {
+                // รายละเอียดอยู่ใน log เท่านั้น ผู้ใช้เห็นข้อความทั่วไป
+                tracing::error!("Data export {} failed: {:?}", export_id, e);
+                self.data_export_repository
+                    .fail(export_id, "Export failed, please request a new one")
+                    .await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn build(&self, export_id: Uuid, user_id: Uuid) -> Result<CompletedDataExport> {
+        let sections = self.data_export_repository.collect_sections(user_id).await?;
+        let generated_at = Utc::now();
+
+        // บีบอัดใช้ CPU - ไม่ทำบน async worker thread
+        let archive = tokio::task::spawn_blocking(move || {
+            build_archive(export_id, user_id, generated_at, &sections)
+        })
+        .await??;
+
+        Ok(CompletedDataExport::new(archive.bytes, archive.sha256))
+    }
+
+    /// ทำงานที่ค้างอยู่ทั้งหมด (รวมถึงงานที่ค้างจาก server restart) คืนค่าจำนวนที่ทำ
+    pub async fn process_pending(&self) -> Result<usize> {
+        let stale_before = Utc::now() - Duration::minutes(STALE_DATA_EXPORT_MINUTES);
+        let export_ids = self
+            .data_export_repository
+            .list_claimable(stale_before)
+            .await?;
+
+        let mut processed = 0;
+        for export_id in export_ids {
+            if self.process(export_id).await? {
+                processed += 1;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    /// ลบไฟล์ของ export ที่ลิงก์หมดอายุแล้ว
+    pub async fn expire_old_exports(&self) -> Result<u64> {
+        self.data_export_repository.expire_old().await
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            impersonation::{ImpersonationTokenResponse, StartImpersonationRequest},
+            user::{Role, UserStatusEnum},
+        },
+        repo::user::UserRepository,
+    },
+    infrastructure::jwt_authentication::{
+        self,
+        jwt_keys::JwtKeySet,
+        jwt_model::{Claims, ImpersonationActor, TokenKind},
+    },
+};
+
+pub struct ImpersonationUseCase<T>
+where
+    T: UserRepository + Send + Sync,
+{
+    user_repository: Arc<T>,
+    jwt_keys: Arc<JwtKeySet>,
+}
+
+impl<T> ImpersonationUseCase<T>
+where
+    T: UserRepository + Send + Sync,
+{
+    pub fn new(user_repository: Arc<T>, jwt_keys: Arc<JwtKeySet>) -> Self {
+        Self {
+            user_repository,
+            jwt_keys,
+        }
+    }
+
+    /// ออก access token อายุสั้นของ user ที่มี `act` claim ระบุ admin ผู้ขอ
+    /// ไม่สร้าง session / refresh token - หมดอายุแล้วต้องขอใหม่
+    pub async fn start(
+        &self,
+        admin_id: Uuid,
+        target_user_id: Uuid,
+        request: StartImpersonationRequest,
+    ) -> Result<ImpersonationTokenResponse> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        if admin_id == target_user_id {
+            return Err(anyhow::anyhow!("Forbidden: cannot impersonate yourself"));
+        }
+
+        let user = self.user_repository.find_by_id(target_user_id).await?;
+
+        if user.role == Role::Admin {
+            return Err(anyhow::anyhow!("Forbidden: admin accounts cannot be impersonated"));
+        }
+        if user.status == UserStatusEnum::Suspended {
+            return Err(anyhow::anyhow!("Forbidden: account is suspended"));
+        }
+        if user.status == UserStatusEnum::Deleted {
+            return Err(anyhow::anyhow!("User not found"));
+        }
+
+        let issued_at = Utc::now();
+        let expires_at = issued_at + request.lifetime();
+        let jti = Uuid::new_v4().to_string();
+
+        let claims = Claims {
+            sub: user.id.to_string(),
+            role: user.role,
+            exp: expires_at.timestamp() as usize,
+            iat: issued_at.timestamp() as usize,
+            jti: jti.clone(),
+            sid: None,
+            mfa_pending: false,
+            act: Some(ImpersonationActor {
+                sub: admin_id.to_string(),
+                write: request.allow_write,
+            }),
+        };
+
+        let access_token =
+            jwt_authentication::generate_token(&self.jwt_keys, TokenKind::UserAccess, &claims)?;
+
+        Ok(ImpersonationTokenResponse {
+            access_token,
+            token_type: "Bearer".to_string(),
+            jti,
+            expires_at,
+            expires_in: (expires_at - issued_at).num_seconds(),
+            user_id: user.id,
+            username: user.username,
+            role: user.role,
+            read_only: !request.allow_write,
+        })
+    }
+}
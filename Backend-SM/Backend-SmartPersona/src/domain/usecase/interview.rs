@@ -0,0 +1,526 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            interview::{
+                CalendarFeed, CalendarFeedResponse, CancelInterviewRequest, ConfirmInterviewRequest,
+                Interview, InterviewParticipants, InterviewStatus, InterviewWithSlots, NewInterview,
+                ProposeInterviewRequest,
+            },
+            job_application::ApplicationStatus,
+        },
+        repo::interview::InterviewRepository,
+    },
+    infrastructure::{
+        calendar::{self, CalendarEvent, CalendarMethod, CalendarPerson},
+        email::{EmailService, InterviewProposalEmail},
+    },
+};
+
+/// นัดที่ผ่านไปแล้วนานกว่านี้ไม่อยู่ใน ICS feed
+const CALENDAR_FEED_HISTORY_DAYS: i64 = 90;
+
+/// ผู้เรียกเป็นฝ่ายไหนของใบสมัคร
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InterviewParty {
+    Candidate,
+    Company,
+}
+
+pub struct InterviewUseCase<T>
+where
+    T: InterviewRepository + Send + Sync,
+{
+    interview_repository: Arc<T>,
+    email_service: Arc<EmailService>,
+    backend_url: String,
+}
+
+impl<T> InterviewUseCase<T>
+where
+    T: InterviewRepository + Send + Sync,
+{
+    pub fn new(interview_repository: Arc<T>, email_service: Arc<EmailService>, backend_url: String) -> Self {
+        Self {
+            interview_repository,
+            email_service,
+            backend_url,
+        }
+    }
+
+    // =================================================================
+    // 🏢 บริษัท: เสนอ / เลื่อนนัด
+    // =================================================================
+
+    /// เสนอช่วงเวลาสัมภาษณ์ - ใบสมัครต้องอยู่ในขั้น interview
+    pub async fn propose(
+        &self,
+        user_id: Uuid,
+        application_id: Uuid,
+        request: ProposeInterviewRequest,
+    ) -> Result<InterviewWithSlots> {
+        validate_proposal(&request)?;
+
+        let participants = self.participants(application_id).await?;
+        ensure_party(&participants, user_id, InterviewParty::Company)?;
+        ensure_interview_stage(&participants)?;
+
+        let details = request.details();
+        let new_interview = NewInterview {
+            application_id,
+            status: InterviewStatus::Proposed.as_str().to_string(),
+            location: details.location,
+            video_url: details.video_url,
+            note: details.note,
+            created_by: Some(user_id),
+        };
+
+        let created = self
+            .interview_repository
+            .create(&new_interview, &request.slot_times())
+            .await?;
+
+        self.notify_proposal(&participants, &created, false).await;
+        Ok(created)
+    }
+
+    /// เลื่อนนัด: แทนที่ช่วงเวลาทั้งหมดแล้วให้ผู้สมัครเลือกใหม่
+    /// ถ้าเคยยืนยันเวลาแล้วจะส่ง .ics ยกเลิกเวลาเดิมให้ทั้งสองฝ่าย
+    pub async fn reschedule(
+        &self,
+        user_id: Uuid,
+        interview_id: Uuid,
+        request: ProposeInterviewRequest,
+    ) -> Result<InterviewWithSlots> {
+        validate_proposal(&request)?;
+
+        let current = self.find_interview(interview_id).await?;
+        let participants = self.participants(current.interview.application_id).await?;
+        ensure_party(&participants, user_id, InterviewParty::Company)?;
+        ensure_interview_stage(&participants)?;
+
+        let rescheduled = self
+            .interview_repository
+            .reschedule(interview_id, &request.details(), &request.slot_times())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Conflict: interview has been cancelled"))?;
+
+        if current.interview.is(InterviewStatus::Scheduled) {
+            // เวลาเดิมกับ SEQUENCE ใหม่ - calendar app จะลบนัดเดิมออก
+            let previous = Interview {
+                sequence: rescheduled.interview.sequence,
+                ..current.interview
+            };
+            self.notify_cancelled(&participants, &previous, None, None).await;
+        }
+        self.notify_proposal(&participants, &rescheduled, true).await;
+
+        Ok(rescheduled)
+    }
+
+    // =================================================================
+    // 👤 ผู้สมัคร: เลือกเวลา
+    // =================================================================
+
+    pub async fn confirm(
+        &self,
+        user_id: Uuid,
+        interview_id: Uuid,
+        request: ConfirmInterviewRequest,
+    ) -> Result<InterviewWithSlots> {
+        let current = self.find_interview(interview_id).await?;
+        let participants = self.participants(current.interview.application_id).await?;
+        ensure_party(&participants, user_id, InterviewParty::Candidate)?;
+
+        if !current.interview.is(InterviewStatus::Proposed) {
+            return Err(anyhow::anyhow!("Conflict: interview is not waiting for a time selection"));
+        }
+
+        let slot = current
+            .slots
+            .iter()
+            .find(|slot| slot.id == request.slot_id)
+            .ok_or_else(|| anyhow::anyhow!("Validation error: slot_id is not one of the proposed slots"))?;
+        if slot.starts_at <= Utc::now() {
+            return Err(anyhow::anyhow!("Validation error: the selected slot has already started"));
+        }
+
+        let interview = self
+            .interview_repository
+            .confirm(interview_id, slot)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Conflict: interview is not waiting for a time selection"))?;
+
+        self.notify_scheduled(&participants, &interview).await;
+
+        Ok(InterviewWithSlots {
+            interview,
+            slots: current.slots,
+        })
+    }
+
+    // =================================================================
+    // 🤝 ทั้งสองฝ่าย
+    // =================================================================
+
+    pub async fn cancel(
+        &self,
+        user_id: Uuid,
+        interview_id: Uuid,
+        request: CancelInterviewRequest,
+    ) -> Result<Interview> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let current = self.find_interview(interview_id).await?;
+        let participants = self.participants(current.interview.application_id).await?;
+        let party = party_of(&participants, user_id)?;
+
+        let reason = request
+            .reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+
+        let interview = self
+            .interview_repository
+            .cancel(interview_id, reason.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Conflict: interview has already been cancelled"))?;
+
+        // นัดที่ยังไม่ได้ยืนยันไม่อยู่ในปฏิทินของใคร - แจ้งเฉพาะอีกฝ่าย
+        let only_notify = (!current.interview.is(InterviewStatus::Scheduled)).then_some(match party {
+            InterviewParty::Candidate => InterviewParty::Company,
+            InterviewParty::Company => InterviewParty::Candidate,
+        });
+        self.notify_cancelled(&participants, &interview, reason.as_deref(), only_notify)
+            .await;
+
+        Ok(interview)
+    }
+
+    /// นัดทั้งหมดของใบสมัคร (ล่าสุดก่อน)
+    pub async fn list_for_application(
+        &self,
+        user_id: Uuid,
+        application_id: Uuid,
+    ) -> Result<Vec<InterviewWithSlots>> {
+        let participants = self.participants(application_id).await?;
+        party_of(&participants, user_id)?;
+
+        self.interview_repository.find_by_application(application_id).await
+    }
+
+    // =================================================================
+    // 📅 ICS feed
+    // =================================================================
+
+    /// URL ของ feed - สร้าง token ให้ตอนขอครั้งแรก
+    /// เก็บแค่ hash ของ token: feed ที่มีอยู่แล้วไม่คืน URL (ทำ URL หายต้อง rotate)
+    pub async fn feed_url(&self, user_id: Uuid) -> Result<CalendarFeedResponse> {
+        match self.interview_repository.find_feed(user_id).await? {
+            Some(feed) => Ok(CalendarFeedResponse::existing(&feed)),
+            None => self.rotate_feed(user_id).await,
+        }
+    }
+
+    /// เปลี่ยน token - URL เดิมที่แชร์ออกไปใช้ไม่ได้อีก
+    pub async fn rotate_feed(&self, user_id: Uuid) -> Result<CalendarFeedResponse> {
+        let (feed, feed_token) = CalendarFeed::generate(user_id);
+        let feed = self.interview_repository.save_feed(&feed).await?;
+
+        Ok(CalendarFeedResponse::new(&self.backend_url, &feed, &feed_token))
+    }
+
+    /// VCALENDAR (METHOD:PUBLISH) ของเจ้าของ token
+    pub async fn feed_calendar(&self, feed_token: &str) -> Result<String> {
+        let feed = self
+            .interview_repository
+            .find_feed_by_token_hash(&hash_token(feed_token))
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Calendar feed not found"))?;
+
+        let since = Utc::now() - Duration::days(CALENDAR_FEED_HISTORY_DAYS);
+        let events: Vec<CalendarEvent> = self
+            .interview_repository
+            .find_calendar_entries(feed.user_id, since)
+            .await?
+            .iter()
+            .filter_map(|(interview, participants)| {
+                let party = if participants.candidate_id == feed.user_id {
+                    InterviewParty::Candidate
+                } else {
+                    InterviewParty::Company
+                };
+                calendar_event(interview, participants, party)
+            })
+            .collect();
+
+        Ok(calendar::calendar(
+            CalendarMethod::Publish,
+            Some("Smart Persona Interviews"),
+            &events,
+        ))
+    }
+
+    // =================================================================
+    // 🛠️ Helpers
+    // =================================================================
+
+    async fn find_interview(&self, interview_id: Uuid) -> Result<InterviewWithSlots> {
+        self.interview_repository
+            .find_by_id(interview_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Interview not found"))
+    }
+
+    async fn participants(&self, application_id: Uuid) -> Result<InterviewParticipants> {
+        self.interview_repository
+            .find_participants(application_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Application not found"))
+    }
+
+    async fn notify_proposal(
+        &self,
+        participants: &InterviewParticipants,
+        interview: &InterviewWithSlots,
+        rescheduled: bool,
+    ) {
+        let Some(candidate_email) = participants.candidate_email.as_deref() else {
+            tracing::warn!("Candidate of application {} has no email address", participants.application_id);
+            return;
+        };
+        if !self.email_service.is_configured() {
+            tracing::warn!("Email service not configured, interview proposal email not sent");
+            return;
+        }
+
+        let slots: Vec<String> = interview
+            .slots
+            .iter()
+            .map(|slot| calendar::display_range(slot.starts_at, slot.ends_at))
+            .collect();
+        let proposal = InterviewProposalEmail {
+            candidate_name: &participants.candidate_name,
+            company_name: &participants.company_name,
+            job_title: &participants.job_title,
+            slots: &slots,
+            place: &place(&interview.interview),
+            note: interview.interview.note.as_deref(),
+            rescheduled,
+        };
+
+        if let Err(e) = self
+            .email_service
+            .send_interview_proposal_email(candidate_email, &proposal)
+            .await
+        {
+            tracing::error!("Failed to send interview proposal email: {}", e);
+        }
+    }
+
+    /// .ics (REQUEST) ให้ทั้งสองฝ่าย
+    async fn notify_scheduled(&self, participants: &InterviewParticipants, interview: &Interview) {
+        if !self.email_service.is_configured() {
+            tracing::warn!("Email service not configured, interview invitation not sent");
+            return;
+        }
+        let (Some(starts_at), Some(ends_at)) = (interview.starts_at, interview.ends_at) else {
+            return;
+        };
+
+        for party in [InterviewParty::Candidate, InterviewParty::Company] {
+            let (Some(email), name) = recipient(participants, party) else {
+                continue;
+            };
+            let Some(event) = calendar_event(interview, participants, party) else {
+                continue;
+            };
+            let ics = calendar::calendar(CalendarMethod::Request, None, std::slice::from_ref(&event));
+
+            if let Err(e) = self
+                .email_service
+                .send_interview_scheduled_email(
+                    email,
+                    name,
+                    &event.summary,
+                    &calendar::display_range(starts_at, ends_at),
+                    &place(interview),
+                    &ics,
+                )
+                .await
+            {
+                tracing::error!("Failed to send interview invitation: {}", e);
+            }
+        }
+    }
+
+    /// แจ้งยกเลิก - แนบ .ics (CANCEL) เมื่อนัดมีเวลาอยู่ในปฏิทินแล้ว
+    /// `only` = แจ้งเฉพาะฝ่ายนั้น (None = ทั้งสองฝ่าย)
+    async fn notify_cancelled(
+        &self,
+        participants: &InterviewParticipants,
+        interview: &Interview,
+        reason: Option<&str>,
+        only: Option<InterviewParty>,
+    ) {
+        if !self.email_service.is_configured() {
+            tracing::warn!("Email service not configured, interview cancellation not sent");
+            return;
+        }
+
+        for party in [InterviewParty::Candidate, InterviewParty::Company] {
+            if only.is_some_and(|only| only != party) {
+                continue;
+            }
+            let (Some(email), name) = recipient(participants, party) else {
+                continue;
+            };
+
+            let event = calendar_event(interview, participants, party).map(|event| CalendarEvent {
+                cancelled: true,
+                ..event
+            });
+            let ics = event
+                .as_ref()
+                .map(|event| calendar::calendar(CalendarMethod::Cancel, None, std::slice::from_ref(event)));
+            let summary = event
+                .map(|event| event.summary)
+                .unwrap_or_else(|| summary(participants, party));
+
+            if let Err(e) = self
+                .email_service
+                .send_interview_cancelled_email(email, name, &summary, reason, ics.as_deref())
+                .await
+            {
+                tracing::error!("Failed to send interview cancellation email: {}", e);
+            }
+        }
+    }
+}
+
+fn validate_proposal(request: &ProposeInterviewRequest) -> Result<()> {
+    if let Err(errors) = request.validate() {
+        return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+    }
+    request
+        .validate_schedule()
+        .map_err(|e| anyhow::anyhow!("Validation error: {}", e))
+}
+
+fn party_of(participants: &InterviewParticipants, user_id: Uuid) -> Result<InterviewParty> {
+    if participants.candidate_id == user_id {
+        Ok(InterviewParty::Candidate)
+    } else if participants.company_user_id == user_id {
+        Ok(InterviewParty::Company)
+    } else {
+        Err(anyhow::anyhow!("Forbidden: you are not part of this application"))
+    }
+}
+
+fn ensure_party(participants: &InterviewParticipants, user_id: Uuid, expected: InterviewParty) -> Result<()> {
+    if party_of(participants, user_id)? != expected {
+        return Err(anyhow::anyhow!(match expected {
+            InterviewParty::Candidate => "Forbidden: only the candidate can do this",
+            InterviewParty::Company => "Forbidden: only the hiring company can do this",
+        }));
+    }
+    Ok(())
+}
+
+fn ensure_interview_stage(participants: &InterviewParticipants) -> Result<()> {
+    if participants.application_status != ApplicationStatus::Interview.as_str() {
+        return Err(anyhow::anyhow!(
+            "Conflict: application is '{}', move it to the interview stage first",
+            participants.application_status
+        ));
+    }
+    Ok(())
+}
+
+fn recipient(participants: &InterviewParticipants, party: InterviewParty) -> (Option<&str>, &str) {
+    match party {
+        InterviewParty::Candidate => (
+            participants.candidate_email.as_deref(),
+            &participants.candidate_name,
+        ),
+        InterviewParty::Company => (
+            participants.company_email.as_deref(),
+            &participants.company_name,
+        ),
+    }
+}
+
+/// หัวข้อนัดในมุมของแต่ละฝ่าย
+fn summary(participants: &InterviewParticipants, party: InterviewParty) -> String {
+    match party {
+        InterviewParty::Candidate => format!(
+            "Interview: {} at {}",
+            participants.job_title, participants.company_name
+        ),
+        InterviewParty::Company => format!(
+            "Interview: {} for {}",
+            participants.candidate_name, participants.job_title
+        ),
+    }
+}
+
+fn place(interview: &Interview) -> String {
+    [interview.location.as_deref(), interview.video_url.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// VEVENT ของนัดที่มีเวลาแล้ว - None ถ้ายังไม่ได้เลือกเวลา
+fn calendar_event(
+    interview: &Interview,
+    participants: &InterviewParticipants,
+    party: InterviewParty,
+) -> Option<CalendarEvent> {
+    let (starts_at, ends_at) = (interview.starts_at?, interview.ends_at?);
+
+    let description = [
+        interview.note.clone(),
+        interview.video_url.as_ref().map(|url| format!("Video call: {}", url)),
+        interview.cancel_reason.as_ref().map(|reason| format!("Cancelled: {}", reason)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\n\n");
+
+    Some(CalendarEvent {
+        uid: interview.calendar_uid(),
+        sequence: interview.sequence,
+        starts_at,
+        ends_at,
+        summary: summary(participants, party),
+        description: Some(description).filter(|description| !description.is_empty()),
+        location: interview.location.clone().or_else(|| interview.video_url.clone()),
+        url: interview.video_url.clone(),
+        cancelled: interview.is(InterviewStatus::Cancelled),
+        organizer: participants.company_email.as_ref().map(|email| CalendarPerson {
+            name: participants.company_name.clone(),
+            email: email.clone(),
+        }),
+        attendees: participants
+            .candidate_email
+            .iter()
+            .map(|email| CalendarPerson {
+                name: participants.candidate_name.clone(),
+                email: email.clone(),
+            })
+            .collect(),
+        last_modified: interview.updated_at,
+    })
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            company_post::{ScreeningQuestion, is_open_post},
+            job_application::{
+                ApplicationActorRole, ApplicationPipeline, ApplicationStatus, ApplicationTimeline,
+                ApplyForJobRequest, JobApplicationEntity, NewApplicationScreeningAnswer,
+                NewApplicationStatusHistory, NewJobApplication, ScreeningAnswerRequest,
+                UpdateApplicationStatusRequest, WithdrawApplicationRequest,
+            },
+            user::Role,
+        },
+        repo::job_application::JobApplicationRepository,
+    },
+    infrastructure::supabase::storage::RESUME_EXTENSIONS,
+};
+
+/// ผลของการเปลี่ยนสถานะ - ใช้ตัดสินใจว่าต้องแจ้งอีกฝ่ายหรือไม่
+pub struct ApplicationStatusChange {
+    pub application: JobApplicationEntity,
+    pub from: ApplicationStatus,
+    pub to: ApplicationStatus,
+}
+
+/// ตรวจคำตอบทุกข้อของประกาศ - คืนคำตอบที่จะบันทึก และผลว่าติด knockout หรือไม่
+fn screen_answers(
+    questions: &[ScreeningQuestion],
+    answers: &[ScreeningAnswerRequest],
+) -> Result<(Vec<NewApplicationScreeningAnswer>, bool)> {
+    for (index, answer) in answers.iter().enumerate() {
+        if !questions.iter().any(|question| question.id == answer.question_id) {
+            return Err(anyhow::anyhow!(
+                "Validation error: question {} is not a screening question of this job",
+                answer.question_id
+            ));
+        }
+        if answers[..index].iter().any(|other| other.question_id == answer.question_id) {
+            return Err(anyhow::anyhow!(
+                "Validation error: question {} is answered more than once",
+                answer.question_id
+            ));
+        }
+    }
+
+    let mut screened = Vec::with_capacity(questions.len());
+    for question in questions {
+        let answer = answers
+            .iter()
+            .find(|answer| answer.question_id == question.id)
+            .and_then(|answer| answer.answer.as_deref());
+        let outcome = question
+            .evaluate(answer)
+            .map_err(|e| anyhow::anyhow!("Validation error: {}", e))?;
+
+        screened.push(NewApplicationScreeningAnswer {
+            // repository ใส่ id ของใบสมัครที่สร้าง
+            application_id: Uuid::nil(),
+            question_id: Some(question.id),
+            position: question.position,
+            kind: question.kind.clone(),
+            prompt: question.prompt.clone(),
+            answer: outcome.answer,
+            knocked_out: outcome.knocked_out,
+        });
+    }
+
+    let knocked_out = screened.iter().any(|answer| answer.knocked_out);
+    Ok((screened, knocked_out))
+}
+
+pub struct JobApplicationUseCase {
+    repo: Arc<dyn JobApplicationRepository>,
+    pipeline: ApplicationPipeline,
+    /// public URL ของโฟลเดอร์ resume ใน storage (ลงท้ายด้วย '/')
+    resume_url_prefix: String,
+}
+
+impl JobApplicationUseCase {
+    pub fn new(
+        repo: Arc<dyn JobApplicationRepository>,
+        pipeline: ApplicationPipeline,
+        resume_url_prefix: String,
+    ) -> Self {
+        Self {
+            repo,
+            pipeline,
+            resume_url_prefix,
+        }
+    }
+
+    /// เงื่อนไขการสมัคร: ประกาศเปิดรับ, บริษัทได้รับอนุมัติและไม่ถูกแบน,
+    /// ผู้สมัครเป็น PersonaUser ที่ไม่ใช่เจ้าของบริษัทและกรอกโปรไฟล์ขั้นต่ำแล้ว
+    async fn ensure_can_apply(&self, user_id: Uuid, job_id: Uuid) -> Result<()> {
+        let eligibility = self
+            .repo
+            .find_apply_eligibility(user_id, job_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Job post not found"))?;
+
+        if eligibility.applicant_role != Role::PersonaUser {
+            return Err(anyhow::anyhow!("Forbidden: only persona users can apply for jobs"));
+        }
+        if eligibility.company_user_id == user_id {
+            return Err(anyhow::anyhow!("Forbidden: you cannot apply to your own company's job post"));
+        }
+        if !is_open_post(&eligibility.post_status, eligibility.post_closes_at, Utc::now()) {
+            return Err(anyhow::anyhow!("Conflict: this job post is not accepting applications"));
+        }
+        if !eligibility.company_approved() {
+            return Err(anyhow::anyhow!(
+                "Conflict: this company is not approved to receive applications"
+            ));
+        }
+
+        let missing = eligibility.missing_profile_fields();
+        if !missing.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Validation error: please complete your profile before applying (missing: {})",
+                missing.join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// StorageService ตั้งชื่อไฟล์เป็น {uuid}_{user_id}.{ext} - ใช้ resume ของคนอื่นไม่ได้
+    fn is_own_resume(&self, user_id: Uuid, resume_url: &str) -> bool {
+        resume_url
+            .strip_prefix(&self.resume_url_prefix)
+            .and_then(|file_name| file_name.rsplit_once('.'))
+            .is_some_and(|(stem, extension)| {
+                !stem.contains('/')
+                    && stem.ends_with(&format!("_{}", user_id))
+                    && RESUME_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+            })
+    }
+
+    /// ยื่นใบสมัคร พร้อมจดหมาย, resume และคำตอบคัดกรอง
+    /// - ติด knockout ก็ยังยื่นได้ บริษัทกรองดูจากรายชื่อผู้สมัคร
+    pub async fn apply(&self, user_id: Uuid, request: ApplyForJobRequest) -> Result<JobApplicationEntity> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        self.ensure_can_apply(user_id, request.job_id).await?;
+
+        if self.repo.check_existing(user_id, request.job_id).await? {
+            return Err(anyhow::anyhow!("Conflict: You have already applied for this job"));
+        }
+
+        let trimmed = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let cover_letter = trimmed(request.cover_letter);
+        let resume_url = trimmed(request.resume_url);
+        if let Some(resume_url) = &resume_url
+            && !self.is_own_resume(user_id, resume_url)
+        {
+            return Err(anyhow::anyhow!(
+                "Validation error: resume_url must be a PDF / Word file uploaded through /api/storage/upload/resume"
+            ));
+        }
+
+        let questions = self.repo.find_screening_questions(request.job_id).await?;
+        let (answers, knocked_out) = screen_answers(&questions, &request.answers)?;
+
+        self.repo
+            .create(
+                NewJobApplication {
+                    job_id: request.job_id,
+                    user_id,
+                    status: ApplicationStatus::Applied.as_str().to_string(),
+                    cover_letter,
+                    resume_url,
+                    knocked_out,
+                },
+                answers,
+            )
+            .await
+    }
+
+    pub async fn find_application(&self, application_id: Uuid) -> Result<JobApplicationEntity> {
+        self.repo
+            .find_by_id(application_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Application not found"))
+    }
+
+    /// เปลี่ยนสถานะตาม pipeline - สิทธิ์ของ actor ต่อใบสมัครต้องตรวจมาก่อนแล้ว
+    pub async fn change_status(
+        &self,
+        application_id: Uuid,
+        actor_id: Uuid,
+        actor_role: ApplicationActorRole,
+        request: UpdateApplicationStatusRequest,
+    ) -> Result<ApplicationStatusChange> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        let to = ApplicationStatus::parse(&request.status).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Validation error: unknown status '{}'. Must be one of applied, screening, interview, offer, hired, rejected, withdrawn",
+                request.status
+            )
+        })?;
+
+        let application = self.find_application(application_id).await?;
+        let from = ApplicationStatus::parse(&application.status)
+            .ok_or_else(|| anyhow::anyhow!("Application has unknown status '{}'", application.status))?;
+
+        if !self.pipeline.can_transition(from, to, actor_role) {
+            let allowed = self
+                .pipeline
+                .next_statuses(from, actor_role)
+                .iter()
+                .map(|status| status.as_str())
+                .collect::<Vec<_>>();
+
+            return Err(anyhow::anyhow!(
+                "Invalid status transition from '{}' to '{}' (allowed: {})",
+                from.as_str(),
+                to.as_str(),
+                if allowed.is_empty() { "none".to_string() } else { allowed.join(", ") }
+            ));
+        }
+
+        let history = NewApplicationStatusHistory::new(
+            application_id,
+            Some(from),
+            to,
+            Some(actor_id),
+            actor_role,
+            request.note,
+        );
+
+        let application = self
+            .repo
+            .transition_status(from.as_str(), history)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("Conflict: application status was changed by someone else, please reload")
+            })?;
+
+        Ok(ApplicationStatusChange { application, from, to })
+    }
+
+    /// ผู้สมัครถอนใบสมัครของตัวเอง - บันทึกใน history เหมือนการเปลี่ยนสถานะอื่น
+    pub async fn withdraw(
+        &self,
+        application_id: Uuid,
+        user_id: Uuid,
+        request: WithdrawApplicationRequest,
+    ) -> Result<ApplicationStatusChange> {
+        let application = self.find_application(application_id).await?;
+        // ไม่บอกว่ามีใบสมัครนี้อยู่ถ้าไม่ใช่ของผู้เรียก
+        if application.user_id != user_id {
+            return Err(anyhow::anyhow!("Application not found"));
+        }
+
+        self.change_status(
+            application_id,
+            user_id,
+            ApplicationActorRole::Candidate,
+            UpdateApplicationStatusRequest {
+                status: ApplicationStatus::Withdrawn.as_str().to_string(),
+                note: request.reason,
+            },
+        )
+        .await
+    }
+
+    /// timeline ของใบสมัคร พร้อมสถานะที่ผู้เรียกเปลี่ยนต่อได้
+    pub async fn timeline(
+        &self,
+        application: JobApplicationEntity,
+        viewer_role: ApplicationActorRole,
+    ) -> Result<ApplicationTimeline> {
+        let history = self.repo.find_status_history(application.id).await?;
+        let next_statuses = ApplicationStatus::parse(&application.status)
+            .map(|status| self.pipeline.next_statuses(status, viewer_role))
+            .unwrap_or_default();
+
+        Ok(ApplicationTimeline {
+            application_id: application.id,
+            status: application.status,
+            next_statuses,
+            history,
+        })
+    }
+}
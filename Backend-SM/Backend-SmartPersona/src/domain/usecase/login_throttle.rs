@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{
+    config::config_model::RateLimit,
+    domain::{
+        entities::login_throttle::{
+            LoginLockout, LoginLockoutEvent, NewLoginLockoutEvent, ThrottleScope, normalize_username,
+        },
+        repo::login_throttle::LoginThrottleRepository,
+    },
+};
+
+/// จำนวนประวัติการล็อกสูงสุดที่ Admin ดึงได้ต่อครั้ง
+const MAX_EVENTS_LIMIT: i64 = 500;
+
+pub struct LoginThrottleUseCase<T>
+where
+    T: LoginThrottleRepository + Send + Sync,
+{
+    login_throttle_repository: Arc<T>,
+    policy: RateLimit,
+}
+
+impl<T> LoginThrottleUseCase<T>
+where
+    T: LoginThrottleRepository + Send + Sync,
+{
+    pub fn new(login_throttle_repository: Arc<T>, policy: RateLimit) -> Self {
+        Self {
+            login_throttle_repository,
+            policy,
+        }
+    }
+
+    // =================================================================
+    // 🚦 Rate limit
+    // =================================================================
+
+    /// ตรวจ bucket ต่อ IP (ใช้กับ endpoint ที่ไม่มี username เช่น register, MFA)
+    pub async fn check_ip(&self, scope: ThrottleScope, ip_address: &str) -> Result<()> {
+        self.hit_bucket(
+            format!("ip:{}:{}", scope.as_str(), ip_address),
+            self.policy.ip_max_attempts,
+            self.policy.ip_window_secs,
+        )
+        .await
+    }
+
+    /// ตรวจ bucket ต่อ IP และต่อ username แล้วตรวจว่าบัญชีถูกล็อกอยู่หรือไม่
+    pub async fn check_login(&self, scope: ThrottleScope, ip_address: &str, username: &str) -> Result<()> {
+        let username = normalize_username(username);
+
+        self.check_ip(scope, ip_address).await?;
+        self.hit_bucket(
+            format!("user:{}:{}", scope.as_str(), username),
+            self.policy.username_max_attempts,
+            self.policy.username_window_secs,
+        )
+        .await?;
+
+        if let Some(locked_until) = self
+            .login_throttle_repository
+            .find_lockout(&username)
+            .await?
+            .filter(LoginLockout::is_locked)
+            .and_then(|lockout| lockout.locked_until)
+        {
+            return Err(anyhow::anyhow!(
+                "Account temporarily locked. Try again in {} seconds",
+                seconds_until(locked_until)
+            ));
+        }
+
+        Ok(())
+    }
+
+    // =================================================================
+    // 🔒 Lockout
+    // =================================================================
+
+    /// login ผิด - ครบ threshold จะล็อกบัญชี โดยระยะเวลาเพิ่มเป็นสองเท่าทุกครั้งที่ถูกล็อกซ้ำ
+    pub async fn record_failure(&self, username: &str, ip_address: &str) -> Result<()> {
+        let username = normalize_username(username);
+
+        let lockout = self
+            .login_throttle_repository
+            .record_failure(&username, Some(ip_address.to_string()))
+            .await?;
+
+        if lockout.failed_attempts < self.policy.lockout_threshold {
+            return Ok(());
+        }
+
+        let lockout_level = lockout.lockout_level + 1;
+        let locked_until = Utc::now() + self.lockout_duration(lockout_level);
+
+        tracing::warn!(
+            "Locking login for {} until {} after {} failed attempts (level {})",
+            username,
+            locked_until,
+            lockout.failed_attempts,
+            lockout_level
+        );
+
+        self.login_throttle_repository
+            .lock(&NewLoginLockoutEvent {
+                username,
+                ip_address: Some(ip_address.to_string()),
+                lockout_level,
+                failed_attempts: lockout.failed_attempts,
+                locked_until,
+            })
+            .await
+    }
+
+    /// login สำเร็จ - รีเซ็ตตัวนับและระดับการล็อก
+    pub async fn record_success(&self, username: &str) -> Result<()> {
+        self.login_throttle_repository
+            .clear(&normalize_username(username))
+            .await?;
+        Ok(())
+    }
+
+    // =================================================================
+    // 🛡️ Admin
+    // =================================================================
+
+    pub async fn list_active_lockouts(&self) -> Result<Vec<LoginLockout>> {
+        self.login_throttle_repository.list_active_lockouts().await
+    }
+
+    pub async fn list_lockout_events(&self, limit: Option<i64>) -> Result<Vec<LoginLockoutEvent>> {
+        let limit = limit.unwrap_or(100).clamp(1, MAX_EVENTS_LIMIT);
+        self.login_throttle_repository.list_events(limit).await
+    }
+
+    /// Admin ปลดล็อกบัญชีก่อนหมดเวลา
+    pub async fn unlock(&self, username: &str) -> Result<()> {
+        if !self
+            .login_throttle_repository
+            .clear(&normalize_username(username))
+            .await?
+        {
+            return Err(anyhow::anyhow!("Lockout not found"));
+        }
+        Ok(())
+    }
+
+    // =================================================================
+    // 🧹 Maintenance
+    // =================================================================
+
+    /// ลบ bucket ที่ window หมดอายุแล้ว (window ที่ยาวที่สุด)
+    pub async fn cleanup_stale_buckets(&self) -> Result<usize> {
+        let longest_window = self.policy.ip_window_secs.max(self.policy.username_window_secs);
+        self.login_throttle_repository
+            .delete_stale_buckets(Utc::now() - Duration::seconds(longest_window))
+            .await
+    }
+
+    // =================================================================
+    // 🛠️ Helpers
+    // =================================================================
+
+    async fn hit_bucket(&self, bucket_key: String, max_attempts: i32, window_secs: i64) -> Result<()> {
+        let window = Duration::seconds(window_secs);
+        let (hits, window_started_at) = self
+            .login_throttle_repository
+            .hit(&bucket_key, window)
+            .await?;
+
+        if hits > max_attempts {
+            return Err(anyhow::anyhow!(
+                "Too many requests. Try again in {} seconds",
+                seconds_until(window_started_at + window)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn lockout_duration(&self, lockout_level: i32) -> Duration {
+        let multiplier = 2_i64.saturating_pow(lockout_level.saturating_sub(1).clamp(0, 30) as u32);
+        Duration::seconds(
+            self.policy
+                .lockout_base_secs
+                .saturating_mul(multiplier)
+                .min(self.policy.lockout_max_secs),
+        )
+    }
+}
+
+fn seconds_until(until: DateTime<Utc>) -> i64 {
+    (until - Utc::now()).num_seconds().max(1)
+}
@@ -1,11 +1,25 @@
+pub mod account_deletion;
+pub mod account_moderation;
+pub mod account_token;
 pub mod admin_data;
+pub mod admin_invite;
 pub mod ads;
 pub mod ai_analysis;
+pub mod audit_log;
 pub mod authentication;
 pub mod company;
+pub mod company_api_key;
 pub mod company_gallery;
+pub mod company_ownership;
 pub mod company_post;
+pub mod data_export;
+pub mod impersonation;
+pub mod interview;
+pub mod job_application;
+pub mod login_throttle;
+pub mod oidc;
 pub mod saved_job;
+pub mod saved_search;
 pub mod social;
 pub mod user;
 pub mod user_address;
@@ -14,8 +28,10 @@ pub mod user_education;
 pub mod user_experience;
 pub mod user_job_match;
 pub mod user_job_preference;
+pub mod user_mfa;
 pub mod user_portfolio;
 pub mod user_privacy_settings;
 pub mod user_profile;
+pub mod user_session;
 pub mod user_share;
 pub mod user_skill;
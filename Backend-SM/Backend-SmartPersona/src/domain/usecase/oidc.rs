@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use rand::{Rng, distributions::Alphanumeric};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            oidc::{
+                NewOidcAuthRequest, NewUserIdentity, OidcCallbackOutcome, OidcCallbackQuery,
+                UserIdentity, generate_username, pkce_code_challenge,
+            },
+            user::{RegisterUserEntity, Role, UserStatusEnum},
+        },
+        repo::{oidc::OidcRepository, user::UserRepository},
+        value_object::user::normalize_email,
+    },
+    infrastructure::{
+        hashingpassword,
+        oidc::client::{OidcClient, OidcIdentityClaims},
+    },
+};
+
+/// จำนวนครั้งที่ลองสุ่ม username ใหม่เมื่อชนกับบัญชีที่มีอยู่
+const USERNAME_ATTEMPTS: usize = 3;
+
+pub struct OidcUseCase<TUser, TOidc>
+where
+    TUser: UserRepository + Send + Sync,
+    TOidc: OidcRepository + Send + Sync,
+{
+    user_repository: Arc<TUser>,
+    oidc_repository: Arc<TOidc>,
+    oidc_client: Arc<OidcClient>,
+}
+
+impl<TUser, TOidc> OidcUseCase<TUser, TOidc>
+where
+    TUser: UserRepository + Send + Sync,
+    TOidc: OidcRepository + Send + Sync,
+{
+    pub fn new(
+        user_repository: Arc<TUser>,
+        oidc_repository: Arc<TOidc>,
+        oidc_client: Arc<OidcClient>,
+    ) -> Self {
+        Self {
+            user_repository,
+            oidc_repository,
+            oidc_client,
+        }
+    }
+
+    pub fn providers(&self) -> Vec<String> {
+        self.oidc_client.provider_names()
+    }
+
+    // =================================================================
+    // ➡️ Authorization request
+    // =================================================================
+
+    /// บันทึก state / nonce / PKCE verifier แล้วคืนค่า URL ของ provider
+    /// link_user_id มีค่าเมื่อ user ที่ login อยู่แล้วขอผูกบัญชีเพิ่ม
+    pub async fn begin(
+        &self,
+        provider: &str,
+        link_user_id: Option<Uuid>,
+        return_to: Option<String>,
+    ) -> Result<String> {
+        self.oidc_client.provider(provider)?;
+
+        let (new_request, raw_state) =
+            NewOidcAuthRequest::generate(provider.to_string(), link_user_id, return_to);
+        self.oidc_repository.create_auth_request(&new_request).await?;
+
+        self.oidc_client.authorization_url(
+            provider,
+            &raw_state,
+            &new_request.nonce,
+            &pkce_code_challenge(&new_request.code_verifier),
+        )
+    }
+
+    // =================================================================
+    // ⬅️ Callback
+    // =================================================================
+
+    pub async fn complete(&self, provider: &str, query: OidcCallbackQuery) -> Result<OidcCallbackOutcome> {
+        if let Some(error) = query.error {
+            return Err(anyhow::anyhow!(
+                "Provider returned an error: {} {}",
+                error,
+                query.error_description.unwrap_or_default()
+            ));
+        }
+
+        let (Some(code), Some(state)) = (query.code, query.state) else {
+            return Err(anyhow::anyhow!("Invalid OIDC callback: missing code or state"));
+        };
+
+        let auth_request = self
+            .oidc_repository
+            .take_auth_request(&hash_token(&state))
+            .await?
+            .filter(|auth_request| auth_request.provider == provider)
+            .filter(|auth_request| auth_request.expires_at > chrono::Utc::now())
+            .ok_or_else(|| anyhow::anyhow!("Invalid or expired state"))?;
+
+        let id_token = self
+            .oidc_client
+            .exchange_code(provider, &code, &auth_request.code_verifier)
+            .await?;
+        let claims = self
+            .oidc_client
+            .verify_id_token(provider, &id_token, &auth_request.nonce)
+            .await?;
+
+        match auth_request.link_user_id {
+            Some(user_id) => {
+                self.link_identity(user_id, provider, claims).await?;
+                Ok(OidcCallbackOutcome::Linked {
+                    return_to: auth_request.return_to,
+                })
+            }
+            None => {
+                let user_id = self.sign_in_identity(provider, claims).await?;
+                Ok(OidcCallbackOutcome::SignedIn {
+                    user: self.user_repository.find_by_id(user_id).await?,
+                    return_to: auth_request.return_to,
+                })
+            }
+        }
+    }
+
+    // =================================================================
+    // 🔗 Linked identities
+    // =================================================================
+
+    pub async fn list_identities(&self, user_id: Uuid) -> Result<Vec<UserIdentity>> {
+        self.oidc_repository.list_identities(user_id).await
+    }
+
+    /// ยกเลิกการผูกบัญชี - บัญชีที่สร้างจาก OIDC ยังเข้าสู่ระบบได้ด้วยการรีเซ็ตรหัสผ่านทางอีเมล
+    pub async fn unlink(&self, user_id: Uuid, provider: &str) -> Result<()> {
+        if !self.oidc_repository.delete_identity(user_id, provider).await? {
+            return Err(anyhow::anyhow!("Linked identity not found"));
+        }
+        Ok(())
+    }
+
+    /// ลบ state ที่ผู้ใช้ไม่ได้กลับมาที่ callback
+    pub async fn cleanup_expired_requests(&self) -> Result<usize> {
+        self.oidc_repository.delete_expired_auth_requests().await
+    }
+
+    // =================================================================
+    // 🛠️ Helpers
+    // =================================================================
+
+    async fn link_identity(&self, user_id: Uuid, provider: &str, claims: OidcIdentityClaims) -> Result<()> {
+        if let Some(identity) = self
+            .oidc_repository
+            .find_identity(provider, &claims.subject)
+            .await?
+        {
+            if identity.user_id == user_id {
+                return Ok(());
+            }
+            return Err(anyhow::anyhow!("This identity is already linked to another account"));
+        }
+
+        self.oidc_repository
+            .create_identity(&NewUserIdentity {
+                user_id,
+                provider: provider.to_string(),
+                email: verified_email(&claims),
+                subject: claims.subject,
+                last_login_at: None,
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// หา user จากบัญชีที่ผูกไว้ ถ้ายังไม่มีจะสร้างบัญชีใหม่
+    async fn sign_in_identity(&self, provider: &str, claims: OidcIdentityClaims) -> Result<Uuid> {
+        if let Some(identity) = self
+            .oidc_repository
+            .find_identity(provider, &claims.subject)
+            .await?
+        {
+            let user = self.user_repository.find_by_id(identity.user_id).await?;
+            if user.role == Role::Admin {
+                return Err(anyhow::anyhow!(
+                    "Forbidden: Admin accounts must sign in with password and MFA"
+                ));
+            }
+
+            self.oidc_repository.touch_identity(identity.id).await?;
+            return Ok(user.id);
+        }
+
+        let email = verified_email(&claims);
+
+        // ไม่ผูกกับบัญชีเดิมอัตโนมัติจากอีเมล - เจ้าของบัญชีต้อง login แล้วผูกเอง
+        if let Some(email) = &email
+            && self.user_repository.find_by_email(email.clone()).await?.is_some()
+        {
+            return Err(anyhow::anyhow!(
+                "An account with this email already exists. Sign in and link the provider from account settings"
+            ));
+        }
+
+        // บัญชีใหม่ไม่มีรหัสผ่านที่ใช้ได้ (ตั้งได้ภายหลังผ่าน password reset)
+        let unusable_password: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(64)
+            .map(char::from)
+            .collect();
+        let password_hash = hashingpassword::hash(unusable_password)?;
+        let username_hint = claims.preferred_username.as_deref().or(email.as_deref());
+
+        let mut last_error = None;
+        for _ in 0..USERNAME_ATTEMPTS {
+            let now = chrono::Utc::now().naive_utc();
+            let new_user = RegisterUserEntity {
+                username: generate_username(username_hint),
+                password_hash: password_hash.clone(),
+                email: email.clone(),
+                role: Some(Role::PersonaUser),
+                // อีเมลยืนยันแล้วโดย provider หรือไม่มีอีเมลเลย
+                status: Some(UserStatusEnum::Active),
+                created_at: now,
+                updated_at: now,
+            };
+
+            match self
+                .oidc_repository
+                .create_user_with_identity(new_user, provider.to_string(), claims.subject.clone())
+                .await
+            {
+                Ok(user_id) => return Ok(user_id),
+                Err(e) if e.to_string().contains("Username already taken") => last_error = Some(e),
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| anyhow::anyhow!("Failed to create account")))
+    }
+}
+
+/// เก็บเฉพาะอีเมลที่ provider ยืนยันแล้ว
+fn verified_email(claims: &OidcIdentityClaims) -> Option<String> {
+    claims
+        .email
+        .as_deref()
+        .filter(|_| claims.email_verified)
+        .map(normalize_email)
+}
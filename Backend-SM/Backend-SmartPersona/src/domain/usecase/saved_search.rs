@@ -0,0 +1,310 @@
+use std::{collections::HashSet, sync::Arc};
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            company_post::JobSearchSort,
+            saved_search::{
+                CreateSavedSearchRequest, MAX_ALERT_MATCHES_PER_RUN, MAX_SAVED_SEARCHES_PER_USER,
+                SavedSearch, SavedSearchCriteria, UNSUBSCRIBE_LINK_TTL_DAYS,
+                UpdateSavedSearchRequest, generate_unsubscribe_token, unsubscribe_url,
+            },
+        },
+        repo::{company_post::CompanyPostRepository, saved_search::SavedSearchRepository},
+        usecase::company_post::job_search_filter,
+    },
+    infrastructure::email::{EmailService, JobAlertEmailPost, JobAlertEmailSection},
+};
+
+/// ตัวจับคู่มองย้อนหลังช้ากว่าเวลาปัจจุบันเล็กน้อย
+/// กันประกาศที่ transaction ยัง commit ไม่เสร็จตอนจับคู่หลุดไป
+const ALERT_MATCH_LAG_SECONDS: i64 = 60;
+
+/// ตรวจเงื่อนไขด้วยกฎเดียวกับ /posts/search
+fn validate_criteria(criteria: &SavedSearchCriteria) -> Result<()> {
+    if criteria.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Validation error: a saved search needs at least one search condition"
+        ));
+    }
+    job_search_filter(criteria.to_query()).map(|_| ())
+}
+
+pub struct SavedSearchUseCase<T>
+where
+    T: SavedSearchRepository + Send + Sync,
+{
+    saved_search_repository: Arc<T>,
+    company_post_repository: Arc<dyn CompanyPostRepository>,
+    email_service: Arc<EmailService>,
+    backend_url: String,
+}
+
+impl<T> SavedSearchUseCase<T>
+where
+    T: SavedSearchRepository + Send + Sync,
+{
+    pub fn new(
+        saved_search_repository: Arc<T>,
+        company_post_repository: Arc<dyn CompanyPostRepository>,
+        email_service: Arc<EmailService>,
+        backend_url: String,
+    ) -> Self {
+        Self {
+            saved_search_repository,
+            company_post_repository,
+            email_service,
+            backend_url,
+        }
+    }
+
+    // =================================================================
+    // 💾 จัดการการค้นหาที่บันทึกไว้
+    // =================================================================
+
+    pub async fn list(&self, user_id: Uuid) -> Result<Vec<SavedSearch>> {
+        self.saved_search_repository.find_by_user(user_id).await
+    }
+
+    /// บันทึกการค้นหา - แจ้งเฉพาะประกาศที่เผยแพร่หลังจากนี้
+    pub async fn create(&self, user_id: Uuid, request: CreateSavedSearchRequest) -> Result<SavedSearch> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+        validate_criteria(&request.criteria)?;
+
+        if self.saved_search_repository.count_by_user(user_id).await? >= MAX_SAVED_SEARCHES_PER_USER {
+            return Err(anyhow::anyhow!(
+                "Conflict: you can save up to {} searches",
+                MAX_SAVED_SEARCHES_PER_USER
+            ));
+        }
+
+        let new_saved_search = request.into_new_saved_search(user_id, Utc::now());
+        self.saved_search_repository.create(&new_saved_search).await
+    }
+
+    pub async fn update(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        request: UpdateSavedSearchRequest,
+    ) -> Result<SavedSearch> {
+        if let Err(errors) = request.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+        if let Some(criteria) = &request.criteria {
+            validate_criteria(criteria)?;
+        }
+
+        let current = self
+            .saved_search_repository
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Saved search not found"))?;
+
+        let changes = request.into_update(&current, Utc::now());
+        if !changes.has_changes() {
+            return Ok(current);
+        }
+
+        self.saved_search_repository
+            .update(id, user_id, &changes)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Saved search not found"))
+    }
+
+    pub async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<()> {
+        if !self.saved_search_repository.delete(id, user_id).await? {
+            return Err(anyhow::anyhow!("Saved search not found"));
+        }
+        Ok(())
+    }
+
+    /// ลิงก์ยกเลิกในอีเมล - ใช้ได้โดยไม่ต้อง login
+    /// token ของ digest ทั้งฉบับ (List-Unsubscribe) ปิดทุกการค้นหาในฉบับนั้น
+    pub async fn unsubscribe(&self, unsubscribe_token: &str) -> Result<Vec<SavedSearch>> {
+        let unsubscribed = self
+            .saved_search_repository
+            .unsubscribe(&hash_token(unsubscribe_token))
+            .await?;
+
+        if unsubscribed.is_empty() {
+            return Err(anyhow::anyhow!("Unsubscribe link not found"));
+        }
+        Ok(unsubscribed)
+    }
+
+    // =================================================================
+    // 🔔 ตัวจับคู่ + digest
+    // =================================================================
+
+    /// จับคู่ประกาศที่เพิ่งเผยแพร่ แล้วส่ง digest ให้ผู้ใช้ที่ถึงรอบ
+    /// คืนค่า (จำนวนประกาศที่จับคู่ได้, จำนวนอีเมลที่ส่ง)
+    pub async fn process_alerts(&self) -> Result<(usize, usize)> {
+        let matched = self.match_new_posts().await?;
+
+        // ยังไม่ได้ตั้งค่า SMTP - เก็บประกาศที่จับคู่ไว้ส่งเมื่อพร้อม
+        if !self.email_service.is_configured() {
+            return Ok((matched, 0));
+        }
+
+        let sent = self.send_digests().await?;
+        Ok((matched, sent))
+    }
+
+    async fn match_new_posts(&self) -> Result<usize> {
+        let until = Utc::now() - Duration::seconds(ALERT_MATCH_LAG_SECONDS);
+        let mut matched = 0;
+
+        for saved_search in self.saved_search_repository.find_alerting().await? {
+            if saved_search.matched_until >= until {
+                continue;
+            }
+
+            let filter = saved_search
+                .criteria()
+                .map_err(anyhow::Error::from)
+                .and_then(|criteria| job_search_filter(criteria.to_query()));
+            let mut filter = match filter {
+                Ok(filter) => filter,
+                Err(e) => {
+                    tracing::warn!("Skipping saved search {} with invalid criteria: {}", saved_search.id, e);
+                    continue;
+                }
+            };
+            filter.published_after = Some(saved_search.matched_until);
+            filter.published_before = Some(until);
+            filter.sort = JobSearchSort::Recent;
+            filter.limit = MAX_ALERT_MATCHES_PER_RUN;
+
+            let post_ids: Vec<Uuid> = self
+                .company_post_repository
+                .search(&filter)
+                .await?
+                .into_iter()
+                .map(|hit| hit.post.post.id)
+                .collect();
+
+            matched += self
+                .saved_search_repository
+                .record_matches(saved_search.id, &post_ids, until)
+                .await?;
+        }
+
+        Ok(matched)
+    }
+
+    /// digest หนึ่งฉบับต่อผู้ใช้ - ประกาศเดียวกันอยู่ได้แค่ใต้การค้นหาแรกที่ตรง
+    async fn send_digests(&self) -> Result<usize> {
+        let now = Utc::now();
+        let due: Vec<SavedSearch> = self
+            .saved_search_repository
+            .find_with_pending_matches()
+            .await?
+            .into_iter()
+            .filter(|saved_search| saved_search.is_digest_due(now))
+            .collect();
+
+        let mut user_ids: Vec<Uuid> = due.iter().map(|saved_search| saved_search.user_id).collect();
+        user_ids.dedup();
+
+        let mut sent = 0;
+        for user_id in user_ids {
+            let searches: Vec<&SavedSearch> = due
+                .iter()
+                .filter(|saved_search| saved_search.user_id == user_id)
+                .collect();
+            let search_ids: Vec<Uuid> = searches.iter().map(|saved_search| saved_search.id).collect();
+
+            match self.send_digest(user_id, &searches).await {
+                Ok(true) => sent += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    // ไม่ปิดรอบ - ลองส่งใหม่ในรอบถัดไป
+                    tracing::error!("Failed to send job alert digest to user {}: {}", user_id, e);
+                    continue;
+                }
+            }
+
+            self.saved_search_repository.mark_sent(&search_ids, now).await?;
+        }
+
+        Ok(sent)
+    }
+
+    /// false ถ้าไม่มีอะไรต้องส่ง (ประกาศปิดไปแล้ว / เคยส่งแล้ว / ไม่มีอีเมล)
+    async fn send_digest(&self, user_id: Uuid, searches: &[&SavedSearch]) -> Result<bool> {
+        let search_ids: Vec<Uuid> = searches.iter().map(|saved_search| saved_search.id).collect();
+        let pending = self
+            .saved_search_repository
+            .find_pending_posts(user_id, &search_ids)
+            .await?;
+
+        let mut seen = HashSet::new();
+        let matches: Vec<(&SavedSearch, Vec<JobAlertEmailPost>)> = searches
+            .iter()
+            .map(|saved_search| {
+                let posts: Vec<JobAlertEmailPost> = pending
+                    .iter()
+                    .filter(|post| post.saved_search_id == saved_search.id)
+                    .filter(|post| seen.insert(post.post_id))
+                    .map(|post| JobAlertEmailPost {
+                        title: post.title.clone(),
+                        company_name: post.company_name.clone(),
+                        location: post.location.clone(),
+                        job_type: post.job_type.clone(),
+                    })
+                    .collect();
+                (*saved_search, posts)
+            })
+            .filter(|(_, posts)| !posts.is_empty())
+            .collect();
+
+        if matches.is_empty() {
+            return Ok(false);
+        }
+
+        let (name, email) = self.saved_search_repository.find_recipient(user_id).await?;
+        let Some(email) = email else {
+            tracing::warn!("Skipping job alert digest for user {} without an email address", user_id);
+            return Ok(false);
+        };
+
+        // token จริงอยู่แค่ในอีเมลฉบับนี้ - database เก็บเฉพาะ hash
+        let expired_before = Utc::now() - Duration::days(UNSUBSCRIBE_LINK_TTL_DAYS);
+        // one-click unsubscribe (List-Unsubscribe, RFC 8058) ใส่ได้ URL เดียว - token นี้ปิดทุกการค้นหาใน digest
+        let digest_unsubscribe_token = generate_unsubscribe_token();
+        let mut sections = Vec::with_capacity(matches.len());
+        for (saved_search, posts) in matches {
+            let unsubscribe_token = generate_unsubscribe_token();
+            self.saved_search_repository
+                .add_unsubscribe_token(saved_search.id, &hash_token(&unsubscribe_token), expired_before)
+                .await?;
+            self.saved_search_repository
+                .add_unsubscribe_token(saved_search.id, &hash_token(&digest_unsubscribe_token), expired_before)
+                .await?;
+            sections.push(JobAlertEmailSection {
+                search_name: saved_search.name.clone(),
+                unsubscribe_url: unsubscribe_url(&self.backend_url, &unsubscribe_token),
+                posts,
+            });
+        }
+
+        self.email_service
+            .send_job_alert_digest(
+                &email,
+                &name,
+                &sections,
+                &unsubscribe_url(&self.backend_url, &digest_unsubscribe_token),
+            )
+            .await?;
+        Ok(true)
+    }
+}
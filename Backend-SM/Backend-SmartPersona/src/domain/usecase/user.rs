@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
-use crate::{domain::{entities::user::{UserEntity, Role}, repo::user::UserRepository, value_object::user::RegisterUserModel}, infrastructure::hashingpassword};
+use crate::{domain::{entities::user::{UserEntity, Role}, repo::user::UserRepository, value_object::user::{AdminAccountModel, RegisterUserModel}}, infrastructure::hashingpassword};
 use anyhow::{Ok, Result};
 use uuid::Uuid;
+use validator::Validate;
 pub struct UserUseCase<T>
 where
     T: UserRepository + Send + Sync,
@@ -20,24 +21,59 @@ where
         &self,
         mut register_user_model: RegisterUserModel,
     ) -> anyhow::Result<Uuid> {
+        if let Err(errors) = register_user_model.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        // สมัครเองได้เฉพาะ PersonaUser / CompanyUser
+        if register_user_model.self_service_role().is_none() {
+            return Err(anyhow::anyhow!("Forbidden: Admin accounts cannot be self-registered"));
+        }
+
+        if self
+            .user_repository
+            .find_by_email(register_user_model.email.clone())
+            .await?
+            .is_some()
+        {
+            return Err(anyhow::anyhow!("Email already registered"));
+        }
+
         let hashed_password = hashingpassword::hash(register_user_model.password.clone())?;
 
         register_user_model.password = hashed_password;
         let register_user_entity = register_user_model.to_entity();
         
-        // Repository will handle default role if None
         let user_id = self.user_repository.register(register_user_entity).await?;
         Ok(user_id)
     }
+    /// สร้าง Admin คนแรกของระบบ (ใช้โดย bootstrap CLI) - ใช้ได้เฉพาะตอนที่ยังไม่มี Admin
+    pub async fn bootstrap_admin(&self, admin_account_model: AdminAccountModel) -> Result<Uuid> {
+        if let Err(errors) = admin_account_model.validate() {
+            return Err(anyhow::anyhow!("Validation error: {:?}", errors));
+        }
+
+        if self.user_repository.exists_with_role(Role::Admin).await? {
+            return Err(anyhow::anyhow!(
+                "An admin account already exists, invite new admins from the admin panel"
+            ));
+        }
+
+        let password_hash = hashingpassword::hash(admin_account_model.password.clone())?;
+        self.user_repository
+            .register(admin_account_model.to_entity(password_hash))
+            .await
+    }
+
     pub async fn get_user_by_id(&self, user_id: Uuid) -> Result<UserEntity> {
         self.user_repository.find_by_id(user_id).await
     }
 
     pub async fn ban_user(&self, user_id: Uuid) -> Result<UserEntity> {
-        self.user_repository.update_status(user_id, "suspended".to_string()).await
+        self.user_repository.suspend(user_id).await
     }
 
     pub async fn unban_user(&self, user_id: Uuid) -> Result<UserEntity> {
-        self.user_repository.update_status(user_id, "active".to_string()).await
+        self.user_repository.reactivate(user_id).await
     }
 }
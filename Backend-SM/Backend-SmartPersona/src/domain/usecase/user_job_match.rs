@@ -3,7 +3,9 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::user_job_match::{CreateUserJobMatchRequest, NewUserJobMatch, UserJobMatchEntity},
+    entities::user_job_match::{
+        CreateUserJobMatchRequest, NewUserJobMatch, UserJobMatchEntity, UserJobMatchWithJob,
+    },
     repo::user_job_match::UserJobMatchRepository,
 };
 
@@ -40,7 +42,7 @@ where
         self.repo.create_many(&new_matches).await
     }
 
-    pub async fn get_matches(&self, user_id: Uuid) -> Result<Vec<UserJobMatchEntity>> {
-        self.repo.get_by_user_id(user_id).await
+    pub async fn get_matches(&self, user_id: Uuid) -> Result<Vec<UserJobMatchWithJob>> {
+        self.repo.get_with_jobs_by_user_id(user_id).await
     }
 }
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            account_token::hash_token,
+            user_mfa::{
+                MfaEnrollmentResponse, MfaStatusResponse, RECOVERY_CODE_COUNT, UserMfa,
+                generate_recovery_code, normalize_recovery_code,
+            },
+        },
+        repo::user_mfa::UserMfaRepository,
+    },
+    infrastructure::totp,
+};
+
+pub struct UserMfaUseCase<T>
+where
+    T: UserMfaRepository + Send + Sync,
+{
+    user_mfa_repository: Arc<T>,
+}
+
+impl<T> UserMfaUseCase<T>
+where
+    T: UserMfaRepository + Send + Sync,
+{
+    pub fn new(user_mfa_repository: Arc<T>) -> Self {
+        Self {
+            user_mfa_repository,
+        }
+    }
+
+    pub async fn is_enabled(&self, user_id: Uuid) -> Result<bool> {
+        Ok(self
+            .user_mfa_repository
+            .find_by_user(user_id)
+            .await?
+            .is_some_and(|mfa| mfa.is_enabled()))
+    }
+
+    pub async fn get_status(&self, user_id: Uuid) -> Result<MfaStatusResponse> {
+        let mfa = self
+            .user_mfa_repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|mfa| mfa.is_enabled());
+
+        let recovery_codes_remaining = match mfa {
+            Some(_) => {
+                self.user_mfa_repository
+                    .count_unused_recovery_codes(user_id)
+                    .await?
+            }
+            None => 0,
+        };
+
+        Ok(MfaStatusResponse {
+            enabled: mfa.is_some(),
+            enabled_at: mfa.and_then(|mfa| mfa.enabled_at),
+            recovery_codes_remaining,
+        })
+    }
+
+    /// เริ่มลงทะเบียน - สร้าง secret ใหม่ (ยังไม่เปิดใช้งานจนกว่าจะยืนยันรหัสแรก)
+    pub async fn begin_enrollment(
+        &self,
+        user_id: Uuid,
+        account_name: &str,
+    ) -> Result<MfaEnrollmentResponse> {
+        if self.is_enabled(user_id).await? {
+            return Err(anyhow::anyhow!("MFA is already enabled"));
+        }
+
+        let secret = totp::generate_secret();
+        self.user_mfa_repository
+            .save_pending_secret(user_id, &secret)
+            .await?;
+
+        let issuer = std::env::var("APP_NAME").unwrap_or_else(|_| "Smart Persona".to_string());
+
+        Ok(MfaEnrollmentResponse {
+            provisioning_uri: totp::provisioning_uri(&issuer, account_name, &secret),
+            secret,
+        })
+    }
+
+    /// ยืนยันรหัสแรกจากแอป แล้วเปิดใช้งาน MFA - คืนค่า recovery codes (แสดงครั้งเดียว)
+    pub async fn confirm_enrollment(&self, user_id: Uuid, code: &str) -> Result<Vec<String>> {
+        let mfa = self
+            .user_mfa_repository
+            .find_by_user(user_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("MFA enrollment not started"))?;
+
+        if mfa.is_enabled() {
+            return Err(anyhow::anyhow!("MFA is already enabled"));
+        }
+
+        let step = totp::verify(&mfa.totp_secret, code, totp::current_step())
+            .ok_or_else(|| anyhow::anyhow!("Invalid MFA code"))?;
+
+        let (recovery_codes, hashes) = new_recovery_codes();
+        if !self
+            .user_mfa_repository
+            .enable(user_id, step as i64, hashes)
+            .await?
+        {
+            return Err(anyhow::anyhow!("MFA is already enabled"));
+        }
+
+        Ok(recovery_codes)
+    }
+
+    /// ตรวจสอบปัจจัยที่สอง: รหัส TOTP หรือ recovery code
+    pub async fn verify(&self, user_id: Uuid, code: &str) -> Result<()> {
+        let mfa = self
+            .user_mfa_repository
+            .find_by_user(user_id)
+            .await?
+            .filter(|mfa| mfa.is_enabled())
+            .ok_or_else(|| anyhow::anyhow!("MFA is not enabled"))?;
+
+        if self.verify_totp(&mfa, code).await? {
+            return Ok(());
+        }
+
+        let code_hash = hash_token(&normalize_recovery_code(code));
+        if self
+            .user_mfa_repository
+            .use_recovery_code(user_id, &code_hash)
+            .await?
+        {
+            tracing::info!("User {} signed in with a recovery code", user_id);
+            return Ok(());
+        }
+
+        Err(anyhow::anyhow!("Invalid MFA code"))
+    }
+
+    /// ออก recovery codes ชุดใหม่ (ต้องยืนยันด้วยรหัสปัจจุบัน)
+    pub async fn regenerate_recovery_codes(&self, user_id: Uuid, code: &str) -> Result<Vec<String>> {
+        self.verify(user_id, code).await?;
+
+        let (recovery_codes, hashes) = new_recovery_codes();
+        self.user_mfa_repository
+            .replace_recovery_codes(user_id, hashes)
+            .await?;
+
+        Ok(recovery_codes)
+    }
+
+    /// ปิด MFA (ต้องยืนยันด้วยรหัสปัจจุบัน)
+    pub async fn disable(&self, user_id: Uuid, code: &str) -> Result<()> {
+        self.verify(user_id, code).await?;
+        self.user_mfa_repository.delete(user_id).await
+    }
+
+    /// รหัสแต่ละช่วงเวลาใช้ได้ครั้งเดียว
+    async fn verify_totp(&self, mfa: &UserMfa, code: &str) -> Result<bool> {
+        let Some(step) = totp::verify(&mfa.totp_secret, code, totp::current_step()) else {
+            return Ok(false);
+        };
+
+        self.user_mfa_repository
+            .record_used_step(mfa.user_id, step as i64)
+            .await
+    }
+}
+
+/// คืนค่า (codes สำหรับแสดงผู้ใช้, hashes สำหรับบันทึก)
+fn new_recovery_codes() -> (Vec<String>, Vec<String>) {
+    let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+        .map(|_| generate_recovery_code())
+        .collect();
+    let hashes = recovery_codes.iter().map(|code| hash_token(code)).collect();
+    (recovery_codes, hashes)
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user_session::SessionResponse, repo::user_session::UserSessionRepository,
+};
+
+pub struct UserSessionUseCase<T>
+where
+    T: UserSessionRepository + Send + Sync,
+{
+    user_session_repository: Arc<T>,
+}
+
+impl<T> UserSessionUseCase<T>
+where
+    T: UserSessionRepository + Send + Sync,
+{
+    pub fn new(user_session_repository: Arc<T>) -> Self {
+        Self {
+            user_session_repository,
+        }
+    }
+
+    /// รายการ session/device ที่ยังใช้งานได้ของ user
+    pub async fn get_active_sessions(
+        &self,
+        user_id: Uuid,
+        current_family_id: Option<Uuid>,
+    ) -> Result<Vec<SessionResponse>> {
+        let sessions = self
+            .user_session_repository
+            .list_active_by_user(user_id)
+            .await?;
+
+        Ok(sessions
+            .into_iter()
+            .map(|session| SessionResponse::from_session(session, current_family_id))
+            .collect())
+    }
+
+    /// Revoke session หนึ่งของ user (logout device นั้น)
+    pub async fn revoke_session(&self, user_id: Uuid, family_id: Uuid) -> Result<()> {
+        let revoked = self
+            .user_session_repository
+            .revoke_family(user_id, family_id, "user_revoked")
+            .await?;
+
+        if revoked == 0 {
+            return Err(anyhow::anyhow!("Session not found"));
+        }
+
+        Ok(())
+    }
+
+    /// Revoke ทุก session ยกเว้น session ปัจจุบัน
+    pub async fn revoke_other_sessions(
+        &self,
+        user_id: Uuid,
+        current_family_id: Option<Uuid>,
+    ) -> Result<usize> {
+        self.user_session_repository
+            .revoke_all_for_user(user_id, current_family_id, "user_revoked")
+            .await
+    }
+
+    /// ลบ refresh token ที่หมดอายุแล้ว
+    pub async fn cleanup_expired_sessions(&self) -> Result<usize> {
+        self.user_session_repository.delete_expired().await
+    }
+}
@@ -106,26 +106,46 @@ where
             .get_by_user_id(share.user_id)
             .await?;
 
-        if let Some(settings) = privacy_settings {
-            if !settings.show_profile {
-                return Err(anyhow!("Profile is not available for viewing"));
-            }
-        } else {
+        let settings = match privacy_settings {
+            Some(settings) if settings.show_profile => settings,
             // ถ้าไม่มี privacy settings ให้ default เป็น private (show_profile = false) เพื่อความปลอดภัย
             // Users must explicitly enable public profile
-            return Err(anyhow!("Profile is not available for viewing"));
-        }
+            _ => return Err(anyhow!("Profile is not available for viewing")),
+        };
 
         // เพิ่ม view count
-        self.repository.increment_view_count(share.id).await?;
+        let share = self.repository.increment_view_count(share.id).await?;
 
         // ดึงข้อมูล profile ที่แชร์
-        let shared_profile = self
+        let mut shared_profile = self
             .repository
             .get_shared_profile_info(token)
             .await?
             .ok_or_else(|| anyhow!("Profile information not found"))?;
 
+        // ซ่อนฟิลด์ที่ผู้ใช้ไม่อนุญาตให้แสดงตาม privacy settings
+        if !settings.show_name {
+            shared_profile.first_name_th = None;
+            shared_profile.last_name_th = None;
+            shared_profile.first_name_en = None;
+            shared_profile.last_name_en = None;
+        }
+        if !settings.show_title {
+            shared_profile.title = None;
+        }
+        if !settings.show_gender {
+            shared_profile.gender = None;
+        }
+        if !settings.show_birth_date {
+            shared_profile.birth_date = None;
+        }
+        if !settings.show_religion {
+            shared_profile.religion = None;
+        }
+        if !settings.show_nationality {
+            shared_profile.nationality = None;
+        }
+
         // สร้าง response
         let response = SharedProfileResponse {
             profile: shared_profile,
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// รัศมีเฉลี่ยของโลก (km) - ใช้ทั้งใน Rust และสูตร haversine ใน SQL
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// พิกัด WGS84 (องศา)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// กรอบสี่เหลี่ยมที่ครอบวงกลมรัศมีที่ต้องการ - ใช้กรองด้วย index ก่อนคำนวณระยะจริง
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoBoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    /// None = ครอบทุกลองจิจูด (ใกล้ขั้วโลก หรือข้ามเส้น 180°)
+    pub longitude_range: Option<(f64, f64)>,
+}
+
+impl GeoPoint {
+    /// None ถ้าพิกัดอยู่นอกช่วงที่ถูกต้อง
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        let valid = latitude.is_finite()
+            && longitude.is_finite()
+            && (-90.0..=90.0).contains(&latitude)
+            && (-180.0..=180.0).contains(&longitude);
+
+        valid.then_some(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// กรอบของวงกลมรัศมี `radius_km` บนทรงกลม
+    /// (สูตรจาก "Finding Points Within a Distance of a Latitude/Longitude Using Bounding Coordinates")
+    pub fn bounding_box(&self, radius_km: f64) -> GeoBoundingBox {
+        let angular_radius = radius_km / EARTH_RADIUS_KM;
+        let latitude = self.latitude.to_radians();
+
+        let min_latitude = (latitude - angular_radius).to_degrees();
+        let max_latitude = (latitude + angular_radius).to_degrees();
+
+        let longitude_range = if min_latitude > -90.0 && max_latitude < 90.0 {
+            let delta = (angular_radius.sin() / latitude.cos()).asin().to_degrees();
+            let range = (self.longitude - delta, self.longitude + delta);
+            (range.0 >= -180.0 && range.1 <= 180.0).then_some(range)
+        } else {
+            None
+        };
+
+        GeoBoundingBox {
+            min_latitude: min_latitude.max(-90.0),
+            max_latitude: max_latitude.min(90.0),
+            longitude_range,
+        }
+    }
+}
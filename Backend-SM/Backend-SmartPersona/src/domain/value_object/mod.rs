@@ -1 +1,3 @@
+pub mod geo;
+pub mod salary;
 pub mod user;
\ No newline at end of file
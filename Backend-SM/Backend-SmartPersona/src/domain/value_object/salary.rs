@@ -0,0 +1,301 @@
+use serde::{Deserialize, Serialize};
+
+/// สกุลเงินเมื่อไม่ได้ระบุ
+pub const DEFAULT_SALARY_CURRENCY: &str = "THB";
+
+/// ค่าที่บ่งบอกว่าเงินเดือนต่อรองได้
+const NEGOTIABLE_MARKERS: &[&str] = &[
+    "ตามตกลง",
+    "ตามประสบการณ์",
+    "ตามโครงสร้าง",
+    "ต่อรอง",
+    "negotiable",
+    "nego",
+];
+
+/// มีตัวเลขตัวเดียวแล้วเป็นเพดาน ("ไม่เกิน 20,000") - ต้องตรวจก่อน MIN_MARKERS เพราะ "up to" มีคำว่า "up"
+const MAX_MARKERS: &[&str] = &["ไม่เกิน", "สูงสุด", "up to", "max"];
+
+/// มีตัวเลขตัวเดียวแล้วเป็นขั้นต่ำ ("15,000 บาทขึ้นไป", "30k+")
+const MIN_MARKERS: &[&str] = &["ขึ้นไป", "เริ่มต้น", "อย่างน้อย", "+", "up", "from", "starting", "min"];
+
+/// ตัวเลขที่ส่วนจำนวนเต็มยาวกว่านี้ (เบอร์โทร / เลขบัญชี) ไม่ใช่เงินเดือน - คูณ k แล้วยังไม่ล้น i64
+const MAX_AMOUNT_DIGITS: usize = 15;
+
+/// (สกุลเงิน, คำที่บ่งบอก) - ไม่พบคำใดเลยถือเป็น THB
+const CURRENCY_MARKERS: &[(&str, &[&str])] = &[
+    ("USD", &["$", "usd", "ดอลลาร์"]),
+    ("EUR", &["€", "eur", "ยูโร"]),
+    ("JPY", &["¥", "jpy", "เยน"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SalaryPeriod {
+    Hourly,
+    Daily,
+    #[default]
+    Monthly,
+    Yearly,
+}
+
+impl SalaryPeriod {
+    /// ค่าที่เก็บในคอลัมน์ `salary_period`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SalaryPeriod::Hourly => "hourly",
+            SalaryPeriod::Daily => "daily",
+            SalaryPeriod::Monthly => "monthly",
+            SalaryPeriod::Yearly => "yearly",
+        }
+    }
+
+    fn unit(&self) -> &'static str {
+        match self {
+            SalaryPeriod::Hourly => "hour",
+            SalaryPeriod::Daily => "day",
+            SalaryPeriod::Monthly => "month",
+            SalaryPeriod::Yearly => "year",
+        }
+    }
+
+    /// หน่วยเวลาจากข้อความ - ไม่พบถือเป็นรายเดือน
+    fn detect(text: &str) -> Self {
+        let contains_any = |markers: &[&str]| markers.iter().any(|marker| text.contains(marker));
+
+        if contains_any(&["ชั่วโมง", "ชม.", "/hr", "hour"]) {
+            SalaryPeriod::Hourly
+        } else if contains_any(&["รายวัน", "ต่อวัน", "/วัน", "/day", "daily", "per day"]) {
+            SalaryPeriod::Daily
+        } else if contains_any(&["ต่อปี", "/ปี", "/year", "/yr", "yearly", "annual", "per year"]) {
+            SalaryPeriod::Yearly
+        } else {
+            SalaryPeriod::Monthly
+        }
+    }
+}
+
+/// เงินเดือนแบบมีโครงสร้างของประกาศงาน
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Salary {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    /// ISO 4217 ตัวพิมพ์ใหญ่
+    pub currency: String,
+    pub period: SalaryPeriod,
+    pub negotiable: bool,
+}
+
+impl Default for Salary {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            currency: DEFAULT_SALARY_CURRENCY.to_string(),
+            period: SalaryPeriod::default(),
+            negotiable: false,
+        }
+    }
+}
+
+impl Salary {
+    /// แยกข้อมูลจาก salary_range แบบข้อความอิสระ เช่น "25,000 - 35,000 บาท", "30k+",
+    /// "150-200 บาท/ชั่วโมง", "ตามตกลง" - ต้องตรงกับ backfill ใน migration add_structured_salary
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim().to_lowercase();
+        let contains_any = |markers: &[&str]| markers.iter().any(|marker| text.contains(marker));
+
+        let numbers = amounts(&text);
+        let (min, max) = match numbers.as_slice() {
+            [] => (None, None),
+            [amount] if contains_any(MAX_MARKERS) => (None, Some(*amount)),
+            [amount] if contains_any(MIN_MARKERS) => (Some(*amount), None),
+            [amount] => (Some(*amount), Some(*amount)),
+            // ตัวเลขถัดไป (เช่น "โบนัส 2 เดือน") ไม่ใช่ช่วงเงินเดือน
+            [first, second, ..] => (Some(*first.min(second)), Some(*first.max(second))),
+        };
+
+        let currency = CURRENCY_MARKERS
+            .iter()
+            .find(|(_, markers)| contains_any(markers))
+            .map_or(DEFAULT_SALARY_CURRENCY, |(currency, _)| currency);
+
+        Self {
+            min,
+            max,
+            currency: currency.to_string(),
+            period: SalaryPeriod::detect(&text),
+            negotiable: contains_any(NEGOTIABLE_MARKERS),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min.is_some_and(|min| min < 0) || self.max.is_some_and(|max| max < 0) {
+            return Err("salary_min and salary_max must not be negative".to_string());
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max)
+            && min > max
+        {
+            return Err("salary_min must not be greater than salary_max".to_string());
+        }
+        if self.currency.len() != 3 || !self.currency.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err("salary_currency must be a 3-letter ISO 4217 code".to_string());
+        }
+        Ok(())
+    }
+
+    /// ข้อความสำหรับ salary_range เมื่อผู้ประกาศส่งมาแค่ข้อมูลแบบมีโครงสร้าง
+    pub fn display(&self) -> String {
+        let amount = match (self.min, self.max) {
+            (Some(min), Some(max)) if min == max => Some(group_thousands(min)),
+            (Some(min), Some(max)) => Some(format!("{} - {}", group_thousands(min), group_thousands(max))),
+            (Some(min), None) => Some(format!("{}+", group_thousands(min))),
+            (None, Some(max)) => Some(format!("up to {}", group_thousands(max))),
+            (None, None) => None,
+        };
+
+        match (amount, self.negotiable) {
+            (Some(amount), negotiable) => format!(
+                "{} {}/{}{}",
+                amount,
+                self.currency,
+                self.period.unit(),
+                if negotiable { " (negotiable)" } else { "" }
+            ),
+            (None, _) => "Negotiable".to_string(),
+        }
+    }
+}
+
+/// ตัวเลขทั้งหมดในข้อความตามลำดับ ("25,000" = 25000, "30k" = 30000, ทศนิยมปัดเศษ)
+fn amounts(text: &str) -> Vec<i64> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut amounts = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if !chars[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let mut digits = String::new();
+        while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == ',') {
+            if chars[index] != ',' {
+                digits.push(chars[index]);
+            }
+            index += 1;
+        }
+        let integer_digits = digits.len();
+        if index + 1 < chars.len() && chars[index] == '.' && chars[index + 1].is_ascii_digit() {
+            digits.push('.');
+            index += 1;
+            while index < chars.len() && chars[index].is_ascii_digit() {
+                digits.push(chars[index]);
+                index += 1;
+            }
+        }
+
+        // "30k" / "30 k" แต่ไม่ใช่ "30 kbps"
+        let mut lookahead = index;
+        while lookahead < chars.len() && chars[lookahead] == ' ' {
+            lookahead += 1;
+        }
+        let thousands = lookahead < chars.len()
+            && chars[lookahead] == 'k'
+            && chars.get(lookahead + 1).is_none_or(|next| !next.is_ascii_alphanumeric());
+
+        if integer_digits <= MAX_AMOUNT_DIGITS
+            && let Ok(amount) = digits.parse::<f64>()
+        {
+            let amount = if thousands { amount * 1000.0 } else { amount };
+            amounts.push(amount.round() as i64);
+        }
+        if thousands {
+            index = lookahead + 1;
+        }
+    }
+
+    amounts
+}
+
+fn group_thousands(amount: i64) -> String {
+    let digits = amount.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (position, digit) in digits.chars().enumerate() {
+        if position > 0 && (digits.len() - position).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if amount < 0 { format!("-{}", grouped) } else { grouped }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(text: &str) -> (Option<i64>, Option<i64>, SalaryPeriod, bool) {
+        let salary = Salary::parse(text);
+        (salary.min, salary.max, salary.period, salary.negotiable)
+    }
+
+    #[test]
+    fn parses_thai_range() {
+        let salary = Salary::parse("25,000 - 35,000 บาท");
+        assert_eq!(salary.min, Some(25_000));
+        assert_eq!(salary.max, Some(35_000));
+        assert_eq!(salary.currency, "THB");
+        assert_eq!(salary.period, SalaryPeriod::Monthly);
+        assert!(!salary.negotiable);
+    }
+
+    #[test]
+    fn parses_open_ended_minimum() {
+        assert_eq!(parsed("30k+"), (Some(30_000), None, SalaryPeriod::Monthly, false));
+        assert_eq!(parsed("15,000 บาทขึ้นไป"), (Some(15_000), None, SalaryPeriod::Monthly, false));
+    }
+
+    #[test]
+    fn parses_ceiling() {
+        assert_eq!(parsed("ไม่เกิน 20,000"), (None, Some(20_000), SalaryPeriod::Monthly, false));
+        assert_eq!(parsed("up to 2k"), (None, Some(2_000), SalaryPeriod::Monthly, false));
+    }
+
+    #[test]
+    fn parses_negotiable_without_amount() {
+        assert_eq!(parsed("ตามตกลง"), (None, None, SalaryPeriod::Monthly, true));
+        assert_eq!(Salary::parse("ตามตกลง").display(), "Negotiable");
+    }
+
+    #[test]
+    fn detects_period() {
+        assert_eq!(parsed("150-200 บาท/ชั่วโมง"), (Some(150), Some(200), SalaryPeriod::Hourly, false));
+        assert_eq!(
+            parsed("600,000 - 900,000 บาท/ปี"),
+            (Some(600_000), Some(900_000), SalaryPeriod::Yearly, false)
+        );
+        assert_eq!(parsed("$40k-60k per year").2, SalaryPeriod::Yearly);
+        assert_eq!(Salary::parse("$40k-60k per year").currency, "USD");
+    }
+
+    #[test]
+    fn thousands_suffix_only_before_non_ascii_letters() {
+        // อักษรไทยหลัง k ไม่ทำให้ไม่นับ (ตรงกับ backfill ใน migration)
+        assert_eq!(parsed("30kบาท"), (Some(30_000), Some(30_000), SalaryPeriod::Monthly, false));
+        assert_eq!(parsed("50 kbps").0, Some(50));
+    }
+
+    #[test]
+    fn skips_overlong_digit_runs() {
+        assert_eq!(parsed("โทร 08123456789012345678"), (None, None, SalaryPeriod::Monthly, false));
+        assert_eq!(parsed("20,000 บาท ติดต่อ 1234567890123456k"), (Some(20_000), Some(20_000), SalaryPeriod::Monthly, false));
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        let salary = Salary::parse("25,000 - 35,000 บาท");
+        assert_eq!(salary.display(), "25,000 - 35,000 THB/month");
+        assert_eq!(Salary::parse(&salary.display()), salary);
+    }
+}
@@ -1,23 +1,92 @@
 use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
 
 use crate::domain::entities::user::{RegisterUserEntity, Role, UserStatusEnum};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// สมัครสมาชิกด้วยตัวเอง - เลือกได้เฉพาะ PersonaUser / CompanyUser
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct RegisterUserModel {
+    #[validate(length(min = 3, max = 50), custom(function = "validate_username"))]
     pub username: String,
+    #[validate(length(min = 8, max = 128), custom(function = "validate_password_strength"))]
     pub password: String,
+    #[validate(email, length(max = 255))]
+    pub email: String,
     #[serde(default)]
     pub role: Option<Role>,
 }
 impl RegisterUserModel {
+    /// Role ที่สมัครเองได้ (ค่าเริ่มต้น PersonaUser) - Admin สร้างได้ผ่านคำเชิญหรือ CLI เท่านั้น
+    pub fn self_service_role(&self) -> Option<Role> {
+        match self.role {
+            None => Some(Role::PersonaUser),
+            Some(Role::Admin) => None,
+            Some(role) => Some(role),
+        }
+    }
+
     pub fn to_entity(&self) -> RegisterUserEntity {
         RegisterUserEntity {
             username: self.username.clone(),
             password_hash: self.password.clone(),
-            role: self.role,
+            email: Some(normalize_email(&self.email)),
+            role: self.self_service_role(),
             status: Some(UserStatusEnum::Pending), // Default to Pending for new users
             created_at: chrono::Utc::now().naive_utc(),
             updated_at: chrono::Utc::now().naive_utc(),
         }
     }
 }
+
+/// ข้อมูลบัญชี Admin (ใช้โดย bootstrap CLI)
+#[derive(Debug, Clone, Validate)]
+pub struct AdminAccountModel {
+    #[validate(length(min = 3, max = 50), custom(function = "validate_username"))]
+    pub username: String,
+    #[validate(length(min = 8, max = 128), custom(function = "validate_password_strength"))]
+    pub password: String,
+    #[validate(email, length(max = 255))]
+    pub email: String,
+}
+
+impl AdminAccountModel {
+    /// Admin ไม่ต้องยืนยันอีเมล - เปิดใช้งานทันที
+    pub fn to_entity(&self, password_hash: String) -> RegisterUserEntity {
+        RegisterUserEntity {
+            username: self.username.clone(),
+            password_hash,
+            email: Some(normalize_email(&self.email)),
+            role: Some(Role::Admin),
+            status: Some(UserStatusEnum::Active),
+            created_at: chrono::Utc::now().naive_utc(),
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+}
+
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Username: ตัวอักษรภาษาอังกฤษ ตัวเลข และ `_` `.` `-` เท่านั้น
+pub fn validate_username(username: &str) -> Result<(), ValidationError> {
+    let valid = username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+
+    if !valid {
+        return Err(ValidationError::new("username_invalid_characters"));
+    }
+    Ok(())
+}
+
+/// Password ต้องมีทั้งตัวอักษรและตัวเลข
+pub fn validate_password_strength(password: &str) -> Result<(), ValidationError> {
+    let has_letter = password.chars().any(|c| c.is_alphabetic());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+
+    if !(has_letter && has_digit) {
+        return Err(ValidationError::new("password_too_weak"));
+    }
+    Ok(())
+}
@@ -2,11 +2,14 @@ use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Result;
 use axum::{
-    Router,
+    Extension, Router,
     http::{self, Method},
     routing::{get, post},
 };
 use tokio::net::TcpListener;
+use tower_governor::{
+    GovernorLayer, governor::GovernorConfigBuilder,
+};
 use tower_http::{
     cors::{Any, CorsLayer},
     limit::RequestBodyLimitLayer,
@@ -22,9 +25,17 @@ use crate::{
         ai_service_client::client::AIServiceClient,
         axum_http::{
             default_routers,
-            routers::{self, ai_handlers},
+            middleware::{AccountStatusCache, SessionStatusCache},
+            routers::{
+                self, ai_handlers,
+                login_throttle::{ClientIpKeyExtractor, TrustedProxies},
+            },
+        },
+        oidc::client::OidcClient,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{user::UserPostgres, user_session::UserSessionPostgres},
         },
-        postgres::postgres_connection::DbPool,
         supabase::client::SupabaseClient,
     },
 };
@@ -37,11 +48,49 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
     let ads_postgres = crate::infrastructure::postgres::repositories::ads::AdsPostgres::new(Arc::clone(&db_pool));
     let ads_use_case = Arc::new(crate::domain::usecase::ads::AdsUseCase::new(Arc::new(ads_postgres)));
 
+    // Persistent login rate limit / lockout (shared by authentication, register and admin routes)
+    let login_throttle_use_case = routers::login_throttle::build_use_case(
+        Arc::clone(&db_pool),
+        config.rate_limit.clone(),
+    );
+
+    // Forwarded headers are only honoured when the peer is a configured proxy
+    let trusted_proxies = Arc::new(TrustedProxies::new(config.server.trusted_proxies.clone()));
+
+    // In-process burst limit per IP in front of the database-backed limiter
+    let auth_governor = Arc::new(
+        GovernorConfigBuilder::default()
+            .key_extractor(ClientIpKeyExtractor(Arc::clone(&trusted_proxies)))
+            .per_millisecond(config.rate_limit.burst_replenish_ms)
+            .burst_size(config.rate_limit.burst_size)
+            .finish()
+            .ok_or_else(|| anyhow::anyhow!("Invalid rate limit burst configuration"))?,
+    );
+
+    // ขั้นตอนใบสมัครงาน - ตั้งค่าผิดให้ล้มตั้งแต่ตอน start
+    let application_pipeline = crate::domain::entities::job_application::ApplicationPipeline::new(
+        &config.application_pipeline.stages,
+        config.application_pipeline.allow_skip,
+    )
+    .map_err(|e| anyhow::anyhow!("Invalid APPLICATION_PIPELINE_STAGES: {}", e))?;
+
+    // Append-only audit log of admin actions - written through the `AdminAudit` extractor
+    let audit_log_use_case = routers::audit_log::build_use_case(Arc::clone(&db_pool));
+
+    // Company API keys - checked by `user_authorization` as an alternative to cookie / JWT
+    let company_api_key_use_case = routers::company_api_key::build_use_case(Arc::clone(&db_pool));
+
+    // OpenID Connect providers (sign-in + account linking)
+    let oidc_client = Arc::new(OidcClient::new(config.oidc_providers.clone()));
+
     // Initialize Supabase client
     let supabase_client = Arc::new(SupabaseClient::new(&config.supabase));
 
     let user_routes = Router::new()
-        .merge(routers::user::routes(Arc::clone(&db_pool)))
+        .merge(routers::user::routes(
+            Arc::clone(&db_pool),
+            Arc::clone(&login_throttle_use_case),
+        ))
         .merge(routers::user_profile::routes(Arc::clone(&db_pool)))
         .merge(routers::user_address::routes(Arc::clone(&db_pool)))
         .merge(routers::user_education::routes(Arc::clone(&db_pool)))
@@ -51,13 +100,38 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
         .merge(routers::user_skill::routes(Arc::clone(&db_pool)))
         .merge(routers::saved_job::routes(Arc::clone(&db_pool)))
         .merge(routers::user_privacy_settings::routes(Arc::clone(&db_pool)))
+        .merge(routers::user_session::routes(Arc::clone(&db_pool)))
+        .merge(routers::user_mfa::routes(
+            Arc::clone(&db_pool),
+            Arc::clone(&login_throttle_use_case),
+        ))
+        .merge(routers::data_export::routes(Arc::clone(&db_pool)))
+        .merge(routers::account_deletion::routes(
+            Arc::clone(&db_pool),
+            Arc::clone(&supabase_client),
+            config.account_deletion.grace_period_days,
+        ))
+        .merge(routers::oidc::routes(Arc::clone(&db_pool), Arc::clone(&oidc_client)))
+        .merge(routers::user_share::routes(
+            Arc::clone(&db_pool),
+            config.app.frontend_url.clone(),
+        ))
         .merge(routers::company::routes(Arc::clone(&db_pool)))
+        .merge(routers::company_api_key::routes(Arc::clone(&db_pool)))
         .merge(routers::company_gallery::routes(Arc::clone(&db_pool)))
         .merge(routers::company_post::routes(Arc::clone(&db_pool)))
         .merge(routers::social::routes(Arc::clone(&db_pool)))
         .nest("/ai-score", routers::user_ai_score::routes(Arc::clone(&db_pool)))
         .merge(routers::user_job_match::routes(Arc::clone(&db_pool)))
         .merge(routers::applicant::routes(Arc::clone(&db_pool)))
+        .merge(routers::interview::routes(
+            Arc::clone(&db_pool),
+            config.app.backend_url.clone(),
+        ))
+        .merge(routers::saved_search::routes(
+            Arc::clone(&db_pool),
+            config.app.backend_url.clone(),
+        ))
         .merge({
             let email_service = Arc::new(
                 crate::infrastructure::email::service::EmailService::new()
@@ -70,6 +144,9 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
                 Arc::new(crate::infrastructure::postgres::repositories::job_application::JobApplicationPostgres::new(Arc::clone(&db_pool))),
                 email_service,
                 Arc::clone(&db_pool),
+                application_pipeline,
+                crate::infrastructure::supabase::storage::StorageService::new(Arc::clone(&supabase_client))
+                    .resume_url_prefix(),
             )
         });
 
@@ -78,27 +155,170 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
     
     // Public privacy settings routes (no authentication required)
     let public_privacy_routes = routers::user_privacy_settings::public_routes(Arc::clone(&db_pool));
-    
+
+    // Public shared profile routes (no authentication required)
+    let public_share_routes = routers::user_share::public_routes(Arc::clone(&db_pool));
+
+    // Public data export downloads (expiring token link)
+    let public_data_export_routes = routers::data_export::public_routes(Arc::clone(&db_pool));
+
+    // Public ICS feeds for calendar apps (token link)
+    let public_calendar_routes =
+        routers::interview::public_routes(Arc::clone(&db_pool), config.app.backend_url.clone());
+
+    // Unsubscribe links in job alert emails (token link)
+    let public_saved_search_routes =
+        routers::saved_search::public_routes(Arc::clone(&db_pool), config.app.backend_url.clone());
+
+    // Clean up expired share links every hour
+    tokio::spawn(routers::user_share::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Clean up expired refresh token sessions every hour
+    tokio::spawn(routers::user_session::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Clean up expired verification / reset tokens every hour
+    tokio::spawn(routers::account_token::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Clean up expired admin invites every hour
+    tokio::spawn(routers::admin_invite::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Clean up abandoned OIDC sign-in requests every hour
+    tokio::spawn(routers::oidc::run_maintenance(
+        Arc::clone(&db_pool),
+        Arc::clone(&oidc_client),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Pick up pending personal data exports and purge expired archives every five minutes
+    tokio::spawn(routers::data_export::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(5 * 60),
+    ));
+
+    // Erase accounts whose deletion grace period has passed every hour
+    tokio::spawn(routers::account_deletion::run_maintenance(
+        Arc::clone(&db_pool),
+        Arc::clone(&supabase_client),
+        Duration::from_secs(60 * 60),
+    ));
+
+    // Index job posts that predate search or were edited outside the repository every five minutes
+    tokio::spawn(routers::company_post::run_maintenance(
+        Arc::clone(&db_pool),
+        Duration::from_secs(5 * 60),
+    ));
+
+    // Publish scheduled job posts and expire posts past their closing time every minute
+    tokio::spawn(routers::company_post::run_scheduler(
+        Arc::clone(&db_pool),
+        Duration::from_secs(60),
+    ));
+
+    // Match newly published job posts to saved searches and send alert digests every five minutes
+    tokio::spawn(routers::saved_search::run_alerts(
+        Arc::clone(&db_pool),
+        config.app.backend_url.clone(),
+        Duration::from_secs(5 * 60),
+    ));
+
+    // Clean up rate limit buckets every ten minutes
+    tokio::spawn(routers::login_throttle::run_maintenance(
+        Arc::clone(&login_throttle_use_case),
+        Duration::from_secs(10 * 60),
+    ));
+
+    // Drop idle IPs from the in-process burst limiter
+    let governor_limiter = auth_governor.limiter().clone();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            ticker.tick().await;
+            governor_limiter.retain_recent();
+        }
+    });
+
+    // Account status cache for the auth middleware (bans take effect within the TTL)
+    let account_status_cache = Arc::new(AccountStatusCache::new(
+        Arc::new(UserPostgres::new(Arc::clone(&db_pool))),
+        Duration::from_secs(30),
+    ));
+
+    // Session family cache for the auth middleware (revoked sessions stop working within the TTL)
+    let session_status_cache = Arc::new(SessionStatusCache::new(
+        Arc::new(UserSessionPostgres::new(Arc::clone(&db_pool))),
+        Duration::from_secs(30),
+    ));
+
     let app = Router::new()
         .fallback(default_routers::not_found)
         .nest("/api", public_company_routes) // Public company routes at /api/companies/:company_id
         .nest("/api/user", public_privacy_routes) // Public privacy settings at /api/user/privacy-settings/:user_id
+        .nest("/api", public_share_routes) // Public shared profiles at /api/shared/:token
+        .nest("/api", public_data_export_routes) // Data export downloads at /api/data-exports/download/:token
+        .nest("/api", public_calendar_routes) // Interview ICS feeds at /api/calendar/:token.ics
+        .nest("/api", public_saved_search_routes) // Job alert unsubscribe at /api/saved-searches/unsubscribe/:token
         .nest("/api/user", user_routes)
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&config.jwt.keys),
+                Arc::clone(&login_throttle_use_case),
+            )
+            .merge(routers::account_token::routes(Arc::clone(&db_pool)))
+            .merge(routers::admin_invite::public_routes(Arc::clone(&db_pool)))
+            .merge(routers::oidc::public_routes(
+                Arc::clone(&db_pool),
+                Arc::clone(&config.jwt.keys),
+                Arc::clone(&oidc_client),
+                Arc::clone(&login_throttle_use_case),
+                config.app.frontend_url.clone(),
+            ))
+            .layer(GovernorLayer {
+                config: Arc::clone(&auth_governor),
+            }),
         )
         .nest(
             "/admin",
-            routers::admin_handlers::routes(Arc::clone(&db_pool)),
+            routers::admin_handlers::routes(Arc::clone(&db_pool))
+                .merge(routers::admin_invite::routes(Arc::clone(&db_pool)))
+                .merge(routers::user_mfa::admin_routes(
+                    Arc::clone(&db_pool),
+                    Arc::clone(&login_throttle_use_case),
+                ))
+                .merge(routers::login_throttle::admin_routes(Arc::clone(&login_throttle_use_case)))
+                .merge(routers::audit_log::admin_routes(Arc::clone(&audit_log_use_case)))
+                .merge(routers::impersonation::admin_routes(
+                    Arc::clone(&db_pool),
+                    Arc::clone(&config.jwt.keys),
+                )),
         )
         .nest(
             "/api/storage",
             routers::storage::routes(Arc::clone(&supabase_client)),
         )
         .route("/health-check", get(default_routers::health_check))
+        .merge(routers::jwks::routes(Arc::clone(&config.jwt.keys)))
         .nest("/api/ai", ai_handlers::routes(ai_analysis_use_case))
         .nest("/api/ads", routers::ads::ads_router(ads_use_case))
+        .layer(Extension(account_status_cache))
+        .layer(Extension(trusted_proxies))
+        .layer(Extension(session_status_cache))
+        .layer(Extension(company_api_key_use_case))
+        .layer(Extension(audit_log_use_case))
+        .layer(Extension(Arc::clone(&config.jwt.keys)))
         .layer(TimeoutLayer::new(Duration::from_secs(
             config.server.timeout,
         )))
@@ -126,7 +346,9 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
                 .allow_headers([
                     axum::http::header::AUTHORIZATION,
                     axum::http::header::CONTENT_TYPE,
-                ]),
+                    http::HeaderName::from_static("x-api-key"),
+                ])
+                .expose_headers([http::HeaderName::from_static("x-impersonation")]),
         )
         .layer(TraceLayer::new_for_http());
 
@@ -135,7 +357,8 @@ pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<DbPool>) -> Result<(
     let listener = TcpListener::bind(addr).await?;
     info!("Server running on {}", config.server.port);
 
-    axum::serve(listener, app)
+    // ConnectInfo gives the rate limiters the peer address when no proxy header is present
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
     Ok(())
@@ -1,71 +1,62 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use axum::{
-    extract::Request,
-    http::{header, StatusCode},
+    extract::{OriginalUri, Request},
+    http::{HeaderMap, HeaderValue, Method, header, StatusCode},
     middleware::Next,
     response::Response,
 };
+use tokio::sync::RwLock;
 
 use crate::{
-    config::config_loader::{get_admin_secret, get_user_secret},
-    infrastructure::jwt_authentication::{self, jwt_model::{Claims, Roles}},
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+            company_api_key::{ApiKeyScope, is_api_key},
+            user::{Role, UserStatusEnum},
+        },
+        repo::{user::UserRepository, user_session::UserSessionRepository},
+    },
+    infrastructure::{
+        axum_http::routers::{
+            audit_log::AuditLogUseCasePostgres, company_api_key::CompanyApiKeyUseCasePostgres,
+            login_throttle::client_ip,
+        },
+        jwt_authentication::{
+            self,
+            jwt_keys::JwtKeySet,
+            jwt_model::{Claims, ImpersonationActor, TokenKind},
+        },
+    },
 };
 use uuid::Uuid;
 
 use tracing::{info, error};
 
 pub async fn user_authorization(
-    mut req: Request,
+    req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
     info!("Checking authorization for request: {:?}", req.uri());
-    for (name, value) in req.headers() {
-        if name == header::AUTHORIZATION {
-             info!("Header {}: {:?}", name, value);
-        }
+
+    // Company API key (ATS integrations) - X-API-Key header or `Authorization: Bearer spk_...`
+    if let Some(raw_key) = api_key_from_headers(req.headers()) {
+        return authorize_api_key(req, next, raw_key).await;
     }
 
+    let keys = jwt_keys(&req)?;
+
     // Check Authorization header first
     if let Some(auth_header) = req.headers().get(header::AUTHORIZATION) {
         if let Ok(auth_str) = auth_header.to_str() {
-            if auth_str.starts_with("Bearer ") {
-                let token = auth_str[7..].trim();
-                
-                if let Ok(user_secret) = get_user_secret() {
-                    match jwt_authentication::verify_token(user_secret.user_secret, token.to_string()) {
-                        Ok(claims) => {
-                            if claims.role == Roles::UserAndCompany || claims.role == Roles::Admin {
-                                if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                                    req.extensions_mut().insert(user_id);
-                                    req.extensions_mut().insert::<Claims>(claims);
-                                    return Ok(next.run(req).await);
-                                } else {
-                                    error!("Failed to parse user_id from claims.sub: {}", claims.sub);
-                                }
-                            } else {
-                                info!("Role mismatch for User secret: {:?}", claims.role);
-                            }
-                        },
-                        Err(e) => info!("Failed to verify with User secret: {}", e),
-                    }
-                }
-                
-                if let Ok(admin_secret) = get_admin_secret() {
-                    match jwt_authentication::verify_token(admin_secret.admin_secret, token.to_string()) {
-                        Ok(claims) => {
-                            if claims.role == Roles::Admin {
-                                if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                                    req.extensions_mut().insert(user_id);
-                                    req.extensions_mut().insert::<Claims>(claims);
-                                    return Ok(next.run(req).await);
-                                } else {
-                                    error!("Failed to parse user_id from claims.sub: {}", claims.sub);
-                                }
-                            } else {
-                                info!("Role mismatch for Admin secret: {:?}", claims.role);
-                            }
-                        },
-                        Err(e) => info!("Failed to verify with Admin secret: {}", e),
-                    }
+            if let Some(token) = auth_str.strip_prefix("Bearer ") {
+                match verify_access_token(&keys, token.trim()) {
+                    Ok(claims) => return authorize(req, next, claims).await,
+                    Err(e) => info!("Failed to verify bearer token: {}", e),
                 }
             }
         }
@@ -75,42 +66,15 @@ pub async fn user_authorization(
         if let Ok(cookie_str) = cookie_header.to_str() {
             // Try regular user token first
             if let Some(token) = get_cookie_value(cookie_str, "act") {
-                if let Ok(user_secret) = get_user_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(user_secret.user_secret, token.clone()) {
-                        if claims.role == Roles::UserAndCompany || claims.role == Roles::Admin {
-                            if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                                req.extensions_mut().insert(user_id);
-                            }
-                            req.extensions_mut().insert::<Claims>(claims);
-                            return Ok(next.run(req).await);
-                        }
-                    }
-                }
-                if let Ok(admin_secret) = get_admin_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(admin_secret.admin_secret, token) {
-                        if claims.role == Roles::Admin {
-                            if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                                req.extensions_mut().insert(user_id);
-                            }
-                            req.extensions_mut().insert::<Claims>(claims);
-                            return Ok(next.run(req).await);
-                        }
-                    }
+                if let Ok(claims) = verify_access_token(&keys, &token) {
+                    return authorize(req, next, claims).await;
                 }
             }
-            
+
             // Also try admin-specific cookie
             if let Some(token) = get_cookie_value(cookie_str, "act_admin") {
-                if let Ok(admin_secret) = get_admin_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(admin_secret.admin_secret, token) {
-                        if claims.role == Roles::Admin {
-                            if let Ok(user_id) = Uuid::parse_str(&claims.sub) {
-                                req.extensions_mut().insert(user_id);
-                            }
-                            req.extensions_mut().insert::<Claims>(claims);
-                            return Ok(next.run(req).await);
-                        }
-                    }
+                if let Ok(claims) = verify_admin_token(&keys, &token) {
+                    return authorize(req, next, claims).await;
                 }
             }
         }
@@ -120,23 +84,17 @@ pub async fn user_authorization(
 }
 
 pub async fn admin_authorization(
-    mut req: Request,
+    req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    let keys = jwt_keys(&req)?;
+
     if let Some(cookie_header) = req.headers().get(header::COOKIE) {
         if let Ok(cookie_str) = cookie_header.to_str() {
             // Use admin-specific cookie name
             if let Some(token) = get_cookie_value(cookie_str, "act_admin") {
-                if let Ok(admin_secret) = get_admin_secret() {
-                    if let Ok(claims) = jwt_authentication::verify_token(admin_secret.admin_secret, token) {
-                        if claims.role == Roles::Admin {
-                            if let Ok(admin_id) = Uuid::parse_str(&claims.sub) {
-                                req.extensions_mut().insert(admin_id);
-                            }
-                            req.extensions_mut().insert::<Claims>(claims);
-                            return Ok(next.run(req).await);
-                        }
-                    }
+                if let Ok(claims) = verify_admin_token(&keys, &token) {
+                    return authorize(req, next, claims).await;
                 }
             }
         }
@@ -145,6 +103,403 @@ pub async fn admin_authorization(
     Err(StatusCode::UNAUTHORIZED)
 }
 
+/// Key set ที่ติดตั้งไว้ทั้ง app เป็น `Extension<Arc<JwtKeySet>>`
+fn jwt_keys(req: &Request) -> Result<Arc<JwtKeySet>, StatusCode> {
+    req.extensions()
+        .get::<Arc<JwtKeySet>>()
+        .cloned()
+        .ok_or_else(|| {
+            error!("JwtKeySet extension is not installed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+/// Access token ของ user ใช้ได้ทุก role, access token ของ admin ต้องเป็น Admin เท่านั้น
+fn verify_access_token(keys: &JwtKeySet, token: &str) -> anyhow::Result<Claims> {
+    match jwt_authentication::verify_token(keys, TokenKind::UserAccess, token) {
+        Ok(claims) => Ok(claims),
+        Err(_) => verify_admin_token(keys, token),
+    }
+}
+
+fn verify_admin_token(keys: &JwtKeySet, token: &str) -> anyhow::Result<Claims> {
+    let claims = jwt_authentication::verify_token(keys, TokenKind::AdminAccess, token)?;
+    if claims.role != Role::Admin {
+        return Err(anyhow::anyhow!("Role mismatch for admin token: {:?}", claims.role));
+    }
+    Ok(claims)
+}
+
+fn api_key_from_headers(headers: &HeaderMap) -> Option<String> {
+    if let Some(value) = headers.get("x-api-key") {
+        return value.to_str().ok().map(|key| key.trim().to_string());
+    }
+
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| is_api_key(token))
+        .map(str::to_string)
+}
+
+/// Endpoints ที่ API key เรียกได้ และ scope ที่ต้องมี - นอกจากนี้ใช้ได้เฉพาะ cookie / JWT
+fn required_api_scope(method: &Method, path: &str) -> Option<ApiKeyScope> {
+    let segments: Vec<&str> = path.trim_end_matches('/').split('/').skip(1).collect();
+
+    match (method.as_str(), segments.as_slice()) {
+        ("GET", ["api", "user", "companies", _, "posts"]) => Some(ApiKeyScope::JobsRead),
+        ("POST", ["api", "user", "companies", _, "posts"]) => Some(ApiKeyScope::JobsWrite),
+        ("GET", ["api", "user", "posts", _]) => Some(ApiKeyScope::JobsRead),
+        ("PUT" | "DELETE", ["api", "user", "posts", _]) => Some(ApiKeyScope::JobsWrite),
+        ("GET", ["api", "user", "candidates", _]) => Some(ApiKeyScope::ApplicationsRead),
+        ("GET", ["api", "user", "company", "applicant", _]) => Some(ApiKeyScope::ApplicationsRead),
+        ("PATCH", ["api", "user", "applications", _, "status"]) => {
+            Some(ApiKeyScope::ApplicationsWrite)
+        }
+        _ => None,
+    }
+}
+
+/// Authenticate a company API key and act as the company owner within the key's scopes
+async fn authorize_api_key(
+    mut req: Request,
+    next: Next,
+    raw_key: String,
+) -> Result<Response, StatusCode> {
+    let Some(api_key_use_case) = req
+        .extensions()
+        .get::<Arc<CompanyApiKeyUseCasePostgres>>()
+        .cloned()
+    else {
+        error!("CompanyApiKeyUseCase extension is not installed");
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    let ip_address = client_ip(req.headers(), req.extensions());
+
+    let principal = match api_key_use_case.authenticate(&raw_key, Some(ip_address)).await {
+        Ok(principal) => principal,
+        Err(e) if e.to_string().contains("Invalid API key") => {
+            info!("Rejected invalid or revoked API key");
+            return Err(StatusCode::UNAUTHORIZED);
+        }
+        Err(e) => {
+            error!("Failed to verify API key: {}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let path = request_path(&req);
+
+    let Some(required_scope) = required_api_scope(req.method(), &path) else {
+        info!("API key {} used on unsupported endpoint {} {}", principal.key_id, req.method(), path);
+        return Err(StatusCode::FORBIDDEN);
+    };
+
+    if !principal.scopes.contains(&required_scope) {
+        info!(
+            "API key {} is missing scope {} for {} {}",
+            principal.key_id,
+            required_scope.as_str(),
+            req.method(),
+            path
+        );
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let claims = Claims {
+        sub: principal.owner_user_id.to_string(),
+        role: Role::CompanyUser,
+        exp: 0,
+        iat: 0,
+        jti: format!("api_key:{}", principal.key_id),
+        sid: None,
+        mfa_pending: false,
+        act: None,
+    };
+
+    req.extensions_mut().insert(principal);
+    authorize(req, next, claims).await
+}
+
+/// nested routers เห็น path ที่ตัด prefix แล้ว - ใช้ path เต็มจาก OriginalUri
+fn request_path(req: &Request) -> String {
+    req.extensions()
+        .get::<OriginalUri>()
+        .map(|OriginalUri(uri)| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string())
+}
+
+fn is_read_only_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// Request ที่แก้ไขข้อมูลระหว่าง impersonation - บันทึกใน audit log ก่อนทำงาน แล้วเติม status ที่ตอบกลับ
+struct ImpersonatedWrite {
+    audit_log_use_case: Arc<AuditLogUseCasePostgres>,
+    new_event: NewAuditEvent,
+}
+
+impl ImpersonatedWrite {
+    fn from_request(
+        req: &Request,
+        actor: &ImpersonationActor,
+        user_id: Uuid,
+        jti: &str,
+    ) -> Result<Self, StatusCode> {
+        let Some(audit_log_use_case) = req.extensions().get::<Arc<AuditLogUseCasePostgres>>().cloned()
+        else {
+            error!("AuditLogUseCase extension is not installed");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        };
+
+        let new_event = NewAuditEvent {
+            actor_id: Uuid::parse_str(&actor.sub).ok(),
+            actor_role: Some("admin".to_string()),
+            ip_address: Some(client_ip(req.headers(), req.extensions())),
+            user_agent: req
+                .headers()
+                .get(header::USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.chars().take(512).collect()),
+            ..NewAuditEvent::new(AuditAction::ImpersonationRequest, AuditTarget::User, user_id)
+                .after(serde_json::json!({
+                    "method": req.method().as_str(),
+                    "path": request_path(req),
+                    "jti": jti,
+                }))
+        };
+
+        Ok(Self {
+            audit_log_use_case,
+            new_event,
+        })
+    }
+
+    /// บันทึกก่อนส่ง request ต่อ - บันทึกไม่ได้ให้ตอบ 500 โดยไม่แก้ไขข้อมูล
+    async fn record(self) -> Result<RecordedWrite, StatusCode> {
+        match self.audit_log_use_case.record(self.new_event).await {
+            Ok(event) => Ok(RecordedWrite {
+                audit_log_use_case: self.audit_log_use_case,
+                event_id: event.id,
+            }),
+            Err(e) => {
+                error!("Failed to write impersonation audit event: {}", e);
+                Err(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+}
+
+/// Impersonated write ที่บันทึกแล้ว - รอเติม status ที่ตอบกลับ
+struct RecordedWrite {
+    audit_log_use_case: Arc<AuditLogUseCasePostgres>,
+    event_id: Uuid,
+}
+
+impl RecordedWrite {
+    async fn complete(self, status: StatusCode) {
+        if let Err(e) = self
+            .audit_log_use_case
+            .complete(self.event_id, status.as_u16())
+            .await
+        {
+            error!(
+                "Failed to record response status of impersonation audit event {}: {}",
+                self.event_id, e
+            );
+        }
+    }
+}
+
+/// Short-lived cache of account status so bans take effect without a query per request.
+/// Installed once for the whole app as an `Extension<Arc<AccountStatusCache>>`.
+pub struct AccountStatusCache {
+    user_repository: Arc<dyn UserRepository + Send + Sync>,
+    ttl: Duration,
+    entries: RwLock<HashMap<Uuid, (UserStatusEnum, Instant)>>,
+}
+
+impl AccountStatusCache {
+    pub fn new(user_repository: Arc<dyn UserRepository + Send + Sync>, ttl: Duration) -> Self {
+        Self {
+            user_repository,
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn status(&self, user_id: Uuid) -> anyhow::Result<UserStatusEnum> {
+        let cached = self.entries.read().await.get(&user_id).copied();
+        if let Some((status, _)) = cached.filter(|(_, fetched_at)| fetched_at.elapsed() < self.ttl) {
+            return Ok(status);
+        }
+
+        let user = self.user_repository.find_by_id(user_id).await?;
+
+        let mut entries = self.entries.write().await;
+        entries.retain(|_, (_, fetched_at)| fetched_at.elapsed() < self.ttl);
+        entries.insert(user_id, (user.status, Instant::now()));
+
+        Ok(user.status)
+    }
+
+    /// Drop the cached status (called right after ban/unban)
+    pub async fn invalidate(&self, user_id: Uuid) {
+        self.entries.write().await.remove(&user_id);
+    }
+}
+
+/// Short-lived cache of session family state so revoked sessions (logout, "sign out this device",
+/// refresh token reuse) stop their access tokens too. Installed as an `Extension<Arc<SessionStatusCache>>`.
+pub struct SessionStatusCache {
+    user_session_repository: Arc<dyn UserSessionRepository + Send + Sync>,
+    ttl: Duration,
+    /// family_id -> (user_id, active, fetched_at)
+    entries: RwLock<HashMap<Uuid, (Uuid, bool, Instant)>>,
+}
+
+impl SessionStatusCache {
+    pub fn new(user_session_repository: Arc<dyn UserSessionRepository + Send + Sync>, ttl: Duration) -> Self {
+        Self {
+            user_session_repository,
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn is_active(&self, user_id: Uuid, family_id: Uuid) -> anyhow::Result<bool> {
+        let cached = self.entries.read().await.get(&family_id).copied();
+        if let Some((owner_id, active, _)) = cached.filter(|(_, _, fetched_at)| fetched_at.elapsed() < self.ttl) {
+            return Ok(active && owner_id == user_id);
+        }
+
+        let active = self
+            .user_session_repository
+            .is_family_active(user_id, family_id)
+            .await?;
+
+        let mut entries = self.entries.write().await;
+        entries.retain(|_, (_, _, fetched_at)| fetched_at.elapsed() < self.ttl);
+        entries.insert(family_id, (user_id, active, Instant::now()));
+
+        Ok(active)
+    }
+
+    /// Drop every cached session of the user (called right after the user revokes sessions)
+    pub async fn invalidate_user(&self, user_id: Uuid) {
+        self.entries
+            .write()
+            .await
+            .retain(|_, (owner_id, _, _)| *owner_id != user_id);
+    }
+}
+
+/// Reject suspended accounts and revoked sessions, then attach user_id and claims for the handlers
+async fn authorize(mut req: Request, next: Next, claims: Claims) -> Result<Response, StatusCode> {
+    // Token from the first login step only - the second factor has not been verified
+    if claims.mfa_pending {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let Ok(user_id) = Uuid::parse_str(&claims.sub) else {
+        error!("Failed to parse user_id from claims.sub: {}", claims.sub);
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let Some(account_status_cache) = req.extensions().get::<Arc<AccountStatusCache>>().cloned()
+    else {
+        error!("AccountStatusCache extension is not installed");
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    match account_status_cache.status(user_id).await {
+        Ok(UserStatusEnum::Suspended) => {
+            info!("Rejected request from suspended account: {}", user_id);
+            return Err(StatusCode::FORBIDDEN);
+        }
+        Ok(UserStatusEnum::Deleted) => return Err(StatusCode::UNAUTHORIZED),
+        Ok(_) => {}
+        Err(e) if e.to_string().contains("not found") => return Err(StatusCode::UNAUTHORIZED),
+        Err(e) => {
+            error!("Failed to load account status for {}: {}", user_id, e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+
+    // Access tokens carry their session family - revoking the session ends them too
+    // (impersonation and API key principals have no session)
+    if let Some(sid) = claims.sid.as_deref() {
+        let Ok(family_id) = Uuid::parse_str(sid) else {
+            return Err(StatusCode::UNAUTHORIZED);
+        };
+
+        let Some(session_status_cache) = req.extensions().get::<Arc<SessionStatusCache>>().cloned()
+        else {
+            error!("SessionStatusCache extension is not installed");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        };
+
+        match session_status_cache.is_active(user_id, family_id).await {
+            Ok(true) => {}
+            Ok(false) => {
+                info!("Rejected access token from revoked session {} of {}", family_id, user_id);
+                return Err(StatusCode::UNAUTHORIZED);
+            }
+            Err(e) => {
+                error!("Failed to load session status for {}: {}", family_id, e);
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    }
+
+    // Impersonation: read-only unless the admin asked for write access, and every write is audited
+    let impersonated_write = match &claims.act {
+        Some(actor) if is_read_only_method(req.method()) => {
+            info!("Impersonated request by admin {} as {}", actor.sub, user_id);
+            None
+        }
+        Some(actor) if actor.write => {
+            Some(ImpersonatedWrite::from_request(&req, actor, user_id, &claims.jti)?)
+        },
+        Some(actor) => {
+            info!(
+                "Rejected {} {} from read-only impersonation by admin {}",
+                req.method(),
+                request_path(&req),
+                actor.sub
+            );
+            return Err(StatusCode::FORBIDDEN);
+        }
+        None => None,
+    };
+    let impersonation_mode = claims.act.as_ref().map(|actor| {
+        if actor.write { "read-write" } else { "read-only" }
+    });
+
+    let recorded_write = match impersonated_write {
+        Some(impersonated_write) => Some(impersonated_write.record().await?),
+        None => None,
+    };
+
+    req.extensions_mut().insert(user_id);
+    req.extensions_mut().insert::<Claims>(claims);
+    let mut response = next.run(req).await;
+
+    if let Some(mode) = impersonation_mode {
+        response
+            .headers_mut()
+            .insert("x-impersonation", HeaderValue::from_static(mode));
+    }
+    if let Some(recorded_write) = recorded_write {
+        recorded_write.complete(response.status()).await;
+    }
+
+    Ok(response)
+}
+
 fn get_cookie_value(cookie_header: &str, key: &str) -> Option<String> {
     cookie_header.split("; ").find_map(|cookie| {
         let mut parts = cookie.splitn(2, '=');
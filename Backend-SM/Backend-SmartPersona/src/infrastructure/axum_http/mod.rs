@@ -1,4 +1,5 @@
 pub mod default_routers;
 pub mod http_serve;
 pub mod middleware;
+pub mod role_guard;
 pub mod routers;
@@ -0,0 +1,105 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{StatusCode, request::Parts},
+};
+use uuid::Uuid;
+
+use crate::{domain::entities::user::Role, infrastructure::jwt_authentication::jwt_model::Claims};
+
+/// Read claims inserted by `user_authorization` and check the caller's role.
+/// Missing claims -> 401, wrong role -> 403.
+fn authorize_role(parts: &Parts, allowed: &[Role]) -> Result<Uuid, StatusCode> {
+    let claims = parts
+        .extensions
+        .get::<Claims>()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    if !allowed.contains(&claims.role) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)
+}
+
+/// Extractor: caller must be a `Role::CompanyUser`, yields the user_id
+pub struct RequireCompany(pub Uuid);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequireCompany
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        authorize_role(parts, &[Role::CompanyUser]).map(RequireCompany)
+    }
+}
+
+/// Extractor: caller must be a `Role::PersonaUser`, yields the user_id
+pub struct RequirePersona(pub Uuid);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequirePersona
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        authorize_role(parts, &[Role::PersonaUser]).map(RequirePersona)
+    }
+}
+
+/// Extractor: caller must be a `Role::Admin`, yields the user_id
+pub struct RequireAdmin(pub Uuid);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequireAdmin
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        authorize_role(parts, &[Role::Admin]).map(RequireAdmin)
+    }
+}
+
+/// Map ownership policy errors: not found -> 404, not owner -> 403
+pub fn ownership_error(e: anyhow::Error) -> (StatusCode, String) {
+    let message = e.to_string();
+    if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message)
+    } else if message.contains("Not authorized") {
+        (StatusCode::FORBIDDEN, message)
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+}
+
+/// Extractor: caller must not be using an impersonation token (sensitive endpoints such as
+/// MFA, sessions, linked identities and API keys). Missing claims -> 401, impersonating -> 403.
+pub struct NotImpersonating;
+
+#[async_trait]
+impl<S> FromRequestParts<S> for NotImpersonating
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+
+        if claims.is_impersonation() {
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        Ok(NotImpersonating)
+    }
+}
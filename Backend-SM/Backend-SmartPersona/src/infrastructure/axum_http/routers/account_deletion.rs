@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+
+use crate::{
+    domain::{
+        entities::account_deletion::{AccountDeletionResponse, RequestAccountDeletionRequest},
+        repo::{account_deletion::AccountDeletionRepository, user::UserRepository},
+        usecase::account_deletion::AccountDeletionUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::user_authorization, role_guard::NotImpersonating,
+            routers::user::AuthenticatedUserId,
+        },
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{account_deletion::AccountDeletionPostgres, user::UserPostgres},
+        },
+        supabase::{client::SupabaseClient, storage::StorageService},
+    },
+};
+
+type AccountDeletionUseCasePostgres = AccountDeletionUseCase<AccountDeletionPostgres, UserPostgres>;
+
+fn build_use_case(
+    db_pool: Arc<DbPool>,
+    supabase_client: Arc<SupabaseClient>,
+    grace_period_days: i64,
+) -> Arc<AccountDeletionUseCasePostgres> {
+    Arc::new(AccountDeletionUseCase::new(
+        Arc::new(AccountDeletionPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(UserPostgres::new(db_pool)),
+        Arc::new(StorageService::new(supabase_client)),
+        chrono::Duration::days(grace_period_days),
+    ))
+}
+
+/// Protected routes (require authentication) - schedule / cancel deletion of the caller's account
+pub fn routes(
+    db_pool: Arc<DbPool>,
+    supabase_client: Arc<SupabaseClient>,
+    grace_period_days: i64,
+) -> Router {
+    let account_deletion_use_case = build_use_case(db_pool, supabase_client, grace_period_days);
+
+    Router::new()
+        .route(
+            "/account/deletion",
+            get(get_deletion)
+                .post(request_deletion)
+                .delete(cancel_deletion),
+        )
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(account_deletion_use_case)
+}
+
+/// Periodically erase accounts whose grace period has passed
+pub async fn run_maintenance(
+    db_pool: Arc<DbPool>,
+    supabase_client: Arc<SupabaseClient>,
+    interval: std::time::Duration,
+) {
+    // grace period ใช้ตอนสร้างคำขอเท่านั้น - job อ่าน scheduled_for จาก database
+    let account_deletion_use_case = build_use_case(db_pool, supabase_client, 0);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        match account_deletion_use_case.process_due().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Erased {} deleted account(s)", count),
+            Err(e) => tracing::error!("Account deletion job failed: {}", e),
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn account_deletion_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Forbidden") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("already scheduled") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Schedule deletion of the caller's account after the grace period
+/// POST /api/user/account/deletion
+pub async fn request_deletion<T, U>(
+    State(account_deletion_use_case): State<Arc<AccountDeletionUseCase<T, U>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Json(request): Json<RequestAccountDeletionRequest>,
+) -> impl IntoResponse
+where
+    T: AccountDeletionRepository + Send + Sync + 'static,
+    U: UserRepository + Send + Sync + 'static,
+{
+    match account_deletion_use_case
+        .request_deletion(user_id, request)
+        .await
+    {
+        Ok(deletion) => (
+            StatusCode::ACCEPTED,
+            Json(AccountDeletionResponse::from(deletion)),
+        )
+            .into_response(),
+        Err(e) => account_deletion_error_response(e),
+    }
+}
+
+/// Get the caller's latest deletion request
+/// GET /api/user/account/deletion
+pub async fn get_deletion<T, U>(
+    State(account_deletion_use_case): State<Arc<AccountDeletionUseCase<T, U>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: AccountDeletionRepository + Send + Sync + 'static,
+    U: UserRepository + Send + Sync + 'static,
+{
+    match account_deletion_use_case.get_status(user_id).await {
+        Ok(deletion) => (StatusCode::OK, Json(AccountDeletionResponse::from(deletion))).into_response(),
+        Err(e) => account_deletion_error_response(e),
+    }
+}
+
+/// Cancel a scheduled deletion while still inside the grace period
+/// DELETE /api/user/account/deletion
+pub async fn cancel_deletion<T, U>(
+    State(account_deletion_use_case): State<Arc<AccountDeletionUseCase<T, U>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: AccountDeletionRepository + Send + Sync + 'static,
+    U: UserRepository + Send + Sync + 'static,
+{
+    match account_deletion_use_case.cancel(user_id).await {
+        Ok(deletion) => (StatusCode::OK, Json(AccountDeletionResponse::from(deletion))).into_response(),
+        Err(e) => account_deletion_error_response(e),
+    }
+}
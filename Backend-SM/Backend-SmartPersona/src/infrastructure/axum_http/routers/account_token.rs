@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::account_token::{
+            AccountTokenMessage, ConfirmEmailRequest, ConfirmPasswordResetRequest,
+            EmailVerificationRequest, PasswordResetRequest,
+        },
+        repo::{
+            account_token::AccountTokenRepository, user::UserRepository,
+            user_session::UserSessionRepository,
+        },
+        usecase::account_token::AccountTokenUseCase,
+    },
+    infrastructure::{
+        axum_http::middleware::SessionStatusCache,
+        email::EmailService,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{
+                account_token::AccountTokenPostgres, user::UserPostgres,
+                user_session::UserSessionPostgres,
+            },
+        },
+    },
+};
+
+pub type AccountTokenUseCasePostgres =
+    AccountTokenUseCase<UserPostgres, AccountTokenPostgres, UserSessionPostgres>;
+
+pub fn build_use_case(db_pool: Arc<DbPool>) -> Arc<AccountTokenUseCasePostgres> {
+    let user_repository = UserPostgres::new(Arc::clone(&db_pool));
+    let user_session_repository = UserSessionPostgres::new(Arc::clone(&db_pool));
+    let account_token_repository = AccountTokenPostgres::new(db_pool);
+
+    // Email service is optional - tokens are still issued when it is not configured
+    let email_service = EmailService::new().unwrap_or_else(|e| {
+        tracing::warn!("Email service not configured: {}. Using dummy service.", e);
+        EmailService::dummy()
+    });
+
+    Arc::new(AccountTokenUseCase::new(
+        Arc::new(user_repository),
+        Arc::new(account_token_repository),
+        Arc::new(user_session_repository),
+        Arc::new(email_service),
+    ))
+}
+
+/// Public routes for email verification and password reset (mounted under /authentication)
+pub fn routes(db_pool: Arc<DbPool>) -> Router {
+    let account_token_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/verify-email/request", post(request_email_verification))
+        .route("/verify-email/confirm", post(confirm_email))
+        .route("/password-reset/request", post(request_password_reset))
+        .route("/password-reset/confirm", post(confirm_password_reset))
+        .with_state(account_token_use_case)
+}
+
+/// Periodically purge expired verification / reset tokens
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let account_token_use_case = build_use_case(db_pool);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = account_token_use_case.cleanup_expired_tokens().await {
+            tracing::error!("Account token cleanup failed: {}", e);
+        }
+    }
+}
+
+/// Generic response that does not reveal whether the account exists
+fn accepted_response() -> Response {
+    let response = AccountTokenMessage {
+        message: "If an account with that email exists, an email has been sent".to_string(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Map use case errors to HTTP status codes
+fn token_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Invalid or expired token") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("suspended") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Resend the verification email for a pending account
+/// POST /authentication/verify-email/request
+pub async fn request_email_verification<TUser, TToken, TSession>(
+    State(account_token_use_case): State<Arc<AccountTokenUseCase<TUser, TToken, TSession>>>,
+    Json(request): Json<EmailVerificationRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TToken: AccountTokenRepository + Send + Sync + 'static,
+    TSession: UserSessionRepository + Send + Sync + 'static,
+{
+    if let Err(errors) = request.validate() {
+        return (StatusCode::BAD_REQUEST, format!("Validation error: {:?}", errors))
+            .into_response();
+    }
+
+    match account_token_use_case
+        .request_email_verification(request.email)
+        .await
+    {
+        Ok(_) => accepted_response(),
+        Err(e) => token_error_response(e),
+    }
+}
+
+/// Verify email address and activate the account
+/// POST /authentication/verify-email/confirm
+pub async fn confirm_email<TUser, TToken, TSession>(
+    State(account_token_use_case): State<Arc<AccountTokenUseCase<TUser, TToken, TSession>>>,
+    Json(request): Json<ConfirmEmailRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TToken: AccountTokenRepository + Send + Sync + 'static,
+    TSession: UserSessionRepository + Send + Sync + 'static,
+{
+    match account_token_use_case.confirm_email(&request.token).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(AccountTokenMessage {
+                message: "Email verified successfully".to_string(),
+            }),
+        )
+            .into_response(),
+        Err(e) => token_error_response(e),
+    }
+}
+
+/// Send a password reset email
+/// POST /authentication/password-reset/request
+pub async fn request_password_reset<TUser, TToken, TSession>(
+    State(account_token_use_case): State<Arc<AccountTokenUseCase<TUser, TToken, TSession>>>,
+    Json(request): Json<PasswordResetRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TToken: AccountTokenRepository + Send + Sync + 'static,
+    TSession: UserSessionRepository + Send + Sync + 'static,
+{
+    if let Err(errors) = request.validate() {
+        return (StatusCode::BAD_REQUEST, format!("Validation error: {:?}", errors))
+            .into_response();
+    }
+
+    match account_token_use_case
+        .request_password_reset(request.email)
+        .await
+    {
+        Ok(_) => accepted_response(),
+        Err(e) => token_error_response(e),
+    }
+}
+
+/// Set a new password using a reset token
+/// POST /authentication/password-reset/confirm
+pub async fn confirm_password_reset<TUser, TToken, TSession>(
+    State(account_token_use_case): State<Arc<AccountTokenUseCase<TUser, TToken, TSession>>>,
+    Extension(session_status_cache): Extension<Arc<SessionStatusCache>>,
+    Json(request): Json<ConfirmPasswordResetRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TToken: AccountTokenRepository + Send + Sync + 'static,
+    TSession: UserSessionRepository + Send + Sync + 'static,
+{
+    if let Err(errors) = request.validate() {
+        return (StatusCode::BAD_REQUEST, format!("Validation error: {:?}", errors))
+            .into_response();
+    }
+
+    match account_token_use_case
+        .reset_password(&request.token, request.new_password)
+        .await
+    {
+        Ok(user_id) => {
+            // Access tokens of the revoked sessions stop working right away
+            session_status_cache.invalidate_user(user_id).await;
+            (
+                StatusCode::OK,
+                Json(AccountTokenMessage {
+                    message: "Password has been reset successfully".to_string(),
+                }),
+            )
+                .into_response()
+        }
+        Err(e) => token_error_response(e),
+    }
+}
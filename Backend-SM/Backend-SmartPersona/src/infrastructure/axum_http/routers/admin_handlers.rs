@@ -1,35 +1,54 @@
 use axum::{
-    Json, Router, extract::{State, Path}, http::StatusCode, middleware, response::IntoResponse,
+    Extension, Json, Router, extract::{State, Path}, http::StatusCode, middleware, response::IntoResponse,
     routing::{get, post},
 };
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::company::UpdateCompany,
+        entities::audit_event::{AuditAction, AuditTarget, NewAuditEvent},
         repo::admin_data::AdminRepository,
         repo::company::CompanyRepository,
         repo::user::UserRepository,
         usecase::admin_data::AdminUseCase,
         usecase::company::CompanyUseCase,
 
+        usecase::account_moderation::AccountModerationUseCase,
         usecase::user::UserUseCase,
         usecase::user_skill::UserSkillUseCase,
     },
     infrastructure::{
-        axum_http::middleware::admin_authorization,
+        axum_http::{
+            middleware::{AccountStatusCache, admin_authorization},
+            routers::audit_log::AdminAudit,
+        },
         email::EmailService,
         postgres::{
             postgres_connection::DbPool,
+            repositories::account_moderation::AccountModerationPostgres,
             repositories::admin_data::AdminPostgres,
             repositories::company::CompanyPostgres,
 
             repositories::user::UserPostgres,
+            repositories::user_session::UserSessionPostgres,
+            repositories::user_share::ProfileSharePostgres,
             repositories::user_skill::UserSkillPostgres,
         },
     },
 };
 
+type AccountModerationUseCasePostgres =
+    AccountModerationUseCase<AccountModerationPostgres, UserSessionPostgres, ProfileSharePostgres>;
+
+type AdminState<T> = (
+    Arc<AdminUseCase<T>>,
+    Arc<CompanyUseCase<CompanyPostgres>>,
+    Arc<UserUseCase<UserPostgres>>,
+    Arc<EmailService>,
+    Arc<UserSkillUseCase<UserSkillPostgres>>,
+    Arc<AccountModerationUseCasePostgres>,
+);
+
 pub fn routes(db_pool: Arc<DbPool>) -> Router {
     let admin_repository = AdminPostgres::new(Arc::clone(&db_pool));
     let admin_use_case = AdminUseCase::new(Arc::new(admin_repository));
@@ -44,6 +63,12 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
     let user_skill_repository = UserSkillPostgres::new(Arc::clone(&db_pool));
     let user_skill_use_case = UserSkillUseCase::new(Arc::new(user_skill_repository));
 
+    let account_moderation_use_case = AccountModerationUseCase::new(
+        Arc::new(AccountModerationPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(UserSessionPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(ProfileSharePostgres::new(Arc::clone(&db_pool))),
+    );
+
     // Initialize email service (optional - will log warning if not configured)
     let email_service = match EmailService::new() {
         Ok(service) => {
@@ -69,11 +94,11 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
         .route("/users/:id/ban", post(ban_user))
         .route("/users/:id/unban", post(unban_user))
         .route("/users/:id/skills", get(get_user_skills))
-        .with_state((Arc::new(admin_use_case), Arc::new(company_use_case), Arc::new(user_use_case), email_service, Arc::new(user_skill_use_case)))
+        .with_state((Arc::new(admin_use_case), Arc::new(company_use_case), Arc::new(user_use_case), email_service, Arc::new(user_skill_use_case), Arc::new(account_moderation_use_case)))
         .layer(middleware::from_fn(admin_authorization))
 }
 
-async fn new_users_today<T>(State((admin_use_case, _, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>) -> impl IntoResponse
+async fn new_users_today<T>(State((admin_use_case, _, _, _, _, _)): State<AdminState<T>>) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
@@ -83,7 +108,7 @@ where
     }
 }
 
-async fn users_last_7_days<T>(State((admin_use_case, _, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>) -> impl IntoResponse
+async fn users_last_7_days<T>(State((admin_use_case, _, _, _, _, _)): State<AdminState<T>>) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
@@ -99,7 +124,7 @@ where
     }
 }
 
-async fn dashboard_stats<T>(State((admin_use_case, _, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>) -> impl IntoResponse
+async fn dashboard_stats<T>(State((admin_use_case, _, _, _, _, _)): State<AdminState<T>>) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
@@ -109,7 +134,7 @@ where
     }
 }
 
-async fn get_all_companies<T>(State((_, company_use_case, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>) -> impl IntoResponse
+async fn get_all_companies<T>(State((_, company_use_case, _, _, _, _)): State<AdminState<T>>) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
@@ -120,8 +145,10 @@ where
 }
 
 async fn approve_company<T>(
-    State((_, company_use_case, _, email_service, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, company_use_case, _, email_service, _, account_moderation_use_case)): State<AdminState<T>>,
+    audit: AdminAudit,
     Path(company_id): Path<uuid::Uuid>,
+    payload: Option<Json<ModerationRequest>>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
@@ -133,12 +160,14 @@ where
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     };
 
-    // Update company status to "approved"
-    let mut update_data = UpdateCompany::default();
-    update_data.status = Some("approved".to_string());
-    update_data.updated_at = Some(chrono::Utc::now());
+    // Update company status to "approved" (audit event is written in the same transaction)
+    let audit_event = audit.event(
+        NewAuditEvent::new(AuditAction::CompanyApprove, AuditTarget::Company, company_id)
+            .reason(payload.and_then(|Json(payload)| payload.reason))
+            .before(serde_json::json!({ "status": company.status })),
+    );
 
-    match company_use_case.update_company(company.user_id, update_data).await {
+    match account_moderation_use_case.approve_company(company_id, audit_event).await {
         Ok(updated_company) => {
             // Send approval email (only if email service is configured)
             if email_service.is_configured() {
@@ -159,13 +188,18 @@ where
 
             (StatusCode::OK, Json(updated_company)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to approve company {}: {}", company_id, e);
+            moderation_error_response(e)
+        }
     }
 }
 
 async fn reject_company<T>(
-    State((_, company_use_case, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, company_use_case, _, _, _, account_moderation_use_case)): State<AdminState<T>>,
+    audit: AdminAudit,
     Path(company_id): Path<uuid::Uuid>,
+    payload: Option<Json<ModerationRequest>>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
@@ -173,9 +207,19 @@ where
     
     match company_use_case.get_company_by_id(company_id).await {
         Ok(Some(company)) => {
-            match company_use_case.delete_company(company.user_id).await {
+            // บริษัทถูกลบ - เก็บข้อมูลทั้งหมดไว้ใน before_state
+            let audit_event = audit.event(
+                NewAuditEvent::new(AuditAction::CompanyReject, AuditTarget::Company, company_id)
+                    .reason(payload.and_then(|Json(payload)| payload.reason))
+                    .before(serde_json::to_value(&company).unwrap_or_default()),
+            );
+
+            match account_moderation_use_case.reject_company(company_id, audit_event).await {
                 Ok(_) => (StatusCode::OK, "Company rejected successfully").into_response(),
-                Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+                Err(e) => {
+                    tracing::error!("Failed to reject company {}: {}", company_id, e);
+                    moderation_error_response(e)
+                }
             }
         }
         Ok(None) => (StatusCode::NOT_FOUND, "Company not found").into_response(),
@@ -183,25 +227,47 @@ where
     }
 }
 
+/// Optional body of moderation actions - the reason is kept in the audit log
 #[derive(serde::Deserialize)]
-struct BanRequest {
+struct ModerationRequest {
     reason: Option<String>,
 }
 
+/// Ban / unban ของบัญชีที่อยู่ผิดสถานะ (ลบแล้ว / ไม่ได้ถูกระงับ) ตอบ 409
+fn moderation_error_response(e: anyhow::Error) -> axum::response::Response {
+    let message = e.to_string();
+    if message.contains("Conflict") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
 async fn ban_user<T>(
-    State((_, _, user_use_case, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, _, user_use_case, _, _, account_moderation_use_case)): State<AdminState<T>>,
+    Extension(account_status_cache): Extension<Arc<AccountStatusCache>>,
+    audit: AdminAudit,
     Path(user_id): Path<uuid::Uuid>,
-    Json(payload): Json<BanRequest>,
+    Json(payload): Json<ModerationRequest>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
     // Check if user exists first
     match user_use_case.get_user_by_id(user_id).await {
-        Ok(_) => {
-            // User exists, proceed with ban
-            match user_use_case.ban_user(user_id).await {
+        Ok(existing_user) => {
+            let audit_event = audit.event(
+                NewAuditEvent::new(AuditAction::UserBan, AuditTarget::User, user_id)
+                    .reason(payload.reason.clone())
+                    .before(serde_json::json!({ "status": existing_user.status })),
+            );
+
+            // User exists, proceed with ban (also revokes sessions and share links)
+            match account_moderation_use_case.suspend_account(user_id, audit_event).await {
                 Ok(user) => {
+                    account_status_cache.invalidate(user_id).await;
                     if let Some(reason) = payload.reason {
                         tracing::info!("User {} banned. Reason: {}", user_id, reason);
                     } else {
@@ -211,7 +277,7 @@ where
                 }
                 Err(e) => {
                     tracing::error!("Failed to ban user {}: {}", user_id, e);
-                    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+                    moderation_error_response(e)
                 }
             }
         }
@@ -228,24 +294,34 @@ where
 }
 
 async fn unban_user<T>(
-    State((_, _, user_use_case, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, _, user_use_case, _, _, account_moderation_use_case)): State<AdminState<T>>,
+    Extension(account_status_cache): Extension<Arc<AccountStatusCache>>,
+    audit: AdminAudit,
     Path(user_id): Path<uuid::Uuid>,
+    payload: Option<Json<ModerationRequest>>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
 {
     // Check if user exists first
     match user_use_case.get_user_by_id(user_id).await {
-        Ok(_) => {
+        Ok(existing_user) => {
+            let audit_event = audit.event(
+                NewAuditEvent::new(AuditAction::UserUnban, AuditTarget::User, user_id)
+                    .reason(payload.and_then(|Json(payload)| payload.reason))
+                    .before(serde_json::json!({ "status": existing_user.status })),
+            );
+
             // User exists, proceed with unban
-            match user_use_case.unban_user(user_id).await {
+            match account_moderation_use_case.reactivate_account(user_id, audit_event).await {
                 Ok(user) => {
+                    account_status_cache.invalidate(user_id).await;
                     tracing::info!("User {} unbanned", user_id);
                     (StatusCode::OK, Json(user)).into_response()
                 }
                 Err(e) => {
                     tracing::error!("Failed to unban user {}: {}", user_id, e);
-                    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+                    moderation_error_response(e)
                 }
             }
         }
@@ -262,9 +338,11 @@ where
 }
 
 async fn ban_company<T>(
-    State((_, company_use_case, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, company_use_case, _, _, _, account_moderation_use_case)): State<AdminState<T>>,
+    Extension(account_status_cache): Extension<Arc<AccountStatusCache>>,
+    audit: AdminAudit,
     Path(company_id): Path<uuid::Uuid>,
-    Json(payload): Json<BanRequest>,
+    Json(payload): Json<ModerationRequest>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
@@ -276,13 +354,16 @@ where
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     };
 
-    // Update company status to "banned"
-    let mut update_data = UpdateCompany::default();
-    update_data.status = Some("banned".to_string());
-    update_data.updated_at = Some(chrono::Utc::now());
+    let audit_event = audit.event(
+        NewAuditEvent::new(AuditAction::CompanyBan, AuditTarget::Company, company_id)
+            .reason(payload.reason.clone())
+            .before(serde_json::json!({ "status": company.status })),
+    );
 
-    match company_use_case.update_company(company.user_id, update_data).await {
+    // Ban the company and suspend its login account so the ban takes effect immediately
+    match account_moderation_use_case.ban_company(company_id, audit_event).await {
         Ok(updated_company) => {
+            account_status_cache.invalidate(company.user_id).await;
             if let Some(reason) = payload.reason {
                 tracing::info!("Company {} banned. Reason: {}", company_id, reason);
             } else {
@@ -290,13 +371,19 @@ where
             }
             (StatusCode::OK, Json(updated_company)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to ban company {}: {}", company_id, e);
+            moderation_error_response(e)
+        }
     }
 }
 
 async fn unban_company<T>(
-    State((_, company_use_case, _, _, _)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, company_use_case, _, _, _, account_moderation_use_case)): State<AdminState<T>>,
+    Extension(account_status_cache): Extension<Arc<AccountStatusCache>>,
+    audit: AdminAudit,
     Path(company_id): Path<uuid::Uuid>,
+    payload: Option<Json<ModerationRequest>>,
 ) -> impl IntoResponse
 where
     T: AdminRepository + Send + Sync,
@@ -308,22 +395,28 @@ where
         Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     };
 
-    // Update company status to "approved" (or "active" if preferred)
-    let mut update_data = UpdateCompany::default();
-    update_data.status = Some("approved".to_string());
-    update_data.updated_at = Some(chrono::Utc::now());
+    let audit_event = audit.event(
+        NewAuditEvent::new(AuditAction::CompanyUnban, AuditTarget::Company, company_id)
+            .reason(payload.and_then(|Json(payload)| payload.reason))
+            .before(serde_json::json!({ "status": company.status })),
+    );
 
-    match company_use_case.update_company(company.user_id, update_data).await {
+    // Restore the company to "approved" and reactivate its login account
+    match account_moderation_use_case.unban_company(company_id, audit_event).await {
         Ok(updated_company) => {
+            account_status_cache.invalidate(company.user_id).await;
             tracing::info!("Company {} unbanned", company_id);
             (StatusCode::OK, Json(updated_company)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to unban company {}: {}", company_id, e);
+            moderation_error_response(e)
+        }
     }
 }
 
 async fn get_user_skills<T>(
-    State((_, _, _, _, user_skill_use_case)): State<(Arc<AdminUseCase<T>>, Arc<CompanyUseCase<CompanyPostgres>>, Arc<UserUseCase<UserPostgres>>, Arc<EmailService>, Arc<UserSkillUseCase<UserSkillPostgres>>)>,
+    State((_, _, _, _, user_skill_use_case, _)): State<AdminState<T>>,
     Path(user_id): Path<uuid::Uuid>,
 ) -> impl IntoResponse
 where
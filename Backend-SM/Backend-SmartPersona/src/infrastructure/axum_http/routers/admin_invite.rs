@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            admin_invite::{AcceptAdminInviteRequest, CreateAdminInviteRequest},
+            audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+        },
+        repo::{admin_invite::AdminInviteRepository, user::UserRepository},
+        usecase::admin_invite::AdminInviteUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::admin_authorization, role_guard::RequireAdmin,
+            routers::audit_log::AdminAudit,
+        },
+        email::EmailService,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{admin_invite::AdminInvitePostgres, user::UserPostgres},
+        },
+    },
+};
+
+pub type AdminInviteUseCasePostgres = AdminInviteUseCase<UserPostgres, AdminInvitePostgres>;
+
+pub fn build_use_case(db_pool: Arc<DbPool>) -> Arc<AdminInviteUseCasePostgres> {
+    let user_repository = UserPostgres::new(Arc::clone(&db_pool));
+    let admin_invite_repository = AdminInvitePostgres::new(db_pool);
+
+    // Email service is optional - the invite is still created when it is not configured
+    let email_service = EmailService::new().unwrap_or_else(|e| {
+        tracing::warn!("Email service not configured: {}. Using dummy service.", e);
+        EmailService::dummy()
+    });
+
+    Arc::new(AdminInviteUseCase::new(
+        Arc::new(user_repository),
+        Arc::new(admin_invite_repository),
+        Arc::new(email_service),
+    ))
+}
+
+/// Admin-only routes for inviting new administrators (mounted under /admin)
+pub fn routes(db_pool: Arc<DbPool>) -> Router {
+    Router::new()
+        .route("/invites", post(create_invite))
+        .with_state(build_use_case(db_pool))
+        .layer(middleware::from_fn(admin_authorization))
+}
+
+/// Public route for accepting an invite (mounted under /authentication)
+pub fn public_routes(db_pool: Arc<DbPool>) -> Router {
+    Router::new()
+        .route("/admin-invite/accept", post(accept_invite))
+        .with_state(build_use_case(db_pool))
+}
+
+/// Periodically purge expired admin invites
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let admin_invite_use_case = build_use_case(db_pool);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = admin_invite_use_case.cleanup_expired_invites().await {
+            tracing::error!("Admin invite cleanup failed: {}", e);
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn invite_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") || message.contains("Invalid or expired token") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("already") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Invite a new administrator by email
+/// POST /admin/invites
+pub async fn create_invite<TUser, TInvite>(
+    State(admin_invite_use_case): State<Arc<AdminInviteUseCase<TUser, TInvite>>>,
+    RequireAdmin(admin_id): RequireAdmin,
+    audit: AdminAudit,
+    Json(request): Json<CreateAdminInviteRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TInvite: AdminInviteRepository + Send + Sync + 'static,
+{
+    match admin_invite_use_case.create_invite(admin_id, request).await {
+        Ok(invite) => {
+            if let Err(response) = audit
+                .record(
+                    NewAuditEvent::new(AuditAction::AdminInviteCreate, AuditTarget::AdminInvite, invite.id)
+                        .after(serde_json::json!({
+                            "email": invite.email,
+                            "expires_at": invite.expires_at,
+                        })),
+                )
+                .await
+            {
+                return response;
+            }
+            (StatusCode::CREATED, Json(invite)).into_response()
+        }
+        Err(e) => invite_error_response(e),
+    }
+}
+
+/// Accept an admin invite and create the admin account
+/// POST /authentication/admin-invite/accept
+pub async fn accept_invite<TUser, TInvite>(
+    State(admin_invite_use_case): State<Arc<AdminInviteUseCase<TUser, TInvite>>>,
+    Json(request): Json<AcceptAdminInviteRequest>,
+) -> impl IntoResponse
+where
+    TUser: UserRepository + Send + Sync + 'static,
+    TInvite: AdminInviteRepository + Send + Sync + 'static,
+{
+    match admin_invite_use_case.accept_invite(request).await {
+        Ok(user_id) => (
+            StatusCode::CREATED,
+            Json(serde_json::json!({ "user_id": user_id })),
+        )
+            .into_response(),
+        Err(e) => invite_error_response(e),
+    }
+}
@@ -14,7 +14,6 @@ use uuid::Uuid;
 use crate::{
     domain::{
         entities::{
-            user::Role,
             user_education::UserEducationEntity,
             user_experience::UserExperienceEntity,
             user_job_preference::UserJobPreferenceEntity,
@@ -23,14 +22,15 @@ use crate::{
             user_skill::UserSkillEntity,
         },
         repo::{
-            user::UserRepository, user_education::UserEducationRepository,
+            user_education::UserEducationRepository,
             user_experience::UserExperienceRepository,
             user_job_preference::UserJobPreferenceRepository,
             user_portfolio::UserPortfolioRepository, user_profile::UserProfileRepository,
             user_skill::UserSkillRepository,
         },
         usecase::{
-            user::UserUseCase, user_education::UserEducationUseCase,
+            company_ownership::CompanyOwnershipPolicy,
+            user_education::UserEducationUseCase,
             user_experience::UserExperienceUseCase,
             user_job_preference::UserJobPreferenceUseCase,
             user_portfolio::UserPortfolioUseCase, user_profile::UserProfileUseCase,
@@ -38,10 +38,13 @@ use crate::{
         },
     },
     infrastructure::{
-        axum_http::middleware::user_authorization,
-        jwt_authentication::jwt_model::Claims,
+        axum_http::{
+            middleware::user_authorization,
+            role_guard::{RequireCompany, ownership_error},
+        },
         postgres::{
-            postgres_connection::DbPool, repositories::user::UserPostgres,
+            postgres_connection::DbPool,
+            repositories::company_ownership::CompanyOwnershipPostgres,
             repositories::user_education::UserEducationPostgres,
             repositories::user_experience::UserExperiencePostgres,
             repositories::user_job_preference::UserJobPreferencePostgres,
@@ -52,29 +55,7 @@ use crate::{
     },
 };
 
-/// Custom extractor for Claims from JWT
-pub struct OptionalClaims(pub Option<Claims>);
-
-#[axum::async_trait]
-impl<S> axum::extract::FromRequestParts<S> for OptionalClaims
-where
-    S: Send + Sync,
-{
-    type Rejection = std::convert::Infallible;
-
-    async fn from_request_parts(
-        parts: &mut axum::http::request::Parts,
-        _state: &S,
-    ) -> Result<Self, Self::Rejection> {
-        let claims = parts.extensions.get::<Claims>().cloned();
-        Ok(OptionalClaims(claims))
-    }
-}
-
 pub fn routes(db_pool: Arc<DbPool>) -> Router {
-    let user_repository = UserPostgres::new(Arc::clone(&db_pool));
-    let user_use_case = Arc::new(UserUseCase::new(Arc::new(user_repository)));
-
     let user_profile_repository = UserProfilePostgres::new(Arc::clone(&db_pool));
     let user_profile_use_case =
         Arc::new(UserProfileUseCase::new(Arc::new(user_profile_repository)));
@@ -102,17 +83,21 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
         user_job_preference_repository,
     )));
 
+    let ownership_policy = Arc::new(CompanyOwnershipPolicy::new(Arc::new(
+        CompanyOwnershipPostgres::new(Arc::clone(&db_pool)),
+    )));
+
     Router::new()
         .route("/company/applicant/:user_id", get(get_applicant_data))
         .layer(middleware::from_fn(user_authorization))
         .with_state((
-            user_use_case,
             user_profile_use_case,
             user_education_use_case,
             user_experience_use_case,
             user_skill_use_case,
             user_portfolio_use_case,
             user_job_preference_use_case,
+            ownership_policy,
         ))
 }
 
@@ -127,7 +112,6 @@ pub struct ApplicantData {
 }
 
 pub async fn get_applicant_data<
-    TUser,
     TProfile,
     TEducation,
     TExperience,
@@ -136,27 +120,27 @@ pub async fn get_applicant_data<
     TJobPref,
 >(
     State((
-        user_use_case,
         user_profile_use_case,
         user_education_use_case,
         user_experience_use_case,
         user_skill_use_case,
         user_portfolio_use_case,
         user_job_preference_use_case,
+        ownership_policy,
     )): State<(
-        Arc<UserUseCase<TUser>>,
         Arc<UserProfileUseCase<TProfile>>,
         Arc<UserEducationUseCase<TEducation>>,
         Arc<UserExperienceUseCase<TExperience>>,
         Arc<UserSkillUseCase<TSkill>>,
         Arc<UserPortfolioUseCase<TPortfolio>>,
         Arc<UserJobPreferenceUseCase<TJobPref>>,
+        Arc<CompanyOwnershipPolicy>,
     )>,
+    // Only company users can access applicant data
+    RequireCompany(company_user_id): RequireCompany,
     Path(user_id): Path<Uuid>,
-    OptionalClaims(claims_opt): OptionalClaims,
 ) -> impl IntoResponse
 where
-    TUser: UserRepository + Send + Sync + 'static,
     TProfile: UserProfileRepository + Send + Sync + 'static,
     TEducation: UserEducationRepository + Send + Sync + 'static,
     TExperience: UserExperienceRepository + Send + Sync + 'static,
@@ -164,28 +148,12 @@ where
     TPortfolio: UserPortfolioRepository + Send + Sync + 'static,
     TJobPref: UserJobPreferenceRepository + Send + Sync + 'static,
 {
-    // 1. Verify Company User Role
-    let mut is_company_user = false;
-    if let Some(claims) = &claims_opt {
-        if let Ok(requester_id) = Uuid::parse_str(&claims.sub) {
-            match user_use_case.get_user_by_id(requester_id).await {
-                Ok(user) => {
-                    if user.role == Role::CompanyUser {
-                        is_company_user = true;
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to fetch requester user info: {}", e);
-                }
-            }
-        }
-    }
-
-    if !is_company_user {
-        return (StatusCode::FORBIDDEN, "Only company users can access applicant data").into_response();
+    // เฉพาะบริษัทที่ผู้ใช้คนนี้สมัครงานด้วยเท่านั้น (รวมถึง API key ของบริษัท)
+    if let Err(e) = ownership_policy.ensure_applicant_of(company_user_id, user_id).await {
+        return ownership_error(e).into_response();
     }
 
-    // 2. Fetch All Data (Parallel-ish)
+    // 1. Fetch All Data (Parallel-ish)
     let profile = user_profile_use_case.get_profile_by_user_id(user_id).await;
     let educations = user_education_use_case.get_user_educations(user_id).await;
     let experiences = user_experience_use_case.get_user_experiences(user_id).await;
@@ -195,7 +163,7 @@ where
         .get_preference_by_user_id(user_id)
         .await;
 
-    // 3. Handle Results
+    // 2. Handle Results
     let profile_data = match profile {
         Ok(p) => p,
         Err(_) => None,
@@ -229,7 +197,7 @@ where
         Err(_) => None,
     };
 
-    // 4. Return Aggregated Data
+    // 3. Return Aggregated Data
     let response = ApplicantData {
         profile: profile_data,
         educations: educations_data,
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router, async_trait,
+    extract::{FromRequestParts, Query, State},
+    http::{StatusCode, header, request::Parts},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            audit_event::{AuditLogQuery, NewAuditEvent},
+            user::Role,
+        },
+        repo::audit_event::AuditEventRepository,
+        usecase::audit_log::AuditLogUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::admin_authorization, role_guard::RequireAdmin,
+            routers::login_throttle::client_ip,
+        },
+        jwt_authentication::jwt_model::Claims,
+        postgres::{postgres_connection::DbPool, repositories::audit_event::AuditEventPostgres},
+    },
+};
+
+pub type AuditLogUseCasePostgres = AuditLogUseCase<AuditEventPostgres>;
+
+/// สร้างครั้งเดียวใน http_serve แล้วติดตั้งเป็น `Extension<Arc<AuditLogUseCasePostgres>>`
+pub fn build_use_case(db_pool: Arc<DbPool>) -> Arc<AuditLogUseCasePostgres> {
+    Arc::new(AuditLogUseCase::new(Arc::new(AuditEventPostgres::new(db_pool))))
+}
+
+/// Admin-only routes for reading the audit log (mounted under /admin)
+pub fn admin_routes(audit_log_use_case: Arc<AuditLogUseCasePostgres>) -> Router {
+    Router::new()
+        .route("/audit-log", get(get_audit_log))
+        .with_state(audit_log_use_case)
+        .layer(middleware::from_fn(admin_authorization))
+}
+
+/// Extractor: the admin performing a privileged action, with request context for the audit log.
+/// Missing claims -> 401, not an admin -> 403.
+pub struct AdminAudit {
+    audit_log_use_case: Arc<AuditLogUseCasePostgres>,
+    pub actor_id: Uuid,
+    ip_address: String,
+    user_agent: Option<String>,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AdminAudit
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims = parts
+            .extensions
+            .get::<Claims>()
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+        if claims.role != Role::Admin {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        let actor_id = Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+        let audit_log_use_case = parts
+            .extensions
+            .get::<Arc<AuditLogUseCasePostgres>>()
+            .cloned()
+            .ok_or_else(|| {
+                tracing::error!("AuditLogUseCase extension is not installed");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(512).collect());
+
+        Ok(Self {
+            audit_log_use_case,
+            actor_id,
+            ip_address: client_ip(&parts.headers, &parts.extensions),
+            user_agent,
+        })
+    }
+}
+
+impl AdminAudit {
+    /// ใส่ผู้กระทำและ request context ให้ event - สำหรับ repository ที่บันทึก event
+    /// ใน transaction เดียวกับการกระทำ
+    pub fn event(&self, new_event: NewAuditEvent) -> NewAuditEvent {
+        NewAuditEvent {
+            actor_id: Some(self.actor_id),
+            actor_role: Some("admin".to_string()),
+            ip_address: Some(self.ip_address.clone()),
+            user_agent: self.user_agent.clone(),
+            ..new_event
+        }
+    }
+
+    /// บันทึก event หลังการกระทำสำเร็จ - บันทึกไม่สำเร็จคืน 500 ให้ handler ตอบกลับทันที
+    /// (การกระทำที่ไม่มี audit event ต้องไม่ถูกรายงานว่าสำเร็จ)
+    /// การกระทำที่แก้ไขข้อมูลใน database ควรบันทึกผ่าน `event` ใน transaction เดียวกันแทน
+    pub async fn record(&self, new_event: NewAuditEvent) -> Result<(), Response> {
+        let action = new_event.action.clone();
+
+        self.audit_log_use_case.record(self.event(new_event)).await.map(|_| ()).map_err(|e| {
+            tracing::error!(
+                "Failed to write audit event {} by {}: {}",
+                action,
+                self.actor_id,
+                e
+            );
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to write audit log").into_response()
+        })
+    }
+}
+
+/// Audit log, newest first
+/// GET /admin/audit-log?actor_id=&action=&target_type=&target_id=&from=&to=&page=1&per_page=50
+pub async fn get_audit_log<T>(
+    State(audit_log_use_case): State<Arc<AuditLogUseCase<T>>>,
+    RequireAdmin(_admin_id): RequireAdmin,
+    Query(query): Query<AuditLogQuery>,
+) -> impl IntoResponse
+where
+    T: AuditEventRepository + Send + Sync + 'static,
+{
+    match audit_log_use_case.search(query).await {
+        Ok(page) => (StatusCode::OK, Json(page)).into_response(),
+        Err(e) if e.to_string().contains("Invalid date range") => {
+            (StatusCode::BAD_REQUEST, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -1,6 +1,4 @@
-use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration as StdDuration, Instant};
 
 use axum::{
     Json, Router,
@@ -11,35 +9,194 @@ use axum::{
 };
 use axum_extra::extract::cookie::{Cookie, CookieJar};
 use cookie::time::{Duration, OffsetDateTime};
-use tokio::sync::RwLock;
 
 use crate::{
     config::{config_loader::get_stage, stage::Stage},
-    domain::repo::user::UserRepository,
+    domain::{
+        entities::{
+            login_throttle::ThrottleScope,
+            user_session::{SessionDevice, SessionScope},
+        },
+        repo::{
+            user::UserRepository, user_mfa::UserMfaRepository,
+            user_session::UserSessionRepository,
+        },
+        usecase::user_mfa::UserMfaUseCase,
+    },
     infrastructure::{
-        jwt_authentication::authentication_model::LoginModel,
-        postgres::{postgres_connection::DbPool, repositories::user::UserPostgres},
+        axum_http::routers::login_throttle::{
+            ClientIp, LoginThrottleUseCasePostgres, throttle_error_response,
+        },
+        jwt_authentication::{
+            authentication_model::{LoginModel, LoginOutcome, MfaEnrollModel, MfaLoginModel},
+            jwt_keys::JwtKeySet,
+            jwt_model::Passport,
+        },
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{
+                user::UserPostgres, user_mfa::UserMfaPostgres,
+                user_session::UserSessionPostgres,
+            },
+        },
     },
 };
 
 use crate::domain::usecase::authentication::AuthenticationUseCase;
 
-pub fn routes(db_pool: Arc<DbPool>) -> Router {
+/// Router state: use case + persistent login rate limit / lockout
+type AuthState<T, TSession, TMfa> = (
+    Arc<AuthenticationUseCase<T, TSession, TMfa>>,
+    Arc<LoginThrottleUseCasePostgres>,
+);
+
+pub type AuthenticationUseCasePostgres =
+    AuthenticationUseCase<UserPostgres, UserSessionPostgres, UserMfaPostgres>;
+
+pub fn build_use_case(db_pool: Arc<DbPool>, jwt_keys: Arc<JwtKeySet>) -> Arc<AuthenticationUseCasePostgres> {
     let user_repository = UserPostgres::new(Arc::clone(&db_pool));
-    let authentication_use_case = AuthenticationUseCase::new(Arc::new(user_repository));
-    let rate_limit_state = Arc::new(RwLock::new(HashMap::<String, Vec<Instant>>::new()));
+    let user_session_repository = UserSessionPostgres::new(Arc::clone(&db_pool));
+    let user_mfa_use_case = UserMfaUseCase::new(Arc::new(UserMfaPostgres::new(db_pool)));
+
+    Arc::new(AuthenticationUseCase::new(
+        Arc::new(user_repository),
+        Arc::new(user_session_repository),
+        Arc::new(user_mfa_use_case),
+        jwt_keys,
+    ))
+}
+
+pub fn routes(
+    db_pool: Arc<DbPool>,
+    jwt_keys: Arc<JwtKeySet>,
+    login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>,
+) -> Router {
+    let authentication_use_case = build_use_case(db_pool, jwt_keys);
 
     Router::new()
-        .route("/login", post(user_login::<UserPostgres>))
-        .route("/refresh-token", post(user_refresh_token::<UserPostgres>))
-        .route("/logout", post(user_logout))
-        .route("/admin/login", post(admin_login::<UserPostgres>))
+        .route("/login", post(user_login::<UserPostgres, UserSessionPostgres, UserMfaPostgres>))
+        .route(
+            "/mfa/verify",
+            post(user_verify_mfa::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
+        .route(
+            "/refresh-token",
+            post(user_refresh_token::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
+        .route("/logout", post(user_logout::<UserPostgres, UserSessionPostgres, UserMfaPostgres>))
+        .route(
+            "/admin/login",
+            post(admin_login::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
+        .route(
+            "/admin/mfa/enroll",
+            post(admin_enroll_mfa::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
+        .route(
+            "/admin/mfa/verify",
+            post(admin_verify_mfa::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
         .route(
             "/admin/refresh-token",
-            post(admin_refresh_token::<UserPostgres>),
+            post(admin_refresh_token::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
         )
-        .route("/admin/logout", post(admin_logout))
-        .with_state((Arc::new(authentication_use_case), rate_limit_state))
+        .route(
+            "/admin/logout",
+            post(admin_logout::<UserPostgres, UserSessionPostgres, UserMfaPostgres>),
+        )
+        .with_state((authentication_use_case, login_throttle_use_case))
+}
+
+/// นับผลการ login: เข้าสู่ระบบสำเร็จล้างตัวนับ, รหัสผ่าน / รหัส MFA ผิดนับเข้า lockout
+/// รหัสผ่านถูกแต่ยังต้องยืนยัน MFA (`completed` เป็น false) ยังไม่ล้างตัวนับ
+/// (บัญชีที่ยังไม่ยืนยันอีเมล / ถูกระงับ / Admin ที่เข้าผิดหน้าไม่นับ เพราะรหัสผ่านถูกต้องแล้ว)
+async fn track_login_attempt<R>(
+    login_throttle_use_case: &LoginThrottleUseCasePostgres,
+    username: &str,
+    ip_address: &str,
+    result: &anyhow::Result<R>,
+    completed: impl FnOnce(&R) -> bool,
+) {
+    let tracked = match result {
+        Ok(outcome) if completed(outcome) => login_throttle_use_case.record_success(username).await,
+        Ok(_) => Ok(()),
+        Err(e) if is_account_state_error(e) => Ok(()),
+        Err(_) => {
+            login_throttle_use_case
+                .record_failure(username, ip_address)
+                .await
+        }
+    };
+
+    if let Err(e) = tracked {
+        tracing::error!("Failed to record login attempt for {}: {}", username, e);
+    }
+}
+
+/// ขั้นยืนยัน MFA: token ที่ถูกต้องผูกกับบัญชี จึงใช้ bucket ต่อ username และ lockout เดียวกับ login
+/// token ที่อ่านไม่ได้จะล้มในขั้นถัดไปอยู่แล้ว - นับแค่ต่อ IP
+async fn check_mfa_attempt(
+    login_throttle_use_case: &LoginThrottleUseCasePostgres,
+    ip_address: &str,
+    username: Option<&str>,
+) -> anyhow::Result<()> {
+    match username {
+        Some(username) => {
+            login_throttle_use_case
+                .check_login(ThrottleScope::Mfa, ip_address, username)
+                .await
+        }
+        None => login_throttle_use_case.check_ip(ThrottleScope::Mfa, ip_address).await,
+    }
+}
+
+fn is_account_state_error(e: &anyhow::Error) -> bool {
+    let message = e.to_string();
+    message.contains("not verified") || message.contains("suspended") || message.contains("Forbidden")
+}
+
+/// Set-Cookie headers for an access/refresh token pair
+pub fn passport_cookie_headers(
+    passport: &Passport,
+    request_headers: &HeaderMap,
+    access_cookie_name: &'static str,
+    refresh_cookie_name: &'static str,
+) -> HeaderMap {
+    let cookie_domain = get_cookie_domain(request_headers);
+
+    let mut act_cookie = Cookie::build((access_cookie_name, passport.access_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(14));
+
+    let mut rft_cookie = Cookie::build((refresh_cookie_name, passport.refresh_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(14));
+
+    // Only set domain for smartpersona.local subdomains
+    if let Some(domain) = cookie_domain.filter(|domain| domain.contains("smartpersona.local")) {
+        act_cookie = act_cookie.domain(domain.clone());
+        rft_cookie = rft_cookie.domain(domain);
+    }
+
+    if get_stage() == Stage::Production {
+        rft_cookie = rft_cookie.secure(true);
+        act_cookie = act_cookie.secure(true);
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
+    );
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
+    );
+    headers
 }
 
 // Helper function to extract domain from Origin header
@@ -71,6 +228,19 @@ fn get_cookie_domain(headers: &HeaderMap) -> Option<String> {
     None
 }
 
+/// Device information stored with the session (shown in the session list)
+pub fn session_device(headers: &HeaderMap, ip_address: &str) -> SessionDevice {
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.chars().take(512).collect());
+
+    SessionDevice {
+        user_agent,
+        ip_address: Some(ip_address.to_string()),
+    }
+}
+
 fn build_removal_cookie(name: &str, domain: Option<String>) -> Cookie<'static> {
     let mut cookie = Cookie::build((name.to_string(), ""))
         .path("/")
@@ -87,91 +257,100 @@ fn build_removal_cookie(name: &str, domain: Option<String>) -> Cookie<'static> {
     cookie.build()
 }
 
-pub async fn user_login<T>(
-    State((authentication_use_case, rate_limit_state)): State<(
-        Arc<AuthenticationUseCase<T>>,
-        Arc<RwLock<HashMap<String, Vec<Instant>>>>,
-    )>,
+/// Map login errors: unverified/suspended accounts get 403, everything else 401
+fn login_error_response(e: anyhow::Error) -> axum::response::Response {
+    if is_account_state_error(&e) {
+        (StatusCode::FORBIDDEN, e.to_string()).into_response()
+    } else {
+        (StatusCode::UNAUTHORIZED, e.to_string()).into_response()
+    }
+}
+
+pub async fn user_login<T, TSession, TMfa>(
+    State((authentication_use_case, login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    ClientIp(ip_address): ClientIp,
     headers: HeaderMap,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
     T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
+    let username = login_model.username.clone();
+
+    if let Err(e) = login_throttle_use_case
+        .check_login(ThrottleScope::UserLogin, &ip_address, &username)
+        .await
     {
-        let mut rate_map = rate_limit_state.write().await;
-        let now = Instant::now();
-        let ip = "127.0.0.1".to_string();
-
-        let requests = rate_map.entry(ip.clone()).or_insert_with(Vec::new);
-        requests.retain(|&timestamp| now.duration_since(timestamp) < StdDuration::from_secs(60));
-
-        if requests.len() >= 5 {
-            return (
-                StatusCode::TOO_MANY_REQUESTS,
-                "Too many requests. Try again later.",
-            )
-                .into_response();
+        return throttle_error_response(e);
+    }
+
+    let result = authentication_use_case
+        .user_login(login_model, session_device(&headers, &ip_address))
+        .await;
+    track_login_attempt(&login_throttle_use_case, &username, &ip_address, &result, |outcome| {
+        matches!(outcome, LoginOutcome::Authenticated(_))
+    })
+    .await;
+
+    match result {
+        Ok(LoginOutcome::Authenticated(passport)) => {
+            let cookie_headers = passport_cookie_headers(&passport, &headers, "act", "rft");
+            (StatusCode::OK, cookie_headers, "Login successfully").into_response()
         }
+        // Second step: POST /authentication/mfa/verify
+        Ok(LoginOutcome::MfaRequired(challenge)) => (StatusCode::ACCEPTED, Json(challenge)).into_response(),
+        Err(e) => login_error_response(e),
+    }
+}
 
-        requests.push(now);
+/// Second login step for accounts with MFA enabled
+/// POST /authentication/mfa/verify
+pub async fn user_verify_mfa<T, TSession, TMfa>(
+    State((authentication_use_case, login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    ClientIp(ip_address): ClientIp,
+    headers: HeaderMap,
+    Json(mfa_login_model): Json<MfaLoginModel>,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
+{
+    let username = authentication_use_case
+        .pending_mfa_username(SessionScope::User, &mfa_login_model.mfa_token)
+        .await
+        .ok();
+    if let Err(e) = check_mfa_attempt(&login_throttle_use_case, &ip_address, username.as_deref()).await {
+        return throttle_error_response(e);
     }
-    match authentication_use_case.user_login(login_model).await {
-        Ok(passport) => {
-            let cookie_domain = get_cookie_domain(&headers);
-            
-            let mut act_cookie = Cookie::build(("act", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            // Set domain if we can determine it from Origin/Referer
-            if let Some(domain) = cookie_domain {
-                // Only set domain for smartpersona.local subdomains
-                if domain.contains("smartpersona.local") {
-                    act_cookie = act_cookie.domain(domain.clone());
-                    rft_cookie = rft_cookie.domain(domain);
-                }
-            }
 
-            if get_stage() == Stage::Production {
-                rft_cookie = rft_cookie.secure(true);
-                act_cookie = act_cookie.secure(true);
-            }
+    let result = authentication_use_case
+        .user_complete_mfa_login(mfa_login_model, session_device(&headers, &ip_address))
+        .await;
+    if let Some(username) = &username {
+        track_login_attempt(&login_throttle_use_case, username, &ip_address, &result, |_| true).await;
+    }
 
-            let mut headers = HeaderMap::new();
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, "Login successfully").into_response()
+    match result {
+        Ok(passport) => {
+            let cookie_headers = passport_cookie_headers(&passport, &headers, "act", "rft");
+            (StatusCode::OK, cookie_headers, "Login successfully").into_response()
         }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Err(e) => login_error_response(e),
     }
 }
 
-pub async fn user_refresh_token<T>(
-    State((authentication_use_case, _rate_limit_state)): State<(
-        Arc<AuthenticationUseCase<T>>,
-        Arc<RwLock<HashMap<String, Vec<Instant>>>>,
-    )>,
+pub async fn user_refresh_token<T, TSession, TMfa>(
+    State((authentication_use_case, _login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
     headers: HeaderMap,
     jar: CookieJar,
 ) -> impl IntoResponse
 where
     T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
     if let Some(rft) = jar.get("rft") {
         let refresh_token = rft.value().to_string();
@@ -230,7 +409,29 @@ where
     (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
 }
 
-pub async fn user_logout(headers: HeaderMap) -> impl IntoResponse {
+pub async fn user_logout<T, TSession, TMfa>(
+    State((authentication_use_case, _login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
+{
+    // Revoke the server-side session so the refresh token cannot be used again
+    let revoke_result = match jar.get("rft") {
+        Some(rft) => {
+            authentication_use_case
+                .user_logout(rft.value().to_string())
+                .await
+        }
+        None => Ok(()),
+    };
+    if let Err(e) = revoke_result {
+        tracing::info!("Logout without a valid session: {}", e);
+    }
+
     let cookie_domain = get_cookie_domain(&headers);
     let act_cookie = build_removal_cookie("act", cookie_domain.clone());
     let rft_cookie = build_removal_cookie("rft", cookie_domain);
@@ -248,93 +449,125 @@ pub async fn user_logout(headers: HeaderMap) -> impl IntoResponse {
     (StatusCode::OK, header_map, "Logged out").into_response()
 }
 
-pub async fn admin_login<T>(
-    State((authentication_use_case, rate_limit_state)): State<(
-        Arc<AuthenticationUseCase<T>>,
-        Arc<RwLock<HashMap<String, Vec<Instant>>>>,
-    )>,
-    headers: HeaderMap,
+pub async fn admin_login<T, TSession, TMfa>(
+    State((authentication_use_case, login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    ClientIp(ip_address): ClientIp,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
     T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
+{
+    let username = login_model.username.clone();
+
+    if let Err(e) = login_throttle_use_case
+        .check_login(ThrottleScope::AdminLogin, &ip_address, &username)
+        .await
+    {
+        return throttle_error_response(e);
+    }
+
+    let result = authentication_use_case.admin_login(login_model).await;
+    track_login_attempt(&login_throttle_use_case, &username, &ip_address, &result, |outcome| {
+        matches!(outcome, LoginOutcome::Authenticated(_))
+    })
+    .await;
+
+    // Admins always get an MFA challenge - cookies are only set after /admin/mfa/verify
+    match result {
+        Ok(LoginOutcome::MfaRequired(challenge)) => (StatusCode::ACCEPTED, Json(challenge)).into_response(),
+        Ok(LoginOutcome::Authenticated(_)) => {
+            (StatusCode::INTERNAL_SERVER_ERROR, "Admin login requires MFA").into_response()
+        }
+        Err(e) => login_error_response(e),
+    }
+}
+
+/// Admin without MFA: get the TOTP secret / provisioning URI using the pending token
+/// POST /authentication/admin/mfa/enroll
+pub async fn admin_enroll_mfa<T, TSession, TMfa>(
+    State((authentication_use_case, _login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    Json(mfa_enroll_model): Json<MfaEnrollModel>,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
-    // Simple rate limiting
+    match authentication_use_case
+        .admin_begin_mfa_enrollment(mfa_enroll_model.mfa_token)
+        .await
     {
-        let mut rate_map = rate_limit_state.write().await;
-        let now = Instant::now();
-        let ip = "127.0.0.1".to_string(); // Simple fallback
-
-        let requests = rate_map.entry(ip.clone()).or_insert_with(Vec::new);
-        requests.retain(|&timestamp| now.duration_since(timestamp) < StdDuration::from_secs(60));
-
-        if requests.len() >= 5 {
-            return (
-                StatusCode::TOO_MANY_REQUESTS,
-                "Too many requests. Try again later.",
-            )
-                .into_response();
+        Ok(enrollment) => (StatusCode::OK, Json(enrollment)).into_response(),
+        Err(e) if e.to_string().contains("already enabled") => {
+            (StatusCode::CONFLICT, e.to_string()).into_response()
         }
+        Err(e) => login_error_response(e),
+    }
+}
 
-        requests.push(now);
+/// Second admin login step (also confirms enrollment for admins without MFA)
+/// POST /authentication/admin/mfa/verify
+pub async fn admin_verify_mfa<T, TSession, TMfa>(
+    State((authentication_use_case, login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    ClientIp(ip_address): ClientIp,
+    headers: HeaderMap,
+    Json(mfa_login_model): Json<MfaLoginModel>,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
+{
+    let username = authentication_use_case
+        .pending_mfa_username(SessionScope::Admin, &mfa_login_model.mfa_token)
+        .await
+        .ok();
+    if let Err(e) = check_mfa_attempt(&login_throttle_use_case, &ip_address, username.as_deref()).await {
+        return throttle_error_response(e);
     }
-    match authentication_use_case.admin_login(login_model).await {
-        Ok(passport) => {
-            let cookie_domain = get_cookie_domain(&headers);
-            
-            // Use separate cookie names for admin to avoid conflicts with user cookies
-            let mut act_cookie = Cookie::build(("act_admin", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            let mut rft_cookie = Cookie::build(("rft_admin", passport.refresh_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(14));
-
-            // Set domain if we can determine it from Origin/Referer
-            if let Some(domain) = cookie_domain {
-                // Only set domain for smartpersona.local subdomains
-                if domain.contains("smartpersona.local") {
-                    act_cookie = act_cookie.domain(domain.clone());
-                    rft_cookie = rft_cookie.domain(domain);
-                }
-            }
 
-            if get_stage() == Stage::Production {
-                rft_cookie = rft_cookie.secure(true);
-                act_cookie = act_cookie.secure(true);
-            }
+    let result = authentication_use_case
+        .admin_complete_mfa_login(mfa_login_model, session_device(&headers, &ip_address))
+        .await;
+    if let Some(username) = &username {
+        track_login_attempt(&login_throttle_use_case, username, &ip_address, &result, |_| true).await;
+    }
 
-            let mut headers = HeaderMap::new();
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&act_cookie.to_string()).unwrap(),
-            );
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&rft_cookie.to_string()).unwrap(),
-            );
-
-            (StatusCode::OK, headers, "Admin login successfully").into_response()
+    match result {
+        Ok(result) => {
+            // Use separate cookie names for admin to avoid conflicts with user cookies
+            let cookie_headers =
+                passport_cookie_headers(&result.passport, &headers, "act_admin", "rft_admin");
+
+            match result.recovery_codes {
+                // MFA was just enabled - show the recovery codes once
+                Some(recovery_codes) => (
+                    StatusCode::OK,
+                    cookie_headers,
+                    Json(serde_json::json!({
+                        "message": "Admin login successfully",
+                        "recovery_codes": recovery_codes,
+                    })),
+                )
+                    .into_response(),
+                None => (StatusCode::OK, cookie_headers, "Admin login successfully").into_response(),
+            }
         }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Err(e) => login_error_response(e),
     }
 }
 
-pub async fn admin_refresh_token<T>(
-    State((authentication_use_case, _rate_limit_state)): State<(
-        Arc<AuthenticationUseCase<T>>,
-        Arc<RwLock<HashMap<String, Vec<Instant>>>>,
-    )>,
+pub async fn admin_refresh_token<T, TSession, TMfa>(
+    State((authentication_use_case, _login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
     headers: HeaderMap,
     jar: CookieJar,
 ) -> impl IntoResponse
 where
     T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
 {
     // Use admin-specific cookie name
     if let Some(rft) = jar.get("rft_admin") {
@@ -395,7 +628,29 @@ where
     (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
 }
 
-pub async fn admin_logout(headers: HeaderMap) -> impl IntoResponse {
+pub async fn admin_logout<T, TSession, TMfa>(
+    State((authentication_use_case, _login_throttle_use_case)): State<AuthState<T, TSession, TMfa>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
+    TMfa: UserMfaRepository + Send + Sync,
+{
+    // Revoke the server-side session so the refresh token cannot be used again
+    let revoke_result = match jar.get("rft_admin") {
+        Some(rft) => {
+            authentication_use_case
+                .admin_logout(rft.value().to_string())
+                .await
+        }
+        None => Ok(()),
+    };
+    if let Err(e) = revoke_result {
+        tracing::info!("Logout without a valid session: {}", e);
+    }
+
     let cookie_domain = get_cookie_domain(&headers);
     let act_cookie = build_removal_cookie("act_admin", cookie_domain.clone());
     let rft_cookie = build_removal_cookie("rft_admin", cookie_domain);
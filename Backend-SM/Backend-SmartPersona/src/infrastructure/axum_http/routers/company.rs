@@ -16,7 +16,7 @@ use crate::{
         usecase::company::CompanyUseCase,
     },
     infrastructure::{
-        axum_http::middleware::user_authorization,
+        axum_http::{middleware::user_authorization, role_guard::RequireCompany},
         email::EmailService,
         postgres::{postgres_connection::DbPool, repositories::company::CompanyPostgres},
     },
@@ -104,7 +104,7 @@ where
 /// POST /api/user/company
 pub async fn create_company<T>(
     State((company_use_case, email_service)): State<(Arc<CompanyUseCase<T>>, Arc<EmailService>)>,
-    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    RequireCompany(user_id): RequireCompany,
     Json(company_request): Json<CompanyRequest>,
 ) -> impl IntoResponse
 where
@@ -149,7 +149,7 @@ where
 /// ⭐ แนะนำให้ Frontend ใช้ endpoint นี้ - สะดวกที่สุด!
 pub async fn upsert_company<T>(
     State((company_use_case, email_service)): State<(Arc<CompanyUseCase<T>>, Arc<EmailService>)>,
-    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    RequireCompany(user_id): RequireCompany,
     Json(company_request): Json<CompanyRequest>,
 ) -> impl IntoResponse
 where
@@ -209,7 +209,7 @@ where
 /// PATCH /api/user/company
 pub async fn update_company<T>(
     State((company_use_case, _)): State<(Arc<CompanyUseCase<T>>, Arc<EmailService>)>,
-    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    RequireCompany(user_id): RequireCompany,
     Json(company_request): Json<CompanyRequest>,
 ) -> impl IntoResponse
 where
@@ -238,7 +238,7 @@ where
 /// DELETE /api/user/company
 pub async fn delete_company<T>(
     State((company_use_case, _)): State<(Arc<CompanyUseCase<T>>, Arc<EmailService>)>,
-    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    RequireCompany(user_id): RequireCompany,
 ) -> impl IntoResponse
 where
     T: CompanyRepository + Send + Sync + 'static,
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    domain::{
+        entities::company_api_key::CreateApiKeyRequest,
+        repo::company_api_key::CompanyApiKeyRepository,
+        usecase::company_api_key::CompanyApiKeyUseCase,
+    },
+    infrastructure::{
+        axum_http::{middleware::user_authorization, role_guard::{NotImpersonating, RequireCompany}},
+        postgres::{postgres_connection::DbPool, repositories::company_api_key::CompanyApiKeyPostgres},
+    },
+};
+
+pub type CompanyApiKeyUseCasePostgres = CompanyApiKeyUseCase<CompanyApiKeyPostgres>;
+
+/// Shared with `user_authorization`, installed as `Extension<Arc<CompanyApiKeyUseCasePostgres>>`
+pub fn build_use_case(db_pool: Arc<DbPool>) -> Arc<CompanyApiKeyUseCasePostgres> {
+    Arc::new(CompanyApiKeyUseCase::new(Arc::new(CompanyApiKeyPostgres::new(
+        db_pool,
+    ))))
+}
+
+/// Protected routes (company owner) - manage API keys for ATS integrations
+pub fn routes(db_pool: Arc<DbPool>) -> Router {
+    let company_api_key_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/company/api-keys", get(list_api_keys).post(create_api_key))
+        .route("/company/api-keys/:id", delete(revoke_api_key))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(company_api_key_use_case)
+}
+
+/// Map use case errors to HTTP status codes
+fn api_key_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Invalid scope") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Too many active API keys") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// List API keys of the caller's company (secrets are never returned)
+/// GET /api/user/company/api-keys
+pub async fn list_api_keys<T>(
+    State(company_api_key_use_case): State<Arc<CompanyApiKeyUseCase<T>>>,
+    RequireCompany(user_id): RequireCompany,
+) -> impl IntoResponse
+where
+    T: CompanyApiKeyRepository + Send + Sync + 'static,
+{
+    match company_api_key_use_case.list_keys(user_id).await {
+        Ok(keys) => (StatusCode::OK, Json(keys)).into_response(),
+        Err(e) => api_key_error_response(e),
+    }
+}
+
+/// Create a scoped API key - the key itself is only shown in this response
+/// POST /api/user/company/api-keys
+pub async fn create_api_key<T>(
+    State(company_api_key_use_case): State<Arc<CompanyApiKeyUseCase<T>>>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> impl IntoResponse
+where
+    T: CompanyApiKeyRepository + Send + Sync + 'static,
+{
+    if let Err(errors) = request.validate() {
+        return (StatusCode::BAD_REQUEST, format!("Validation error: {:?}", errors))
+            .into_response();
+    }
+
+    match company_api_key_use_case.create_key(user_id, request).await {
+        Ok(created) => (StatusCode::CREATED, Json(created)).into_response(),
+        Err(e) => api_key_error_response(e),
+    }
+}
+
+/// Revoke an API key - takes effect on the next request
+/// DELETE /api/user/company/api-keys/:id
+pub async fn revoke_api_key<T>(
+    State(company_api_key_use_case): State<Arc<CompanyApiKeyUseCase<T>>>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: CompanyApiKeyRepository + Send + Sync + 'static,
+{
+    match company_api_key_use_case.revoke_key(user_id, id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => api_key_error_response(e),
+    }
+}
@@ -1,6 +1,7 @@
 use axum::{
     Json, Router,
     extract::{Path, State},
+    http::StatusCode,
     middleware,
     routing::{delete, get, post},
 };
@@ -11,19 +12,33 @@ use crate::{
     domain::{
         entities::company_gallery::{CompanyGalleryEntity, CreateGalleryRequest},
         repo::company_gallery::CompanyGalleryRepository,
-        usecase::company_gallery::CompanyGalleryUsecase,
+        usecase::{
+            company_gallery::CompanyGalleryUsecase, company_ownership::CompanyOwnershipPolicy,
+        },
     },
     infrastructure::{
-        axum_http::middleware::user_authorization,
+        axum_http::{
+            middleware::user_authorization,
+            role_guard::{RequireCompany, ownership_error},
+        },
         postgres::{
-            postgres_connection::DbPool, repositories::company_gallery::CompanyGalleryPostgres,
+            postgres_connection::DbPool,
+            repositories::{
+                company_gallery::CompanyGalleryPostgres,
+                company_ownership::CompanyOwnershipPostgres,
+            },
         },
     },
 };
 
+type GalleryState = (Arc<CompanyGalleryUsecase>, Arc<CompanyOwnershipPolicy>);
+
 pub fn routes(db_pool: Arc<DbPool>) -> Router {
-    let repo = CompanyGalleryPostgres::new(db_pool);
+    let repo = CompanyGalleryPostgres::new(Arc::clone(&db_pool));
     let usecase = Arc::new(CompanyGalleryUsecase::new(Arc::new(repo)));
+    let ownership_policy = Arc::new(CompanyOwnershipPolicy::new(Arc::new(
+        CompanyOwnershipPostgres::new(db_pool),
+    )));
 
     Router::new()
         .route(
@@ -32,22 +47,28 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
         )
         .route("/galleries/:id", get(get_gallery).delete(delete_gallery))
         .layer(middleware::from_fn(user_authorization))
-        .with_state(usecase)
+        .with_state((usecase, ownership_policy))
 }
 
 async fn create_gallery(
-    State(usecase): State<Arc<CompanyGalleryUsecase>>,
+    State((usecase, ownership_policy)): State<GalleryState>,
+    RequireCompany(user_id): RequireCompany,
     Path(company_id): Path<Uuid>,
     Json(req): Json<CreateGalleryRequest>,
-) -> Result<Json<CompanyGalleryEntity>, String> {
+) -> Result<Json<CompanyGalleryEntity>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_company_owner(user_id, company_id)
+        .await
+        .map_err(ownership_error)?;
+
     match usecase.create_gallery(company_id, req).await {
         Ok(gallery) => Ok(Json(gallery)),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
     }
 }
 
 async fn get_galleries(
-    State(usecase): State<Arc<CompanyGalleryUsecase>>,
+    State((usecase, _)): State<GalleryState>,
     Path(company_id): Path<Uuid>,
 ) -> Result<Json<Vec<CompanyGalleryEntity>>, String> {
     match usecase.get_galleries(company_id).await {
@@ -57,7 +78,7 @@ async fn get_galleries(
 }
 
 async fn get_gallery(
-    State(usecase): State<Arc<CompanyGalleryUsecase>>,
+    State((usecase, _)): State<GalleryState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CompanyGalleryEntity>, String> {
     match usecase.get_gallery(id).await {
@@ -68,11 +89,17 @@ async fn get_gallery(
 }
 
 async fn delete_gallery(
-    State(usecase): State<Arc<CompanyGalleryUsecase>>,
+    State((usecase, ownership_policy)): State<GalleryState>,
+    RequireCompany(user_id): RequireCompany,
     Path(id): Path<Uuid>,
-) -> Result<Json<()>, String> {
+) -> Result<Json<()>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_gallery_owner(user_id, id)
+        .await
+        .map_err(ownership_error)?;
+
     match usecase.delete_gallery(id).await {
         Ok(_) => Ok(Json(())),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
     }
 }
@@ -1,7 +1,9 @@
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::StatusCode,
     middleware,
+    response::{IntoResponse, Response},
     routing::{delete, get, post, put},
 };
 use std::sync::Arc;
@@ -9,27 +11,64 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::company_post::{CompanyPostEntity, CreatePostRequest, UpdatePostRequest},
+        entities::company_post::{
+            CandidateScreeningQuestion, CompanyPostEntity, CreatePostRequest, JobSearchPage,
+            JobSearchQuery, PostStatus, ScreeningQuestion, SetScreeningQuestionsRequest,
+            UpdatePostRequest,
+        },
         repo::company_post::CompanyPostRepository,
-        usecase::company_post::CompanyPostUsecase,
+        usecase::{company_ownership::CompanyOwnershipPolicy, company_post::CompanyPostUsecase},
     },
     infrastructure::{
-        axum_http::middleware::user_authorization,
-        postgres::{postgres_connection::DbPool, repositories::company_post::CompanyPostPostgres},
+        axum_http::{
+            middleware::user_authorization,
+            role_guard::{RequireCompany, ownership_error},
+            routers::user::AuthenticatedUserId,
+        },
+        email::EmailService,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{
+                company_ownership::CompanyOwnershipPostgres, company_post::CompanyPostPostgres,
+                user_address::UserAddressPostgres,
+            },
+        },
     },
 };
 
+type PostState = (Arc<CompanyPostUsecase>, Arc<CompanyOwnershipPolicy>);
+
+/// จำนวนประกาศที่ index ต่อรอบของ run_maintenance
+const SEARCH_INDEX_BATCH_SIZE: i64 = 200;
+
+/// Email service is optional - posts still close without notifying candidates when it is not configured
+fn email_service() -> Arc<EmailService> {
+    Arc::new(EmailService::new().unwrap_or_else(|e| {
+        tracing::warn!("Email service not configured: {}. Using dummy service.", e);
+        EmailService::dummy()
+    }))
+}
+
 pub fn routes(db_pool: Arc<DbPool>) -> Router {
-    let repo = CompanyPostPostgres::new(db_pool);
-    let usecase = Arc::new(CompanyPostUsecase::new(Arc::new(repo)));
+    let repo = CompanyPostPostgres::new(Arc::clone(&db_pool));
+    let usecase = Arc::new(
+        CompanyPostUsecase::new(Arc::new(repo))
+            .with_user_addresses(Arc::new(UserAddressPostgres::new(Arc::clone(&db_pool))))
+            .with_email_service(email_service()),
+    );
+    let ownership_policy = Arc::new(CompanyOwnershipPolicy::new(Arc::new(
+        CompanyOwnershipPostgres::new(db_pool),
+    )));
 
     // Public routes - no auth required
     let public_routes = Router::new()
         .route("/posts", get(get_all_posts))
+        .route("/posts/search", get(search_posts))
         .with_state(usecase.clone());
 
     // Protected routes - auth required
     let protected_routes = Router::new()
+        .route("/posts/nearby", get(search_posts_nearby))
         .route(
             "/companies/:company_id/posts",
             post(create_post).get(get_posts),
@@ -38,66 +77,155 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
             "/posts/:id",
             get(get_post).put(update_post).delete(delete_post),
         )
+        .route(
+            "/posts/:id/screening-questions",
+            get(get_screening_questions).put(set_screening_questions),
+        )
         .layer(middleware::from_fn(user_authorization))
-        .with_state(usecase);
+        .with_state((usecase, ownership_policy));
 
     // Merge both routers
     public_routes.merge(protected_routes)
 }
 
+/// Build search vectors for new posts, posts indexed before an edit, and rows that predate search
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let usecase = CompanyPostUsecase::new(Arc::new(CompanyPostPostgres::new(db_pool)));
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        loop {
+            match usecase.index_pending_posts(SEARCH_INDEX_BATCH_SIZE).await {
+                Ok(0) => break,
+                Ok(count) => tracing::info!("Indexed {} job post(s) for search", count),
+                Err(e) => {
+                    tracing::error!("Job post search indexing failed: {}", e);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Publish scheduled posts and expire posts whose closing time has passed
+pub async fn run_scheduler(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let usecase = CompanyPostUsecase::new(Arc::new(CompanyPostPostgres::new(db_pool)))
+        .with_email_service(email_service());
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        match usecase.process_due_posts().await {
+            Ok((0, 0)) => {}
+            Ok((published, expired)) => tracing::info!(
+                "Published {} scheduled job post(s), expired {} job post(s)",
+                published,
+                expired
+            ),
+            Err(e) => tracing::error!("Job post scheduler failed: {}", e),
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn post_error(e: anyhow::Error) -> (StatusCode, String) {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message)
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message)
+    } else if message.contains("Conflict") {
+        (StatusCode::CONFLICT, message)
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+}
+
+/// Create a post - status draft / scheduled / published (default), publish_at / closes_at optional
+/// POST /api/user/companies/:company_id/posts
 async fn create_post(
-    State(usecase): State<Arc<CompanyPostUsecase>>,
+    State((usecase, ownership_policy)): State<PostState>,
+    RequireCompany(user_id): RequireCompany,
     Path(company_id): Path<Uuid>,
     Json(req): Json<CreatePostRequest>,
-) -> Result<Json<CompanyPostEntity>, String> {
-    match usecase.create_post(company_id, req).await {
-        Ok(post) => Ok(Json(post)),
-        Err(e) => Err(e.to_string()),
-    }
+) -> Result<Json<CompanyPostEntity>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_company_owner(user_id, company_id)
+        .await
+        .map_err(ownership_error)?;
+
+    usecase.create_post(company_id, req).await.map(Json).map_err(post_error)
 }
 
+/// Posts of a company - drafts and scheduled posts are only listed for the owning company
+/// GET /api/user/companies/:company_id/posts
 async fn get_posts(
-    State(usecase): State<Arc<CompanyPostUsecase>>,
+    State((usecase, ownership_policy)): State<PostState>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
     Path(company_id): Path<Uuid>,
 ) -> Result<Json<Vec<CompanyPostEntity>>, String> {
-    match usecase.get_posts(company_id).await {
+    let is_owner = ownership_policy.ensure_company_owner(user_id, company_id).await.is_ok();
+
+    match usecase.get_posts(company_id, is_owner).await {
         Ok(posts) => Ok(Json(posts)),
         Err(e) => Err(e.to_string()),
     }
 }
 
+/// A post - drafts and scheduled posts are only visible to the owning company
+/// GET /api/user/posts/:id
 async fn get_post(
-    State(usecase): State<Arc<CompanyPostUsecase>>,
+    State((usecase, ownership_policy)): State<PostState>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CompanyPostEntity>, String> {
     match usecase.get_post(id).await {
-        Ok(Some(post)) => Ok(Json(post)),
-        Ok(None) => Err("Post not found".to_string()),
+        Ok(Some(post))
+            if PostStatus::parse(&post.status).is_some_and(|status| status.is_public())
+                || ownership_policy.ensure_post_owner(user_id, id).await.is_ok() =>
+        {
+            Ok(Json(post))
+        }
+        Ok(_) => Err("Post not found".to_string()),
         Err(e) => Err(e.to_string()),
     }
 }
 
+/// Edit a post - status changes follow the lifecycle, closing a published post notifies open applicants
+/// PUT /api/user/posts/:id
 async fn update_post(
-    State(usecase): State<Arc<CompanyPostUsecase>>,
+    State((usecase, ownership_policy)): State<PostState>,
+    RequireCompany(user_id): RequireCompany,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdatePostRequest>,
-) -> Result<Json<CompanyPostEntity>, String> {
-    match usecase.update_post(id, req).await {
-        Ok(post) => Ok(Json(post)),
-        Err(e) => Err(e.to_string()),
-    }
+) -> Result<Json<CompanyPostEntity>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_post_owner(user_id, id)
+        .await
+        .map_err(ownership_error)?;
+
+    usecase.update_post(id, req).await.map(Json).map_err(post_error)
 }
 
 async fn delete_post(
-    State(usecase): State<Arc<CompanyPostUsecase>>,
+    State((usecase, ownership_policy)): State<PostState>,
+    RequireCompany(user_id): RequireCompany,
     Path(id): Path<Uuid>,
-) -> Result<Json<()>, String> {
+) -> Result<Json<()>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_post_owner(user_id, id)
+        .await
+        .map_err(ownership_error)?;
+
     match usecase.delete_post(id).await {
         Ok(_) => Ok(Json(())),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
     }
 }
 
+/// Published posts (Public endpoint)
+/// GET /api/user/posts
 async fn get_all_posts(
     State(usecase): State<Arc<CompanyPostUsecase>>,
 ) -> Result<Json<Vec<CompanyPostEntity>>, String> {
@@ -106,3 +234,80 @@ async fn get_all_posts(
         Err(e) => Err(e.to_string()),
     }
 }
+
+/// Full-text search over open job posts with filters and cursor pagination
+/// GET /api/user/posts/search?q=&job_type=&location=&salary_min=&salary_max=&salary_currency=THB&salary_period=monthly&tags=&company_id=&posted_after=&posted_before=&lat=&lng=&radius_km=25&sort=relevance|recent|distance|salary&limit=20&cursor=
+async fn search_posts(
+    State(usecase): State<Arc<CompanyPostUsecase>>,
+    Query(query): Query<JobSearchQuery>,
+) -> Result<Json<JobSearchPage>, (StatusCode, String)> {
+    match usecase.search_posts(query).await {
+        Ok(page) => Ok(Json(page)),
+        Err(e) if e.to_string().contains("Validation error") => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Jobs near the caller - same parameters as /posts/search, lat/lng default to the caller's address
+/// GET /api/user/posts/nearby?radius_km=25&sort=distance
+async fn search_posts_nearby(
+    State((usecase, _)): State<PostState>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Query(query): Query<JobSearchQuery>,
+) -> Result<Json<JobSearchPage>, (StatusCode, String)> {
+    match usecase.search_posts_near_user(user_id, query).await {
+        Ok(page) => Ok(Json(page)),
+        Err(e) if e.to_string().contains("Validation error") => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Screening questions of a post - the owning company also sees the knockout answers
+/// GET /api/user/posts/:id/screening-questions
+async fn get_screening_questions(
+    State((usecase, ownership_policy)): State<PostState>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> Result<Response, (StatusCode, String)> {
+    let questions = match usecase.get_screening_questions(id).await {
+        Ok(questions) => questions,
+        Err(e) if e.to_string().contains("not found") => {
+            return Err((StatusCode::NOT_FOUND, e.to_string()));
+        }
+        Err(e) => return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    };
+
+    if ownership_policy.ensure_post_owner(user_id, id).await.is_ok() {
+        return Ok(Json(questions).into_response());
+    }
+
+    let questions: Vec<CandidateScreeningQuestion> =
+        questions.into_iter().map(CandidateScreeningQuestion::from).collect();
+    Ok(Json(questions).into_response())
+}
+
+/// Replace the screening questions of a post (owning company only)
+/// PUT /api/user/posts/:id/screening-questions
+async fn set_screening_questions(
+    State((usecase, ownership_policy)): State<PostState>,
+    RequireCompany(user_id): RequireCompany,
+    Path(id): Path<Uuid>,
+    Json(req): Json<SetScreeningQuestionsRequest>,
+) -> Result<Json<Vec<ScreeningQuestion>>, (StatusCode, String)> {
+    ownership_policy
+        .ensure_post_owner(user_id, id)
+        .await
+        .map_err(ownership_error)?;
+
+    match usecase.set_screening_questions(id, req).await {
+        Ok(questions) => Ok(Json(questions)),
+        Err(e) if e.to_string().contains("Validation error") => {
+            Err((StatusCode::BAD_REQUEST, e.to_string()))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::data_export::DataExportResponse, repo::data_export::DataExportRepository,
+        usecase::data_export::DataExportUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::user_authorization, role_guard::NotImpersonating,
+            routers::user::AuthenticatedUserId,
+        },
+        postgres::{postgres_connection::DbPool, repositories::data_export::DataExportPostgres},
+    },
+};
+
+type DataExportUseCasePostgres = DataExportUseCase<DataExportPostgres>;
+
+fn build_use_case(db_pool: Arc<DbPool>) -> Arc<DataExportUseCasePostgres> {
+    Arc::new(DataExportUseCase::new(Arc::new(DataExportPostgres::new(
+        db_pool,
+    ))))
+}
+
+/// Protected routes (require authentication) - request and poll personal data exports
+pub fn routes(db_pool: Arc<DbPool>) -> Router {
+    let data_export_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/data-exports", get(list_exports).post(request_export))
+        .route("/data-exports/:id", get(get_export))
+        .route("/data-exports/:id/download-link", post(issue_download_link))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(data_export_use_case)
+}
+
+/// Public routes - the unguessable token in the link is the credential
+pub fn public_routes(db_pool: Arc<DbPool>) -> Router {
+    let data_export_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/data-exports/download/:token", get(download_export))
+        .with_state(data_export_use_case)
+}
+
+/// Periodically pick up pending exports (e.g. after a restart) and purge expired archives
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let data_export_use_case = build_use_case(db_pool);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        match data_export_use_case.expire_old_exports().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Expired {} data export archive(s)", count),
+            Err(e) => tracing::error!("Data export cleanup failed: {}", e),
+        }
+        match data_export_use_case.process_pending().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("Processed {} pending data export(s)", count),
+            Err(e) => tracing::error!("Data export worker failed: {}", e),
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn data_export_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("already in progress") || message.contains("Conflict") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else if message.contains("Too many export requests") {
+        (StatusCode::TOO_MANY_REQUESTS, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Request a copy of all personal data (PDPA / GDPR) - built in the background
+/// POST /api/user/data-exports
+pub async fn request_export<T>(
+    State(data_export_use_case): State<Arc<DataExportUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: DataExportRepository + Send + Sync + 'static,
+{
+    match data_export_use_case.request_export(user_id).await {
+        Ok(export) => {
+            tokio::spawn({
+                let data_export_use_case = Arc::clone(&data_export_use_case);
+                let export_id = export.id;
+
+                async move {
+                    if let Err(e) = data_export_use_case.process(export_id).await {
+                        tracing::error!("Data export {} could not be processed: {}", export_id, e);
+                    }
+                }
+            });
+
+            (StatusCode::ACCEPTED, Json(DataExportResponse::from(export))).into_response()
+        }
+        Err(e) => data_export_error_response(e),
+    }
+}
+
+/// List the caller's export requests (newest first)
+/// GET /api/user/data-exports
+pub async fn list_exports<T>(
+    State(data_export_use_case): State<Arc<DataExportUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: DataExportRepository + Send + Sync + 'static,
+{
+    match data_export_use_case.list_exports(user_id).await {
+        Ok(exports) => {
+            let exports: Vec<DataExportResponse> =
+                exports.into_iter().map(DataExportResponse::from).collect();
+            (StatusCode::OK, Json(exports)).into_response()
+        }
+        Err(e) => data_export_error_response(e),
+    }
+}
+
+/// Poll the status of one export - `downloadable` turns true once the archive is ready
+/// GET /api/user/data-exports/:id
+pub async fn get_export<T>(
+    State(data_export_use_case): State<Arc<DataExportUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: DataExportRepository + Send + Sync + 'static,
+{
+    match data_export_use_case.get_export(user_id, id).await {
+        Ok(export) => (StatusCode::OK, Json(DataExportResponse::from(export))).into_response(),
+        Err(e) => data_export_error_response(e),
+    }
+}
+
+/// Issue a fresh download link for a ready export (replaces the previous link)
+/// POST /api/user/data-exports/:id/download-link
+pub async fn issue_download_link<T>(
+    State(data_export_use_case): State<Arc<DataExportUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: DataExportRepository + Send + Sync + 'static,
+{
+    match data_export_use_case.issue_download_link(user_id, id).await {
+        Ok(link) => (StatusCode::OK, Json(link)).into_response(),
+        Err(e) => data_export_error_response(e),
+    }
+}
+
+/// Download the ZIP archive (Public endpoint - link expires)
+/// GET /api/data-exports/download/:token
+pub async fn download_export<T>(
+    State(data_export_use_case): State<Arc<DataExportUseCase<T>>>,
+    Path(token): Path<String>,
+) -> impl IntoResponse
+where
+    T: DataExportRepository + Send + Sync + 'static,
+{
+    match data_export_use_case.download(&token).await {
+        Ok(download) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", download.file_name),
+                ),
+                (header::CACHE_CONTROL, "no-store".to_string()),
+            ],
+            download.archive,
+        )
+            .into_response(),
+        Err(e) if e.to_string().contains("not found or expired") => {
+            (StatusCode::GONE, e.to_string()).into_response()
+        }
+        Err(e) => data_export_error_response(e),
+    }
+}
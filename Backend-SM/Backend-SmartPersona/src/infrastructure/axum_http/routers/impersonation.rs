@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+            impersonation::StartImpersonationRequest,
+        },
+        repo::user::UserRepository,
+        usecase::impersonation::ImpersonationUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::admin_authorization, role_guard::RequireAdmin,
+            routers::audit_log::AdminAudit,
+        },
+        jwt_authentication::jwt_keys::JwtKeySet,
+        postgres::{postgres_connection::DbPool, repositories::user::UserPostgres},
+    },
+};
+
+/// Admin-only routes for "view as user" (mounted under /admin)
+pub fn admin_routes(db_pool: Arc<DbPool>, jwt_keys: Arc<JwtKeySet>) -> Router {
+    let impersonation_use_case = Arc::new(ImpersonationUseCase::new(
+        Arc::new(UserPostgres::new(db_pool)),
+        jwt_keys,
+    ));
+
+    Router::new()
+        .route("/impersonation/:user_id", post(start_impersonation))
+        .with_state(impersonation_use_case)
+        .layer(middleware::from_fn(admin_authorization))
+}
+
+/// Map use case errors to HTTP status codes
+fn impersonation_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Forbidden") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Mint a short-lived, read-only by default access token for a persona / company user
+/// POST /admin/impersonation/:user_id
+pub async fn start_impersonation<T>(
+    State(impersonation_use_case): State<Arc<ImpersonationUseCase<T>>>,
+    RequireAdmin(admin_id): RequireAdmin,
+    audit: AdminAudit,
+    Path(user_id): Path<Uuid>,
+    Json(request): Json<StartImpersonationRequest>,
+) -> impl IntoResponse
+where
+    T: UserRepository + Send + Sync + 'static,
+{
+    let reason = request.reason.clone();
+
+    match impersonation_use_case
+        .start(admin_id, user_id, request)
+        .await
+    {
+        Ok(token) => {
+            if let Err(response) = audit
+                .record(
+                    NewAuditEvent::new(AuditAction::ImpersonationStart, AuditTarget::User, user_id)
+                        .reason(Some(reason))
+                        .after(serde_json::json!({
+                            "jti": token.jti,
+                            "expires_at": token.expires_at,
+                            "read_only": token.read_only,
+                        })),
+                )
+                .await
+            {
+                return response;
+            }
+
+            tracing::info!(
+                "Admin {} started impersonating user {} (read_only={})",
+                admin_id,
+                user_id,
+                token.read_only
+            );
+            (StatusCode::CREATED, Json(token)).into_response()
+        }
+        Err(e) => impersonation_error_response(e),
+    }
+}
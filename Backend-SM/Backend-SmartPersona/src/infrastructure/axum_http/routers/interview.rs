@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::interview::{
+            CancelInterviewRequest, ConfirmInterviewRequest, ProposeInterviewRequest,
+        },
+        repo::interview::InterviewRepository,
+        usecase::interview::InterviewUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::user_authorization,
+            role_guard::{NotImpersonating, RequireCompany, RequirePersona},
+            routers::user::AuthenticatedUserId,
+        },
+        email::EmailService,
+        postgres::{postgres_connection::DbPool, repositories::interview::InterviewPostgres},
+    },
+};
+
+type InterviewUseCasePostgres = InterviewUseCase<InterviewPostgres>;
+
+fn build_use_case(db_pool: Arc<DbPool>, backend_url: String) -> Arc<InterviewUseCasePostgres> {
+    // Email service is optional - interviews still work in-app when it is not configured
+    let email_service = EmailService::new().unwrap_or_else(|e| {
+        tracing::warn!("Email service not configured: {}. Using dummy service.", e);
+        EmailService::dummy()
+    });
+
+    Arc::new(InterviewUseCase::new(
+        Arc::new(InterviewPostgres::new(db_pool)),
+        Arc::new(email_service),
+        backend_url,
+    ))
+}
+
+/// Protected routes (require authentication) - interview scheduling and calendar feed URL
+pub fn routes(db_pool: Arc<DbPool>, backend_url: String) -> Router {
+    let interview_use_case = build_use_case(db_pool, backend_url);
+
+    Router::new()
+        .route(
+            "/applications/:application_id/interviews",
+            get(list_interviews).post(propose_interview),
+        )
+        .route("/interviews/:interview_id/confirm", post(confirm_interview))
+        .route("/interviews/:interview_id/reschedule", post(reschedule_interview))
+        .route("/interviews/:interview_id/cancel", post(cancel_interview))
+        .route("/calendar/feed", get(get_calendar_feed))
+        .route("/calendar/feed/rotate", post(rotate_calendar_feed))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(interview_use_case)
+}
+
+/// Public routes - calendar apps cannot send an Authorization header, the token in the URL is the credential
+pub fn public_routes(db_pool: Arc<DbPool>, backend_url: String) -> Router {
+    let interview_use_case = build_use_case(db_pool, backend_url);
+
+    Router::new()
+        .route("/calendar/:feed_file", get(calendar_feed))
+        .with_state(interview_use_case)
+}
+
+/// Map use case errors to HTTP status codes
+fn interview_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Forbidden") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Conflict") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Propose interview slots for an application in the interview stage (company only)
+/// POST /api/user/applications/:application_id/interviews
+pub async fn propose_interview<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    RequireCompany(user_id): RequireCompany,
+    Path(application_id): Path<Uuid>,
+    Json(request): Json<ProposeInterviewRequest>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.propose(user_id, application_id, request).await {
+        Ok(interview) => (StatusCode::CREATED, Json(interview)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// List interviews of an application (candidate or hiring company)
+/// GET /api/user/applications/:application_id/interviews
+pub async fn list_interviews<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(application_id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.list_for_application(user_id, application_id).await {
+        Ok(interviews) => (StatusCode::OK, Json(interviews)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Pick one of the proposed slots (candidate only) - sends .ics invitations to both sides
+/// POST /api/user/interviews/:interview_id/confirm
+pub async fn confirm_interview<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    RequirePersona(user_id): RequirePersona,
+    Path(interview_id): Path<Uuid>,
+    Json(request): Json<ConfirmInterviewRequest>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.confirm(user_id, interview_id, request).await {
+        Ok(interview) => (StatusCode::OK, Json(interview)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Replace the proposed slots (company only) - the candidate picks again
+/// POST /api/user/interviews/:interview_id/reschedule
+pub async fn reschedule_interview<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    RequireCompany(user_id): RequireCompany,
+    Path(interview_id): Path<Uuid>,
+    Json(request): Json<ProposeInterviewRequest>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.reschedule(user_id, interview_id, request).await {
+        Ok(interview) => (StatusCode::OK, Json(interview)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Cancel an interview (candidate or hiring company)
+/// POST /api/user/interviews/:interview_id/cancel
+pub async fn cancel_interview<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(interview_id): Path<Uuid>,
+    Json(request): Json<CancelInterviewRequest>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.cancel(user_id, interview_id, request).await {
+        Ok(interview) => (StatusCode::OK, Json(interview)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Get the caller's ICS feed (created on first request - the URL is only returned then or on rotate)
+/// GET /api/user/calendar/feed
+pub async fn get_calendar_feed<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.feed_url(user_id).await {
+        Ok(feed) => (StatusCode::OK, Json(feed)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Issue a new feed URL - the previous URL stops working
+/// POST /api/user/calendar/feed/rotate
+pub async fn rotate_calendar_feed<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    match interview_use_case.rotate_feed(user_id).await {
+        Ok(feed) => (StatusCode::OK, Json(feed)).into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
+
+/// Subscribed calendar of the token owner's interviews (Public endpoint)
+/// GET /api/calendar/:token.ics
+pub async fn calendar_feed<T>(
+    State(interview_use_case): State<Arc<InterviewUseCase<T>>>,
+    Path(feed_file): Path<String>,
+) -> impl IntoResponse
+where
+    T: InterviewRepository + Send + Sync + 'static,
+{
+    let feed_token = feed_file.strip_suffix(".ics").unwrap_or(&feed_file);
+
+    match interview_use_case.feed_calendar(feed_token).await {
+        Ok(ics) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CONTENT_DISPOSITION, "inline; filename=\"interviews.ics\""),
+                (header::CACHE_CONTROL, "no-store"),
+            ],
+            ics,
+        )
+            .into_response(),
+        Err(e) => interview_error_response(e),
+    }
+}
@@ -1,36 +1,57 @@
 use axum::{
-    extract::{Path, State, Extension},
+    extract::{Path, Query, State, Extension},
     routing::{get, post},
     http::StatusCode,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     middleware,
     Json, Router,
 };
 use std::sync::Arc;
 use uuid::Uuid;
+use validator::Validate;
 use tracing::{info, error};
-use crate::domain::entities::job_application::{NewJobApplication, JobApplicationWithUser};
+use crate::domain::entities::job_application::{
+    ApplicationActorRole, ApplicationPipeline, ApplicationStatus, ApplyForJobRequest,
+    CandidateListQuery, UpdateApplicationStatusRequest, WithdrawApplicationRequest,
+};
 use crate::domain::repo::job_application::JobApplicationRepository;
+use crate::domain::usecase::job_application::JobApplicationUseCase;
 use crate::infrastructure::jwt_authentication::jwt_model::Claims;
 use crate::infrastructure::axum_http::middleware::user_authorization;
+use crate::infrastructure::axum_http::role_guard::{RequireCompany, RequirePersona, ownership_error};
+use crate::infrastructure::axum_http::routers::user::AuthenticatedUserId;
+use crate::domain::usecase::company_ownership::CompanyOwnershipPolicy;
+use crate::infrastructure::postgres::repositories::company_ownership::CompanyOwnershipPostgres;
 use crate::infrastructure::email::service::EmailService;
 
 // State struct to hold both repository and email service
 pub struct JobApplicationState {
     pub repo: Arc<dyn JobApplicationRepository>,
+    pub use_case: Arc<JobApplicationUseCase>,
     pub email_service: Arc<EmailService>,
     pub db_pool: Arc<crate::infrastructure::postgres::postgres_connection::DbPool>,
+    pub ownership_policy: Arc<CompanyOwnershipPolicy>,
 }
 
 pub fn router(
     repo: Arc<dyn JobApplicationRepository>,
     email_service: Arc<EmailService>,
     db_pool: Arc<crate::infrastructure::postgres::postgres_connection::DbPool>,
+    pipeline: ApplicationPipeline,
+    resume_url_prefix: String,
 ) -> Router {
+    let ownership_policy = Arc::new(CompanyOwnershipPolicy::new(Arc::new(
+        CompanyOwnershipPostgres::new(Arc::clone(&db_pool)),
+    )));
+
+    let use_case = Arc::new(JobApplicationUseCase::new(Arc::clone(&repo), pipeline, resume_url_prefix));
+
     let state = Arc::new(JobApplicationState {
         repo,
+        use_case,
         email_service,
         db_pool,
+        ownership_policy,
     });
 
     Router::new()
@@ -38,46 +59,28 @@ pub fn router(
         .route("/candidates/:job_id", get(get_job_candidates))
         .route("/my-applications", get(get_my_applications))
         .route("/applications/:application_id/status", axum::routing::patch(update_application_status))
+        .route("/applications/:application_id/history", get(get_application_history))
+        .route("/applications/:application_id/withdraw", post(withdraw_application))
         .layer(middleware::from_fn(user_authorization))
         .with_state(state)
 }
 
-#[derive(serde::Deserialize)]
-struct ApplyRequest {
-    job_id: Uuid,
-}
-
+/// Apply for a job with an optional cover letter, resume and answers to the post's screening questions
+/// POST /apply
 async fn apply_for_job(
     State(state): State<Arc<JobApplicationState>>,
-    Extension(claims): Extension<Claims>,
-    Json(payload): Json<ApplyRequest>,
+    RequirePersona(user_id): RequirePersona,
+    Json(payload): Json<ApplyForJobRequest>,
 ) -> impl IntoResponse {
-    let user_id = match Uuid::parse_str(&claims.sub) {
-        Ok(id) => id,
-        Err(_) => return (StatusCode::BAD_REQUEST, "Invalid user ID").into_response(),
-    };
-
-    // Check if already applied
-    match state.repo.check_existing(user_id, payload.job_id).await {
-        Ok(true) => return (StatusCode::CONFLICT, "You have already applied for this job").into_response(),
-        Ok(false) => {},
-        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
-    }
-
-    let new_application = NewJobApplication {
-        job_id: payload.job_id,
-        user_id,
-        status: "pending".to_string(),
-    };
+    let job_id = payload.job_id;
 
     // Create application
-    match state.repo.create(new_application).await {
-        Ok(_) => {
+    match state.use_case.apply(user_id, payload).await {
+        Ok(application) => {
             // Send email notification to company
             tokio::spawn({
                 let email_service = Arc::clone(&state.email_service);
                 let db_pool = Arc::clone(&state.db_pool);
-                let job_id = payload.job_id;
                 let user_id = user_id;
 
                 async move {
@@ -154,18 +157,29 @@ async fn apply_for_job(
             });
 
             (StatusCode::CREATED, Json(serde_json::json!({
-                "message": "Application submitted successfully"
+                "message": "Application submitted successfully",
+                "application": application
             }))).into_response()
         },
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => application_error_response(e),
     }
 }
 
+/// Candidates of a job post with their cover letter, resume and screening answers
+/// GET /candidates/:job_id?knockout=passed|failed
 async fn get_job_candidates(
     State(state): State<Arc<JobApplicationState>>,
+    RequireCompany(user_id): RequireCompany,
     Path(job_id): Path<Uuid>,
+    Query(query): Query<CandidateListQuery>,
 ) -> impl IntoResponse {
-    match state.repo.find_by_job_id(job_id).await {
+    // เฉพาะบริษัทเจ้าของประกาศงานเท่านั้นที่ดูผู้สมัครได้
+    if let Err(e) = state.ownership_policy.ensure_post_owner(user_id, job_id).await {
+        return ownership_error(e).into_response();
+    }
+
+    let knocked_out = query.knockout.map(|knockout| knockout.knocked_out());
+    match state.repo.find_by_job_id(job_id, knocked_out).await {
         Ok(candidates) => (StatusCode::OK, Json(candidates)).into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -186,25 +200,42 @@ async fn get_my_applications(
     }
 }
 
-#[derive(serde::Deserialize)]
-struct UpdateStatusRequest {
-    status: String,
+/// Map use case errors to HTTP status codes
+fn application_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Forbidden") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Invalid status transition") || message.contains("Conflict") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
 }
 
+/// Move an application to another pipeline stage (company only) - recorded in the status history
+/// PATCH /applications/:application_id/status
 async fn update_application_status(
     State(state): State<Arc<JobApplicationState>>,
+    RequireCompany(user_id): RequireCompany,
     Path(application_id): Path<Uuid>,
-    Json(payload): Json<UpdateStatusRequest>,
+    Json(payload): Json<UpdateApplicationStatusRequest>,
 ) -> impl IntoResponse {
-    // Validate status
-    if !["pending", "accepted", "rejected"].contains(&payload.status.as_str()) {
-        return (StatusCode::BAD_REQUEST, "Invalid status. Must be 'pending', 'accepted', or 'rejected'").into_response();
+    if let Err(e) = state.ownership_policy.ensure_application_owner(user_id, application_id).await {
+        return ownership_error(e).into_response();
     }
 
-    match state.repo.update_status(application_id, payload.status.clone()).await {
-        Ok(_) => {
-            // Send email notification if accepted
-            if payload.status == "accepted" {
+    match state
+        .use_case
+        .change_status(application_id, user_id, ApplicationActorRole::Company, payload)
+        .await
+    {
+        Ok(change) => {
+            // แจ้งผู้สมัครเมื่อบริษัทเริ่มพิจารณา (ออกจาก applied ไปขั้นที่ไม่ใช่ rejected)
+            if change.from == ApplicationStatus::Applied && change.to != ApplicationStatus::Rejected {
                 tokio::spawn({
                     let email_service = Arc::clone(&state.email_service);
                     let db_pool = Arc::clone(&state.db_pool);
@@ -299,9 +330,98 @@ async fn update_application_status(
             }
 
             (StatusCode::OK, Json(serde_json::json!({
-                "message": "Application status updated successfully"
+                "message": "Application status updated successfully",
+                "application": change.application
             }))).into_response()
         },
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => application_error_response(e),
+    }
+}
+
+/// Status timeline of an application - visible to the applicant and the company that owns the post
+/// GET /applications/:application_id/history
+async fn get_application_history(
+    State(state): State<Arc<JobApplicationState>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(application_id): Path<Uuid>,
+) -> impl IntoResponse {
+    let application = match state.use_case.find_application(application_id).await {
+        Ok(application) => application,
+        Err(e) => return application_error_response(e),
+    };
+
+    let viewer_role = if application.user_id == user_id {
+        ApplicationActorRole::Candidate
+    } else {
+        if let Err(e) = state.ownership_policy.ensure_application_owner(user_id, application_id).await {
+            return ownership_error(e).into_response();
+        }
+        ApplicationActorRole::Company
+    };
+
+    match state.use_case.timeline(application, viewer_role).await {
+        Ok(timeline) => (StatusCode::OK, Json(timeline)).into_response(),
+        Err(e) => application_error_response(e),
+    }
+}
+
+/// Withdraw the caller's own application - recorded in the status history and the company is notified
+/// POST /applications/:application_id/withdraw
+async fn withdraw_application(
+    State(state): State<Arc<JobApplicationState>>,
+    RequirePersona(user_id): RequirePersona,
+    Path(application_id): Path<Uuid>,
+    payload: Option<Json<WithdrawApplicationRequest>>,
+) -> impl IntoResponse {
+    let request = payload.map(|Json(payload)| payload).unwrap_or_default();
+    if let Err(errors) = request.validate() {
+        return (StatusCode::BAD_REQUEST, format!("Validation error: {:?}", errors)).into_response();
+    }
+    let reason = request.reason.clone();
+
+    match state.use_case.withdraw(application_id, user_id, request).await {
+        Ok(change) => {
+            tokio::spawn({
+                let email_service = Arc::clone(&state.email_service);
+                let repo = Arc::clone(&state.repo);
+
+                async move {
+                    let contacts = match repo.find_contacts(application_id).await {
+                        Ok(Some(contacts)) => contacts,
+                        Ok(None) => return,
+                        Err(e) => {
+                            error!("Failed to fetch application contacts for email notification: {}", e);
+                            return;
+                        }
+                    };
+                    let Some(company_email) = contacts.company_email.as_deref() else {
+                        error!("Failed to fetch company email");
+                        return;
+                    };
+
+                    let reason = reason.as_deref().map(str::trim).filter(|reason| !reason.is_empty());
+                    if let Err(e) = email_service
+                        .send_application_withdrawn_notification(
+                            company_email,
+                            &contacts.company_name,
+                            &contacts.applicant_name,
+                            &contacts.job_title,
+                            reason,
+                        )
+                        .await
+                    {
+                        error!("Failed to send application withdrawn email: {}", e);
+                    } else {
+                        info!("Application withdrawn email sent successfully");
+                    }
+                }
+            });
+
+            (StatusCode::OK, Json(serde_json::json!({
+                "message": "Application withdrawn successfully",
+                "application": change.application
+            }))).into_response()
+        },
+        Err(e) => application_error_response(e),
     }
 }
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::header,
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::infrastructure::jwt_authentication::jwt_keys::JwtKeySet;
+
+/// Public route - ให้ service อื่น verify token ได้ด้วย public key
+pub fn routes(jwt_keys: Arc<JwtKeySet>) -> Router {
+    Router::new()
+        .route("/.well-known/jwks.json", get(get_jwks))
+        .with_state(jwt_keys)
+}
+
+/// Public signing keys (JWKS) - รวม key เก่าที่ยังใช้ verify ได้ระหว่างหมุน key
+/// GET /.well-known/jwks.json
+pub async fn get_jwks(State(jwt_keys): State<Arc<JwtKeySet>>) -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(jwt_keys.jwks()),
+    )
+}
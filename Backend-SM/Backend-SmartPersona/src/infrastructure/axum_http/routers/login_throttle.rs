@@ -0,0 +1,262 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    Json, Router,
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts, Path, Query, State},
+    http::{Extensions, HeaderMap, Request, StatusCode, request::Parts},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use ipnet::IpNet;
+use tower_governor::{GovernorError, key_extractor::KeyExtractor};
+
+use crate::{
+    config::config_model::RateLimit,
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+            login_throttle::LockoutEventsQuery,
+        },
+        repo::login_throttle::LoginThrottleRepository,
+        usecase::login_throttle::LoginThrottleUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::admin_authorization, role_guard::RequireAdmin,
+            routers::audit_log::AdminAudit,
+        },
+        postgres::{postgres_connection::DbPool, repositories::login_throttle::LoginThrottlePostgres},
+    },
+};
+
+pub type LoginThrottleUseCasePostgres = LoginThrottleUseCase<LoginThrottlePostgres>;
+
+/// สร้างครั้งเดียวใน http_serve แล้วใช้ร่วมกันระหว่าง authentication / register / admin routes
+pub fn build_use_case(db_pool: Arc<DbPool>, policy: RateLimit) -> Arc<LoginThrottleUseCasePostgres> {
+    Arc::new(LoginThrottleUseCase::new(
+        Arc::new(LoginThrottlePostgres::new(db_pool)),
+        policy,
+    ))
+}
+
+/// Admin-only routes for reviewing login lockouts (mounted under /admin)
+pub fn admin_routes(login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>) -> Router {
+    Router::new()
+        .route("/security/lockouts", get(get_active_lockouts))
+        .route("/security/lockouts/:username", delete(unlock_account))
+        .route("/security/lockout-events", get(get_lockout_events))
+        .with_state(login_throttle_use_case)
+        .layer(middleware::from_fn(admin_authorization))
+}
+
+/// Periodically purge rate limit buckets whose window has passed
+pub async fn run_maintenance(
+    login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>,
+    interval: std::time::Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = login_throttle_use_case.cleanup_stale_buckets().await {
+            tracing::error!("Rate limit bucket cleanup failed: {}", e);
+        }
+    }
+}
+
+// =================================================================
+// 🌐 Client IP
+// =================================================================
+
+/// Reverse proxies ที่เชื่อ X-Forwarded-For / X-Real-IP ได้ (TRUSTED_PROXIES)
+/// ติดตั้งครั้งเดียวทั้ง app เป็น `Extension<Arc<TrustedProxies>>`
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(Vec<IpNet>);
+
+impl TrustedProxies {
+    pub fn new(networks: Vec<IpNet>) -> Self {
+        Self(networks)
+    }
+
+    fn contains(&self, ip: &IpAddr) -> bool {
+        self.0.iter().any(|network| network.contains(ip))
+    }
+
+    /// peer address เว้นแต่ peer เป็น proxy ที่เชื่อถือ - จึงไล่ X-Forwarded-For จากขวา
+    /// (hop ที่ใกล้ที่สุด) แล้วใช้ hop แรกที่ไม่ใช่ proxy ที่เชื่อถือ
+    /// ค่าทางซ้ายของ hop นั้น client ปลอมเองได้ จึงไม่ใช้
+    pub fn resolve(&self, headers: &HeaderMap, peer: Option<IpAddr>) -> Option<IpAddr> {
+        let peer = peer.map(|ip| ip.to_canonical())?;
+        if !self.contains(&peer) {
+            return Some(peer);
+        }
+
+        let forwarded: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect();
+
+        for hop in forwarded.iter().rev() {
+            match parse_forwarded_ip(hop) {
+                Some(ip) if self.contains(&ip) => continue,
+                Some(ip) => return Some(ip),
+                // ค่าที่อ่านไม่ออกมาจาก client - หยุดที่ hop ล่าสุดที่เชื่อถือได้
+                None => break,
+            }
+        }
+
+        if forwarded.is_empty()
+            && let Some(real_ip) = headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_forwarded_ip)
+        {
+            return Some(real_ip);
+        }
+
+        Some(peer)
+    }
+}
+
+/// "203.0.113.7", "203.0.113.7:443" หรือ "[2001:db8::1]:443"
+fn parse_forwarded_ip(value: &str) -> Option<IpAddr> {
+    let value = value.trim();
+    value
+        .parse::<IpAddr>()
+        .or_else(|_| value.parse::<SocketAddr>().map(|addr| addr.ip()))
+        .ok()
+        .map(|ip| ip.to_canonical())
+}
+
+/// IP ของ client จาก request extensions (ConnectInfo + TrustedProxies)
+pub fn client_ip(headers: &HeaderMap, extensions: &Extensions) -> String {
+    let peer = extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    let resolved = match extensions.get::<Arc<TrustedProxies>>() {
+        Some(trusted_proxies) => trusted_proxies.resolve(headers, peer),
+        None => peer,
+    };
+
+    resolved
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Extractor สำหรับ handler ที่ต้องใช้ IP ของ client (rate limit / audit)
+pub struct ClientIp(pub String);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(client_ip(&parts.headers, &parts.extensions)))
+    }
+}
+
+/// Key ของ burst limiter (tower_governor) - ใช้กฎเดียวกับ `client_ip`
+#[derive(Debug, Clone)]
+pub struct ClientIpKeyExtractor(pub Arc<TrustedProxies>);
+
+impl KeyExtractor for ClientIpKeyExtractor {
+    type Key = IpAddr;
+
+    fn extract<T>(&self, req: &Request<T>) -> Result<Self::Key, GovernorError> {
+        let peer = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        self.0
+            .resolve(req.headers(), peer)
+            .ok_or(GovernorError::UnableToExtractKey)
+    }
+}
+
+/// Rate limit / lockout errors are 429, anything else is a storage failure
+pub fn throttle_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Too many requests") || message.contains("temporarily locked") {
+        (StatusCode::TOO_MANY_REQUESTS, message).into_response()
+    } else {
+        tracing::error!("Login throttle check failed: {}", message);
+        (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
+    }
+}
+
+/// Accounts that are currently locked
+/// GET /admin/security/lockouts
+pub async fn get_active_lockouts<T>(
+    State(login_throttle_use_case): State<Arc<LoginThrottleUseCase<T>>>,
+    RequireAdmin(_admin_id): RequireAdmin,
+) -> impl IntoResponse
+where
+    T: LoginThrottleRepository + Send + Sync + 'static,
+{
+    match login_throttle_use_case.list_active_lockouts().await {
+        Ok(lockouts) => (StatusCode::OK, Json(lockouts)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Lockout history, newest first
+/// GET /admin/security/lockout-events?limit=100
+pub async fn get_lockout_events<T>(
+    State(login_throttle_use_case): State<Arc<LoginThrottleUseCase<T>>>,
+    RequireAdmin(_admin_id): RequireAdmin,
+    Query(query): Query<LockoutEventsQuery>,
+) -> impl IntoResponse
+where
+    T: LoginThrottleRepository + Send + Sync + 'static,
+{
+    match login_throttle_use_case.list_lockout_events(query.limit).await {
+        Ok(events) => (StatusCode::OK, Json(events)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Clear the lockout of an account before it expires
+/// DELETE /admin/security/lockouts/:username
+pub async fn unlock_account<T>(
+    State(login_throttle_use_case): State<Arc<LoginThrottleUseCase<T>>>,
+    RequireAdmin(admin_id): RequireAdmin,
+    audit: AdminAudit,
+    Path(username): Path<String>,
+) -> impl IntoResponse
+where
+    T: LoginThrottleRepository + Send + Sync + 'static,
+{
+    match login_throttle_use_case.unlock(&username).await {
+        Ok(_) => {
+            if let Err(response) = audit
+                .record(NewAuditEvent::new(
+                    AuditAction::LoginLockoutClear,
+                    AuditTarget::LoginLockout,
+                    &username,
+                ))
+                .await
+            {
+                return response;
+            }
+            tracing::info!("Admin {} cleared login lockout for {}", admin_id, username);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) if e.to_string().contains("not found") => {
+            (StatusCode::NOT_FOUND, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
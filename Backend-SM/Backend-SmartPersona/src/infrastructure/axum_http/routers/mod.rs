@@ -1,11 +1,23 @@
+pub mod account_deletion;
+pub mod account_token;
 pub mod admin_handlers;
+pub mod admin_invite;
 pub mod ads;
 pub mod ai_handlers;
+pub mod audit_log;
 pub mod authentication;
 pub mod company;
+pub mod company_api_key;
 pub mod company_gallery;
 pub mod company_post;
+pub mod data_export;
+pub mod impersonation;
+pub mod interview;
+pub mod jwks;
+pub mod login_throttle;
+pub mod oidc;
 pub mod saved_job;
+pub mod saved_search;
 pub mod social;
 pub mod storage;
 pub mod user;
@@ -15,9 +27,11 @@ pub mod user_education;
 pub mod user_experience;
 pub mod user_job_match;
 pub mod user_job_preference;
+pub mod user_mfa;
 pub mod user_portfolio;
 pub mod user_privacy_settings;
 pub mod user_profile;
+pub mod user_session;
 pub mod user_share;
 pub mod user_skill;
 pub mod job_application;
@@ -0,0 +1,279 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Redirect, Response},
+    routing::{delete, get, post},
+};
+use reqwest::Url;
+
+use crate::{
+    domain::{
+        entities::{
+            login_throttle::ThrottleScope,
+            oidc::{
+                OidcAuthorizationResponse, OidcCallbackOutcome, OidcCallbackQuery,
+                OidcProviderResponse, OidcStartQuery,
+            },
+        },
+        usecase::oidc::OidcUseCase,
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::user_authorization,
+            role_guard::NotImpersonating,
+            routers::{
+                authentication::{
+                    self, AuthenticationUseCasePostgres, passport_cookie_headers, session_device,
+                },
+                login_throttle::{ClientIp, LoginThrottleUseCasePostgres, throttle_error_response},
+                user::AuthenticatedUserId,
+            },
+        },
+        jwt_authentication::{authentication_model::LoginOutcome, jwt_keys::JwtKeySet},
+        oidc::client::OidcClient,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{oidc::OidcPostgres, user::UserPostgres},
+        },
+    },
+};
+
+pub type OidcUseCasePostgres = OidcUseCase<UserPostgres, OidcPostgres>;
+
+/// Public sign-in state: OIDC use case + authentication (issue Passport) + throttle
+#[derive(Clone)]
+pub struct OidcLoginState {
+    pub oidc_use_case: Arc<OidcUseCasePostgres>,
+    pub authentication_use_case: Arc<AuthenticationUseCasePostgres>,
+    pub login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>,
+    pub frontend_url: String,
+}
+
+pub fn build_use_case(db_pool: Arc<DbPool>, oidc_client: Arc<OidcClient>) -> Arc<OidcUseCasePostgres> {
+    Arc::new(OidcUseCase::new(
+        Arc::new(UserPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(OidcPostgres::new(db_pool)),
+        oidc_client,
+    ))
+}
+
+/// Public sign-in routes (mounted under /authentication)
+pub fn public_routes(
+    db_pool: Arc<DbPool>,
+    jwt_keys: Arc<JwtKeySet>,
+    oidc_client: Arc<OidcClient>,
+    login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>,
+    frontend_url: String,
+) -> Router {
+    let state = OidcLoginState {
+        oidc_use_case: build_use_case(Arc::clone(&db_pool), oidc_client),
+        authentication_use_case: authentication::build_use_case(db_pool, jwt_keys),
+        login_throttle_use_case,
+        frontend_url,
+    };
+
+    Router::new()
+        .route("/oidc/providers", get(get_providers))
+        .route("/oidc/:provider/start", get(start_login))
+        .route("/oidc/:provider/callback", get(callback))
+        .with_state(state)
+}
+
+/// Protected routes for managing linked identities (mounted under /api/user)
+pub fn routes(db_pool: Arc<DbPool>, oidc_client: Arc<OidcClient>) -> Router {
+    Router::new()
+        .route("/oidc/identities", get(get_identities))
+        .route("/oidc/identities/:provider", delete(unlink_identity))
+        .route("/oidc/:provider/link", post(start_link))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(build_use_case(db_pool, oidc_client))
+}
+
+/// Periodically purge authorization requests that never came back
+pub async fn run_maintenance(
+    db_pool: Arc<DbPool>,
+    oidc_client: Arc<OidcClient>,
+    interval: std::time::Duration,
+) {
+    let oidc_use_case = build_use_case(db_pool, oidc_client);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = oidc_use_case.cleanup_expired_requests().await {
+            tracing::error!("OIDC auth request cleanup failed: {}", e);
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn oidc_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Unknown OIDC provider") || message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Invalid")
+        || message.contains("Provider returned an error")
+        || message.contains("token exchange failed")
+    {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("already") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else if message.contains("Forbidden") || message.contains("not verified") || message.contains("suspended") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else {
+        tracing::error!("OIDC sign-in failed: {}", message);
+        (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
+    }
+}
+
+/// frontend_url + path ที่ตรวจแล้วว่าเป็น path ภายใน
+fn frontend_redirect(frontend_url: &str, return_to: Option<String>) -> String {
+    format!(
+        "{}{}",
+        frontend_url.trim_end_matches('/'),
+        return_to.unwrap_or_else(|| "/".to_string())
+    )
+}
+
+/// Configured providers and their sign-in URLs
+/// GET /authentication/oidc/providers
+pub async fn get_providers(State(state): State<OidcLoginState>) -> impl IntoResponse {
+    let providers: Vec<OidcProviderResponse> = state
+        .oidc_use_case
+        .providers()
+        .into_iter()
+        .map(|name| OidcProviderResponse {
+            login_url: format!("/authentication/oidc/{}/start", name),
+            name,
+        })
+        .collect();
+
+    (StatusCode::OK, Json(providers))
+}
+
+/// Redirect the browser to the provider (authorization code + PKCE)
+/// GET /authentication/oidc/:provider/start?return_to=/path
+pub async fn start_login(
+    State(state): State<OidcLoginState>,
+    ClientIp(ip_address): ClientIp,
+    Path(provider): Path<String>,
+    Query(query): Query<OidcStartQuery>,
+) -> impl IntoResponse {
+    if let Err(e) = state
+        .login_throttle_use_case
+        .check_ip(ThrottleScope::Oidc, &ip_address)
+        .await
+    {
+        return throttle_error_response(e);
+    }
+
+    match state
+        .oidc_use_case
+        .begin(&provider, None, query.return_to)
+        .await
+    {
+        Ok(authorization_url) => Redirect::to(&authorization_url).into_response(),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// Provider redirect target: sign in (or finish linking) and set the Passport cookies
+/// GET /authentication/oidc/:provider/callback
+pub async fn callback(
+    State(state): State<OidcLoginState>,
+    ClientIp(ip_address): ClientIp,
+    headers: HeaderMap,
+    Path(provider): Path<String>,
+    Query(query): Query<OidcCallbackQuery>,
+) -> impl IntoResponse {
+    let outcome = match state.oidc_use_case.complete(&provider, query).await {
+        Ok(outcome) => outcome,
+        Err(e) => return oidc_error_response(e),
+    };
+
+    let (user, return_to) = match outcome {
+        OidcCallbackOutcome::Linked { return_to } => {
+            return Redirect::to(&frontend_redirect(&state.frontend_url, return_to)).into_response();
+        }
+        OidcCallbackOutcome::SignedIn { user, return_to } => (user, return_to),
+    };
+
+    match state
+        .authentication_use_case
+        .user_login_verified(user, session_device(&headers, &ip_address))
+        .await
+    {
+        Ok(LoginOutcome::Authenticated(passport)) => {
+            let cookie_headers = passport_cookie_headers(&passport, &headers, "act", "rft");
+            (
+                cookie_headers,
+                Redirect::to(&frontend_redirect(&state.frontend_url, return_to)),
+            )
+                .into_response()
+        }
+        // MFA ยังต้องยืนยันที่ POST /authentication/mfa/verify
+        Ok(LoginOutcome::MfaRequired(challenge)) => {
+            let mfa_url = Url::parse_with_params(
+                &frontend_redirect(&state.frontend_url, Some("/login/mfa".to_string())),
+                &[("mfa_token", challenge.mfa_token.as_str())],
+            );
+            match mfa_url {
+                Ok(mfa_url) => Redirect::to(mfa_url.as_str()).into_response(),
+                Err(e) => oidc_error_response(e.into()),
+            }
+        }
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// Identities linked to the current user
+/// GET /api/user/oidc/identities
+pub async fn get_identities(
+    State(oidc_use_case): State<Arc<OidcUseCasePostgres>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse {
+    match oidc_use_case.list_identities(user_id).await {
+        Ok(identities) => (StatusCode::OK, Json(identities)).into_response(),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// Start linking a provider to the current account
+/// POST /api/user/oidc/:provider/link
+pub async fn start_link(
+    State(oidc_use_case): State<Arc<OidcUseCasePostgres>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(provider): Path<String>,
+    Query(query): Query<OidcStartQuery>,
+) -> impl IntoResponse {
+    match oidc_use_case
+        .begin(&provider, Some(user_id), query.return_to)
+        .await
+    {
+        Ok(authorization_url) => (
+            StatusCode::OK,
+            Json(OidcAuthorizationResponse { authorization_url }),
+        )
+            .into_response(),
+        Err(e) => oidc_error_response(e),
+    }
+}
+
+/// Remove a linked provider
+/// DELETE /api/user/oidc/identities/:provider
+pub async fn unlink_identity(
+    State(oidc_use_case): State<Arc<OidcUseCasePostgres>>,
+    _: NotImpersonating,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    match oidc_use_case.unlink(user_id, &provider).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => oidc_error_response(e),
+    }
+}
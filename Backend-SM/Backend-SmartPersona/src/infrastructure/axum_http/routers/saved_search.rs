@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::{Html, IntoResponse, Response},
+    routing::{get, patch},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::saved_search::{CreateSavedSearchRequest, UpdateSavedSearchRequest},
+        repo::saved_search::SavedSearchRepository,
+        usecase::saved_search::SavedSearchUseCase,
+    },
+    infrastructure::{
+        axum_http::{middleware::user_authorization, routers::user::AuthenticatedUserId},
+        email::EmailService,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{company_post::CompanyPostPostgres, saved_search::SavedSearchPostgres},
+        },
+    },
+};
+
+type SavedSearchUseCasePostgres = SavedSearchUseCase<SavedSearchPostgres>;
+
+fn build_use_case(db_pool: Arc<DbPool>, backend_url: String) -> Arc<SavedSearchUseCasePostgres> {
+    // Email service is optional - matches are kept until SMTP is configured
+    let email_service = EmailService::new().unwrap_or_else(|e| {
+        tracing::warn!("Email service not configured: {}. Using dummy service.", e);
+        EmailService::dummy()
+    });
+
+    Arc::new(SavedSearchUseCase::new(
+        Arc::new(SavedSearchPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(CompanyPostPostgres::new(db_pool)),
+        Arc::new(email_service),
+        backend_url,
+    ))
+}
+
+/// Protected routes (require authentication) - manage saved searches and alert settings
+pub fn routes(db_pool: Arc<DbPool>, backend_url: String) -> Router {
+    let saved_search_use_case = build_use_case(db_pool, backend_url);
+
+    Router::new()
+        .route("/saved-searches", get(list_saved_searches).post(create_saved_search))
+        .route(
+            "/saved-searches/:id",
+            patch(update_saved_search).delete(delete_saved_search),
+        )
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(saved_search_use_case)
+}
+
+/// Public routes - the unsubscribe link in alert emails works without logging in, the token is the credential
+pub fn public_routes(db_pool: Arc<DbPool>, backend_url: String) -> Router {
+    let saved_search_use_case = build_use_case(db_pool, backend_url);
+
+    Router::new()
+        .route(
+            "/saved-searches/unsubscribe/:token",
+            get(confirm_unsubscribe).post(unsubscribe),
+        )
+        .with_state(saved_search_use_case)
+}
+
+/// Background task - match newly published posts against saved searches and send digests
+pub async fn run_alerts(db_pool: Arc<DbPool>, backend_url: String, interval: std::time::Duration) {
+    let saved_search_use_case = build_use_case(db_pool, backend_url);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        match saved_search_use_case.process_alerts().await {
+            Ok((0, 0)) => {}
+            Ok((matched, sent)) => tracing::info!(
+                "Matched {} job post(s) to saved searches, sent {} alert digest(s)",
+                matched,
+                sent
+            ),
+            Err(e) => tracing::error!("Saved search alerts failed: {}", e),
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn saved_search_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Conflict") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Saved searches of the current user
+/// GET /api/user/saved-searches
+pub async fn list_saved_searches<T>(
+    State(saved_search_use_case): State<Arc<SavedSearchUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: SavedSearchRepository + Send + Sync + 'static,
+{
+    match saved_search_use_case.list(user_id).await {
+        Ok(saved_searches) => (StatusCode::OK, Json(saved_searches)).into_response(),
+        Err(e) => saved_search_error_response(e),
+    }
+}
+
+/// Save a search - criteria uses the /posts/search parameters, frequency instant / daily (default) / weekly
+/// POST /api/user/saved-searches
+pub async fn create_saved_search<T>(
+    State(saved_search_use_case): State<Arc<SavedSearchUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Json(request): Json<CreateSavedSearchRequest>,
+) -> impl IntoResponse
+where
+    T: SavedSearchRepository + Send + Sync + 'static,
+{
+    match saved_search_use_case.create(user_id, request).await {
+        Ok(saved_search) => (StatusCode::CREATED, Json(saved_search)).into_response(),
+        Err(e) => saved_search_error_response(e),
+    }
+}
+
+/// Rename, change criteria / frequency, or turn alerts on and off
+/// PATCH /api/user/saved-searches/:id
+pub async fn update_saved_search<T>(
+    State(saved_search_use_case): State<Arc<SavedSearchUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateSavedSearchRequest>,
+) -> impl IntoResponse
+where
+    T: SavedSearchRepository + Send + Sync + 'static,
+{
+    match saved_search_use_case.update(user_id, id, request).await {
+        Ok(saved_search) => (StatusCode::OK, Json(saved_search)).into_response(),
+        Err(e) => saved_search_error_response(e),
+    }
+}
+
+/// DELETE /api/user/saved-searches/:id
+pub async fn delete_saved_search<T>(
+    State(saved_search_use_case): State<Arc<SavedSearchUseCase<T>>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: SavedSearchRepository + Send + Sync + 'static,
+{
+    match saved_search_use_case.delete(user_id, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => saved_search_error_response(e),
+    }
+}
+
+/// Confirmation page for the email link - GET must not change anything (link scanners / prefetch)
+/// GET /api/saved-searches/unsubscribe/:token
+pub async fn confirm_unsubscribe() -> impl IntoResponse {
+    Html(unsubscribe_page(
+        "ยกเลิกการแจ้งเตือน?",
+        "คุณจะไม่ได้รับอีเมลแจ้งงานใหม่จากการค้นหานี้อีก",
+        r#"<form method="post"><button type="submit" style="padding: 12px 24px; font-size: 16px; cursor: pointer;">ยืนยันการยกเลิก</button></form>"#,
+    ))
+}
+
+/// Turn off alerts from the email link - the confirm page form and RFC 8058 one-click unsubscribe
+/// POST /api/saved-searches/unsubscribe/:token
+pub async fn unsubscribe<T>(
+    State(saved_search_use_case): State<Arc<SavedSearchUseCase<T>>>,
+    Path(token): Path<String>,
+) -> impl IntoResponse
+where
+    T: SavedSearchRepository + Send + Sync + 'static,
+{
+    match saved_search_use_case.unsubscribe(&token).await {
+        Ok(_) => Html(unsubscribe_page(
+            "ยกเลิกการแจ้งเตือนแล้ว",
+            "คุณจะไม่ได้รับอีเมลแจ้งงานใหม่จากการค้นหานี้อีก เปิดการแจ้งเตือนอีกครั้งได้ที่หน้าการค้นหาที่บันทึกไว้",
+            "",
+        ))
+        .into_response(),
+        Err(e) if e.to_string().contains("not found") => (
+            StatusCode::NOT_FOUND,
+            Html(unsubscribe_page(
+                "ลิงก์ไม่ถูกต้อง",
+                "ลิงก์ยกเลิกนี้ใช้ไม่ได้ การค้นหาอาจถูกลบไปแล้ว",
+                "",
+            )),
+        )
+            .into_response(),
+        Err(e) => saved_search_error_response(e),
+    }
+}
+
+/// `action` - HTML เพิ่มใต้ข้อความ (ฟอร์มยืนยัน)
+fn unsubscribe_page(heading: &str, message: &str, action: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head><meta charset="UTF-8"><title>Smart Persona</title></head>
+<body style="font-family: 'Segoe UI', Tahoma, sans-serif; text-align: center; padding: 60px 20px; color: #333;">
+    <h1>{}</h1>
+    <p>{}</p>
+    {}
+</body>
+</html>"#,
+        heading, message, action
+    )
+}
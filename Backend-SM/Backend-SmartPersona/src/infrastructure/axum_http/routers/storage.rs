@@ -14,7 +14,10 @@ use uuid::Uuid;
 use crate::{
     infrastructure::{
         axum_http::middleware::user_authorization,
-        supabase::{client::SupabaseClient, storage::StorageService},
+        supabase::{
+            client::SupabaseClient,
+            storage::{RESUME_EXTENSIONS, StorageService},
+        },
     },
 };
 
@@ -48,11 +51,15 @@ pub fn routes(supabase_client: Arc<SupabaseClient>) -> Router {
         .route("/upload", post(upload_file))
         .route("/upload/profile-image", post(upload_profile_image))
         .route("/upload/cover-image", post(upload_cover_image))
+        .route("/upload/resume", post(upload_resume))
         .route("/delete", delete(delete_file))
         .layer(middleware::from_fn(user_authorization))
         .with_state(storage_service)
 }
 
+/// ขนาดไฟล์ resume สูงสุด
+const MAX_RESUME_BYTES: usize = 5 * 1024 * 1024;
+
 #[derive(serde::Serialize)]
 struct UploadResponse {
     url: String,
@@ -243,6 +250,80 @@ pub async fn upload_cover_image(
     }
 }
 
+/// Upload a resume to attach to job applications (PDF / DOC / DOCX, max 5 MB)
+/// POST /api/storage/upload/resume
+pub async fn upload_resume(
+    State(storage_service): State<Arc<StorageService>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut file_data: Option<axum::body::Bytes> = None;
+    let mut file_name: Option<String> = None;
+
+    while let Some(field) = multipart.next_field().await.unwrap_or(None) {
+        if field.name() == Some("file") {
+            // Get file_name first before consuming field with bytes()
+            let current_file_name = field.file_name().map(|s| s.to_string());
+            if let Ok(data) = field.bytes().await {
+                file_data = Some(data);
+                if let Some(name) = current_file_name {
+                    file_name = Some(name);
+                }
+            }
+        }
+    }
+
+    let (file_data, file_name) = match (file_data, file_name) {
+        (Some(data), Some(name)) => (data, name),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "No file provided"})),
+            )
+                .into_response();
+        }
+    };
+
+    let extension = std::path::Path::new(&file_name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+    if !RESUME_EXTENSIONS.contains(&extension.as_str()) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Resume must be a PDF, DOC or DOCX file"})),
+        )
+            .into_response();
+    }
+    if file_data.len() > MAX_RESUME_BYTES {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(serde_json::json!({"error": "Resume must be at most 5 MB"})),
+        )
+            .into_response();
+    }
+
+    match storage_service
+        .upload_resume(file_data, file_name, user_id)
+        .await
+    {
+        Ok(url) => (
+            StatusCode::OK,
+            Json(UploadResponse {
+                url: url.clone(),
+                message: "Resume uploaded successfully".to_string(),
+            }),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
+
 /// Delete a file
 /// DELETE /api/storage/delete
 pub async fn delete_file(
@@ -14,12 +14,24 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::user_profile::{NewUserProfile, UpdateUserProfile},
-        repo::user::UserRepository,
-        usecase::user::UserUseCase,
+        entities::{
+            login_throttle::ThrottleScope,
+            user_profile::{NewUserProfile, UpdateUserProfile},
+        },
+        repo::{
+            account_token::AccountTokenRepository, user::UserRepository,
+            user_session::UserSessionRepository,
+        },
+        usecase::{account_token::AccountTokenUseCase, user::UserUseCase},
         value_object::user::RegisterUserModel,
     },
-    infrastructure::axum_http::middleware::user_authorization,
+    infrastructure::axum_http::{
+        middleware::user_authorization,
+        routers::{
+            account_token,
+            login_throttle::{ClientIp, LoginThrottleUseCasePostgres, throttle_error_response},
+        },
+    },
     infrastructure::postgres::{postgres_connection::DbPool, repositories::user::UserPostgres},
 };
 
@@ -46,12 +58,26 @@ where
     }
 }
 
-pub fn routes(db_pool: Arc<DbPool>) -> Router {
+/// Register state: user use case + account token use case (for the verification email)
+/// + login throttle (per-IP registration limit)
+type RegisterState<T, TUser, TToken, TSession> = (
+    Arc<UserUseCase<T>>,
+    Arc<AccountTokenUseCase<TUser, TToken, TSession>>,
+    Arc<LoginThrottleUseCasePostgres>,
+);
+
+pub fn routes(db_pool: Arc<DbPool>, login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>) -> Router {
     let user_repository = UserPostgres::new(Arc::clone(&db_pool));
     let user_use_case = Arc::new(UserUseCase::new(Arc::new(user_repository)));
+    let account_token_use_case = account_token::build_use_case(Arc::clone(&db_pool));
 
     Router::new()
         .route("/register", post(register))
+        .with_state((
+            Arc::clone(&user_use_case),
+            account_token_use_case,
+            login_throttle_use_case,
+        ))
         .route(
             "/info",
             get(get_user_info).layer(middleware::from_fn(user_authorization)),
@@ -59,20 +85,57 @@ pub fn routes(db_pool: Arc<DbPool>) -> Router {
         .with_state(user_use_case)
 }
 
-pub async fn register<T>(
-    State(user_use_case): State<Arc<UserUseCase<T>>>,
+pub async fn register<T, TUser, TToken, TSession>(
+    State((user_use_case, account_token_use_case, login_throttle_use_case)): State<
+        RegisterState<T, TUser, TToken, TSession>,
+    >,
+    ClientIp(ip_address): ClientIp,
     Json(register_user_model): Json<RegisterUserModel>,
 ) -> impl IntoResponse
 where
     T: UserRepository + Send + Sync,
+    TUser: UserRepository + Send + Sync,
+    TToken: AccountTokenRepository + Send + Sync,
+    TSession: UserSessionRepository + Send + Sync,
 {
+    if let Err(e) = login_throttle_use_case
+        .check_ip(ThrottleScope::Register, &ip_address)
+        .await
+    {
+        return throttle_error_response(e);
+    }
+
     match user_use_case.register(register_user_model).await {
-        Ok(user_id) => (
-            StatusCode::CREATED,
-            format!("Register user id: {} successfully", user_id),
-        )
-            .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Ok(user_id) => {
+            // Account stays Pending until the email is verified
+            if let Err(e) = account_token_use_case
+                .send_verification_for_user(user_id)
+                .await
+            {
+                tracing::error!("Failed to send verification email: {}", e);
+            }
+
+            (
+                StatusCode::CREATED,
+                format!("Register user id: {} successfully", user_id),
+            )
+                .into_response()
+        }
+        Err(e) => register_error_response(e),
+    }
+}
+
+/// Map registration errors to HTTP status codes
+fn register_error_response(e: anyhow::Error) -> axum::response::Response {
+    let message = e.to_string();
+    if message.contains("Validation error") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else if message.contains("Forbidden") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("already") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
     }
 }
 
@@ -175,7 +175,7 @@ where
     }
 
     match user_address_use_case.get_address_by_user_id(user_id).await {
-        Ok(Some(address)) => (StatusCode::OK, Json(address)).into_response(),
+        Ok(Some(address)) => (StatusCode::OK, Json(address.without_coordinates())).into_response(),
         Ok(None) => (StatusCode::NOT_FOUND, "Address not found").into_response(),
         Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
     }
@@ -192,6 +192,10 @@ where
     T: UserAddressRepository + Send + Sync + 'static,
     TPrivacy: crate::domain::repo::user_privacy_settings::UserPrivacySettingsRepository + Send + Sync + 'static,
 {
+    if let Err(e) = address_request.validate_coordinates() {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
     // Convert request to NewUserAddress with user_id from JWT token
     let new_address = address_request.into_new_address(user_id);
 
@@ -225,6 +229,10 @@ where
     T: UserAddressRepository + Send + Sync + 'static,
     TPrivacy: crate::domain::repo::user_privacy_settings::UserPrivacySettingsRepository + Send + Sync + 'static,
 {
+    if let Err(e) = address_request.validate_coordinates() {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
     // Check if address exists before updating
     match user_address_use_case.get_address_by_user_id(user_id).await {
         Ok(None) => {
@@ -263,6 +271,10 @@ where
     T: UserAddressRepository + Send + Sync + 'static,
     TPrivacy: crate::domain::repo::user_privacy_settings::UserPrivacySettingsRepository + Send + Sync + 'static,
 {
+    if let Err(e) = address_request.validate_coordinates() {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
     // Convert request to NewUserAddress with user_id from JWT token
     let address_data = address_request.into_new_address(user_id);
 
@@ -0,0 +1,242 @@
+use std::{future::Future, sync::Arc};
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            audit_event::{AuditAction, AuditTarget, NewAuditEvent},
+            login_throttle::ThrottleScope,
+            user_mfa::{MfaCodeRequest, MfaRecoveryCodesResponse},
+        },
+        usecase::{user::UserUseCase, user_mfa::UserMfaUseCase},
+    },
+    infrastructure::{
+        axum_http::{
+            middleware::{admin_authorization, user_authorization},
+            role_guard::{NotImpersonating, RequireAdmin, RequireCompany},
+            routers::{
+                audit_log::AdminAudit,
+                login_throttle::{ClientIp, LoginThrottleUseCasePostgres, throttle_error_response},
+            },
+        },
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{user::UserPostgres, user_mfa::UserMfaPostgres},
+        },
+    },
+};
+
+/// Router state: use cases + login rate limit / lockout (รหัส MFA ที่ผิดนับรวมกับการ login)
+type MfaState = (
+    Arc<UserMfaUseCase<UserMfaPostgres>>,
+    Arc<UserUseCase<UserPostgres>>,
+    Arc<LoginThrottleUseCasePostgres>,
+);
+
+fn build_state(db_pool: Arc<DbPool>, login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>) -> MfaState {
+    let user_mfa_use_case = UserMfaUseCase::new(Arc::new(UserMfaPostgres::new(Arc::clone(&db_pool))));
+    let user_use_case = UserUseCase::new(Arc::new(UserPostgres::new(db_pool)));
+    (Arc::new(user_mfa_use_case), Arc::new(user_use_case), login_throttle_use_case)
+}
+
+/// Protected routes - company users can opt in to TOTP (mounted under /api/user)
+pub fn routes(db_pool: Arc<DbPool>, login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>) -> Router {
+    Router::new()
+        .route("/mfa", get(get_mfa_status))
+        .route("/mfa/enroll", post(enroll_mfa))
+        .route("/mfa/confirm", post(confirm_mfa))
+        .route("/mfa/disable", post(disable_mfa))
+        .route("/mfa/recovery-codes", post(regenerate_company_recovery_codes))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(build_state(db_pool, login_throttle_use_case))
+}
+
+/// Admin routes - MFA is mandatory for admins, so only status and new recovery codes (mounted under /admin)
+pub fn admin_routes(db_pool: Arc<DbPool>, login_throttle_use_case: Arc<LoginThrottleUseCasePostgres>) -> Router {
+    Router::new()
+        .route("/mfa", get(get_admin_mfa_status))
+        .route("/mfa/recovery-codes", post(regenerate_admin_recovery_codes))
+        .with_state(build_state(db_pool, login_throttle_use_case))
+        .layer(middleware::from_fn(admin_authorization))
+}
+
+/// Map use case errors to HTTP status codes
+fn mfa_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("Invalid MFA code") {
+        (StatusCode::UNAUTHORIZED, message).into_response()
+    } else if message.contains("already enabled") {
+        (StatusCode::CONFLICT, message).into_response()
+    } else if message.contains("not enabled") || message.contains("not started") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// ตรวจรหัสปัจจุบันด้วย bucket ต่อ IP / ต่อ username และ lockout เดียวกับขั้น MFA ของ login
+/// รหัสผิดนับเข้า lockout, รหัสถูกล้างตัวนับ - บัญชีที่ถูกล็อกตอบ 429
+async fn throttled_code_attempt<R>(
+    (_, user_use_case, login_throttle_use_case): &MfaState,
+    user_id: Uuid,
+    ip_address: &str,
+    attempt: impl Future<Output = anyhow::Result<R>>,
+) -> Result<R, Response> {
+    let username = user_use_case
+        .get_user_by_id(user_id)
+        .await
+        .map_err(mfa_error_response)?
+        .username;
+
+    login_throttle_use_case
+        .check_login(ThrottleScope::Mfa, ip_address, &username)
+        .await
+        .map_err(throttle_error_response)?;
+
+    let result = attempt.await;
+    let tracked = match &result {
+        Ok(_) => login_throttle_use_case.record_success(&username).await,
+        Err(e) if e.to_string().contains("Invalid MFA code") => {
+            login_throttle_use_case
+                .record_failure(&username, ip_address)
+                .await
+        }
+        Err(_) => Ok(()),
+    };
+    if let Err(e) = tracked {
+        tracing::error!("Failed to record MFA attempt for {}: {}", username, e);
+    }
+
+    result.map_err(mfa_error_response)
+}
+
+fn recovery_codes_response(recovery_codes: Vec<String>) -> Response {
+    (StatusCode::OK, Json(MfaRecoveryCodesResponse { recovery_codes })).into_response()
+}
+
+/// MFA status of current company user
+/// GET /api/user/mfa
+pub async fn get_mfa_status(
+    State((user_mfa_use_case, _, _)): State<MfaState>,
+    RequireCompany(user_id): RequireCompany,
+) -> impl IntoResponse {
+    match user_mfa_use_case.get_status(user_id).await {
+        Ok(status) => (StatusCode::OK, Json(status)).into_response(),
+        Err(e) => mfa_error_response(e),
+    }
+}
+
+/// Start TOTP enrollment - returns the secret and otpauth:// URI for the QR code
+/// POST /api/user/mfa/enroll
+pub async fn enroll_mfa(
+    State((user_mfa_use_case, user_use_case, _)): State<MfaState>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+) -> impl IntoResponse {
+    let user = match user_use_case.get_user_by_id(user_id).await {
+        Ok(user) => user,
+        Err(e) => return mfa_error_response(e),
+    };
+
+    let account_name = user.email.as_deref().unwrap_or(&user.username);
+    match user_mfa_use_case.begin_enrollment(user_id, account_name).await {
+        Ok(enrollment) => (StatusCode::OK, Json(enrollment)).into_response(),
+        Err(e) => mfa_error_response(e),
+    }
+}
+
+/// Confirm the first code from the authenticator app and enable MFA
+/// POST /api/user/mfa/confirm
+pub async fn confirm_mfa(
+    State((user_mfa_use_case, _, _)): State<MfaState>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+    Json(request): Json<MfaCodeRequest>,
+) -> impl IntoResponse {
+    match user_mfa_use_case.confirm_enrollment(user_id, &request.code).await {
+        Ok(recovery_codes) => recovery_codes_response(recovery_codes),
+        Err(e) => mfa_error_response(e),
+    }
+}
+
+/// Turn MFA off (requires a current code)
+/// POST /api/user/mfa/disable
+pub async fn disable_mfa(
+    State(state): State<MfaState>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+    ClientIp(ip_address): ClientIp,
+    Json(request): Json<MfaCodeRequest>,
+) -> impl IntoResponse {
+    let attempt = state.0.disable(user_id, &request.code);
+    match throttled_code_attempt(&state, user_id, &ip_address, attempt).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(response) => response,
+    }
+}
+
+/// Replace recovery codes (requires a current code)
+/// POST /api/user/mfa/recovery-codes
+pub async fn regenerate_company_recovery_codes(
+    State(state): State<MfaState>,
+    _: NotImpersonating,
+    RequireCompany(user_id): RequireCompany,
+    ClientIp(ip_address): ClientIp,
+    Json(request): Json<MfaCodeRequest>,
+) -> impl IntoResponse {
+    let attempt = state.0.regenerate_recovery_codes(user_id, &request.code);
+    match throttled_code_attempt(&state, user_id, &ip_address, attempt).await {
+        Ok(recovery_codes) => recovery_codes_response(recovery_codes),
+        Err(response) => response,
+    }
+}
+
+/// MFA status of current admin
+/// GET /admin/mfa
+pub async fn get_admin_mfa_status(
+    State((user_mfa_use_case, _, _)): State<MfaState>,
+    RequireAdmin(user_id): RequireAdmin,
+) -> impl IntoResponse {
+    match user_mfa_use_case.get_status(user_id).await {
+        Ok(status) => (StatusCode::OK, Json(status)).into_response(),
+        Err(e) => mfa_error_response(e),
+    }
+}
+
+/// Replace admin recovery codes (requires a current code)
+/// POST /admin/mfa/recovery-codes
+pub async fn regenerate_admin_recovery_codes(
+    State(state): State<MfaState>,
+    RequireAdmin(user_id): RequireAdmin,
+    ClientIp(ip_address): ClientIp,
+    audit: AdminAudit,
+    Json(request): Json<MfaCodeRequest>,
+) -> impl IntoResponse {
+    let attempt = state.0.regenerate_recovery_codes(user_id, &request.code);
+    match throttled_code_attempt(&state, user_id, &ip_address, attempt).await {
+        Ok(recovery_codes) => {
+            if let Err(response) = audit
+                .record(NewAuditEvent::new(
+                    AuditAction::AdminRecoveryCodesRegenerate,
+                    AuditTarget::User,
+                    user_id,
+                ))
+                .await
+            {
+                return response;
+            }
+            recovery_codes_response(recovery_codes)
+        }
+        Err(response) => response,
+    }
+}
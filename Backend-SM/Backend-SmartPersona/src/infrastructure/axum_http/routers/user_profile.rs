@@ -12,7 +12,8 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::user_profile::UserProfileRequest, repo::user_profile::UserProfileRepository,
+        entities::{user::Role, user_profile::UserProfileRequest},
+        repo::user_profile::UserProfileRepository,
         repo::user::UserRepository,
         usecase::user_profile::UserProfileUseCase,
         usecase::user_privacy_settings::UserPrivacySettingsUseCase,
@@ -20,7 +21,7 @@ use crate::{
     },
     infrastructure::{
         axum_http::middleware::user_authorization,
-        jwt_authentication::jwt_model::{Claims, Roles},
+        jwt_authentication::jwt_model::Claims,
         postgres::{
             postgres_connection::DbPool,
             repositories::user_profile::UserProfilePostgres,
@@ -181,31 +182,10 @@ where
     TPrivacy: crate::domain::repo::user_privacy_settings::UserPrivacySettingsRepository + Send + Sync + 'static,
     TUser: UserRepository + Send + Sync + 'static,
 {
-    // Check if the requester is an admin
-    let mut is_admin = claims_opt
-        .as_ref()
-        .map(|claims| claims.role == Roles::Admin || claims.role == Roles::UserAndCompany)
-        .unwrap_or(false);
-
-    // Check if the requester is a CompanyUser
-    let mut is_company_user = false;
-    if let Some(claims) = &claims_opt {
-        if let Ok(requester_id) = Uuid::parse_str(&claims.sub) {
-            match user_use_case.get_user_by_id(requester_id).await {
-                Ok(user) => {
-                    tracing::info!("Requester found in DB: id={}, role={:?}", user.id, user.role);
-                    if user.role == crate::domain::entities::user::Role::CompanyUser {
-                        is_company_user = true;
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to fetch requester user info: {}", e);
-                }
-            }
-        } else {
-            tracing::warn!("Failed to parse requester ID from claims: {}", claims.sub);
-        }
-    }
+    // Check the requester's role from the JWT claims
+    let requester_role = claims_opt.as_ref().map(|claims| claims.role);
+    let is_admin = requester_role == Some(Role::Admin);
+    let is_company_user = requester_role == Some(Role::CompanyUser);
 
     // Log for debugging
     tracing::info!(
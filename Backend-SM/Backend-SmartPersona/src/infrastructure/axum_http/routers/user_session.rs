@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{repo::user_session::UserSessionRepository, usecase::user_session::UserSessionUseCase},
+    infrastructure::{
+        axum_http::{
+            middleware::{SessionStatusCache, user_authorization},
+            role_guard::NotImpersonating,
+        },
+        jwt_authentication::jwt_model::Claims,
+        postgres::{postgres_connection::DbPool, repositories::user_session::UserSessionPostgres},
+    },
+};
+
+/// Protected routes (require authentication) - manage current user's sessions/devices
+pub fn routes(db_pool: Arc<DbPool>) -> Router {
+    let user_session_repository = UserSessionPostgres::new(db_pool);
+    let user_session_use_case = Arc::new(UserSessionUseCase::new(Arc::new(user_session_repository)));
+
+    Router::new()
+        .route("/sessions", get(get_sessions))
+        .route("/sessions/revoke-others", post(revoke_other_sessions))
+        .route("/sessions/:id", delete(revoke_session))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state(user_session_use_case)
+}
+
+/// Periodically purge expired refresh token sessions
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let user_session_use_case = UserSessionUseCase::new(Arc::new(UserSessionPostgres::new(db_pool)));
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        if let Err(e) = user_session_use_case.cleanup_expired_sessions().await {
+            tracing::error!("Session cleanup failed: {}", e);
+        }
+    }
+}
+
+/// Session family of the token used for this request
+fn current_session_id(claims: &Claims) -> Option<Uuid> {
+    claims
+        .sid
+        .as_deref()
+        .and_then(|sid| Uuid::parse_str(sid).ok())
+}
+
+fn claims_user_id(claims: &Claims) -> Result<Uuid, StatusCode> {
+    Uuid::parse_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)
+}
+
+/// List active sessions/devices of current user
+/// GET /api/user/sessions
+pub async fn get_sessions<T>(
+    State(user_session_use_case): State<Arc<UserSessionUseCase<T>>>,
+    Extension(claims): Extension<Claims>,
+) -> impl IntoResponse
+where
+    T: UserSessionRepository + Send + Sync + 'static,
+{
+    let user_id = match claims_user_id(&claims) {
+        Ok(user_id) => user_id,
+        Err(status) => return status.into_response(),
+    };
+
+    match user_session_use_case
+        .get_active_sessions(user_id, current_session_id(&claims))
+        .await
+    {
+        Ok(sessions) => (StatusCode::OK, Json(sessions)).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Revoke one session (sign out that device)
+/// DELETE /api/user/sessions/:id
+pub async fn revoke_session<T>(
+    State(user_session_use_case): State<Arc<UserSessionUseCase<T>>>,
+    _: NotImpersonating,
+    Extension(session_status_cache): Extension<Arc<SessionStatusCache>>,
+    Extension(claims): Extension<Claims>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: UserSessionRepository + Send + Sync + 'static,
+{
+    let user_id = match claims_user_id(&claims) {
+        Ok(user_id) => user_id,
+        Err(status) => return status.into_response(),
+    };
+
+    match user_session_use_case.revoke_session(user_id, id).await {
+        Ok(_) => {
+            // Access tokens of the revoked device stop working right away
+            session_status_cache.invalidate_user(user_id).await;
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) if e.to_string().contains("not found") => {
+            (StatusCode::NOT_FOUND, e.to_string()).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// Revoke every session except the current one
+/// POST /api/user/sessions/revoke-others
+pub async fn revoke_other_sessions<T>(
+    State(user_session_use_case): State<Arc<UserSessionUseCase<T>>>,
+    _: NotImpersonating,
+    Extension(session_status_cache): Extension<Arc<SessionStatusCache>>,
+    Extension(claims): Extension<Claims>,
+) -> impl IntoResponse
+where
+    T: UserSessionRepository + Send + Sync + 'static,
+{
+    let user_id = match claims_user_id(&claims) {
+        Ok(user_id) => user_id,
+        Err(status) => return status.into_response(),
+    };
+
+    match user_session_use_case
+        .revoke_other_sessions(user_id, current_session_id(&claims))
+        .await
+    {
+        Ok(count) => {
+            session_status_cache.invalidate_user(user_id).await;
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({ "revoked_count": count })),
+            )
+                .into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post},
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::user_share::{
+            CreateShareRequest, CreateShareResponse, PublicProfileShare, ShareListResponse,
+        },
+        repo::{
+            user_privacy_settings::UserPrivacySettingsRepository,
+            user_share::ProfileShareRepository,
+        },
+        usecase::user_share::ProfileShareUseCase,
+    },
+    infrastructure::{
+        axum_http::middleware::user_authorization,
+        postgres::{
+            postgres_connection::DbPool,
+            repositories::{
+                user_privacy_settings::UserPrivacySettingsPostgres,
+                user_share::ProfileSharePostgres,
+            },
+        },
+    },
+};
+
+/// Custom extractor for user_id from JWT claims
+pub struct AuthenticatedUserId(pub Uuid);
+
+#[axum::async_trait]
+impl<S> axum::extract::FromRequestParts<S> for AuthenticatedUserId
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Uuid>()
+            .copied()
+            .map(AuthenticatedUserId)
+            .ok_or(StatusCode::UNAUTHORIZED)
+    }
+}
+
+type ProfileShareUseCasePostgres =
+    ProfileShareUseCase<ProfileSharePostgres, UserPrivacySettingsPostgres>;
+
+/// Router state: use case + frontend base URL (for building share links)
+type ShareState<T, TPrivacy> = (Arc<ProfileShareUseCase<T, TPrivacy>>, Arc<String>);
+
+fn build_use_case(db_pool: Arc<DbPool>) -> Arc<ProfileShareUseCasePostgres> {
+    let profile_share_repository = ProfileSharePostgres::new(Arc::clone(&db_pool));
+    let user_privacy_settings_repository = UserPrivacySettingsPostgres::new(db_pool);
+
+    Arc::new(ProfileShareUseCase::new(
+        Arc::new(profile_share_repository),
+        Arc::new(user_privacy_settings_repository),
+    ))
+}
+
+/// Protected routes (require authentication) - manage current user's share links
+pub fn routes(db_pool: Arc<DbPool>, frontend_url: String) -> Router {
+    let profile_share_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/shares", get(get_user_shares))
+        .route("/shares", post(create_share))
+        .route("/shares/expiring", get(get_expiring_shares))
+        .route("/shares/deactivate-all", post(deactivate_all_shares))
+        .route("/shares/:id", delete(delete_share))
+        .route("/shares/:id/activate", patch(activate_share))
+        .route("/shares/:id/deactivate", patch(deactivate_share))
+        .layer(middleware::from_fn(user_authorization))
+        .with_state((profile_share_use_case, Arc::new(frontend_url)))
+}
+
+/// Public routes (no authentication required)
+pub fn public_routes(db_pool: Arc<DbPool>) -> Router {
+    let profile_share_use_case = build_use_case(db_pool);
+
+    Router::new()
+        .route("/shared/:token", get(get_shared_profile))
+        .with_state(profile_share_use_case)
+}
+
+/// Periodically deactivate and purge expired share links
+pub async fn run_maintenance(db_pool: Arc<DbPool>, interval: std::time::Duration) {
+    let profile_share_use_case = build_use_case(db_pool);
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+        match profile_share_use_case.perform_maintenance().await {
+            Ok(report) if report.has_errors() => {
+                tracing::warn!(
+                    "Share link maintenance finished with {} error(s): {:?}",
+                    report.error_count(),
+                    report.errors
+                );
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Share link maintenance failed: {}", e),
+        }
+    }
+}
+
+/// Map use case errors to HTTP status codes
+fn share_error_response(e: anyhow::Error) -> Response {
+    let message = e.to_string();
+    if message.contains("not found") {
+        (StatusCode::NOT_FOUND, message).into_response()
+    } else if message.contains("Not authorized") {
+        (StatusCode::FORBIDDEN, message).into_response()
+    } else if message.contains("expires_hours") || message.contains("Cannot activate") {
+        (StatusCode::BAD_REQUEST, message).into_response()
+    } else {
+        (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+    }
+}
+
+/// Create a new share link for current user
+/// POST /api/user/shares
+pub async fn create_share<T, TPrivacy>(
+    State((profile_share_use_case, frontend_url)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Json(request): Json<CreateShareRequest>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case
+        .create_share_link(user_id, request.expires_hours)
+        .await
+    {
+        Ok(share) => {
+            let response = CreateShareResponse {
+                share_url: share.get_share_url(&frontend_url),
+                share_token: share.share_token,
+                expires_at: share.expires_at,
+            };
+            (StatusCode::CREATED, Json(response)).into_response()
+        }
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// Get all share links of current user with statistics
+/// GET /api/user/shares
+pub async fn get_user_shares<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    let shares = match profile_share_use_case.get_user_shares(user_id).await {
+        Ok(shares) => shares,
+        Err(e) => return share_error_response(e),
+    };
+
+    let statistics = match profile_share_use_case
+        .get_user_share_statistics(user_id)
+        .await
+    {
+        Ok(statistics) => statistics,
+        Err(e) => return share_error_response(e),
+    };
+
+    let response = ShareListResponse {
+        shares: shares.into_iter().map(PublicProfileShare::from).collect(),
+        statistics,
+    };
+
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// Query parameters for expiring shares endpoint
+#[derive(Debug, Deserialize)]
+pub struct ExpiringParams {
+    pub hours: Option<i64>,
+}
+
+/// Get current user's share links that expire within X hours (default 24)
+/// GET /api/user/shares/expiring?hours=24
+pub async fn get_expiring_shares<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Query(params): Query<ExpiringParams>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    let hours = params.hours.unwrap_or(24);
+
+    match profile_share_use_case
+        .get_expiring_soon_shares(user_id, hours)
+        .await
+    {
+        Ok(shares) => {
+            let shares: Vec<PublicProfileShare> =
+                shares.into_iter().map(PublicProfileShare::from).collect();
+            (StatusCode::OK, Json(shares)).into_response()
+        }
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// Activate a share link
+/// PATCH /api/user/shares/:id/activate
+pub async fn activate_share<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case.activate_share(id, user_id).await {
+        Ok(share) => (StatusCode::OK, Json(PublicProfileShare::from(share))).into_response(),
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// Deactivate a share link
+/// PATCH /api/user/shares/:id/deactivate
+pub async fn deactivate_share<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case.deactivate_share(id, user_id).await {
+        Ok(share) => (StatusCode::OK, Json(PublicProfileShare::from(share))).into_response(),
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// Deactivate every share link of current user
+/// POST /api/user/shares/deactivate-all
+pub async fn deactivate_all_shares<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case
+        .deactivate_all_user_shares(user_id)
+        .await
+    {
+        Ok(count) => (
+            StatusCode::OK,
+            Json(serde_json::json!({ "deactivated_count": count })),
+        )
+            .into_response(),
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// Delete a share link
+/// DELETE /api/user/shares/:id
+pub async fn delete_share<T, TPrivacy>(
+    State((profile_share_use_case, _)): State<ShareState<T, TPrivacy>>,
+    AuthenticatedUserId(user_id): AuthenticatedUserId,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case.delete_share(id, user_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// View a shared profile through its token (Public endpoint - no authentication required)
+/// GET /api/shared/:token
+pub async fn get_shared_profile<T, TPrivacy>(
+    State(profile_share_use_case): State<Arc<ProfileShareUseCase<T, TPrivacy>>>,
+    Path(token): Path<String>,
+) -> impl IntoResponse
+where
+    T: ProfileShareRepository + Send + Sync + 'static,
+    TPrivacy: UserPrivacySettingsRepository + Send + Sync + 'static,
+{
+    match profile_share_use_case.get_shared_profile(&token).await {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(e) => {
+            let message = e.to_string();
+            if message.contains("not found") {
+                (StatusCode::NOT_FOUND, message).into_response()
+            } else if message.contains("deactivated") || message.contains("expired") {
+                (StatusCode::GONE, message).into_response()
+            } else if message.contains("not available") {
+                (StatusCode::FORBIDDEN, message).into_response()
+            } else {
+                (StatusCode::INTERNAL_SERVER_ERROR, message).into_response()
+            }
+        }
+    }
+}
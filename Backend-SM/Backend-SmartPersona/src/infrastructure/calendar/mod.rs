@@ -0,0 +1,179 @@
+//! สร้างไฟล์ iCalendar (RFC 5545) สำหรับนัดสัมภาษณ์
+//!
+//! ใช้ทั้งไฟล์แนบในอีเมล (METHOD:REQUEST / CANCEL) และ ICS feed ที่ calendar app subscribe
+//! (METHOD:PUBLISH) - เวลาทั้งหมดเป็น UTC จึงไม่ต้องแนบ VTIMEZONE
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+const PRODUCT_ID: &str = "-//Smart Persona//Interviews//EN";
+
+/// RFC 5545 แนะนำให้ตัดบรรทัดที่ยาวเกิน 75 octets (ไม่นับ CRLF)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// เวลาที่แสดงในอีเมล (ผู้ใช้ส่วนใหญ่อยู่ประเทศไทย ไม่มี daylight saving)
+const DISPLAY_OFFSET_SECS: i32 = 7 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarMethod {
+    /// feed ที่ subscribe - ไม่มีการตอบรับ
+    Publish,
+    /// เชิญ / อัปเดตนัด
+    Request,
+    /// ยกเลิกนัด
+    Cancel,
+}
+
+impl CalendarMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalendarMethod::Publish => "PUBLISH",
+            CalendarMethod::Request => "REQUEST",
+            CalendarMethod::Cancel => "CANCEL",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarPerson {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    /// ต้องคงเดิมตลอดอายุนัด - calendar app ใช้ UID + SEQUENCE ในการอัปเดต / ยกเลิก
+    pub uid: String,
+    pub sequence: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub cancelled: bool,
+    pub organizer: Option<CalendarPerson>,
+    pub attendees: Vec<CalendarPerson>,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// VCALENDAR ทั้งไฟล์ (บรรทัดคั่นด้วย CRLF)
+pub fn calendar(method: CalendarMethod, name: Option<&str>, events: &[CalendarEvent]) -> String {
+    let now = Utc::now();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("METHOD:{}", method.as_str()),
+    ];
+    if let Some(name) = name {
+        lines.push(format!("X-WR-CALNAME:{}", escape_text(name)));
+    }
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", single_line(&event.uid)));
+        lines.push(format!("DTSTAMP:{}", format_utc(now)));
+        lines.push(format!("DTSTART:{}", format_utc(event.starts_at)));
+        lines.push(format!("DTEND:{}", format_utc(event.ends_at)));
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+        lines.push(format!("LAST-MODIFIED:{}", format_utc(event.last_modified)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{}", single_line(url)));
+        }
+        lines.push(format!(
+            "STATUS:{}",
+            if event.cancelled { "CANCELLED" } else { "CONFIRMED" }
+        ));
+        if let Some(organizer) = &event.organizer {
+            lines.push(format!(
+                "ORGANIZER;CN={}:mailto:{}",
+                quote_param(&organizer.name),
+                single_line(&organizer.email)
+            ));
+        }
+        for attendee in &event.attendees {
+            lines.push(format!(
+                "ATTENDEE;CN={};ROLE=REQ-PARTICIPANT;PARTSTAT=ACCEPTED:mailto:{}",
+                quote_param(&attendee.name),
+                single_line(&attendee.email)
+            ));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// ช่วงเวลาสำหรับแสดงในอีเมล เช่น "17/10/2026 14:00-15:00 (GMT+7)"
+pub fn display_range(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> String {
+    let offset = FixedOffset::east_opt(DISPLAY_OFFSET_SECS).expect("valid offset");
+    let (starts_at, ends_at) = (starts_at.with_timezone(&offset), ends_at.with_timezone(&offset));
+
+    let end_format = if starts_at.date_naive() == ends_at.date_naive() {
+        "%H:%M"
+    } else {
+        "%d/%m/%Y %H:%M"
+    };
+    format!(
+        "{}-{} (GMT+7)",
+        starts_at.format("%d/%m/%Y %H:%M"),
+        ends_at.format(end_format)
+    )
+}
+
+fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// TEXT value: escape backslash, ';', ',' และขึ้นบรรทัดใหม่
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\r', '\n'], "\\n")
+}
+
+/// ค่าที่ไม่ใช่ TEXT (UID, URI, mailto) escape ไม่ได้ - ตัด CR / LF ทิ้งกันการแทรก property ใหม่
+fn single_line(value: &str) -> String {
+    value.replace(['\r', '\n'], "")
+}
+
+/// parameter value ใส่ quote เสมอ - DQUOTE อยู่ข้างในไม่ได้
+fn quote_param(value: &str) -> String {
+    format!("\"{}\"", value.replace(['"', '\r', '\n'], ""))
+}
+
+/// ตัดบรรทัดยาวเป็นหลายบรรทัด (บรรทัดต่อขึ้นต้นด้วยช่องว่าง) โดยไม่ตัดกลางตัวอักษร UTF-8
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3);
+    let mut line_octets = 0;
+
+    for ch in line.chars() {
+        // บรรทัดต่อเสียไป 1 octet ให้ช่องว่างนำหน้า
+        if line_octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(ch);
+        line_octets += ch.len_utf8();
+    }
+
+    folded
+}
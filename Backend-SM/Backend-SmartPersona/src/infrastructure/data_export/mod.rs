@@ -0,0 +1,135 @@
+//! แพ็กข้อมูลส่วนบุคคลเป็น ZIP: manifest.json + json/<section>.json + csv/<section>.csv
+
+use std::io::{Cursor, Write};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+use crate::domain::entities::data_export::{DATA_EXPORT_FORMAT_VERSION, DataExportSection};
+
+/// BOM ให้ Excel อ่าน CSV ภาษาไทยเป็น UTF-8
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+pub struct ExportArchive {
+    pub bytes: Vec<u8>,
+    pub sha256: String,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// ค่าของหนึ่ง cell - object / array เก็บเป็น JSON
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// แปลง records เป็น CSV - หัวตารางคือ key ทั้งหมดตามลำดับที่พบครั้งแรก
+fn to_csv(records: &[Value]) -> Result<Vec<u8>> {
+    let mut columns: Vec<&str> = Vec::new();
+    for record in records {
+        if let Value::Object(fields) = record {
+            for key in fields.keys() {
+                if !columns.contains(&key.as_str()) {
+                    columns.push(key);
+                }
+            }
+        }
+    }
+
+    let mut writer = csv::Writer::from_writer(UTF8_BOM.to_vec());
+    writer.write_record(&columns)?;
+
+    let empty = Map::new();
+    for record in records {
+        let fields = record.as_object().unwrap_or(&empty);
+        writer.write_record(
+            columns
+                .iter()
+                .map(|column| fields.get(*column).map(csv_cell).unwrap_or_default()),
+        )?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| anyhow::anyhow!("Failed to write CSV: {}", e.error()))
+}
+
+/// สร้างไฟล์ ZIP คืนค่า bytes พร้อม SHA-256 ของทั้งไฟล์
+pub fn build_archive(
+    export_id: Uuid,
+    user_id: Uuid,
+    generated_at: DateTime<Utc>,
+    sections: &[DataExportSection],
+) -> Result<ExportArchive> {
+    let modified = zip::DateTime::try_from(generated_at.naive_utc())
+        .context("Export timestamp is outside the ZIP date range")?;
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .last_modified_time(modified);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut manifest_sections = Vec::with_capacity(sections.len());
+
+    for section in sections {
+        let files = [
+            (
+                format!("json/{}.json", section.name),
+                "json",
+                serde_json::to_vec_pretty(&section.records)?,
+            ),
+            (
+                format!("csv/{}.csv", section.name),
+                "csv",
+                to_csv(&section.records)?,
+            ),
+        ];
+
+        let mut manifest_files = Vec::with_capacity(files.len());
+        for (path, format, contents) in files {
+            zip.start_file(path.as_str(), options)?;
+            zip.write_all(&contents)?;
+
+            manifest_files.push(json!({
+                "path": path,
+                "format": format,
+                "size": contents.len(),
+                "sha256": sha256_hex(&contents),
+            }));
+        }
+
+        manifest_sections.push(json!({
+            "name": section.name,
+            "description": section.description,
+            "record_count": section.records.len(),
+            "files": manifest_files,
+        }));
+    }
+
+    let manifest = json!({
+        "format_version": DATA_EXPORT_FORMAT_VERSION,
+        "export_id": export_id,
+        "user_id": user_id,
+        "generated_at": generated_at,
+        "notes": [
+            "Each section is provided as JSON (exact values) and CSV (nested values encoded as JSON).",
+            "Passwords, sign-in secrets and third-party access tokens are never included.",
+        ],
+        "sections": manifest_sections,
+    });
+    zip.start_file("manifest.json", options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+
+    let bytes = zip.finish()?.into_inner();
+    let sha256 = sha256_hex(&bytes);
+
+    Ok(ExportArchive { bytes, sha256 })
+}
@@ -1,5 +1,5 @@
 pub mod service;
 
-pub use service::EmailService;
+pub use service::{EmailService, InterviewProposalEmail, JobAlertEmailPost, JobAlertEmailSection};
 
 
@@ -1,6 +1,9 @@
 use anyhow::{Context, Result};
 use lettre::{
-    message::{header::ContentType, Mailbox, Message, MultiPart, SinglePart},
+    message::{
+        header::{ContentType, HeaderName, HeaderValue},
+        Attachment, Mailbox, Message, MultiPart, SinglePart,
+    },
     transport::smtp::authentication::Credentials,
     AsyncSmtpTransport, AsyncTransport, Message as LettreMessage, Tokio1Executor,
 };
@@ -17,6 +20,36 @@ pub struct EmailService {
     from_name: String,
 }
 
+/// เนื้อหาอีเมลเชิญสัมภาษณ์ / เลื่อนนัด
+#[derive(Debug, Clone, Copy)]
+pub struct InterviewProposalEmail<'a> {
+    pub candidate_name: &'a str,
+    pub company_name: &'a str,
+    pub job_title: &'a str,
+    /// ช่วงเวลาที่เสนอ (จัดรูปแบบแล้ว)
+    pub slots: &'a [String],
+    pub place: &'a str,
+    pub note: Option<&'a str>,
+    pub rescheduled: bool,
+}
+
+/// การค้นหาหนึ่งรายการใน digest แจ้งงานใหม่
+#[derive(Debug, Clone)]
+pub struct JobAlertEmailSection {
+    pub search_name: String,
+    /// ยกเลิกแจ้งเตือนของการค้นหานี้ได้โดยไม่ต้อง login
+    pub unsubscribe_url: String,
+    pub posts: Vec<JobAlertEmailPost>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JobAlertEmailPost {
+    pub title: String,
+    pub company_name: String,
+    pub location: String,
+    pub job_type: String,
+}
+
 impl EmailService {
     pub fn new() -> Result<Self> {
         // Gmail SMTP configuration
@@ -78,6 +111,53 @@ impl EmailService {
         subject: &str,
         html_body: &str,
     ) -> Result<()> {
+        let body = MultiPart::alternative()
+            .singlepart(
+                SinglePart::builder()
+                    .header(ContentType::TEXT_HTML)
+                    .body(html_body.to_string()),
+            );
+
+        self.deliver(to, subject, body, Vec::new()).await
+    }
+
+    /// ส่งอีเมลพร้อมนัดแบบ iCalendar - ใส่ทั้ง part text/calendar (Gmail / Outlook แสดงเป็นคำเชิญ)
+    /// และไฟล์แนบ invite.ics สำหรับ client ที่ไม่อ่าน part นั้น
+    /// `method` ต้องตรงกับ METHOD ในไฟล์ (REQUEST / CANCEL)
+    pub async fn send_email_with_calendar(
+        &self,
+        to: &str,
+        subject: &str,
+        html_body: &str,
+        ics: &str,
+        method: &str,
+    ) -> Result<()> {
+        let calendar_type = ContentType::parse(&format!("text/calendar; charset=UTF-8; method={}", method))
+            .context("Invalid calendar content type")?;
+        let attachment_type = ContentType::parse("application/ics")
+            .context("Invalid attachment content type")?;
+
+        let body = MultiPart::mixed()
+            .multipart(
+                MultiPart::alternative()
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_HTML)
+                            .body(html_body.to_string()),
+                    )
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(calendar_type)
+                            .body(ics.to_string()),
+                    ),
+            )
+            .singlepart(Attachment::new("invite.ics".to_string()).body(ics.to_string(), attachment_type));
+
+        self.deliver(to, subject, body, Vec::new()).await
+    }
+
+    /// `headers` - header เพิ่มเติม เช่น List-Unsubscribe ของอีเมลแจ้งเตือน
+    async fn deliver(&self, to: &str, subject: &str, body: MultiPart, headers: Vec<HeaderValue>) -> Result<()> {
         // Parse email addresses
         let from_mailbox: Mailbox = format!("{} <{}>", self.from_name, self.from_email)
             .parse()
@@ -90,19 +170,13 @@ impl EmailService {
         info!("Sending email - From: {} <{}>, To: {}, Subject: {}", 
             self.from_name, self.from_email, to, subject);
 
-        // Create email message with HTML content
-        let email = Message::builder()
+        let email = headers
+            .into_iter()
+            .fold(Message::builder(), |builder, header| builder.raw_header(header))
             .from(from_mailbox.clone())
             .to(to_mailbox)
             .subject(subject)
-            .multipart(
-                MultiPart::alternative()
-                    .singlepart(
-                        SinglePart::builder()
-                            .header(ContentType::TEXT_HTML)
-                            .body(html_body.to_string()),
-                    )
-            )
+            .multipart(body)
             .context("Failed to build email message")?;
 
         // Create SMTP transport
@@ -302,6 +376,163 @@ impl EmailService {
         self.send_email(company_email, subject, &html_body).await
     }
 
+    /// Send the email verification link to a newly registered account
+    pub async fn send_email_verification_email(
+        &self,
+        to_email: &str,
+        username: &str,
+        token: &str,
+    ) -> Result<()> {
+        let subject = "ยืนยันอีเมลของคุณ - Smart Persona";
+
+        let frontend_url = std::env::var("FRONTEND_URL")
+            .unwrap_or_else(|_| "https://smartpersona.com".to_string());
+        let action_url = format!("{}/verify-email?token={}", frontend_url, token);
+
+        let html_body = format!(
+            r#"
+            <!DOCTYPE html>
+            <html>
+            <head>
+                <meta charset="UTF-8">
+                <style>
+                    body {{ font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif; line-height: 1.6; color: #333; }}
+                    .container {{ max-width: 600px; margin: 0 auto; padding: 20px; }}
+                    .header {{ background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 30px; text-align: center; border-radius: 10px 10px 0 0; }}
+                    .content {{ background: #f9f9f9; padding: 30px; border-radius: 0 0 10px 10px; }}
+                    .button {{ display: inline-block; padding: 12px 30px; background: #667eea; color: white; text-decoration: none; border-radius: 5px; margin: 20px 0; }}
+                    .footer {{ text-align: center; margin-top: 20px; color: #666; font-size: 12px; }}
+                </style>
+            </head>
+            <body>
+                <div class="container">
+                    <div class="header">
+                        <h1>ยืนยันอีเมลของคุณ</h1>
+                    </div>
+                    <div class="content">
+                        <p>สวัสดี <strong>{}</strong>,</p>
+                        <p>ขอบคุณที่สมัครใช้งาน Smart Persona กรุณากดปุ่มด้านล่างเพื่อยืนยันอีเมลและเปิดใช้งานบัญชีของคุณ</p>
+                        <a href="{}" class="button">ยืนยันอีเมล</a>
+                        <p>ลิงก์นี้ใช้ได้ครั้งเดียวและจะหมดอายุภายใน 24 ชั่วโมง</p>
+                        <p>หากคุณไม่ได้สมัครใช้งาน สามารถเพิกเฉยต่ออีเมลนี้ได้</p>
+                        <p>ทีมงาน Smart Persona</p>
+                    </div>
+                    <div class="footer">
+                        <p>อีเมลนี้ส่งโดยอัตโนมัติ กรุณาอย่าตอบกลับ</p>
+                    </div>
+                </div>
+            </body>
+            </html>
+            "#,
+            username, action_url
+        );
+
+        self.send_email(to_email, subject, &html_body).await
+    }
+
+    /// Send an invitation to become an administrator
+    pub async fn send_admin_invite_email(&self, to_email: &str, token: &str) -> Result<()> {
+        let subject = "คำเชิญเป็นผู้ดูแลระบบ - Smart Persona";
+
+        let frontend_url = std::env::var("FRONTEND_URL")
+            .unwrap_or_else(|_| "https://smartpersona.com".to_string());
+        let action_url = format!("{}/admin/accept-invite?token={}", frontend_url, token);
+
+        let html_body = format!(
+            r#"
+            <!DOCTYPE html>
+            <html>
+            <head>
+                <meta charset="UTF-8">
+                <style>
+                    body {{ font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif; line-height: 1.6; color: #333; }}
+                    .container {{ max-width: 600px; margin: 0 auto; padding: 20px; }}
+                    .header {{ background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 30px; text-align: center; border-radius: 10px 10px 0 0; }}
+                    .content {{ background: #f9f9f9; padding: 30px; border-radius: 0 0 10px 10px; }}
+                    .button {{ display: inline-block; padding: 12px 30px; background: #667eea; color: white; text-decoration: none; border-radius: 5px; margin: 20px 0; }}
+                    .footer {{ text-align: center; margin-top: 20px; color: #666; font-size: 12px; }}
+                </style>
+            </head>
+            <body>
+                <div class="container">
+                    <div class="header">
+                        <h1>คำเชิญเป็นผู้ดูแลระบบ</h1>
+                    </div>
+                    <div class="content">
+                        <p>สวัสดี,</p>
+                        <p>คุณได้รับเชิญให้เป็นผู้ดูแลระบบของ Smart Persona กรุณากดปุ่มด้านล่างเพื่อตั้งชื่อผู้ใช้และรหัสผ่าน</p>
+                        <a href="{}" class="button">ตอบรับคำเชิญ</a>
+                        <p>ลิงก์นี้ใช้ได้ครั้งเดียวและจะหมดอายุภายใน 3 วัน</p>
+                        <p>หากคุณไม่ได้คาดว่าจะได้รับอีเมลนี้ สามารถเพิกเฉยได้</p>
+                        <p>ทีมงาน Smart Persona</p>
+                    </div>
+                    <div class="footer">
+                        <p>อีเมลนี้ส่งโดยอัตโนมัติ กรุณาอย่าตอบกลับ</p>
+                    </div>
+                </div>
+            </body>
+            </html>
+            "#,
+            action_url
+        );
+
+        self.send_email(to_email, subject, &html_body).await
+    }
+
+    /// Send the password reset link requested from the forgot-password page
+    pub async fn send_password_reset_email(
+        &self,
+        to_email: &str,
+        username: &str,
+        token: &str,
+    ) -> Result<()> {
+        let subject = "รีเซ็ตรหัสผ่าน - Smart Persona";
+
+        let frontend_url = std::env::var("FRONTEND_URL")
+            .unwrap_or_else(|_| "https://smartpersona.com".to_string());
+        let action_url = format!("{}/reset-password?token={}", frontend_url, token);
+
+        let html_body = format!(
+            r#"
+            <!DOCTYPE html>
+            <html>
+            <head>
+                <meta charset="UTF-8">
+                <style>
+                    body {{ font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif; line-height: 1.6; color: #333; }}
+                    .container {{ max-width: 600px; margin: 0 auto; padding: 20px; }}
+                    .header {{ background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 30px; text-align: center; border-radius: 10px 10px 0 0; }}
+                    .content {{ background: #f9f9f9; padding: 30px; border-radius: 0 0 10px 10px; }}
+                    .button {{ display: inline-block; padding: 12px 30px; background: #667eea; color: white; text-decoration: none; border-radius: 5px; margin: 20px 0; }}
+                    .footer {{ text-align: center; margin-top: 20px; color: #666; font-size: 12px; }}
+                </style>
+            </head>
+            <body>
+                <div class="container">
+                    <div class="header">
+                        <h1>รีเซ็ตรหัสผ่าน</h1>
+                    </div>
+                    <div class="content">
+                        <p>สวัสดี <strong>{}</strong>,</p>
+                        <p>เราได้รับคำขอรีเซ็ตรหัสผ่านสำหรับบัญชีของคุณ กรุณากดปุ่มด้านล่างเพื่อตั้งรหัสผ่านใหม่</p>
+                        <a href="{}" class="button">ตั้งรหัสผ่านใหม่</a>
+                        <p>ลิงก์นี้ใช้ได้ครั้งเดียวและจะหมดอายุภายใน 1 ชั่วโมง</p>
+                        <p>หากคุณไม่ได้ส่งคำขอนี้ สามารถเพิกเฉยต่ออีเมลนี้ได้ รหัสผ่านเดิมของคุณจะยังใช้งานได้ตามปกติ</p>
+                        <p>ทีมงาน Smart Persona</p>
+                    </div>
+                    <div class="footer">
+                        <p>อีเมลนี้ส่งโดยอัตโนมัติ กรุณาอย่าตอบกลับ</p>
+                    </div>
+                </div>
+            </body>
+            </html>
+            "#,
+            username, action_url
+        );
+
+        self.send_email(to_email, subject, &html_body).await
+    }
+
     /// Send email notification to company when someone applies for a job
     pub async fn send_job_application_notification(
         &self,
@@ -500,5 +731,283 @@ impl EmailService {
 
         self.send_email(applicant_email, &subject, &html_body).await
     }
+
+    /// ผู้สมัครถอนใบสมัคร - แจ้งบริษัทเจ้าของประกาศ
+    pub async fn send_application_withdrawn_notification(
+        &self,
+        company_email: &str,
+        company_name: &str,
+        applicant_name: &str,
+        job_title: &str,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let subject = format!("↩️ ผู้สมัครถอนใบสมัครตำแหน่ง {} - Smart Persona", job_title);
+
+        let content = format!(
+            r#"<p><strong>{}</strong> ได้ถอนใบสมัครตำแหน่ง <strong>{}</strong> แล้ว</p>
+                        {}
+                        <p>ใบสมัครนี้จะไม่ถูกพิจารณาต่อ แต่ยังดูประวัติได้ในระบบ</p>"#,
+            escape_html(applicant_name),
+            escape_html(job_title),
+            reason
+                .map(|reason| format!("<p><strong>เหตุผล:</strong> {}</p>", escape_html(reason)))
+                .unwrap_or_default()
+        );
+
+        let html_body = notification_email_html("ผู้สมัครถอนใบสมัคร", company_name, &content);
+        self.send_email(company_email, &subject, &html_body).await
+    }
+
+    /// ประกาศปิดรับสมัคร (บริษัทปิดเอง / ถึงวันปิดรับ) - แจ้งผู้สมัครที่ใบสมัครยังไม่จบ
+    pub async fn send_post_closed_notification(
+        &self,
+        applicant_email: &str,
+        applicant_name: &str,
+        job_title: &str,
+        company_name: &str,
+        expired: bool,
+    ) -> Result<()> {
+        let subject = format!("📪 ตำแหน่ง {} ปิดรับสมัครแล้ว - Smart Persona", job_title);
+
+        let content = format!(
+            r#"<p>ประกาศตำแหน่ง <strong>{}</strong> ของ <strong>{}</strong> {}</p>
+                        <p>ใบสมัครของคุณยังอยู่ในระบบ บริษัทอาจติดต่อกลับเพื่อพิจารณาต่อ</p>"#,
+            escape_html(job_title),
+            escape_html(company_name),
+            if expired {
+                "ถึงวันปิดรับสมัครแล้ว"
+            } else {
+                "ปิดรับสมัครแล้ว"
+            }
+        );
+
+        let html_body = notification_email_html("ประกาศปิดรับสมัคร", applicant_name, &content);
+        self.send_email(applicant_email, &subject, &html_body).await
+    }
+
+    /// งานใหม่ที่ตรงกับการค้นหาที่บันทึกไว้ - รวมทุกการค้นหาของผู้ใช้ในฉบับเดียว
+    /// `unsubscribe_url` - ยกเลิกทุกการค้นหาในฉบับนี้ (header List-Unsubscribe)
+    pub async fn send_job_alert_digest(
+        &self,
+        to_email: &str,
+        recipient_name: &str,
+        sections: &[JobAlertEmailSection],
+        unsubscribe_url: &str,
+    ) -> Result<()> {
+        let total: usize = sections.iter().map(|section| section.posts.len()).sum();
+        let subject = format!("🔔 งานใหม่ {} ตำแหน่งที่ตรงกับการค้นหาของคุณ - Smart Persona", total);
+
+        let frontend_url = std::env::var("FRONTEND_URL")
+            .unwrap_or_else(|_| "https://smartpersona.com".to_string());
+        let section_html = sections
+            .iter()
+            .map(|section| {
+                let post_items = section
+                    .posts
+                    .iter()
+                    .map(|post| {
+                        format!(
+                            "<li><strong>{}</strong> - {}<br><small>{} · {}</small></li>",
+                            escape_html(&post.title),
+                            escape_html(&post.company_name),
+                            escape_html(&post.location),
+                            escape_html(&post.job_type)
+                        )
+                    })
+                    .collect::<String>();
+
+                format!(
+                    r#"<h3>{}</h3>
+                        <ul>{}</ul>
+                        <p><small><a href="{}">ยกเลิกการแจ้งเตือนของการค้นหานี้</a></small></p>"#,
+                    escape_html(&section.search_name),
+                    post_items,
+                    escape_html(&section.unsubscribe_url)
+                )
+            })
+            .collect::<String>();
+
+        let content = format!(
+            r#"<p>มีประกาศงานใหม่ที่ตรงกับการค้นหาที่คุณบันทึกไว้:</p>
+                        {}
+                        <a href="{}/jobs" class="button">ดูประกาศงาน</a>"#,
+            section_html,
+            frontend_url.trim_end_matches('/')
+        );
+
+        let html_body = notification_email_html("งานใหม่สำหรับคุณ", recipient_name, &content);
+        let body = MultiPart::alternative().singlepart(
+            SinglePart::builder()
+                .header(ContentType::TEXT_HTML)
+                .body(html_body),
+        );
+
+        // RFC 8058 one-click unsubscribe - mail client ส่ง POST ไปที่ URL นี้โดยตรง
+        let list_headers = vec![
+            HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                format!("<{}>", unsubscribe_url),
+            ),
+            HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                "List-Unsubscribe=One-Click".to_string(),
+            ),
+        ];
+        self.deliver(to_email, &subject, body, list_headers).await
+    }
+
+    /// บริษัทเสนอ (หรือเลื่อน) เวลาสัมภาษณ์ - ผู้สมัครเลือกช่วงเวลาในระบบ
+    pub async fn send_interview_proposal_email(
+        &self,
+        candidate_email: &str,
+        proposal: &InterviewProposalEmail<'_>,
+    ) -> Result<()> {
+        let InterviewProposalEmail {
+            candidate_name,
+            company_name,
+            job_title,
+            slots,
+            place,
+            note,
+            rescheduled,
+        } = *proposal;
+
+        let subject = if rescheduled {
+            format!("📅 {} ขอเลื่อนนัดสัมภาษณ์ตำแหน่ง {} - Smart Persona", company_name, job_title)
+        } else {
+            format!("📅 {} เชิญคุณสัมภาษณ์ตำแหน่ง {} - Smart Persona", company_name, job_title)
+        };
+
+        let frontend_url = std::env::var("FRONTEND_URL")
+            .unwrap_or_else(|_| "https://smartpersona.com".to_string());
+        let slot_items = slots
+            .iter()
+            .map(|slot| format!("<li>{}</li>", escape_html(slot)))
+            .collect::<String>();
+
+        let content = format!(
+            r#"<p><strong>{}</strong> {}สำหรับตำแหน่ง <strong>{}</strong> กรุณาเลือกช่วงเวลาที่สะดวก:</p>
+                        <ul>{}</ul>
+                        <p><strong>สถานที่:</strong> {}</p>
+                        {}
+                        <a href="{}/my-applications" class="button">เลือกเวลาสัมภาษณ์</a>"#,
+            escape_html(company_name),
+            if rescheduled { "ขอเลื่อนนัดสัมภาษณ์ (เวลาเดิมยกเลิกแล้ว) " } else { "เชิญคุณสัมภาษณ์งาน " },
+            escape_html(job_title),
+            slot_items,
+            escape_html(place),
+            note.map(|note| format!("<p><strong>หมายเหตุ:</strong> {}</p>", escape_html(note)))
+                .unwrap_or_default(),
+            frontend_url
+        );
+
+        let html_body = notification_email_html("นัดสัมภาษณ์งาน", candidate_name, &content);
+        self.send_email(candidate_email, &subject, &html_body).await
+    }
+
+    /// ยืนยันเวลาสัมภาษณ์พร้อมไฟล์ .ics (METHOD:REQUEST) - ส่งให้ทั้งผู้สมัครและบริษัท
+    pub async fn send_interview_scheduled_email(
+        &self,
+        to_email: &str,
+        recipient_name: &str,
+        summary: &str,
+        when: &str,
+        place: &str,
+        ics: &str,
+    ) -> Result<()> {
+        let subject = format!("✅ ยืนยันนัดสัมภาษณ์: {} - Smart Persona", summary);
+
+        let content = format!(
+            r#"<p>นัดสัมภาษณ์ได้รับการยืนยันแล้ว</p>
+                        <p><strong>{}</strong></p>
+                        <p><strong>เวลา:</strong> {}<br><strong>สถานที่:</strong> {}</p>
+                        <p>เปิดไฟล์แนบ invite.ics เพื่อเพิ่มนัดลงในปฏิทินของคุณ</p>"#,
+            escape_html(summary),
+            escape_html(when),
+            escape_html(place)
+        );
+
+        let html_body = notification_email_html("ยืนยันนัดสัมภาษณ์", recipient_name, &content);
+        self.send_email_with_calendar(to_email, &subject, &html_body, ics, "REQUEST")
+            .await
+    }
+
+    /// ยกเลิกนัดสัมภาษณ์ - แนบ .ics (METHOD:CANCEL) เมื่อนัดเคยถูกยืนยันลงปฏิทินแล้ว
+    pub async fn send_interview_cancelled_email(
+        &self,
+        to_email: &str,
+        recipient_name: &str,
+        summary: &str,
+        reason: Option<&str>,
+        ics: Option<&str>,
+    ) -> Result<()> {
+        let subject = format!("❌ ยกเลิกนัดสัมภาษณ์: {} - Smart Persona", summary);
+
+        let content = format!(
+            r#"<p>นัดสัมภาษณ์ <strong>{}</strong> ถูกยกเลิกแล้ว</p>
+                        {}"#,
+            escape_html(summary),
+            reason
+                .map(|reason| format!("<p><strong>เหตุผล:</strong> {}</p>", escape_html(reason)))
+                .unwrap_or_default()
+        );
+
+        let html_body = notification_email_html("ยกเลิกนัดสัมภาษณ์", recipient_name, &content);
+        match ics {
+            Some(ics) => {
+                self.send_email_with_calendar(to_email, &subject, &html_body, ics, "CANCEL")
+                    .await
+            }
+            None => self.send_email(to_email, &subject, &html_body).await,
+        }
+    }
+}
+
+/// ข้อความจากผู้ใช้ (ชื่อ, หมายเหตุ, สถานที่) ที่ใส่ลงในอีเมล HTML
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
 }
 
+/// โครงอีเมลแจ้งเตือน (นัดสัมภาษณ์ / ใบสมัคร) - `content` เป็น HTML ที่ escape แล้ว
+fn notification_email_html(heading: &str, recipient_name: &str, content: &str) -> String {
+    format!(
+        r#"
+            <!DOCTYPE html>
+            <html>
+            <head>
+                <meta charset="UTF-8">
+                <style>
+                    body {{ font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif; line-height: 1.6; color: #333; }}
+                    .container {{ max-width: 600px; margin: 0 auto; padding: 20px; }}
+                    .header {{ background: linear-gradient(135deg, #667eea 0%, #764ba2 100%); color: white; padding: 30px; text-align: center; border-radius: 10px 10px 0 0; }}
+                    .content {{ background: #f9f9f9; padding: 30px; border-radius: 0 0 10px 10px; }}
+                    .button {{ display: inline-block; padding: 12px 30px; background: #667eea; color: white; text-decoration: none; border-radius: 5px; margin: 20px 0; }}
+                    .footer {{ text-align: center; margin-top: 20px; color: #666; font-size: 12px; }}
+                </style>
+            </head>
+            <body>
+                <div class="container">
+                    <div class="header">
+                        <h1>{}</h1>
+                    </div>
+                    <div class="content">
+                        <p>สวัสดี คุณ<strong>{}</strong>,</p>
+                        {}
+                        <p>ทีมงาน Smart Persona</p>
+                    </div>
+                    <div class="footer">
+                        <p>อีเมลนี้ส่งโดยอัตโนมัติ กรุณาอย่าตอบกลับ</p>
+                    </div>
+                </div>
+            </body>
+            </html>
+            "#,
+        heading,
+        escape_html(recipient_name),
+        content
+    )
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::infrastructure::jwt_authentication::jwt_model::Passport;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct LoginModel {
     #[validate(length(min = 3, max = 50))]
@@ -8,3 +10,37 @@ pub struct LoginModel {
     #[validate(length(min = 8, max = 128))]
     pub password: String,
 }
+
+/// Second login step: the short-lived token from `MfaChallenge` + TOTP or recovery code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaLoginModel {
+    pub mfa_token: String,
+    pub code: String,
+}
+
+/// Admin without MFA yet: start enrollment with the pending token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaEnrollModel {
+    pub mfa_token: String,
+}
+
+/// Returned instead of a Passport when the account needs a second factor
+#[derive(Debug, Clone, Serialize)]
+pub struct MfaChallenge {
+    pub mfa_required: bool,
+    /// true when the account must enroll TOTP before it can sign in (admins)
+    pub enrollment_required: bool,
+    pub mfa_token: String,
+    pub expires_in: i64,
+}
+
+pub enum LoginOutcome {
+    Authenticated(Passport),
+    MfaRequired(MfaChallenge),
+}
+
+/// Result of the second login step - recovery codes only when MFA was just enabled
+pub struct MfaLoginResult {
+    pub passport: Passport,
+    pub recovery_codes: Option<Vec<String>>,
+}
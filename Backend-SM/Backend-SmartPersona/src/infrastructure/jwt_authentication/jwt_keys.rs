@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}};
+use jsonwebtoken::{DecodingKey, EncodingKey};
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde::Serialize;
+
+/// Ed25519 key ที่ใช้ sign / verify JWT (ระบุด้วย `kid`)
+pub struct JwtSigningKey {
+    pub kid: String,
+    pub encoding_key: EncodingKey,
+    pub decoding_key: DecodingKey,
+    /// Public key (base64url) สำหรับ JWKS
+    public_key: String,
+}
+
+impl JwtSigningKey {
+    /// อ่าน private key แบบ PKCS#8 PEM (`openssl genpkey -algorithm ed25519`)
+    pub fn from_pkcs8_pem(kid: String, pem: &str) -> Result<Self> {
+        let body: String = pem
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .map(str::trim)
+            .collect();
+        let der = STANDARD
+            .decode(body)
+            .with_context(|| format!("JWT key {}: invalid PEM encoding", kid))?;
+
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der)
+            .map_err(|_| anyhow::anyhow!("JWT key {}: not an Ed25519 PKCS#8 private key", kid))?;
+        let public_key = URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref());
+
+        Ok(Self {
+            encoding_key: EncodingKey::from_ed_der(&der),
+            decoding_key: DecodingKey::from_ed_components(&public_key)?,
+            public_key,
+            kid,
+        })
+    }
+}
+
+/// ชุด key ทั้งหมดที่ยังใช้ verify ได้ - sign ด้วย `active_kid` เท่านั้น
+/// หมุน key โดยเพิ่ม key ใหม่เป็น active แล้วเก็บ key เดิมไว้จน token เก่าหมดอายุ
+pub struct JwtKeySet {
+    pub issuer: String,
+    active_kid: String,
+    keys: Vec<JwtSigningKey>,
+}
+
+impl std::fmt::Debug for JwtKeySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeySet")
+            .field("issuer", &self.issuer)
+            .field("active_kid", &self.active_kid)
+            .field("kids", &self.keys.iter().map(|key| &key.kid).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl JwtKeySet {
+    pub fn new(issuer: String, active_kid: Option<String>, keys: Vec<JwtSigningKey>) -> Result<Self> {
+        let active_kid = match active_kid {
+            Some(kid) => kid,
+            None => keys
+                .first()
+                .map(|key| key.kid.clone())
+                .ok_or_else(|| anyhow::anyhow!("No JWT signing keys configured"))?,
+        };
+
+        if !keys.iter().any(|key| key.kid == active_kid) {
+            return Err(anyhow::anyhow!("JWT active kid {} is not configured", active_kid));
+        }
+
+        Ok(Self {
+            issuer,
+            active_kid,
+            keys,
+        })
+    }
+
+    /// `JWT_SIGNING_KEYS=kid1=/path/key1.pem,kid2=/path/key2.pem`
+    /// `JWT_ACTIVE_KID=kid2` (ค่าเริ่มต้นคือ key แรก)
+    pub fn from_env(issuer: String) -> Result<Self> {
+        let entries = std::env::var("JWT_SIGNING_KEYS").context("JWT_SIGNING_KEYS not set")?;
+
+        let keys = entries
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (kid, path) = entry
+                    .split_once('=')
+                    .ok_or_else(|| anyhow::anyhow!("Invalid JWT_SIGNING_KEYS entry: {}", entry))?;
+                let pem = std::fs::read_to_string(Path::new(path.trim()))
+                    .with_context(|| format!("Failed to read JWT key file {}", path))?;
+                JwtSigningKey::from_pkcs8_pem(kid.trim().to_string(), &pem)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let active_kid = std::env::var("JWT_ACTIVE_KID").ok().filter(|kid| !kid.is_empty());
+        Self::new(issuer, active_kid, keys)
+    }
+
+    pub fn signing_key(&self) -> &JwtSigningKey {
+        self.keys
+            .iter()
+            .find(|key| key.kid == self.active_kid)
+            .expect("active kid is validated in JwtKeySet::new")
+    }
+
+    pub fn find(&self, kid: &str) -> Option<&JwtSigningKey> {
+        self.keys.iter().find(|key| key.kid == kid)
+    }
+
+    /// Public keys สำหรับ `/.well-known/jwks.json`
+    pub fn jwks(&self) -> Jwks {
+        Jwks {
+            keys: self
+                .keys
+                .iter()
+                .map(|key| Jwk {
+                    kty: "OKP",
+                    crv: "Ed25519",
+                    alg: "EdDSA",
+                    key_use: "sig",
+                    kid: key.kid.clone(),
+                    x: key.public_key.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Jwk {
+    pub kty: &'static str,
+    pub crv: &'static str,
+    pub alg: &'static str,
+    #[serde(rename = "use")]
+    pub key_use: &'static str,
+    pub kid: String,
+    pub x: String,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::entities::user::Role;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
     pub access_token : String,
@@ -10,12 +12,59 @@ pub struct Passport {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
-    pub role: Roles,
+    pub role: Role,
     pub exp: usize,
-    pub iat: usize
+    pub iat: usize,
+    /// Token id - for refresh tokens this is the `user_sessions.jti` key
+    #[serde(default)]
+    pub jti: String,
+    /// Session family id shared by every token issued from the same login
+    #[serde(default)]
+    pub sid: Option<String>,
+    /// Password verified but the second factor is still pending - not a usable access token
+    #[serde(default)]
+    pub mfa_pending: bool,
+    /// Admin acting as this user (RFC 8693 `act` claim) - only set on impersonation tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<ImpersonationActor>,
+}
+
+impl Claims {
+    pub fn is_impersonation(&self) -> bool {
+        self.act.is_some()
+    }
+}
+
+/// ผู้กระทำจริงของ token impersonation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpersonationActor {
+    /// users.id ของ admin
+    pub sub: String,
+    /// อนุญาต request ที่แก้ไขข้อมูล (ค่าเริ่มต้นอ่านอย่างเดียว)
+    #[serde(default)]
+    pub write: bool,
+}
+
+/// ประเภทของ token - ใส่เป็น `aud` เพื่อไม่ให้ใช้ refresh token แทน access token
+/// หรือใช้ token ของ user แทน token ของ admin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    UserAccess,
+    UserRefresh,
+    AdminAccess,
+    AdminRefresh,
+    /// token ขั้นแรกของ login (รหัสผ่านผ่านแล้ว รอรหัส MFA) - รับเฉพาะที่ endpoint ยืนยัน MFA
+    MfaPending,
+}
+
+impl TokenKind {
+    pub fn audience(&self) -> &'static str {
+        match self {
+            TokenKind::UserAccess => "user",
+            TokenKind::UserRefresh => "user_refresh",
+            TokenKind::AdminAccess => "admin",
+            TokenKind::AdminRefresh => "admin_refresh",
+            TokenKind::MfaPending => "mfa_pending",
+        }
+    }
 }
-#[derive(Debug, Clone, Serialize, Deserialize,PartialEq)]
-pub enum Roles {
-    Admin,
-    UserAndCompany
-}
\ No newline at end of file
@@ -1,23 +1,56 @@
 pub mod authentication_model;
+pub mod jwt_keys;
 pub mod jwt_model;
 use anyhow::Result;
 
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, decode_header, encode, Algorithm, Header, Validation};
+use serde::Serialize;
 
-use crate::infrastructure::jwt_authentication::jwt_model::Claims;
+use crate::infrastructure::jwt_authentication::{
+    jwt_keys::JwtKeySet,
+    jwt_model::{Claims, TokenKind},
+};
+
+/// Claims + registered claims ที่ใส่ตอน sign (aud แยกประเภท token, iss ระบุผู้ออก)
+#[derive(Serialize)]
+struct SignedClaims<'a> {
+    #[serde(flatten)]
+    claims: &'a Claims,
+    aud: &'static str,
+    iss: &'a str,
+}
+
+pub fn generate_token(keys: &JwtKeySet, kind: TokenKind, claims: &Claims) -> Result<String> {
+    let signing_key = keys.signing_key();
+
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(signing_key.kid.clone());
 
-pub fn generate_token(secret: String,claims: &Claims) -> Result<String>{
     let token = encode(
-        &Header::default(), 
-        claims, 
-        &EncodingKey::from_secret(secret.as_ref())
+        &header,
+        &SignedClaims {
+            claims,
+            aud: kind.audience(),
+            iss: &keys.issuer,
+        },
+        &signing_key.encoding_key,
     )?;
     Ok(token)
 }
-pub fn verify_token(secret: String,token: String) -> Result<Claims>{
-    let result = decode(&token,
-         &DecodingKey::from_secret(secret.as_ref()),
-     &Validation::default())?;
+
+pub fn verify_token(keys: &JwtKeySet, kind: TokenKind, token: &str) -> Result<Claims> {
+    let header = decode_header(token)?;
+    let kid = header
+        .kid
+        .ok_or_else(|| anyhow::anyhow!("Token has no kid"))?;
+    let key = keys
+        .find(&kid)
+        .ok_or_else(|| anyhow::anyhow!("Unknown signing key: {}", kid))?;
+
+    let mut validation = Validation::new(Algorithm::EdDSA);
+    validation.set_audience(&[kind.audience()]);
+    validation.set_issuer(&[&keys.issuer]);
+
+    let result = decode::<Claims>(token, &key.decoding_key, &validation)?;
     Ok(result.claims)
 }
-// validation)
\ No newline at end of file
@@ -1,7 +1,12 @@
 pub mod ai_service_client;
 pub mod axum_http;
+pub mod calendar;
+pub mod data_export;
 pub mod email;
 pub mod hashingpassword;
 pub mod jwt_authentication;
+pub mod oidc;
 pub mod postgres;
+pub mod search;
 pub mod supabase;
+pub mod totp;
@@ -0,0 +1,228 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Validation, decode, decode_header,
+    jwk::{Jwk, JwkSet},
+};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+use crate::config::config_model::OidcProvider;
+
+/// โหลด JWKS ของ provider ใหม่ทุกชั่วโมง (หรือทันทีเมื่อเจอ kid ที่ไม่รู้จัก)
+const JWKS_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// ข้อมูลผู้ใช้จาก ID token ที่ตรวจสอบแล้ว
+#[derive(Debug, Clone)]
+pub struct OidcIdentityClaims {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    email: Option<String>,
+    /// บาง provider ส่งเป็น string "true"
+    #[serde(default)]
+    email_verified: Option<serde_json::Value>,
+    name: Option<String>,
+    preferred_username: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+/// OpenID Connect relying party: สร้าง authorization URL, แลก code และตรวจ ID token
+pub struct OidcClient {
+    providers: Vec<OidcProvider>,
+    http_client: Client,
+    jwks_cache: RwLock<HashMap<String, (JwkSet, Instant)>>,
+}
+
+impl OidcClient {
+    pub fn new(providers: Vec<OidcProvider>) -> Self {
+        Self {
+            providers,
+            http_client: Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .unwrap_or_default(),
+            jwks_cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn provider(&self, name: &str) -> Result<&OidcProvider> {
+        self.providers
+            .iter()
+            .find(|provider| provider.name == name)
+            .ok_or_else(|| anyhow::anyhow!("Unknown OIDC provider: {}", name))
+    }
+
+    pub fn provider_names(&self) -> Vec<String> {
+        self.providers.iter().map(|provider| provider.name.clone()).collect()
+    }
+
+    pub fn authorization_url(
+        &self,
+        provider_name: &str,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Result<String> {
+        let provider = self.provider(provider_name)?;
+
+        let url = Url::parse_with_params(
+            &provider.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", provider.client_id.as_str()),
+                ("redirect_uri", provider.redirect_uri.as_str()),
+                ("scope", provider.scopes.as_str()),
+                ("state", state),
+                ("nonce", nonce),
+                ("code_challenge", code_challenge),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .context("Invalid OIDC authorization endpoint")?;
+
+        Ok(url.to_string())
+    }
+
+    /// แลก authorization code เป็น ID token (token endpoint, client_secret_post)
+    pub async fn exchange_code(&self, provider_name: &str, code: &str, code_verifier: &str) -> Result<String> {
+        let provider = self.provider(provider_name)?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", provider.redirect_uri.as_str()),
+            ("client_id", provider.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(client_secret) = provider.client_secret.as_deref() {
+            form.push(("client_secret", client_secret));
+        }
+
+        let response = self
+            .http_client
+            .post(&provider.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .context("OIDC token request failed")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "OIDC token exchange failed ({}): {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            ));
+        }
+
+        response
+            .json::<TokenResponse>()
+            .await
+            .context("Invalid OIDC token response")?
+            .id_token
+            .ok_or_else(|| anyhow::anyhow!("OIDC token response has no id_token"))
+    }
+
+    /// ตรวจลายเซ็น (JWKS ของ provider), iss, aud, exp และ nonce ของ ID token
+    pub async fn verify_id_token(
+        &self,
+        provider_name: &str,
+        id_token: &str,
+        expected_nonce: &str,
+    ) -> Result<OidcIdentityClaims> {
+        let provider = self.provider(provider_name)?;
+        let header = decode_header(id_token).context("Invalid ID token")?;
+
+        // ไม่รับ HS* / none - ID token ต้อง sign ด้วย key ของ provider
+        if matches!(header.alg, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512) {
+            return Err(anyhow::anyhow!("Unsupported ID token algorithm: {:?}", header.alg));
+        }
+
+        let jwk = self.find_jwk(provider, header.kid.as_deref()).await?;
+        let decoding_key = DecodingKey::from_jwk(&jwk).context("Unsupported provider signing key")?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&[&provider.client_id]);
+        validation.set_issuer(&[&provider.issuer]);
+
+        let claims = decode::<IdTokenClaims>(id_token, &decoding_key, &validation)
+            .context("Invalid ID token")?
+            .claims;
+
+        if claims.nonce.as_deref() != Some(expected_nonce) {
+            return Err(anyhow::anyhow!("Invalid ID token: nonce mismatch"));
+        }
+
+        let email_verified = match claims.email_verified {
+            Some(serde_json::Value::Bool(verified)) => verified,
+            Some(serde_json::Value::String(verified)) => verified.eq_ignore_ascii_case("true"),
+            _ => false,
+        };
+
+        Ok(OidcIdentityClaims {
+            subject: claims.sub,
+            email: claims.email,
+            email_verified,
+            name: claims.name,
+            preferred_username: claims.preferred_username,
+        })
+    }
+
+    async fn find_jwk(&self, provider: &OidcProvider, kid: Option<&str>) -> Result<Jwk> {
+        if let Some((jwks, fetched_at)) = self.jwks_cache.read().await.get(&provider.name)
+            && fetched_at.elapsed() < JWKS_CACHE_TTL
+            && let Some(jwk) = select_jwk(jwks, kid)
+        {
+            return Ok(jwk);
+        }
+
+        // key ใหม่ของ provider (rotation) - โหลด JWKS ใหม่
+        let jwks = self
+            .http_client
+            .get(&provider.jwks_uri)
+            .send()
+            .await
+            .context("Failed to fetch provider JWKS")?
+            .error_for_status()
+            .context("Failed to fetch provider JWKS")?
+            .json::<JwkSet>()
+            .await
+            .context("Invalid provider JWKS")?;
+
+        let jwk = select_jwk(&jwks, kid);
+        self.jwks_cache
+            .write()
+            .await
+            .insert(provider.name.clone(), (jwks, Instant::now()));
+
+        jwk.ok_or_else(|| anyhow::anyhow!("Provider signing key not found"))
+    }
+}
+
+/// หา key ตาม kid - ถ้า token ไม่มี kid ใช้ได้เฉพาะเมื่อ JWKS มี key เดียว
+fn select_jwk(jwks: &JwkSet, kid: Option<&str>) -> Option<Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid).cloned(),
+        None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+        None => None,
+    }
+}
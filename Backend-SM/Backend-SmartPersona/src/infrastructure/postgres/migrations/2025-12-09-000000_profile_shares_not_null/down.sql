@@ -0,0 +1,5 @@
+ALTER TABLE profile_shares
+    ALTER COLUMN view_count DROP NOT NULL,
+    ALTER COLUMN is_active DROP NOT NULL,
+    ALTER COLUMN created_at DROP NOT NULL,
+    ALTER COLUMN updated_at DROP NOT NULL;
@@ -0,0 +1,11 @@
+-- เติมค่าให้แถวเก่าก่อนบังคับ NOT NULL
+UPDATE profile_shares SET view_count = 0 WHERE view_count IS NULL;
+UPDATE profile_shares SET is_active = true WHERE is_active IS NULL;
+UPDATE profile_shares SET created_at = NOW() WHERE created_at IS NULL;
+UPDATE profile_shares SET updated_at = NOW() WHERE updated_at IS NULL;
+
+ALTER TABLE profile_shares
+    ALTER COLUMN view_count SET NOT NULL,
+    ALTER COLUMN is_active SET NOT NULL,
+    ALTER COLUMN created_at SET NOT NULL,
+    ALTER COLUMN updated_at SET NOT NULL;
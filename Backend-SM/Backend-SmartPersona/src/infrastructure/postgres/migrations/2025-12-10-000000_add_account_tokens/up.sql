@@ -0,0 +1,19 @@
+-- อีเมลสำหรับยืนยันบัญชีและกู้คืนรหัสผ่าน (เก็บเป็นตัวพิมพ์เล็กเสมอ)
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email VARCHAR(255) UNIQUE;
+
+-- Token แบบใช้ครั้งเดียวสำหรับยืนยันอีเมล / รีเซ็ตรหัสผ่าน (เก็บเฉพาะ SHA-256 hash)
+CREATE TABLE IF NOT EXISTS account_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) UNIQUE NOT NULL,
+    purpose VARCHAR(50) NOT NULL CHECK (purpose IN ('email_verification', 'password_reset')),
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_tokens_user_id ON account_tokens(user_id);
+CREATE INDEX IF NOT EXISTS idx_account_tokens_expires_at ON account_tokens(expires_at);
+
+-- ผู้ใช้เดิมไม่เคยมีช่องทางยืนยันอีเมล จึงเปิดใช้งานให้ก่อน เพื่อไม่ให้ถูกล็อกออกจากระบบ
+UPDATE users SET status = 'active' WHERE status = 'pending';
@@ -0,0 +1,20 @@
+-- Refresh token sessions: หนึ่งแถวต่อ refresh token (jti)
+-- token ที่ถูก rotate แล้วจะอยู่ใน family เดียวกัน ใช้สำหรับตรวจจับการนำ token เก่ากลับมาใช้ซ้ำ
+CREATE TABLE IF NOT EXISTS user_sessions (
+    jti UUID PRIMARY KEY,
+    family_id UUID NOT NULL,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    scope VARCHAR(20) NOT NULL CHECK (scope IN ('user', 'admin')),
+    user_agent TEXT,
+    ip_address VARCHAR(64),
+    session_started_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    rotated_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    revoked_reason VARCHAR(50)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_sessions_family_id ON user_sessions(family_id);
+CREATE INDEX IF NOT EXISTS idx_user_sessions_user_id ON user_sessions(user_id);
+CREATE INDEX IF NOT EXISTS idx_user_sessions_expires_at ON user_sessions(expires_at);
@@ -0,0 +1,14 @@
+-- คำเชิญผู้ดูแลระบบ: บัญชี Admin สร้างได้เฉพาะผ่านคำเชิญจาก Admin หรือ bootstrap CLI
+CREATE TABLE IF NOT EXISTS admin_invites (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    email VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) UNIQUE NOT NULL,
+    invited_by UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    accepted_at TIMESTAMPTZ,
+    accepted_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_invites_email ON admin_invites(email);
+CREATE INDEX IF NOT EXISTS idx_admin_invites_expires_at ON admin_invites(expires_at);
@@ -0,0 +1,32 @@
+-- ตัวนับ rate limit แบบ fixed window (ใช้ร่วมกันทุก instance และไม่หายตอน restart)
+CREATE TABLE IF NOT EXISTS auth_rate_limits (
+    bucket_key VARCHAR(320) PRIMARY KEY,
+    window_started_at TIMESTAMPTZ NOT NULL,
+    hits INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX IF NOT EXISTS idx_auth_rate_limits_window_started_at ON auth_rate_limits(window_started_at);
+
+-- สถานะการล็อกบัญชีต่อ username (นับจำนวนครั้งที่ login ผิดติดกัน)
+CREATE TABLE IF NOT EXISTS login_lockouts (
+    username VARCHAR(255) PRIMARY KEY,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    lockout_level INTEGER NOT NULL DEFAULT 0,
+    locked_until TIMESTAMPTZ,
+    last_failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_ip_address VARCHAR(64)
+);
+
+-- ประวัติการล็อกบัญชี (ให้ Admin ตรวจสอบ)
+CREATE TABLE IF NOT EXISTS login_lockout_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    username VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(64),
+    lockout_level INTEGER NOT NULL,
+    failed_attempts INTEGER NOT NULL,
+    locked_until TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_login_lockout_events_username ON login_lockout_events(username);
+CREATE INDEX IF NOT EXISTS idx_login_lockout_events_created_at ON login_lockout_events(created_at);
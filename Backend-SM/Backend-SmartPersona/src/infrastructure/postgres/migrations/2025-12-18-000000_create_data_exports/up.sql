@@ -0,0 +1,22 @@
+-- คำขอสำเนาข้อมูลส่วนบุคคล (PDPA / GDPR) - ไฟล์ ZIP เก็บไว้ในตารางจนกว่าลิงก์ดาวน์โหลดจะหมดอายุ
+CREATE TABLE IF NOT EXISTS data_exports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    archive BYTEA,
+    archive_size BIGINT,
+    archive_sha256 VARCHAR(64),
+    download_token VARCHAR(64) UNIQUE,
+    error_message TEXT,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ,
+    downloaded_at TIMESTAMPTZ,
+    download_count INTEGER NOT NULL DEFAULT 0,
+    CONSTRAINT data_exports_status_check
+        CHECK (status IN ('pending', 'processing', 'ready', 'failed', 'expired'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_data_exports_user_id ON data_exports(user_id, requested_at DESC);
+CREATE INDEX IF NOT EXISTS idx_data_exports_status ON data_exports(status);
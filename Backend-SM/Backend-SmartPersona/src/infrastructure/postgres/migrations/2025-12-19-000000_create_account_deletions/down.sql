@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS account_deletions;
+-- PostgreSQL ลบค่าออกจาก enum ไม่ได้ - 'deleted' ใน user_status ยังคงอยู่
@@ -0,0 +1,24 @@
+-- บัญชีที่ถูกลบเหลือเป็น tombstone (ไม่มีข้อมูลส่วนบุคคล) เพื่อให้ record ที่ต้องเก็บตามกฎหมายยังอ้างอิงได้
+ALTER TYPE user_status ADD VALUE IF NOT EXISTS 'deleted';
+
+-- คำขอปิดบัญชี - ลบจริงเมื่อพ้น grace period (ยกเลิกได้ก่อนถึงเวลา)
+CREATE TABLE IF NOT EXISTS account_deletions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'scheduled',
+    reason TEXT,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    scheduled_for TIMESTAMPTZ NOT NULL,
+    cancelled_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    -- ไฟล์ใน storage ที่ลบไม่สำเร็จ (ให้ผู้ดูแลตามลบเอง)
+    storage_failures TEXT[] NOT NULL DEFAULT '{}',
+    CONSTRAINT account_deletions_status_check
+        CHECK (status IN ('scheduled', 'cancelled', 'completed'))
+);
+
+-- มีคำขอที่รอดำเนินการได้ครั้งละหนึ่งรายการต่อ user
+CREATE UNIQUE INDEX IF NOT EXISTS idx_account_deletions_scheduled_user
+    ON account_deletions(user_id) WHERE status = 'scheduled';
+CREATE INDEX IF NOT EXISTS idx_account_deletions_due
+    ON account_deletions(scheduled_for) WHERE status = 'scheduled';
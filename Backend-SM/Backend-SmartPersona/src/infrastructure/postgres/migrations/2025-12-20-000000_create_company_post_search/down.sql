@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_company_posts_created_at_id;
+DROP INDEX IF EXISTS idx_company_posts_tags;
+
+DROP TABLE IF EXISTS company_post_search;
@@ -0,0 +1,15 @@
+-- Full-text search index ของประกาศงาน
+-- search_vector สร้างจากฝั่งแอป (ตัดคำภาษาไทยแล้วส่งเป็น tsvector literal - ไม่ขึ้นกับ locale ของ database):
+--   A = title, B = tags, C = description / responsibilities / qualifications
+-- แยกจาก company_posts เพื่อไม่ให้การ index ไปเปลี่ยน updated_at (trigger set_timestamp)
+-- ประกาศที่ไม่มีแถวที่นี่ หรือ indexed_at < company_posts.updated_at จะถูก index ใหม่โดย job เบื้องหลัง
+CREATE TABLE IF NOT EXISTS company_post_search (
+    post_id UUID PRIMARY KEY REFERENCES company_posts(id) ON DELETE CASCADE,
+    search_vector TSVECTOR NOT NULL,
+    indexed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_company_post_search_vector ON company_post_search USING GIN (search_vector);
+
+CREATE INDEX IF NOT EXISTS idx_company_posts_tags ON company_posts USING GIN (tags);
+CREATE INDEX IF NOT EXISTS idx_company_posts_created_at_id ON company_posts (created_at DESC, id DESC);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_company_posts_coordinates;
+
+ALTER TABLE user_addresses DROP CONSTRAINT IF EXISTS user_addresses_coordinates_check;
+ALTER TABLE user_addresses DROP COLUMN IF EXISTS longitude;
+ALTER TABLE user_addresses DROP COLUMN IF EXISTS latitude;
@@ -0,0 +1,14 @@
+-- ค้นหางานตามระยะทาง
+-- พิกัดของที่อยู่ผู้ใช้ใช้เป็นจุดเริ่มต้นของ "งานใกล้ฉัน" เมื่อไม่ได้ส่งพิกัดมา
+ALTER TABLE user_addresses ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION;
+ALTER TABLE user_addresses ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+ALTER TABLE user_addresses DROP CONSTRAINT IF EXISTS user_addresses_coordinates_check;
+ALTER TABLE user_addresses ADD CONSTRAINT user_addresses_coordinates_check CHECK (
+    (latitude IS NULL AND longitude IS NULL)
+    OR (latitude BETWEEN -90 AND 90 AND longitude BETWEEN -180 AND 180)
+);
+
+-- bounding-box prefilter ก่อนคำนวณ haversine
+CREATE INDEX IF NOT EXISTS idx_company_posts_coordinates ON company_posts (latitude, longitude)
+    WHERE latitude IS NOT NULL AND longitude IS NOT NULL;
@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS idx_company_posts_salary;
+
+ALTER TABLE company_posts DROP CONSTRAINT IF EXISTS company_posts_salary_check;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS salary_negotiable;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS salary_period;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS salary_currency;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS salary_max;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS salary_min;
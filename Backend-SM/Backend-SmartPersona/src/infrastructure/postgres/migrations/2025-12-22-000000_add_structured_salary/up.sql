@@ -0,0 +1,78 @@
+-- เงินเดือนแบบมีโครงสร้าง - salary_range ยังเก็บไว้เป็นข้อความที่แสดงผล
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS salary_min BIGINT;
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS salary_max BIGINT;
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS salary_currency VARCHAR(3) NOT NULL DEFAULT 'THB';
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS salary_period VARCHAR(10) NOT NULL DEFAULT 'monthly';
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS salary_negotiable BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Backfill จาก salary_range เดิม ด้วยกฎเดียวกับ Salary::parse (domain/value_object/salary.rs)
+-- ตัวเลข: "25,000" = 25000, "30k" = 30000 / สองตัวแรกคือช่วง / ตัวเดียวดูจากคำว่า "ไม่เกิน", "ขึ้นไป"
+-- "k" ตามด้วยตัวอักษร ASCII ไม่นับเป็นพัน ("30kบาท" นับ, "30 kbps" ไม่นับ) - [[:alnum:]] รวมอักษรไทยด้วยจึงใช้ไม่ได้
+-- ข้ามตัวเลขที่ส่วนจำนวนเต็มยาวเกิน 15 หลัก (เบอร์โทร / เลขบัญชี) - ไม่ใช่เงินเดือนและล้น BIGINT
+-- ไม่แตะ updated_at ของประกาศ (set_timestamp trigger) เพราะเนื้อหาประกาศไม่ได้เปลี่ยน
+ALTER TABLE company_posts DISABLE TRIGGER set_timestamp;
+
+WITH parsed AS (
+    SELECT
+        company_posts.id,
+        source.text,
+        ARRAY(
+            SELECT round(
+                replace(m.match[1], ',', '')::numeric
+                * CASE WHEN m.match[2] IS NULL THEN 1 ELSE 1000 END
+            )::bigint
+            FROM regexp_matches(
+                source.text,
+                '([0-9][0-9,]*(?:\.[0-9]+)?)( *k(?![a-z0-9]))?',
+                'g'
+            ) WITH ORDINALITY AS m(match, n)
+            WHERE length(split_part(replace(m.match[1], ',', ''), '.', 1)) <= 15
+            ORDER BY m.n
+        ) AS amounts
+    FROM company_posts
+    CROSS JOIN LATERAL (SELECT lower(trim(company_posts.salary_range)) AS text) AS source
+    WHERE company_posts.salary_range IS NOT NULL
+)
+UPDATE company_posts
+SET
+    salary_min = CASE
+        WHEN cardinality(parsed.amounts) >= 2 THEN LEAST(parsed.amounts[1], parsed.amounts[2])
+        WHEN cardinality(parsed.amounts) = 1 AND parsed.text ~ '(ไม่เกิน|สูงสุด|up to|max)' THEN NULL
+        WHEN cardinality(parsed.amounts) = 1 THEN parsed.amounts[1]
+    END,
+    salary_max = CASE
+        WHEN cardinality(parsed.amounts) >= 2 THEN GREATEST(parsed.amounts[1], parsed.amounts[2])
+        WHEN cardinality(parsed.amounts) = 1 AND parsed.text ~ '(ไม่เกิน|สูงสุด|up to|max)' THEN parsed.amounts[1]
+        WHEN cardinality(parsed.amounts) = 1
+            AND parsed.text ~ '(ขึ้นไป|เริ่มต้น|อย่างน้อย|\+|up|from|starting|min)' THEN NULL
+        WHEN cardinality(parsed.amounts) = 1 THEN parsed.amounts[1]
+    END,
+    salary_currency = CASE
+        WHEN parsed.text ~ '(\$|usd|ดอลลาร์)' THEN 'USD'
+        WHEN parsed.text ~ '(€|eur|ยูโร)' THEN 'EUR'
+        WHEN parsed.text ~ '(¥|jpy|เยน)' THEN 'JPY'
+        ELSE 'THB'
+    END,
+    salary_period = CASE
+        WHEN parsed.text ~ '(ชั่วโมง|ชม\.|/hr|hour)' THEN 'hourly'
+        WHEN parsed.text ~ '(รายวัน|ต่อวัน|/วัน|/day|daily|per day)' THEN 'daily'
+        WHEN parsed.text ~ '(ต่อปี|/ปี|/year|/yr|yearly|annual|per year)' THEN 'yearly'
+        ELSE 'monthly'
+    END,
+    salary_negotiable = parsed.text ~ '(ตามตกลง|ตามประสบการณ์|ตามโครงสร้าง|ต่อรอง|negotiable|nego)'
+FROM parsed
+WHERE company_posts.id = parsed.id;
+
+ALTER TABLE company_posts ENABLE TRIGGER set_timestamp;
+
+ALTER TABLE company_posts DROP CONSTRAINT IF EXISTS company_posts_salary_check;
+ALTER TABLE company_posts ADD CONSTRAINT company_posts_salary_check CHECK (
+    (salary_min IS NULL OR salary_min >= 0)
+    AND (salary_max IS NULL OR salary_max >= 0)
+    AND (salary_min IS NULL OR salary_max IS NULL OR salary_min <= salary_max)
+    AND salary_period IN ('hourly', 'daily', 'monthly', 'yearly')
+);
+
+-- กรอง / เรียงตามเงินเดือนภายในหน่วยเวลาและสกุลเงินเดียวกัน
+CREATE INDEX IF NOT EXISTS idx_company_posts_salary
+    ON company_posts (salary_period, salary_currency, salary_max, salary_min);
@@ -0,0 +1,8 @@
+DROP TABLE IF EXISTS application_status_history;
+
+ALTER TABLE job_applications DROP CONSTRAINT IF EXISTS job_applications_status_check;
+ALTER TABLE job_applications ALTER COLUMN status SET DEFAULT 'pending';
+
+UPDATE job_applications SET status = 'pending' WHERE status = 'applied';
+UPDATE job_applications SET status = 'accepted' WHERE status IN ('screening', 'interview', 'offer', 'hired');
+UPDATE job_applications SET status = 'rejected' WHERE status = 'withdrawn';
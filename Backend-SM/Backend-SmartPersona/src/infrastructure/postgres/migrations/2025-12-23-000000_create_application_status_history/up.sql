@@ -0,0 +1,45 @@
+-- ขั้นตอนของใบสมัคร: applied → screening → interview → offer → hired (+ rejected / withdrawn)
+-- สถานะเดิม: pending = ยังไม่ได้พิจารณา, accepted = บริษัทสนใจและจะติดต่อกลับ (อีเมลแจ้งผู้สมัคร)
+UPDATE job_applications SET status = 'applied' WHERE status = 'pending';
+UPDATE job_applications SET status = 'screening' WHERE status = 'accepted';
+
+ALTER TABLE job_applications ALTER COLUMN status SET DEFAULT 'applied';
+ALTER TABLE job_applications DROP CONSTRAINT IF EXISTS job_applications_status_check;
+ALTER TABLE job_applications ADD CONSTRAINT job_applications_status_check CHECK (
+    status IN ('applied', 'screening', 'interview', 'offer', 'hired', 'rejected', 'withdrawn')
+);
+
+-- ทุกการเปลี่ยนสถานะ (append-only) - แสดงเป็น timeline ให้ทั้งผู้สมัครและบริษัท
+CREATE TABLE IF NOT EXISTS application_status_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    application_id UUID NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+    -- NULL = ตอนยื่นใบสมัคร
+    from_status VARCHAR(20),
+    to_status VARCHAR(20) NOT NULL,
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    actor_role VARCHAR(20) NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT application_status_history_actor_role_check
+        CHECK (actor_role IN ('candidate', 'company', 'system'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_application_status_history_application
+    ON application_status_history(application_id, created_at);
+
+-- ประวัติของใบสมัครที่มีอยู่ก่อน: ยื่นใบสมัคร แล้วสถานะปัจจุบัน (ไม่รู้ว่าใครเปลี่ยน)
+INSERT INTO application_status_history (application_id, from_status, to_status, actor_id, actor_role, created_at)
+SELECT id, NULL, 'applied', user_id, 'candidate', created_at
+FROM job_applications
+WHERE NOT EXISTS (
+    SELECT 1 FROM application_status_history h WHERE h.application_id = job_applications.id
+);
+
+INSERT INTO application_status_history (application_id, from_status, to_status, actor_role, note, created_at)
+SELECT id, 'applied', status, 'system', 'Migrated from the previous status model', updated_at
+FROM job_applications
+WHERE status <> 'applied'
+  AND NOT EXISTS (
+    SELECT 1 FROM application_status_history h
+    WHERE h.application_id = job_applications.id AND h.from_status IS NOT NULL
+);
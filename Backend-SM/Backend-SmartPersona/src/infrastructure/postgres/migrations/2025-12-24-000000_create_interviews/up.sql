@@ -0,0 +1,53 @@
+-- นัดสัมภาษณ์ของใบสมัครที่อยู่ในขั้น interview
+-- บริษัทเสนอช่วงเวลา (interview_slots) แล้วผู้สมัครเลือกหนึ่งช่วง → scheduled
+CREATE TABLE IF NOT EXISTS interviews (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    application_id UUID NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'proposed',
+    location TEXT,
+    video_url TEXT,
+    note TEXT,
+    -- ช่วงเวลาที่ผู้สมัครเลือก (NULL จนกว่าจะ scheduled)
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    -- SEQUENCE ของ VEVENT (RFC 5545) - เพิ่มทุกครั้งที่เวลา / สถานะเปลี่ยน
+    sequence INTEGER NOT NULL DEFAULT 0,
+    cancel_reason TEXT,
+    cancelled_at TIMESTAMPTZ,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT interviews_status_check CHECK (status IN ('proposed', 'scheduled', 'cancelled')),
+    CONSTRAINT interviews_place_check CHECK (location IS NOT NULL OR video_url IS NOT NULL),
+    CONSTRAINT interviews_time_check CHECK (
+        (starts_at IS NULL AND ends_at IS NULL) OR ends_at > starts_at
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_interviews_application_id ON interviews(application_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_interviews_starts_at ON interviews(starts_at) WHERE starts_at IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS interview_slots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    interview_id UUID NOT NULL REFERENCES interviews(id) ON DELETE CASCADE,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT interview_slots_time_check CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_interview_slots_interview_id ON interview_slots(interview_id, starts_at);
+
+-- token ใน URL ของ ICS feed (subscribe จาก calendar app ซึ่งส่ง header Authorization ไม่ได้)
+-- เก็บค่าจริงเหมือน share token เพราะต้องแสดง URL ซ้ำได้ - ขอ token ใหม่เพื่อยกเลิก URL เดิม
+CREATE TABLE IF NOT EXISTS calendar_feeds (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    feed_token VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+DROP TRIGGER IF EXISTS set_timestamp ON interviews;
+CREATE TRIGGER set_timestamp
+    BEFORE UPDATE ON interviews
+    FOR EACH ROW
+    EXECUTE PROCEDURE trigger_set_timestamp();
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS application_screening_answers;
+DROP INDEX IF EXISTS idx_job_applications_job_id_knocked_out;
+ALTER TABLE job_applications DROP COLUMN IF EXISTS knocked_out;
+ALTER TABLE job_applications DROP COLUMN IF EXISTS resume_url;
+ALTER TABLE job_applications DROP COLUMN IF EXISTS cover_letter;
+DROP TABLE IF EXISTS post_screening_questions;
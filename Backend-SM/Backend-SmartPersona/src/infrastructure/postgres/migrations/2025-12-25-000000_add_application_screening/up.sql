@@ -0,0 +1,40 @@
+-- คำถามคัดกรองของประกาศงาน (บริษัทตั้ง) - ผู้สมัครตอบตอนยื่นใบสมัคร
+-- knockout_answers: คำตอบที่ทำให้ไม่ผ่านเกณฑ์ (ใช้ได้กับ yes_no / multiple_choice)
+CREATE TABLE IF NOT EXISTS post_screening_questions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    post_id UUID NOT NULL REFERENCES company_posts(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    prompt TEXT NOT NULL,
+    required BOOLEAN NOT NULL DEFAULT TRUE,
+    options TEXT[] NOT NULL DEFAULT '{}',
+    knockout_answers TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT post_screening_questions_kind_check CHECK (kind IN ('text', 'yes_no', 'multiple_choice'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_post_screening_questions_post_id ON post_screening_questions(post_id, position);
+
+-- จดหมายสมัครงาน / resume (ไฟล์ที่ upload ผ่าน storage) / ผลคัดกรอง
+ALTER TABLE job_applications ADD COLUMN IF NOT EXISTS cover_letter TEXT;
+ALTER TABLE job_applications ADD COLUMN IF NOT EXISTS resume_url TEXT;
+ALTER TABLE job_applications ADD COLUMN IF NOT EXISTS knocked_out BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX IF NOT EXISTS idx_job_applications_job_id_knocked_out ON job_applications(job_id, knocked_out);
+
+-- คำตอบของผู้สมัคร - เก็บ kind / prompt ไว้ด้วย เพราะบริษัทแก้คำถามของประกาศได้ภายหลัง
+CREATE TABLE IF NOT EXISTS application_screening_answers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    application_id UUID NOT NULL REFERENCES job_applications(id) ON DELETE CASCADE,
+    question_id UUID REFERENCES post_screening_questions(id) ON DELETE SET NULL,
+    position INTEGER NOT NULL,
+    kind VARCHAR(20) NOT NULL,
+    prompt TEXT NOT NULL,
+    -- NULL = ไม่ได้ตอบคำถามที่ไม่บังคับ
+    answer TEXT,
+    knocked_out BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_application_screening_answers_application_id
+    ON application_screening_answers(application_id, position);
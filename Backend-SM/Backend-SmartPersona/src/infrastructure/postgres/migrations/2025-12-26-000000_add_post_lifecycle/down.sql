@@ -0,0 +1,22 @@
+DROP INDEX IF EXISTS idx_company_posts_closes_at;
+DROP INDEX IF EXISTS idx_company_posts_publish_at;
+
+ALTER TABLE company_posts DROP CONSTRAINT IF EXISTS company_posts_status_check;
+ALTER TABLE company_posts ALTER COLUMN status SET DEFAULT 'Active';
+
+ALTER TABLE company_posts DISABLE TRIGGER set_timestamp;
+
+UPDATE company_posts
+SET status = CASE status
+    WHEN 'published' THEN 'active'
+    WHEN 'scheduled' THEN 'draft'
+    WHEN 'expired' THEN 'closed'
+    ELSE status
+END;
+
+ALTER TABLE company_posts ENABLE TRIGGER set_timestamp;
+
+ALTER TABLE company_posts DROP COLUMN IF EXISTS closed_at;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS published_at;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS closes_at;
+ALTER TABLE company_posts DROP COLUMN IF EXISTS publish_at;
@@ -0,0 +1,43 @@
+-- วงจรชีวิตของประกาศ: draft → scheduled → published → closed / expired
+-- publish_at / closes_at: เวลาที่ตั้งไว้ (scheduler เปลี่ยนสถานะให้)
+-- published_at / closed_at: เวลาที่เปลี่ยนสถานะจริง
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS publish_at TIMESTAMPTZ;
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS closes_at TIMESTAMPTZ;
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS published_at TIMESTAMPTZ;
+ALTER TABLE company_posts ADD COLUMN IF NOT EXISTS closed_at TIMESTAMPTZ;
+
+-- สถานะเดิมเป็นข้อความอิสระ ("Active" / "active" / "draft" / "closed" ...)
+-- ไม่แตะ updated_at ของประกาศ (set_timestamp trigger) เพราะเนื้อหาประกาศไม่ได้เปลี่ยน
+ALTER TABLE company_posts DISABLE TRIGGER set_timestamp;
+
+UPDATE company_posts
+SET
+    status = CASE
+        WHEN lower(trim(status)) IN ('active', 'published', 'open') THEN 'published'
+        WHEN lower(trim(status)) = 'draft' THEN 'draft'
+        ELSE 'closed'
+    END,
+    published_at = CASE
+        WHEN lower(trim(status)) = 'draft' THEN NULL
+        ELSE created_at
+    END,
+    closed_at = CASE
+        WHEN lower(trim(status)) IN ('active', 'published', 'open', 'draft') THEN NULL
+        ELSE updated_at
+    END;
+
+ALTER TABLE company_posts ENABLE TRIGGER set_timestamp;
+
+ALTER TABLE company_posts ALTER COLUMN status SET DEFAULT 'draft';
+
+ALTER TABLE company_posts DROP CONSTRAINT IF EXISTS company_posts_status_check;
+ALTER TABLE company_posts ADD CONSTRAINT company_posts_status_check CHECK (
+    status IN ('draft', 'scheduled', 'published', 'closed', 'expired')
+    AND (status <> 'scheduled' OR publish_at IS NOT NULL)
+);
+
+-- scheduler หาประกาศที่ถึงเวลาเผยแพร่ / ปิดรับ
+CREATE INDEX IF NOT EXISTS idx_company_posts_publish_at
+    ON company_posts (publish_at) WHERE status = 'scheduled';
+CREATE INDEX IF NOT EXISTS idx_company_posts_closes_at
+    ON company_posts (closes_at) WHERE status = 'published';
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_company_posts_published_at;
+DROP TABLE IF EXISTS saved_search_matches;
+DROP TABLE IF EXISTS saved_searches;
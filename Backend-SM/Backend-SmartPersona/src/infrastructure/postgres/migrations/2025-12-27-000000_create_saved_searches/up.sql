@@ -0,0 +1,52 @@
+-- การค้นหาที่บันทึกไว้ - แจ้งประกาศใหม่ที่ตรงเงื่อนไขทางอีเมล (instant / daily / weekly)
+-- criteria: พารามิเตอร์ชุดเดียวกับ /posts/search (q, job_type, location, salary_*, tags, company_id, lat, lng, radius_km)
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    criteria JSONB NOT NULL DEFAULT '{}'::jsonb,
+    frequency VARCHAR(10) NOT NULL DEFAULT 'daily',
+    alerts_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    -- ลิงก์ยกเลิกการแจ้งเตือนในอีเมล (ใช้ได้โดยไม่ต้อง login)
+    unsubscribe_token VARCHAR(64) NOT NULL UNIQUE,
+    -- ประกาศที่เผยแพร่หลังเวลานี้ยังไม่ได้จับคู่
+    matched_until TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT saved_searches_frequency_check CHECK (frequency IN ('instant', 'daily', 'weekly'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user_id ON saved_searches(user_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_saved_searches_alerts_enabled ON saved_searches(alerts_enabled) WHERE alerts_enabled;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM information_schema.triggers
+        WHERE trigger_name = 'set_saved_searches_timestamp'
+    ) THEN
+        CREATE TRIGGER set_saved_searches_timestamp
+        BEFORE UPDATE ON saved_searches
+        FOR EACH ROW
+        EXECUTE PROCEDURE trigger_set_timestamp();
+    END IF;
+END $$;
+
+-- ประกาศที่ตรงกับการค้นหา - primary key กันจับคู่ / ส่งประกาศเดิมซ้ำ
+-- sent_at NULL = รอส่งใน digest รอบถัดไป
+CREATE TABLE IF NOT EXISTS saved_search_matches (
+    saved_search_id UUID NOT NULL REFERENCES saved_searches(id) ON DELETE CASCADE,
+    post_id UUID NOT NULL REFERENCES company_posts(id) ON DELETE CASCADE,
+    matched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ,
+    PRIMARY KEY (saved_search_id, post_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_search_matches_pending
+    ON saved_search_matches(saved_search_id) WHERE sent_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_saved_search_matches_post_id ON saved_search_matches(post_id);
+
+-- ตัวจับคู่หาประกาศที่เผยแพร่หลัง matched_until
+CREATE INDEX IF NOT EXISTS idx_company_posts_published_at
+    ON company_posts (published_at) WHERE status = 'published';
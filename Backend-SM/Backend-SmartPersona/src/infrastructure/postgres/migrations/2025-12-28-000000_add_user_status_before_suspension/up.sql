@@ -0,0 +1,3 @@
+-- สถานะก่อนถูกระงับ (pending / active) - เปิดใช้งานอีกครั้งจะคืนค่านี้
+-- บัญชีที่ยังไม่ยืนยันอีเมลจึงไม่กลายเป็น active เพราะถูกแบนแล้วปลดแบน
+ALTER TABLE users ADD COLUMN IF NOT EXISTS status_before_suspension user_status;
@@ -0,0 +1,4 @@
+-- token จริงกู้คืนจาก hash ไม่ได้ - ต้องขอลิงก์ใหม่หลัง rollback
+UPDATE data_exports SET download_token_hash = NULL;
+
+ALTER TABLE data_exports RENAME COLUMN download_token_hash TO download_token;
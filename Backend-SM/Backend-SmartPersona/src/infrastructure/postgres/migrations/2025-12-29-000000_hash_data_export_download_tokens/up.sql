@@ -0,0 +1,7 @@
+-- token ในลิงก์ดาวน์โหลด export เก็บเฉพาะ SHA-256 (hex) เหมือน account_tokens
+-- hash ของ token เดิมตรงกับที่คำนวณตอน lookup ลิงก์ที่ออกไปแล้วจึงยังใช้ได้
+ALTER TABLE data_exports RENAME COLUMN download_token TO download_token_hash;
+
+UPDATE data_exports
+SET download_token_hash = encode(sha256(convert_to(download_token_hash, 'UTF8')), 'hex')
+WHERE download_token_hash IS NOT NULL;
@@ -0,0 +1,10 @@
+-- token จริงกู้คืนจาก hash ไม่ได้ - ลิงก์ที่ส่งไปแล้วใช้ไม่ได้หลัง rollback
+ALTER TABLE saved_searches ADD COLUMN IF NOT EXISTS unsubscribe_token VARCHAR(64) UNIQUE;
+
+UPDATE saved_searches
+SET unsubscribe_token = replace(gen_random_uuid()::text, '-', '') || replace(gen_random_uuid()::text, '-', '')
+WHERE unsubscribe_token IS NULL;
+
+ALTER TABLE saved_searches ALTER COLUMN unsubscribe_token SET NOT NULL;
+
+DROP TABLE IF EXISTS saved_search_unsubscribe_tokens;
@@ -0,0 +1,18 @@
+-- token ลิงก์ยกเลิกแจ้งเตือนเก็บเฉพาะ SHA-256 (hex) เหมือน account_tokens
+-- ออก token ใหม่ทุก digest (ต้องใช้ค่าจริงใส่ในอีเมล) - ลิงก์ในอีเมลฉบับก่อน ๆ ยังใช้ได้
+CREATE TABLE IF NOT EXISTS saved_search_unsubscribe_tokens (
+    token_hash VARCHAR(64) PRIMARY KEY,
+    saved_search_id UUID NOT NULL REFERENCES saved_searches(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_search_unsubscribe_tokens_search
+    ON saved_search_unsubscribe_tokens(saved_search_id, created_at);
+
+-- token เดิมที่ส่งไปในอีเมลแล้ว
+INSERT INTO saved_search_unsubscribe_tokens (token_hash, saved_search_id)
+SELECT encode(sha256(convert_to(unsubscribe_token, 'UTF8')), 'hex'), id
+FROM saved_searches
+ON CONFLICT DO NOTHING;
+
+ALTER TABLE saved_searches DROP COLUMN IF EXISTS unsubscribe_token;
@@ -0,0 +1,28 @@
+-- Impersonated writes are recorded before the request runs and completed with the
+-- response status afterwards. The only UPDATE allowed is adding "status" to
+-- after_state of an event that has none yet - everything else stays append-only.
+CREATE OR REPLACE FUNCTION audit_events_append_only() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'UPDATE'
+        AND OLD.after_state IS NOT NULL
+        AND NOT (OLD.after_state ? 'status')
+        AND NEW.after_state ? 'status'
+        AND (NEW.after_state - 'status') = OLD.after_state
+        AND NEW.id = OLD.id
+        AND NEW.actor_id IS NOT DISTINCT FROM OLD.actor_id
+        AND NEW.actor_role IS NOT DISTINCT FROM OLD.actor_role
+        AND NEW.action = OLD.action
+        AND NEW.target_type = OLD.target_type
+        AND NEW.target_id = OLD.target_id
+        AND NEW.reason IS NOT DISTINCT FROM OLD.reason
+        AND NEW.before_state IS NOT DISTINCT FROM OLD.before_state
+        AND NEW.ip_address IS NOT DISTINCT FROM OLD.ip_address
+        AND NEW.user_agent IS NOT DISTINCT FROM OLD.user_agent
+        AND NEW.created_at = OLD.created_at
+    THEN
+        RETURN NEW;
+    END IF;
+
+    RAISE EXCEPTION 'audit_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
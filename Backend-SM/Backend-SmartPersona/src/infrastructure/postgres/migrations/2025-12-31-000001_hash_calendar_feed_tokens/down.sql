@@ -0,0 +1,4 @@
+-- token จริงกู้คืนจาก hash ไม่ได้ - ลบ feed เดิม แล้วสร้างใหม่ตอนขอ URL ครั้งถัดไป
+DELETE FROM calendar_feeds;
+
+ALTER TABLE calendar_feeds RENAME COLUMN feed_token_hash TO feed_token;
@@ -0,0 +1,6 @@
+-- token ใน URL ของ calendar feed เก็บเฉพาะ SHA-256 (hex) เหมือน account_tokens
+-- hash ของ token เดิมตรงกับที่คำนวณตอน lookup URL ที่ subscribe ไว้แล้วจึงยังใช้ได้
+ALTER TABLE calendar_feeds RENAME COLUMN feed_token TO feed_token_hash;
+
+UPDATE calendar_feeds
+SET feed_token_hash = encode(sha256(convert_to(feed_token_hash, 'UTF8')), 'hex');
@@ -0,0 +1,10 @@
+-- token ของ digest ทั้งฉบับใช้ไม่ได้หลัง rollback (ลิงก์ของแต่ละการค้นหายังใช้ได้)
+DELETE FROM saved_search_unsubscribe_tokens
+WHERE token_hash IN (
+    SELECT token_hash FROM saved_search_unsubscribe_tokens
+    GROUP BY token_hash
+    HAVING COUNT(*) > 1
+);
+
+ALTER TABLE saved_search_unsubscribe_tokens DROP CONSTRAINT IF EXISTS saved_search_unsubscribe_tokens_pkey;
+ALTER TABLE saved_search_unsubscribe_tokens ADD PRIMARY KEY (token_hash);
@@ -0,0 +1,4 @@
+-- ลิงก์ one-click unsubscribe (List-Unsubscribe, RFC 8058) ของ digest ทั้งฉบับ
+-- ใช้ token เดียวกับทุกการค้นหาใน digest จึงต้องให้ token ซ้ำได้ต่อการค้นหา
+ALTER TABLE saved_search_unsubscribe_tokens DROP CONSTRAINT IF EXISTS saved_search_unsubscribe_tokens_pkey;
+ALTER TABLE saved_search_unsubscribe_tokens ADD PRIMARY KEY (token_hash, saved_search_id);
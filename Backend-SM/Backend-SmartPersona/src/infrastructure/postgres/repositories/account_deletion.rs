@@ -0,0 +1,301 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::{prelude::*, sql_types};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            account_deletion::{AccountDeletion, AccountDeletionStatus, NewAccountDeletion},
+            user::UserStatusEnum,
+        },
+        repo::account_deletion::AccountDeletionRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{
+            account_deletions, account_tokens, application_screening_answers, calendar_feeds, companies, company_galleries,
+            company_posts, data_exports, job_applications, mfa_recovery_codes, oidc_auth_requests,
+            profile_shares, saved_jobs, saved_searches, social_analysis, social_connections, social_posts,
+            user_addresses, user_ai_scores, user_educations, user_experiences, user_identities,
+            user_job_matches, user_job_preferences, user_mfa, user_portfolios,
+            user_privacy_settings, user_profiles, user_sessions, user_skills, users,
+        },
+    },
+};
+
+pub struct AccountDeletionPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl AccountDeletionPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// ลบข้อมูลส่วนบุคคลทุกตาราง แล้วเปลี่ยนแถวใน users เป็น tombstone
+///
+/// สิ่งที่เก็บไว้:
+/// - job_applications ของ user: เป็นหลักฐานการรับสมัครงานของบริษัท - อ้างอิง tombstone
+///   ที่ไม่มีชื่อ / อีเมล / โปรไฟล์ จึงระบุตัวบุคคลไม่ได้อีก (ลบจดหมาย, resume และคำตอบคัดกรองออก)
+/// - audit_events: append-only และเก็บเพียง id
+fn erase_user_data(conn: &mut PgConnection, user_id: Uuid, password_hash: &str) -> QueryResult<()> {
+    // โปรไฟล์และประวัติ
+    diesel::delete(user_profiles::table.filter(user_profiles::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_addresses::table.filter(user_addresses::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_educations::table.filter(user_educations::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_experiences::table.filter(user_experiences::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(user_skills::table.filter(user_skills::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_portfolios::table.filter(user_portfolios::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_job_preferences::table.filter(user_job_preferences::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(user_privacy_settings::table.filter(user_privacy_settings::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(profile_shares::table.filter(profile_shares::user_id.eq(user_id))).execute(conn)?;
+
+    // ผลวิเคราะห์ AI และ social
+    diesel::delete(user_ai_scores::table.filter(user_ai_scores::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_job_matches::table.filter(user_job_matches::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(social_analysis::table.filter(social_analysis::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(
+        social_posts::table.filter(
+            social_posts::social_connection_id.eq_any(
+                social_connections::table
+                    .filter(social_connections::user_id.eq(user_id))
+                    .select(social_connections::id),
+            ),
+        ),
+    )
+    .execute(conn)?;
+    diesel::delete(social_connections::table.filter(social_connections::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(saved_jobs::table.filter(saved_jobs::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(saved_searches::table.filter(saved_searches::user_id.eq(user_id)))
+        .execute(conn)?;
+
+    // เนื้อหาที่ผู้สมัครเขียนเองในใบสมัคร
+    let application_ids = job_applications::table
+        .filter(job_applications::user_id.eq(user_id))
+        .select(job_applications::id);
+    diesel::delete(
+        application_screening_answers::table
+            .filter(application_screening_answers::application_id.eq_any(application_ids)),
+    )
+    .execute(conn)?;
+    diesel::update(job_applications::table.filter(job_applications::user_id.eq(user_id)))
+        .set((
+            job_applications::cover_letter.eq(None::<String>),
+            job_applications::resume_url.eq(None::<String>),
+        ))
+        .execute(conn)?;
+
+    // การเข้าสู่ระบบ
+    diesel::delete(user_sessions::table.filter(user_sessions::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(account_tokens::table.filter(account_tokens::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(mfa_recovery_codes::table.filter(mfa_recovery_codes::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(user_mfa::table.filter(user_mfa::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(user_identities::table.filter(user_identities::user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(oidc_auth_requests::table.filter(oidc_auth_requests::link_user_id.eq(user_id)))
+        .execute(conn)?;
+    diesel::delete(data_exports::table.filter(data_exports::user_id.eq(user_id))).execute(conn)?;
+    diesel::delete(calendar_feeds::table.filter(calendar_feeds::user_id.eq(user_id))).execute(conn)?;
+
+    // บัญชีบริษัท: ปิดบริษัทพร้อมประกาศงาน (galleries / api keys / matches ลบตาม cascade)
+    // ใบสมัครของประกาศที่ถูกลบไม่มีนายจ้างให้อ้างอิงแล้ว จึงลบไปด้วย
+    let company_post_ids = company_posts::table
+        .inner_join(companies::table)
+        .filter(companies::user_id.eq(user_id))
+        .select(company_posts::id)
+        .load::<Uuid>(conn)?;
+    diesel::delete(job_applications::table.filter(job_applications::job_id.eq_any(&company_post_ids)))
+        .execute(conn)?;
+    diesel::delete(companies::table.filter(companies::user_id.eq(user_id))).execute(conn)?;
+
+    // Tombstone - username ต้อง unique จึงใช้ id, รหัสผ่านเป็น hash ของค่าสุ่มที่ไม่มีใครรู้
+    diesel::update(users::table.filter(users::id.eq(user_id)))
+        .set((
+            users::username.eq(format!("deleted-{}", user_id.simple())),
+            users::password_hash.eq(password_hash),
+            users::display_name.eq(None::<String>),
+            users::email.eq(None::<String>),
+            users::status.eq(UserStatusEnum::Deleted),
+            users::ai_credits.eq(0),
+            users::updated_at.eq(Utc::now().naive_utc()),
+        ))
+        .execute(conn)?;
+
+    // first_name / last_name จาก migration แรกไม่ได้อยู่ใน schema.rs แต่ยังมีอยู่ในตาราง
+    diesel::sql_query("UPDATE users SET first_name = '', last_name = '' WHERE id = $1")
+        .bind::<sql_types::Uuid, _>(user_id)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl AccountDeletionRepository for AccountDeletionPostgres {
+    async fn create(&self, new_deletion: &NewAccountDeletion) -> Result<AccountDeletion> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(account_deletions::table)
+            .values(new_deletion)
+            .returning(AccountDeletion::as_returning())
+            .get_result::<AccountDeletion>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_latest(&self, user_id: Uuid) -> Result<Option<AccountDeletion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = account_deletions::table
+            .filter(account_deletions::user_id.eq(user_id))
+            .order(account_deletions::requested_at.desc())
+            .select(AccountDeletion::as_select())
+            .first::<AccountDeletion>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_scheduled(&self, user_id: Uuid) -> Result<Option<AccountDeletion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = account_deletions::table
+            .filter(account_deletions::user_id.eq(user_id))
+            .filter(account_deletions::status.eq(AccountDeletionStatus::Scheduled.as_str()))
+            .select(AccountDeletion::as_select())
+            .first::<AccountDeletion>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn cancel(&self, user_id: Uuid) -> Result<Option<AccountDeletion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(
+            account_deletions::table
+                .filter(account_deletions::user_id.eq(user_id))
+                .filter(account_deletions::status.eq(AccountDeletionStatus::Scheduled.as_str()))
+                // พ้น grace period แล้ว job อาจกำลังลบไฟล์อยู่ - ยกเลิกไม่ได้
+                .filter(account_deletions::scheduled_for.gt(Utc::now())),
+        )
+        .set((
+            account_deletions::status.eq(AccountDeletionStatus::Cancelled.as_str()),
+            account_deletions::cancelled_at.eq(Some(Utc::now())),
+        ))
+        .returning(AccountDeletion::as_returning())
+        .get_result::<AccountDeletion>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn list_due(&self) -> Result<Vec<AccountDeletion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = account_deletions::table
+            .filter(account_deletions::status.eq(AccountDeletionStatus::Scheduled.as_str()))
+            .filter(account_deletions::scheduled_for.le(Utc::now()))
+            .order(account_deletions::scheduled_for.asc())
+            .select(AccountDeletion::as_select())
+            .load::<AccountDeletion>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn stored_file_urls(&self, user_id: Uuid) -> Result<Vec<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let mut urls = Vec::new();
+
+        for (profile_image_url, cover_image_url) in user_profiles::table
+            .filter(user_profiles::user_id.eq(user_id))
+            .select((user_profiles::profile_image_url, user_profiles::cover_image_url))
+            .load::<(Option<String>, Option<String>)>(&mut conn)?
+        {
+            urls.extend(profile_image_url);
+            urls.extend(cover_image_url);
+        }
+
+        urls.extend(
+            user_portfolios::table
+                .filter(user_portfolios::user_id.eq(user_id))
+                .select(user_portfolios::image_url)
+                .load::<Option<String>>(&mut conn)?
+                .into_iter()
+                .flatten(),
+        );
+
+        urls.extend(
+            companies::table
+                .filter(companies::user_id.eq(user_id))
+                .select(companies::logo_url)
+                .load::<Option<String>>(&mut conn)?
+                .into_iter()
+                .flatten(),
+        );
+
+        urls.extend(
+            job_applications::table
+                .filter(job_applications::user_id.eq(user_id))
+                .select(job_applications::resume_url)
+                .load::<Option<String>>(&mut conn)?
+                .into_iter()
+                .flatten(),
+        );
+
+        urls.extend(
+            company_galleries::table
+                .inner_join(companies::table)
+                .filter(companies::user_id.eq(user_id))
+                .select(company_galleries::image_url)
+                .load::<String>(&mut conn)?,
+        );
+
+        urls.sort();
+        urls.dedup();
+        Ok(urls)
+    }
+
+    async fn erase_user(
+        &self,
+        deletion: &AccountDeletion,
+        password_hash: String,
+        storage_failures: Vec<String>,
+    ) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let erased = conn.transaction::<bool, diesel::result::Error, _>(|conn| {
+            // ปิดคำขอก่อน - ถ้าผู้ใช้ยกเลิกไปแล้วจะได้ 0 แถวและไม่ลบอะไร
+            let claimed = diesel::update(
+                account_deletions::table
+                    .filter(account_deletions::id.eq(deletion.id))
+                    .filter(account_deletions::status.eq(AccountDeletionStatus::Scheduled.as_str())),
+            )
+            .set((
+                account_deletions::status.eq(AccountDeletionStatus::Completed.as_str()),
+                account_deletions::completed_at.eq(Some(Utc::now())),
+                account_deletions::storage_failures.eq(&storage_failures),
+            ))
+            .execute(conn)?;
+
+            if claimed == 0 {
+                return Ok(false);
+            }
+
+            erase_user_data(conn, deletion.user_id, &password_hash)?;
+            Ok(true)
+        })?;
+
+        Ok(erased)
+    }
+}
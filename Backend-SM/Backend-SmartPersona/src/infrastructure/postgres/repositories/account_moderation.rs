@@ -0,0 +1,150 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{audit_event::NewAuditEvent, company::CompanyEntity, user::UserEntity},
+        repo::account_moderation::AccountModerationRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        repositories::{
+            audit_event::insert_event,
+            user::{reactivate_user, suspend_user},
+        },
+        schema::companies,
+    },
+};
+
+pub struct AccountModerationPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl AccountModerationPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// ล็อกแถวของบริษัทก่อนแก้ไข
+fn lock_company(conn: &mut PgConnection, company_id: Uuid) -> Result<CompanyEntity> {
+    companies::table
+        .find(company_id)
+        .select(CompanyEntity::as_select())
+        .for_update()
+        .first::<CompanyEntity>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("Company not found: {}", company_id))
+}
+
+fn update_company_status(conn: &mut PgConnection, company_id: Uuid, status: &str) -> Result<CompanyEntity> {
+    let result = diesel::update(companies::table.find(company_id))
+        .set((
+            companies::status.eq(status),
+            companies::updated_at.eq(Utc::now()),
+        ))
+        .returning(CompanyEntity::as_returning())
+        .get_result::<CompanyEntity>(conn)?;
+
+    Ok(result)
+}
+
+#[async_trait]
+impl AccountModerationRepository for AccountModerationPostgres {
+    async fn suspend_user(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            let user = suspend_user(conn, user_id)?;
+            insert_event(conn, &audit_event.after(serde_json::json!({ "status": user.status })))?;
+            Ok(user)
+        })
+    }
+
+    async fn reactivate_user(&self, user_id: Uuid, audit_event: NewAuditEvent) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            let user = reactivate_user(conn, user_id)?;
+            insert_event(conn, &audit_event.after(serde_json::json!({ "status": user.status })))?;
+            Ok(user)
+        })
+    }
+
+    async fn set_company_status(
+        &self,
+        company_id: Uuid,
+        status: &str,
+        audit_event: NewAuditEvent,
+    ) -> Result<CompanyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            lock_company(conn, company_id)?;
+            let company = update_company_status(conn, company_id, status)?;
+            insert_event(conn, &audit_event.after(serde_json::json!({ "status": company.status })))?;
+            Ok(company)
+        })
+    }
+
+    async fn delete_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            lock_company(conn, company_id)?;
+            diesel::delete(companies::table.find(company_id)).execute(conn)?;
+            insert_event(conn, &audit_event)?;
+            Ok(())
+        })
+    }
+
+    async fn ban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            let company = lock_company(conn, company_id)?;
+            let account = suspend_user(conn, company.user_id)?;
+            let company = update_company_status(conn, company_id, "banned")?;
+            insert_event(
+                conn,
+                &audit_event.after(serde_json::json!({
+                    "status": company.status,
+                    "account_status": account.status,
+                })),
+            )?;
+            Ok(company)
+        })
+    }
+
+    async fn unban_company(&self, company_id: Uuid, audit_event: NewAuditEvent) -> Result<CompanyEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            let company = lock_company(conn, company_id)?;
+
+            // Conflict เกิดก่อนมีการแก้ไขใด ๆ - transaction ยังใช้ต่อได้
+            let account_status = match reactivate_user(conn, company.user_id) {
+                Ok(account) => Some(account.status),
+                Err(e) if e.to_string().contains("Conflict") => {
+                    tracing::warn!("Account of company {} was not reactivated: {}", company_id, e);
+                    None
+                }
+                Err(e) => return Err(e),
+            };
+
+            let company = update_company_status(conn, company_id, "approved")?;
+            insert_event(
+                conn,
+                &audit_event.after(serde_json::json!({
+                    "status": company.status,
+                    "account_status": account_status,
+                })),
+            )?;
+            Ok(company)
+        })
+    }
+}
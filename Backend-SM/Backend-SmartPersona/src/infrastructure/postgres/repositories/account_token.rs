@@ -0,0 +1,92 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::account_token::{AccountToken, NewAccountToken},
+        repo::account_token::AccountTokenRepository,
+    },
+    infrastructure::postgres::{postgres_connection::DbPool, schema::account_tokens},
+};
+
+pub struct AccountTokenPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl AccountTokenPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AccountTokenRepository for AccountTokenPostgres {
+    async fn create(&self, new_token: &NewAccountToken) -> Result<AccountToken> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(account_tokens::table)
+            .values(new_token)
+            .returning(AccountToken::as_returning())
+            .get_result::<AccountToken>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_hash(&self, token_hash: &str, purpose: &str) -> Result<Option<AccountToken>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = account_tokens::table
+            .filter(account_tokens::token_hash.eq(token_hash))
+            .filter(account_tokens::purpose.eq(purpose))
+            .select(AccountToken::as_select())
+            .first::<AccountToken>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn mark_used(&self, token_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // เงื่อนไข used_at IS NULL กันการใช้ token ซ้ำจาก request ที่เข้ามาพร้อมกัน
+        let rows_affected = diesel::update(
+            account_tokens::table
+                .filter(account_tokens::id.eq(token_id))
+                .filter(account_tokens::used_at.is_null()),
+        )
+        .set(account_tokens::used_at.eq(Some(Utc::now())))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected == 1)
+    }
+
+    async fn invalidate_for_user(&self, user_id: Uuid, purpose: &str) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::update(
+            account_tokens::table
+                .filter(account_tokens::user_id.eq(user_id))
+                .filter(account_tokens::purpose.eq(purpose))
+                .filter(account_tokens::used_at.is_null()),
+        )
+        .set(account_tokens::used_at.eq(Some(Utc::now())))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+
+    async fn delete_expired(&self) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            account_tokens::table.filter(account_tokens::expires_at.lt(Utc::now())),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+}
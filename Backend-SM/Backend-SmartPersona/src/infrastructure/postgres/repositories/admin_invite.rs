@@ -0,0 +1,103 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            admin_invite::{AdminInvite, NewAdminInvite},
+            user::RegisterUserEntity,
+        },
+        repo::admin_invite::AdminInviteRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        repositories::user::map_unique_violation,
+        schema::{admin_invites, users},
+    },
+};
+
+pub struct AdminInvitePostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl AdminInvitePostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AdminInviteRepository for AdminInvitePostgres {
+    async fn create(&self, new_invite: &NewAdminInvite) -> Result<AdminInvite> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(admin_invites::table)
+            .values(new_invite)
+            .returning(AdminInvite::as_returning())
+            .get_result::<AdminInvite>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<AdminInvite>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = admin_invites::table
+            .filter(admin_invites::token_hash.eq(token_hash))
+            .select(AdminInvite::as_select())
+            .first::<AdminInvite>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn accept(&self, invite_id: Uuid, admin: RegisterUserEntity) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // เงื่อนไข accepted_at IS NULL กันการตอบรับคำเชิญเดียวกันพร้อมกัน
+            let rows_affected = diesel::update(
+                admin_invites::table
+                    .filter(admin_invites::id.eq(invite_id))
+                    .filter(admin_invites::accepted_at.is_null())
+                    .filter(admin_invites::expires_at.gt(Utc::now())),
+            )
+            .set(admin_invites::accepted_at.eq(Some(Utc::now())))
+            .execute(conn)?;
+
+            if rows_affected == 0 {
+                return Ok(None);
+            }
+
+            let user_id = diesel::insert_into(users::table)
+                .values(&admin)
+                .returning(users::id)
+                .get_result::<Uuid>(conn)?;
+
+            diesel::update(admin_invites::table.filter(admin_invites::id.eq(invite_id)))
+                .set(admin_invites::accepted_user_id.eq(Some(user_id)))
+                .execute(conn)?;
+
+            Ok(Some(user_id))
+        });
+
+        result.map_err(map_unique_violation)
+    }
+
+    async fn delete_expired(&self) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            admin_invites::table
+                .filter(admin_invites::accepted_at.is_null())
+                .filter(admin_invites::expires_at.lt(Utc::now())),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+}
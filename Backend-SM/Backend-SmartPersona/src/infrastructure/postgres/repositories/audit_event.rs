@@ -0,0 +1,111 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::{pg::Pg, prelude::*};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::audit_event::{AuditEvent, AuditLogQuery, NewAuditEvent},
+        repo::audit_event::AuditEventRepository,
+    },
+    infrastructure::postgres::{postgres_connection::DbPool, schema::audit_events},
+};
+
+/// WHERE clause เดียวกันสำหรับทั้ง select และ count
+fn filtered(query: &AuditLogQuery) -> audit_events::BoxedQuery<'static, Pg> {
+    let mut statement = audit_events::table.into_boxed();
+
+    if let Some(actor_id) = query.actor_id {
+        statement = statement.filter(audit_events::actor_id.eq(actor_id));
+    }
+    if let Some(action) = query.action.clone() {
+        statement = statement.filter(audit_events::action.eq(action));
+    }
+    if let Some(target_type) = query.target_type.clone() {
+        statement = statement.filter(audit_events::target_type.eq(target_type));
+    }
+    if let Some(target_id) = query.target_id.clone() {
+        statement = statement.filter(audit_events::target_id.eq(target_id));
+    }
+    if let Some(from) = query.from {
+        statement = statement.filter(audit_events::created_at.ge(from));
+    }
+    if let Some(to) = query.to {
+        statement = statement.filter(audit_events::created_at.lt(to));
+    }
+
+    statement
+}
+
+/// เพิ่ม event ด้วย connection ที่ให้มา - ใช้ภายใน transaction ของการกระทำที่ต้องบันทึก
+pub(crate) fn insert_event(conn: &mut PgConnection, new_event: &NewAuditEvent) -> Result<AuditEvent> {
+    let result = diesel::insert_into(audit_events::table)
+        .values(new_event)
+        .returning(AuditEvent::as_returning())
+        .get_result::<AuditEvent>(conn)?;
+
+    Ok(result)
+}
+
+pub struct AuditEventPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl AuditEventPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for AuditEventPostgres {
+    async fn append(&self, new_event: &NewAuditEvent) -> Result<AuditEvent> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        insert_event(&mut conn, new_event)
+    }
+
+    async fn set_response_status(&self, event_id: Uuid, status: u16) -> Result<AuditEvent> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction(|conn| {
+            let mut after_state = audit_events::table
+                .find(event_id)
+                .select(audit_events::after_state)
+                .for_update()
+                .first::<Option<serde_json::Value>>(conn)?
+                .unwrap_or_else(|| serde_json::json!({}));
+
+            if let serde_json::Value::Object(details) = &mut after_state {
+                details.insert("status".to_string(), status.into());
+            }
+
+            let result = diesel::update(audit_events::table.find(event_id))
+                .set(audit_events::after_state.eq(Some(after_state)))
+                .returning(AuditEvent::as_returning())
+                .get_result::<AuditEvent>(conn)?;
+
+            Ok(result)
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &AuditLogQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<AuditEvent>, i64)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total = filtered(query).count().get_result::<i64>(&mut conn)?;
+
+        let items = filtered(query)
+            .order((audit_events::created_at.desc(), audit_events::id.desc()))
+            .limit(limit)
+            .offset(offset)
+            .select(AuditEvent::as_select())
+            .load::<AuditEvent>(&mut conn)?;
+
+        Ok((items, total))
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::company_api_key::{CompanyApiKey, NewCompanyApiKey},
+        repo::company_api_key::CompanyApiKeyRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{companies, company_api_keys},
+    },
+};
+
+pub struct CompanyApiKeyPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl CompanyApiKeyPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CompanyApiKeyRepository for CompanyApiKeyPostgres {
+    async fn company_id_for_owner(&self, user_id: Uuid) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = companies::table
+            .filter(companies::user_id.eq(user_id))
+            .select(companies::id)
+            .first::<Uuid>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn create(&self, new_key: &NewCompanyApiKey) -> Result<CompanyApiKey> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(company_api_keys::table)
+            .values(new_key)
+            .returning(CompanyApiKey::as_returning())
+            .get_result::<CompanyApiKey>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_hash(&self, key_hash: &str) -> Result<Option<(CompanyApiKey, Uuid)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = company_api_keys::table
+            .inner_join(companies::table)
+            .filter(company_api_keys::key_hash.eq(key_hash))
+            .select((CompanyApiKey::as_select(), companies::user_id))
+            .first::<(CompanyApiKey, Uuid)>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn list_by_company(&self, company_id: Uuid) -> Result<Vec<CompanyApiKey>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = company_api_keys::table
+            .filter(company_api_keys::company_id.eq(company_id))
+            .order(company_api_keys::created_at.desc())
+            .select(CompanyApiKey::as_select())
+            .load::<CompanyApiKey>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn count_active(&self, company_id: Uuid) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count = company_api_keys::table
+            .filter(company_api_keys::company_id.eq(company_id))
+            .filter(company_api_keys::revoked_at.is_null())
+            .filter(
+                company_api_keys::expires_at
+                    .is_null()
+                    .or(company_api_keys::expires_at.gt(Utc::now())),
+            )
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count as usize)
+    }
+
+    async fn revoke(&self, company_id: Uuid, key_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let affected = diesel::update(
+            company_api_keys::table
+                .filter(company_api_keys::id.eq(key_id))
+                .filter(company_api_keys::company_id.eq(company_id))
+                .filter(company_api_keys::revoked_at.is_null()),
+        )
+        .set(company_api_keys::revoked_at.eq(Some(Utc::now())))
+        .execute(&mut conn)?;
+
+        Ok(affected > 0)
+    }
+
+    async fn touch(&self, key_id: Uuid, ip_address: Option<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now();
+
+        // ไม่เขียน database ทุก request - ความละเอียดระดับนาทีพอสำหรับ last used
+        diesel::update(
+            company_api_keys::table
+                .filter(company_api_keys::id.eq(key_id))
+                .filter(
+                    company_api_keys::last_used_at
+                        .is_null()
+                        .or(company_api_keys::last_used_at.lt(now - Duration::minutes(1))),
+                ),
+        )
+        .set((
+            company_api_keys::last_used_at.eq(Some(now)),
+            company_api_keys::last_used_ip.eq(ip_address),
+        ))
+        .execute(&mut conn)?;
+
+        Ok(())
+    }
+}
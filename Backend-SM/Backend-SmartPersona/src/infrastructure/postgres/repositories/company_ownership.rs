@@ -0,0 +1,91 @@
+use anyhow::Result;
+use axum::async_trait;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::repo::company_ownership::CompanyOwnershipRepository,
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{companies, company_galleries, company_posts, job_applications},
+    },
+};
+
+pub struct CompanyOwnershipPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl CompanyOwnershipPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl CompanyOwnershipRepository for CompanyOwnershipPostgres {
+    async fn company_owner(&self, company_id: Uuid) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = companies::table
+            .filter(companies::id.eq(company_id))
+            .select(companies::user_id)
+            .first::<Uuid>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn post_owner(&self, post_id: Uuid) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = company_posts::table
+            .inner_join(companies::table)
+            .filter(company_posts::id.eq(post_id))
+            .select(companies::user_id)
+            .first::<Uuid>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn gallery_owner(&self, gallery_id: Uuid) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = company_galleries::table
+            .inner_join(companies::table)
+            .filter(company_galleries::id.eq(gallery_id))
+            .select(companies::user_id)
+            .first::<Uuid>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn application_owner(&self, application_id: Uuid) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = job_applications::table
+            .inner_join(company_posts::table.inner_join(companies::table))
+            .filter(job_applications::id.eq(application_id))
+            .select(companies::user_id)
+            .first::<Uuid>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn has_applied_to_owner(&self, applicant_id: Uuid, owner_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::select(diesel::dsl::exists(
+            job_applications::table
+                .inner_join(company_posts::table.inner_join(companies::table))
+                .filter(job_applications::user_id.eq(applicant_id))
+                .filter(companies::user_id.eq(owner_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(result)
+    }
+}
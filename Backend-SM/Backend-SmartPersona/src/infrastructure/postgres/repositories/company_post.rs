@@ -1,17 +1,124 @@
 use anyhow::Result;
 use axum::async_trait;
-use diesel::prelude::*;
+use diesel::{
+    dsl::sql,
+    helper_types::{InnerJoinQuerySource, LeftJoinQuerySource},
+    pg::Pg,
+    prelude::*,
+    sql_types::{BigInt, Bool, Double, Float, Nullable, Text},
+};
+use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::company_post::{CompanyPostEntity, NewCompanyPost, UpdateCompanyPost},
+        entities::{
+            company_post::{
+                CompanyPostEntity, CompanyPostWithCompany, JobSearchFilter, JobSearchHit,
+                JobSearchSort, NewCompanyPost, NewScreeningQuestion, OpenApplicant,
+                PostClosingNotice, PostStatus, ScreeningQuestion, UpdateCompanyPost,
+            },
+            job_application::ApplicationStatus,
+        },
         repo::company_post::CompanyPostRepository,
+        value_object::geo::{EARTH_RADIUS_KM, GeoPoint},
+    },
+    infrastructure::{
+        postgres::{
+            postgres_connection::DbPool,
+            schema::{
+                companies, company_post_search, company_posts, job_applications,
+                post_screening_questions, user_profiles, users,
+            },
+        },
+        search::tsvector_literal,
     },
-    infrastructure::postgres::{postgres_connection::DbPool, schema::company_posts},
 };
 
+define_sql_function! {
+    fn lower(x: Text) -> Text;
+}
+
+define_sql_function! {
+    fn coalesce(x: Nullable<BigInt>, y: Nullable<BigInt>) -> Nullable<BigInt>;
+}
+
+type PostSearchSource = LeftJoinQuerySource<
+    InnerJoinQuerySource<company_posts::table, companies::table>,
+    company_post_search::table,
+>;
+
+type RelevanceExpression =
+    Box<dyn BoxableExpression<PostSearchSource, Pg, SqlType = Nullable<Float>>>;
+
+type DistanceExpression =
+    Box<dyn BoxableExpression<PostSearchSource, Pg, SqlType = Nullable<Double>>>;
+
+/// คะแนนความตรงของประกาศกับคำค้น (คำใน title มีน้ำหนักมากที่สุด)
+fn relevance(ts_query: &str) -> RelevanceExpression {
+    Box::new(
+        sql::<Nullable<Float>>("ts_rank_cd(company_post_search.search_vector, ")
+            .bind::<Text, _>(ts_query.to_string())
+            .sql("::tsquery)"),
+    )
+}
+
+/// ระยะทาง (km) จาก `origin` ถึงพิกัดของประกาศด้วยสูตร haversine - NULL ถ้าประกาศไม่มีพิกัด
+/// (least(1, ...) กัน asin ได้ค่าเกิน 1 จาก floating point error)
+fn distance_km(origin: GeoPoint) -> DistanceExpression {
+    Box::new(
+        sql::<Nullable<Double>>(&format!(
+            "({} * 2 * asin(sqrt(least(1, \
+             power(sin(radians(company_posts.latitude - ",
+            EARTH_RADIUS_KM
+        ))
+        .bind::<Double, _>(origin.latitude)
+        .sql(") / 2), 2) + cos(radians(")
+        .bind::<Double, _>(origin.latitude)
+        .sql(")) * cos(radians(company_posts.latitude)) * power(sin(radians(company_posts.longitude - ")
+        .bind::<Double, _>(origin.longitude)
+        .sql(") / 2), 2)))))"),
+    )
+}
+
+/// escape `%` / `_` ที่ผู้ใช้พิมพ์มา ก่อนใช้กับ ILIKE
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// สร้าง / แทนที่ search_vector ของประกาศ
+/// น้ำหนัก: A = title, B = tags, C = description / responsibilities / qualifications
+fn index_post(conn: &mut PgConnection, post: &CompanyPostEntity) -> QueryResult<()> {
+    let title = std::iter::once(('A', post.title.as_str()));
+    let tags = post
+        .tags
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|tag| ('B', tag.as_str()));
+    let body = [&post.description, &post.responsibilities, &post.qualifications]
+        .into_iter()
+        .flatten()
+        .map(|text| ('C', text.as_str()));
+
+    diesel::sql_query(
+        "INSERT INTO company_post_search (post_id, search_vector, indexed_at) \
+         VALUES ($1, $2::tsvector, NOW()) \
+         ON CONFLICT (post_id) DO UPDATE \
+         SET search_vector = EXCLUDED.search_vector, indexed_at = EXCLUDED.indexed_at",
+    )
+    .bind::<diesel::sql_types::Uuid, _>(post.id)
+    .bind::<Text, _>(tsvector_literal(title.chain(tags).chain(body)))
+    .execute(conn)?;
+
+    Ok(())
+}
+
 pub struct CompanyPostPostgres {
     db_pool: Arc<DbPool>,
 }
@@ -27,24 +134,35 @@ impl CompanyPostRepository for CompanyPostPostgres {
     async fn create(&self, new_post: &NewCompanyPost) -> Result<CompanyPostEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::insert_into(company_posts::table)
-            .values(new_post)
-            .returning(CompanyPostEntity::as_returning())
-            .get_result::<CompanyPostEntity>(&mut conn)?;
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let post = diesel::insert_into(company_posts::table)
+                .values(new_post)
+                .returning(CompanyPostEntity::as_returning())
+                .get_result::<CompanyPostEntity>(conn)?;
+
+            index_post(conn, &post)?;
+            Ok(post)
+        })?;
 
         Ok(result)
     }
 
-    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyPostEntity>> {
+    async fn get_by_company_id(&self, company_id: Uuid, public_only: bool) -> Result<Vec<CompanyPostEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let results = company_posts::table
+        let mut query = company_posts::table
             .filter(company_posts::company_id.eq(company_id))
             .order(company_posts::created_at.desc())
             .select(CompanyPostEntity::as_select())
-            .load::<CompanyPostEntity>(&mut conn)?;
+            .into_boxed();
+        if public_only {
+            query = query.filter(company_posts::status.ne_all([
+                PostStatus::Draft.as_str(),
+                PostStatus::Scheduled.as_str(),
+            ]));
+        }
 
-        Ok(results)
+        Ok(query.load::<CompanyPostEntity>(&mut conn)?)
     }
 
     async fn get_by_id(&self, id: Uuid) -> Result<Option<CompanyPostEntity>> {
@@ -59,10 +177,11 @@ impl CompanyPostRepository for CompanyPostPostgres {
         Ok(result)
     }
 
-    async fn get_all(&self) -> Result<Vec<CompanyPostEntity>> {
+    async fn get_published(&self) -> Result<Vec<CompanyPostEntity>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
         let results = company_posts::table
+            .filter(company_posts::status.eq(PostStatus::Published.as_str()))
             .order(company_posts::created_at.desc())
             .select(CompanyPostEntity::as_select())
             .load::<CompanyPostEntity>(&mut conn)?;
@@ -73,11 +192,16 @@ impl CompanyPostRepository for CompanyPostPostgres {
     async fn update(&self, id: Uuid, update_data: &UpdateCompanyPost) -> Result<CompanyPostEntity> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(company_posts::table)
-            .filter(company_posts::id.eq(id))
-            .set(update_data)
-            .returning(CompanyPostEntity::as_returning())
-            .get_result::<CompanyPostEntity>(&mut conn)?;
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let post = diesel::update(company_posts::table)
+                .filter(company_posts::id.eq(id))
+                .set(update_data)
+                .returning(CompanyPostEntity::as_returning())
+                .get_result::<CompanyPostEntity>(conn)?;
+
+            index_post(conn, &post)?;
+            Ok(post)
+        })?;
 
         Ok(result)
     }
@@ -91,4 +215,346 @@ impl CompanyPostRepository for CompanyPostPostgres {
 
         Ok(())
     }
+
+    async fn search(&self, filter: &JobSearchFilter) -> Result<Vec<JobSearchHit>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let relevance_of = || match &filter.ts_query {
+            Some(ts_query) => relevance(ts_query),
+            None => Box::new(sql::<Nullable<Float>>("NULL::real")) as RelevanceExpression,
+        };
+        let distance_of = || match &filter.near {
+            Some(near) => distance_km(near.origin),
+            None => Box::new(sql::<Nullable<Double>>("NULL::float8")) as DistanceExpression,
+        };
+
+        let mut query = company_posts::table
+            .inner_join(companies::table)
+            .left_join(company_post_search::table)
+            .filter(company_posts::status.eq(PostStatus::Published.as_str()))
+            .filter(
+                company_posts::closes_at
+                    .is_null()
+                    .or(company_posts::closes_at.gt(Utc::now())),
+            )
+            .select((
+                CompanyPostEntity::as_select(),
+                companies::company_name,
+                companies::logo_url,
+                relevance_of(),
+                distance_of(),
+            ))
+            .into_boxed();
+
+        if let Some(ts_query) = &filter.ts_query {
+            query = query.filter(
+                sql::<Bool>("company_post_search.search_vector @@ ")
+                    .bind::<Text, _>(ts_query.clone())
+                    .sql("::tsquery"),
+            );
+        }
+        if !filter.job_types.is_empty() {
+            query = query.filter(lower(company_posts::job_type).eq_any(filter.job_types.clone()));
+        }
+        if let Some(location) = &filter.location {
+            query = query.filter(company_posts::location.ilike(like_pattern(location)));
+        }
+        // ช่วงเงินเดือนของประกาศต้องมีส่วนที่ถึง salary_min และส่วนที่ไม่เกิน salary_max
+        // (ประกาศที่ระบุแค่ขั้นต่ำหรือเพดานใช้ค่านั้นทั้งสองฝั่ง)
+        if let Some(salary_min) = filter.salary_min {
+            query = query.filter(
+                coalesce(company_posts::salary_max, company_posts::salary_min).ge(salary_min),
+            );
+        }
+        if let Some(salary_max) = filter.salary_max {
+            query = query.filter(
+                coalesce(company_posts::salary_min, company_posts::salary_max).le(salary_max),
+            );
+        }
+        if let Some(salary_currency) = &filter.salary_currency {
+            query = query.filter(company_posts::salary_currency.eq(salary_currency.clone()));
+        }
+        if let Some(salary_period) = filter.salary_period {
+            query = query.filter(company_posts::salary_period.eq(salary_period.as_str()));
+        }
+        if !filter.tags.is_empty() {
+            let tags: Vec<Option<String>> = filter.tags.iter().cloned().map(Some).collect();
+            query = query.filter(company_posts::tags.overlaps_with(tags));
+        }
+        if let Some(company_id) = filter.company_id {
+            query = query.filter(company_posts::company_id.eq(company_id));
+        }
+        if let Some(posted_after) = filter.posted_after {
+            query = query.filter(company_posts::created_at.ge(posted_after));
+        }
+        if let Some(posted_before) = filter.posted_before {
+            query = query.filter(company_posts::created_at.lt(posted_before));
+        }
+        if let Some(published_after) = filter.published_after {
+            query = query.filter(company_posts::published_at.gt(published_after));
+        }
+        if let Some(published_before) = filter.published_before {
+            query = query.filter(company_posts::published_at.le(published_before));
+        }
+        if let Some(near) = &filter.near {
+            // กรองด้วยกรอบสี่เหลี่ยมก่อน (ใช้ index ได้) แล้วค่อยตัดมุมด้วยระยะจริง
+            let bounds = near.origin.bounding_box(near.radius_km);
+            query = query.filter(
+                company_posts::latitude.between(bounds.min_latitude, bounds.max_latitude),
+            );
+            if let Some((min_longitude, max_longitude)) = bounds.longitude_range {
+                query = query.filter(
+                    company_posts::longitude.between(min_longitude, max_longitude),
+                );
+            }
+            query = query.filter(distance_of().le(near.radius_km));
+        }
+
+        match filter.sort {
+            JobSearchSort::Relevance => {
+                if let Some(after) = &filter.after {
+                    let after_relevance = after.relevance.unwrap_or_default();
+                    query = query.filter(
+                        relevance_of()
+                            .lt(after_relevance)
+                            .or(relevance_of()
+                                .eq(after_relevance)
+                                .and(company_posts::id.lt(after.id))),
+                    );
+                }
+                query = query.order((relevance_of().desc(), company_posts::id.desc()));
+            }
+            JobSearchSort::Recent => {
+                if let Some(after) = &filter.after {
+                    query = query.filter(
+                        company_posts::created_at.lt(after.created_at).or(company_posts::created_at
+                            .eq(after.created_at)
+                            .and(company_posts::id.lt(after.id))),
+                    );
+                }
+                query = query.order((company_posts::created_at.desc(), company_posts::id.desc()));
+            }
+            JobSearchSort::Distance => {
+                if let Some(after) = &filter.after {
+                    let after_distance = after.distance_km.unwrap_or_default();
+                    query = query.filter(
+                        distance_of()
+                            .gt(after_distance)
+                            .or(distance_of()
+                                .eq(after_distance)
+                                .and(company_posts::id.gt(after.id))),
+                    );
+                }
+                query = query.order((distance_of().asc(), company_posts::id.asc()));
+            }
+            JobSearchSort::Salary => {
+                let top_salary = || coalesce(company_posts::salary_max, company_posts::salary_min);
+                query = query.filter(top_salary().is_not_null());
+                if let Some(after) = &filter.after {
+                    let after_salary = after.salary.unwrap_or_default();
+                    query = query.filter(
+                        top_salary()
+                            .lt(after_salary)
+                            .or(top_salary()
+                                .eq(after_salary)
+                                .and(company_posts::id.lt(after.id))),
+                    );
+                }
+                query = query.order((top_salary().desc(), company_posts::id.desc()));
+            }
+        }
+
+        let rows = query
+            .limit(filter.limit)
+            .load::<(
+                CompanyPostEntity,
+                String,
+                Option<String>,
+                Option<f32>,
+                Option<f64>,
+            )>(&mut conn)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(post, company_name, company_logo, relevance, distance_km)| JobSearchHit {
+                post: CompanyPostWithCompany {
+                    post,
+                    company_name,
+                    company_logo,
+                },
+                relevance,
+                distance_km,
+            })
+            .collect())
+    }
+
+    async fn publish_due(&self, now: DateTime<Utc>) -> Result<Vec<CompanyPostEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = diesel::update(company_posts::table)
+            .filter(company_posts::status.eq(PostStatus::Scheduled.as_str()))
+            .filter(company_posts::publish_at.le(now))
+            .set((
+                company_posts::status.eq(PostStatus::Published.as_str()),
+                company_posts::published_at.eq(Some(now)),
+            ))
+            .returning(CompanyPostEntity::as_returning())
+            .get_results::<CompanyPostEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn expire_due(&self, now: DateTime<Utc>) -> Result<Vec<CompanyPostEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = diesel::update(company_posts::table)
+            .filter(company_posts::status.eq(PostStatus::Published.as_str()))
+            .filter(company_posts::closes_at.le(now))
+            .set((
+                company_posts::status.eq(PostStatus::Expired.as_str()),
+                company_posts::closed_at.eq(Some(now)),
+            ))
+            .returning(CompanyPostEntity::as_returning())
+            .get_results::<CompanyPostEntity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_closing_notice(&self, post_id: Uuid) -> Result<Option<PostClosingNotice>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let Some((job_title, company_name)) = company_posts::table
+            .inner_join(companies::table)
+            .filter(company_posts::id.eq(post_id))
+            .select((company_posts::title, companies::company_name))
+            .first::<(String, String)>(&mut conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let finished = [
+            ApplicationStatus::Hired.as_str(),
+            ApplicationStatus::Rejected.as_str(),
+            ApplicationStatus::Withdrawn.as_str(),
+        ];
+        let rows = job_applications::table
+            .inner_join(users::table)
+            .left_join(user_profiles::table.on(user_profiles::user_id.eq(job_applications::user_id)))
+            .filter(job_applications::job_id.eq(post_id))
+            .filter(job_applications::status.ne_all(finished))
+            .select((
+                job_applications::id,
+                users::username,
+                users::display_name,
+                users::email,
+                user_profiles::first_name_en.nullable(),
+                user_profiles::last_name_en.nullable(),
+                user_profiles::first_name_th.nullable(),
+                user_profiles::last_name_th.nullable(),
+                user_profiles::email.nullable(),
+            ))
+            .load::<(
+                Uuid,
+                String,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+            )>(&mut conn)?;
+
+        // ชื่อ / อีเมลจากโปรไฟล์ก่อน แล้วจึงใช้ของบัญชี
+        let non_empty = |value: Option<String>| value.filter(|value| !value.trim().is_empty());
+        let applicants = rows
+            .into_iter()
+            .map(
+                |(application_id, username, display_name, account_email, first_en, last_en, first_th, last_th, profile_email)| {
+                    let profile_name = format!(
+                        "{} {}",
+                        non_empty(first_en).or(non_empty(first_th)).unwrap_or_default(),
+                        non_empty(last_en).or(non_empty(last_th)).unwrap_or_default()
+                    )
+                    .trim()
+                    .to_string();
+
+                    OpenApplicant {
+                        application_id,
+                        name: non_empty(Some(profile_name))
+                            .or(non_empty(display_name))
+                            .unwrap_or(username),
+                        email: non_empty(profile_email).or(non_empty(account_email)),
+                    }
+                },
+            )
+            .collect();
+
+        Ok(Some(PostClosingNotice {
+            job_title,
+            company_name,
+            applicants,
+        }))
+    }
+
+    async fn index_pending(&self, batch_size: i64) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // ยังไม่เคย index หรือถูกแก้หลังจาก index ครั้งล่าสุด
+        let posts = company_posts::table
+            .left_join(company_post_search::table)
+            .filter(
+                company_post_search::indexed_at
+                    .is_null()
+                    .or(company_post_search::indexed_at.nullable().lt(company_posts::updated_at.nullable())),
+            )
+            .order(company_posts::updated_at.asc())
+            .limit(batch_size)
+            .select(CompanyPostEntity::as_select())
+            .load::<CompanyPostEntity>(&mut conn)?;
+
+        for post in &posts {
+            index_post(&mut conn, post)?;
+        }
+
+        Ok(posts.len())
+    }
+
+    async fn find_screening_questions(&self, post_id: Uuid) -> Result<Vec<ScreeningQuestion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = post_screening_questions::table
+            .filter(post_screening_questions::post_id.eq(post_id))
+            .order(post_screening_questions::position.asc())
+            .select(ScreeningQuestion::as_select())
+            .load::<ScreeningQuestion>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn replace_screening_questions(
+        &self,
+        post_id: Uuid,
+        questions: &[NewScreeningQuestion],
+    ) -> Result<Vec<ScreeningQuestion>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // คำตอบที่ผู้สมัครส่งไปแล้วเก็บ prompt ไว้เอง (question_id เป็น NULL เมื่อคำถามถูกลบ)
+        let mut results = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::delete(post_screening_questions::table.filter(post_screening_questions::post_id.eq(post_id)))
+                .execute(conn)?;
+            if questions.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            diesel::insert_into(post_screening_questions::table)
+                .values(questions)
+                .returning(ScreeningQuestion::as_returning())
+                .get_results::<ScreeningQuestion>(conn)
+        })?;
+
+        results.sort_by_key(|question| question.position);
+        Ok(results)
+    }
 }
@@ -0,0 +1,428 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::{dsl::sql, prelude::*, sql_types::Jsonb};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::data_export::{
+            CompletedDataExport, DataExport, DataExportSection, DataExportStatus, NewDataExport,
+        },
+        repo::data_export::DataExportRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{
+            application_screening_answers, application_status_history, company_posts, data_exports, interviews, job_applications,
+            saved_jobs, saved_searches, social_analysis, social_connections, social_posts, user_addresses,
+            user_ai_scores, user_educations, user_experiences, user_identities, user_job_matches,
+            user_job_preferences, user_portfolios, user_privacy_settings, user_profiles,
+            user_sessions, user_skills, users,
+        },
+    },
+};
+
+pub struct DataExportPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl DataExportPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// ทั้งแถวเป็น JSON object - คอลัมน์ที่เพิ่มในอนาคตจะถูก export อัตโนมัติ
+/// ข้อมูลลับ (password hash, OAuth token) ตัดออกด้วย `- 'column'`
+fn row_json(expression: &'static str) -> diesel::expression::SqlLiteral<Jsonb> {
+    sql::<Jsonb>(expression)
+}
+
+fn section(name: &'static str, description: &'static str, records: Vec<Value>) -> DataExportSection {
+    DataExportSection {
+        name,
+        description,
+        records,
+    }
+}
+
+fn collect_sections(conn: &mut PgConnection, user_id: Uuid) -> QueryResult<Vec<DataExportSection>> {
+    let account = users::table
+        .filter(users::id.eq(user_id))
+        .select(row_json("to_jsonb(users) - 'password_hash'"))
+        .load::<Value>(conn)?;
+
+    let profiles = user_profiles::table
+        .filter(user_profiles::user_id.eq(user_id))
+        .order(user_profiles::created_at.asc())
+        .select(row_json("to_jsonb(user_profiles)"))
+        .load::<Value>(conn)?;
+
+    let addresses = user_addresses::table
+        .filter(user_addresses::user_id.eq(user_id))
+        .order(user_addresses::created_at.asc())
+        .select(row_json("to_jsonb(user_addresses)"))
+        .load::<Value>(conn)?;
+
+    let educations = user_educations::table
+        .filter(user_educations::user_id.eq(user_id))
+        .order(user_educations::start_date.asc())
+        .select(row_json("to_jsonb(user_educations)"))
+        .load::<Value>(conn)?;
+
+    let experiences = user_experiences::table
+        .filter(user_experiences::user_id.eq(user_id))
+        .order(user_experiences::start_date.asc())
+        .select(row_json("to_jsonb(user_experiences)"))
+        .load::<Value>(conn)?;
+
+    let skills = user_skills::table
+        .filter(user_skills::user_id.eq(user_id))
+        .order(user_skills::created_at.asc())
+        .select(row_json("to_jsonb(user_skills)"))
+        .load::<Value>(conn)?;
+
+    let portfolios = user_portfolios::table
+        .filter(user_portfolios::user_id.eq(user_id))
+        .order(user_portfolios::created_at.asc())
+        .select(row_json("to_jsonb(user_portfolios)"))
+        .load::<Value>(conn)?;
+
+    let job_preferences = user_job_preferences::table
+        .filter(user_job_preferences::user_id.eq(user_id))
+        .order(user_job_preferences::created_at.asc())
+        .select(row_json("to_jsonb(user_job_preferences)"))
+        .load::<Value>(conn)?;
+
+    let privacy_settings = user_privacy_settings::table
+        .filter(user_privacy_settings::user_id.eq(user_id))
+        .select(row_json("to_jsonb(user_privacy_settings)"))
+        .load::<Value>(conn)?;
+
+    let linked_identities = user_identities::table
+        .filter(user_identities::user_id.eq(user_id))
+        .order(user_identities::created_at.asc())
+        .select(row_json("to_jsonb(user_identities)"))
+        .load::<Value>(conn)?;
+
+    let sessions = user_sessions::table
+        .filter(user_sessions::user_id.eq(user_id))
+        .order(user_sessions::created_at.asc())
+        .select(row_json("to_jsonb(user_sessions)"))
+        .load::<Value>(conn)?;
+
+    let social_accounts = social_connections::table
+        .filter(social_connections::user_id.eq(user_id))
+        .order(social_connections::created_at.asc())
+        .select(row_json(
+            "to_jsonb(social_connections) - 'access_token' - 'refresh_token'",
+        ))
+        .load::<Value>(conn)?;
+
+    let posts = social_posts::table
+        .inner_join(social_connections::table)
+        .filter(social_connections::user_id.eq(user_id))
+        .order(social_posts::created_at.asc())
+        .select(row_json(
+            "to_jsonb(social_posts) || jsonb_build_object('platform', social_connections.platform)",
+        ))
+        .load::<Value>(conn)?;
+
+    let analyses = social_analysis::table
+        .filter(social_analysis::user_id.eq(user_id))
+        .order(social_analysis::created_at.asc())
+        .select(row_json("to_jsonb(social_analysis)"))
+        .load::<Value>(conn)?;
+
+    let ai_scores = user_ai_scores::table
+        .filter(user_ai_scores::user_id.eq(user_id))
+        .order(user_ai_scores::created_at.asc())
+        .select(row_json("to_jsonb(user_ai_scores)"))
+        .load::<Value>(conn)?;
+
+    let job_matches = user_job_matches::table
+        .filter(user_job_matches::user_id.eq(user_id))
+        .order(user_job_matches::created_at.asc())
+        .select(row_json("to_jsonb(user_job_matches)"))
+        .load::<Value>(conn)?;
+
+    // แนบชื่อตำแหน่งงานไว้ด้วย ผู้ใช้จะได้ไม่ต้องเดาจาก job_id
+    // knocked_out เป็นผลคัดกรองของบริษัท - ผู้สมัครไม่เห็นใน API เช่นกัน
+    let applications = job_applications::table
+        .left_join(company_posts::table)
+        .filter(job_applications::user_id.eq(user_id))
+        .order(job_applications::created_at.asc())
+        .select(row_json(
+            "(to_jsonb(job_applications) - 'knocked_out') || jsonb_build_object('job_title', company_posts.title)",
+        ))
+        .load::<Value>(conn)?;
+
+    let application_answers = application_screening_answers::table
+        .inner_join(job_applications::table)
+        .filter(job_applications::user_id.eq(user_id))
+        .order((application_screening_answers::created_at.asc(), application_screening_answers::position.asc()))
+        .select(row_json("to_jsonb(application_screening_answers) - 'knocked_out'"))
+        .load::<Value>(conn)?;
+
+    // actor_id ของฝั่งบริษัทเป็นข้อมูลของคนอื่น - เหลือแค่ actor_role
+    let application_history = application_status_history::table
+        .inner_join(job_applications::table)
+        .filter(job_applications::user_id.eq(user_id))
+        .order(application_status_history::created_at.asc())
+        .select(row_json("to_jsonb(application_status_history) - 'actor_id'"))
+        .load::<Value>(conn)?;
+
+    let application_interviews = interviews::table
+        .inner_join(job_applications::table)
+        .filter(job_applications::user_id.eq(user_id))
+        .order(interviews::created_at.asc())
+        .select(row_json("to_jsonb(interviews) - 'created_by'"))
+        .load::<Value>(conn)?;
+
+    let bookmarks = saved_jobs::table
+        .left_join(company_posts::table)
+        .filter(saved_jobs::user_id.eq(user_id))
+        .order(saved_jobs::created_at.asc())
+        .select(row_json(
+            "to_jsonb(saved_jobs) || jsonb_build_object('job_title', company_posts.title)",
+        ))
+        .load::<Value>(conn)?;
+
+    let job_alerts = saved_searches::table
+        .filter(saved_searches::user_id.eq(user_id))
+        .order(saved_searches::created_at.asc())
+        .select(row_json("to_jsonb(saved_searches)"))
+        .load::<Value>(conn)?;
+
+    Ok(vec![
+        section("account", "Account (users)", account),
+        section("profile", "Profile (user_profiles)", profiles),
+        section("addresses", "Addresses (user_addresses)", addresses),
+        section("educations", "Education history (user_educations)", educations),
+        section("experiences", "Work experience (user_experiences)", experiences),
+        section("skills", "Skills (user_skills)", skills),
+        section("portfolios", "Portfolio items (user_portfolios)", portfolios),
+        section("job_preferences", "Job preferences (user_job_preferences)", job_preferences),
+        section("privacy_settings", "Privacy settings (user_privacy_settings)", privacy_settings),
+        section("linked_identities", "Linked sign-in providers (user_identities)", linked_identities),
+        section("sessions", "Sign-in sessions and devices (user_sessions)", sessions),
+        section("social_connections", "Connected social accounts, without tokens (social_connections)", social_accounts),
+        section("social_posts", "Imported social posts (social_posts)", posts),
+        section("social_analysis", "Personality analysis from social posts (social_analysis)", analyses),
+        section("ai_scores", "AI profile scores (user_ai_scores)", ai_scores),
+        section("job_matches", "AI job match scores (user_job_matches)", job_matches),
+        section("job_applications", "Job applications (job_applications)", applications),
+        section("application_answers", "Answers to screening questions (application_screening_answers)", application_answers),
+        section("application_history", "Job application status history (application_status_history)", application_history),
+        section("interviews", "Interview appointments (interviews)", application_interviews),
+        section("saved_jobs", "Saved jobs (saved_jobs)", bookmarks),
+        section("saved_searches", "Saved searches and job alerts (saved_searches)", job_alerts),
+    ])
+}
+
+#[async_trait]
+impl DataExportRepository for DataExportPostgres {
+    async fn create(&self, new_export: &NewDataExport) -> Result<DataExport> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(data_exports::table)
+            .values(new_export)
+            .returning(DataExport::as_returning())
+            .get_result::<DataExport>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_for_user(&self, export_id: Uuid, user_id: Uuid) -> Result<Option<DataExport>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = data_exports::table
+            .filter(data_exports::id.eq(export_id))
+            .filter(data_exports::user_id.eq(user_id))
+            .select(DataExport::as_select())
+            .first::<DataExport>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn list_by_user(&self, user_id: Uuid) -> Result<Vec<DataExport>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = data_exports::table
+            .filter(data_exports::user_id.eq(user_id))
+            .order(data_exports::requested_at.desc())
+            .select(DataExport::as_select())
+            .load::<DataExport>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn count_requested_since(&self, user_id: Uuid, since: DateTime<Utc>) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count = data_exports::table
+            .filter(data_exports::user_id.eq(user_id))
+            .filter(data_exports::requested_at.gt(since))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn has_in_progress(&self, user_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let exists = diesel::select(diesel::dsl::exists(
+            data_exports::table
+                .filter(data_exports::user_id.eq(user_id))
+                .filter(data_exports::status.eq_any([
+                    DataExportStatus::Pending.as_str(),
+                    DataExportStatus::Processing.as_str(),
+                ])),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(exists)
+    }
+
+    async fn list_claimable(&self, stale_before: DateTime<Utc>) -> Result<Vec<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = data_exports::table
+            .filter(
+                data_exports::status.eq(DataExportStatus::Pending.as_str()).or(data_exports::status
+                    .eq(DataExportStatus::Processing.as_str())
+                    .and(data_exports::started_at.lt(stale_before))),
+            )
+            .order(data_exports::requested_at.asc())
+            .select(data_exports::id)
+            .load::<Uuid>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn claim(&self, export_id: Uuid, stale_before: DateTime<Utc>) -> Result<Option<Uuid>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // UPDATE แบบมีเงื่อนไข - worker ที่มาช้ากว่าจะได้ 0 แถว
+        let result = diesel::update(
+            data_exports::table
+                .filter(data_exports::id.eq(export_id))
+                .filter(
+                    data_exports::status.eq(DataExportStatus::Pending.as_str()).or(data_exports::status
+                        .eq(DataExportStatus::Processing.as_str())
+                        .and(data_exports::started_at.lt(stale_before))),
+                ),
+        )
+        .set((
+            data_exports::status.eq(DataExportStatus::Processing.as_str()),
+            data_exports::started_at.eq(Some(Utc::now())),
+        ))
+        .returning(data_exports::user_id)
+        .get_result::<Uuid>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn collect_sections(&self, user_id: Uuid) -> Result<Vec<DataExportSection>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // snapshot เดียวกันทุกตาราง - ข้อมูลที่แก้ระหว่าง export จะไม่ปนกันครึ่ง ๆ กลาง ๆ
+        let sections = conn
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|conn| collect_sections(conn, user_id))?;
+
+        Ok(sections)
+    }
+
+    async fn complete(&self, export_id: Uuid, completed: &CompletedDataExport) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(data_exports::table.filter(data_exports::id.eq(export_id)))
+            .set(completed)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn fail(&self, export_id: Uuid, error_message: &str) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(data_exports::table.filter(data_exports::id.eq(export_id)))
+            .set((
+                data_exports::status.eq(DataExportStatus::Failed.as_str()),
+                data_exports::error_message.eq(Some(error_message)),
+                data_exports::completed_at.eq(Some(Utc::now())),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn set_download_token_hash(&self, export_id: Uuid, download_token_hash: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let affected = diesel::update(
+            data_exports::table
+                .filter(data_exports::id.eq(export_id))
+                .filter(data_exports::status.eq(DataExportStatus::Ready.as_str()))
+                .filter(data_exports::expires_at.gt(Utc::now())),
+        )
+        .set(data_exports::download_token_hash.eq(Some(download_token_hash)))
+        .execute(&mut conn)?;
+
+        Ok(affected > 0)
+    }
+
+    async fn find_downloadable(&self, download_token_hash: &str) -> Result<Option<(DataExport, Vec<u8>)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = data_exports::table
+            .filter(data_exports::download_token_hash.eq(download_token_hash))
+            .filter(data_exports::status.eq(DataExportStatus::Ready.as_str()))
+            .filter(data_exports::expires_at.gt(Utc::now()))
+            .select((DataExport::as_select(), data_exports::archive))
+            .first::<(DataExport, Option<Vec<u8>>)>(&mut conn)
+            .optional()?;
+
+        Ok(result.and_then(|(export, archive)| archive.map(|archive| (export, archive))))
+    }
+
+    async fn record_download(&self, export_id: Uuid) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(data_exports::table.filter(data_exports::id.eq(export_id)))
+            .set((
+                data_exports::downloaded_at.eq(Some(Utc::now())),
+                data_exports::download_count.eq(data_exports::download_count + 1),
+            ))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn expire_old(&self) -> Result<u64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let affected = diesel::update(
+            data_exports::table
+                .filter(data_exports::status.eq(DataExportStatus::Ready.as_str()))
+                .filter(data_exports::expires_at.le(Utc::now())),
+        )
+        .set((
+            data_exports::status.eq(DataExportStatus::Expired.as_str()),
+            data_exports::archive.eq(None::<Vec<u8>>),
+            data_exports::download_token_hash.eq(None::<String>),
+        ))
+        .execute(&mut conn)?;
+
+        Ok(affected as u64)
+    }
+}
@@ -0,0 +1,355 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::interview::{
+            CalendarFeed, Interview, InterviewDetails, InterviewParticipants, InterviewSlot,
+            InterviewStatus, InterviewWithSlots, NewInterview, NewInterviewSlot,
+        },
+        repo::interview::InterviewRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{
+            calendar_feeds, companies, company_posts, interview_slots, interviews,
+            job_applications, user_profiles, users,
+        },
+    },
+};
+
+pub struct InterviewPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl InterviewPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// ชื่อและอีเมลสำหรับติดต่อ - ใช้ข้อมูลจากโปรไฟล์ก่อน แล้วจึงใช้ของบัญชี
+pub(crate) fn contact(conn: &mut PgConnection, user_id: Uuid) -> QueryResult<(String, Option<String>)> {
+    let (username, display_name, account_email) = users::table
+        .find(user_id)
+        .select((users::username, users::display_name, users::email))
+        .first::<(String, Option<String>, Option<String>)>(conn)?;
+
+    let profile = user_profiles::table
+        .filter(user_profiles::user_id.eq(user_id))
+        .select((
+            user_profiles::first_name_en.nullable(),
+            user_profiles::last_name_en.nullable(),
+            user_profiles::first_name_th.nullable(),
+            user_profiles::last_name_th.nullable(),
+            user_profiles::email.nullable(),
+        ))
+        .first::<(Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(conn)
+        .optional()?;
+
+    let (profile_name, profile_email) = match profile {
+        Some((first_en, last_en, first_th, last_th, email)) => {
+            let name = format!(
+                "{} {}",
+                first_en.or(first_th).unwrap_or_default(),
+                last_en.or(last_th).unwrap_or_default()
+            )
+            .trim()
+            .to_string();
+            (Some(name).filter(|name| !name.is_empty()), email)
+        }
+        None => (None, None),
+    };
+
+    Ok((
+        profile_name.or(display_name).unwrap_or(username),
+        profile_email.or(account_email).filter(|email| !email.is_empty()),
+    ))
+}
+
+fn load_participants(
+    conn: &mut PgConnection,
+    application_id: Uuid,
+) -> QueryResult<Option<InterviewParticipants>> {
+    let Some((candidate_id, application_status, job_title, company_name, company_user_id)) =
+        job_applications::table
+            .inner_join(company_posts::table.inner_join(companies::table))
+            .filter(job_applications::id.eq(application_id))
+            .select((
+                job_applications::user_id,
+                job_applications::status,
+                company_posts::title,
+                companies::company_name,
+                companies::user_id,
+            ))
+            .first::<(Uuid, String, String, String, Uuid)>(conn)
+            .optional()?
+    else {
+        return Ok(None);
+    };
+
+    let (candidate_name, candidate_email) = contact(conn, candidate_id)?;
+    let (_, company_email) = contact(conn, company_user_id)?;
+
+    Ok(Some(InterviewParticipants {
+        application_id,
+        application_status,
+        job_title,
+        candidate_id,
+        candidate_name,
+        candidate_email,
+        company_user_id,
+        company_name,
+        company_email,
+    }))
+}
+
+fn load_slots(conn: &mut PgConnection, interview_id: Uuid) -> QueryResult<Vec<InterviewSlot>> {
+    interview_slots::table
+        .filter(interview_slots::interview_id.eq(interview_id))
+        .order(interview_slots::starts_at.asc())
+        .select(InterviewSlot::as_select())
+        .load::<InterviewSlot>(conn)
+}
+
+fn insert_slots(
+    conn: &mut PgConnection,
+    interview_id: Uuid,
+    slots: &[(DateTime<Utc>, DateTime<Utc>)],
+) -> QueryResult<Vec<InterviewSlot>> {
+    let new_slots: Vec<NewInterviewSlot> = slots
+        .iter()
+        .map(|(starts_at, ends_at)| NewInterviewSlot {
+            interview_id,
+            starts_at: *starts_at,
+            ends_at: *ends_at,
+        })
+        .collect();
+
+    diesel::insert_into(interview_slots::table)
+        .values(&new_slots)
+        .execute(conn)?;
+
+    load_slots(conn, interview_id)
+}
+
+#[async_trait]
+impl InterviewRepository for InterviewPostgres {
+    async fn find_participants(&self, application_id: Uuid) -> Result<Option<InterviewParticipants>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        Ok(load_participants(&mut conn, application_id)?)
+    }
+
+    async fn create(
+        &self,
+        new_interview: &NewInterview,
+        slots: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Result<InterviewWithSlots> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<InterviewWithSlots, diesel::result::Error, _>(|conn| {
+            let interview = diesel::insert_into(interviews::table)
+                .values(new_interview)
+                .returning(Interview::as_returning())
+                .get_result::<Interview>(conn)?;
+            let slots = insert_slots(conn, interview.id, slots)?;
+
+            Ok(InterviewWithSlots { interview, slots })
+        })?;
+
+        Ok(result)
+    }
+
+    async fn find_by_id(&self, interview_id: Uuid) -> Result<Option<InterviewWithSlots>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let Some(interview) = interviews::table
+            .find(interview_id)
+            .select(Interview::as_select())
+            .first::<Interview>(&mut conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+        let slots = load_slots(&mut conn, interview.id)?;
+
+        Ok(Some(InterviewWithSlots { interview, slots }))
+    }
+
+    async fn find_by_application(&self, application_id: Uuid) -> Result<Vec<InterviewWithSlots>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = interviews::table
+            .filter(interviews::application_id.eq(application_id))
+            .order(interviews::created_at.desc())
+            .select(Interview::as_select())
+            .load::<Interview>(&mut conn)?;
+
+        let slots = InterviewSlot::belonging_to(&results)
+            .order(interview_slots::starts_at.asc())
+            .select(InterviewSlot::as_select())
+            .load::<InterviewSlot>(&mut conn)?
+            .grouped_by(&results);
+
+        Ok(results
+            .into_iter()
+            .zip(slots)
+            .map(|(interview, slots)| InterviewWithSlots { interview, slots })
+            .collect())
+    }
+
+    async fn confirm(&self, interview_id: Uuid, slot: &InterviewSlot) -> Result<Option<Interview>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(
+            interviews::table
+                .filter(interviews::id.eq(interview_id))
+                .filter(interviews::status.eq(InterviewStatus::Proposed.as_str())),
+        )
+        .set((
+            interviews::status.eq(InterviewStatus::Scheduled.as_str()),
+            interviews::starts_at.eq(Some(slot.starts_at)),
+            interviews::ends_at.eq(Some(slot.ends_at)),
+            interviews::sequence.eq(interviews::sequence + 1),
+        ))
+        .returning(Interview::as_returning())
+        .get_result::<Interview>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn reschedule(
+        &self,
+        interview_id: Uuid,
+        details: &InterviewDetails,
+        slots: &[(DateTime<Utc>, DateTime<Utc>)],
+    ) -> Result<Option<InterviewWithSlots>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<Option<InterviewWithSlots>, diesel::result::Error, _>(|conn| {
+            let Some(interview) = diesel::update(
+                interviews::table
+                    .filter(interviews::id.eq(interview_id))
+                    .filter(interviews::status.ne(InterviewStatus::Cancelled.as_str())),
+            )
+            .set((
+                details,
+                interviews::status.eq(InterviewStatus::Proposed.as_str()),
+                interviews::starts_at.eq(None::<DateTime<Utc>>),
+                interviews::ends_at.eq(None::<DateTime<Utc>>),
+                interviews::sequence.eq(interviews::sequence + 1),
+            ))
+            .returning(Interview::as_returning())
+            .get_result::<Interview>(conn)
+            .optional()?
+            else {
+                return Ok(None);
+            };
+
+            diesel::delete(interview_slots::table.filter(interview_slots::interview_id.eq(interview.id)))
+                .execute(conn)?;
+            let slots = insert_slots(conn, interview.id, slots)?;
+
+            Ok(Some(InterviewWithSlots { interview, slots }))
+        })?;
+
+        Ok(result)
+    }
+
+    async fn cancel(&self, interview_id: Uuid, reason: Option<String>) -> Result<Option<Interview>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(
+            interviews::table
+                .filter(interviews::id.eq(interview_id))
+                .filter(interviews::status.ne(InterviewStatus::Cancelled.as_str())),
+        )
+        .set((
+            interviews::status.eq(InterviewStatus::Cancelled.as_str()),
+            interviews::cancel_reason.eq(reason),
+            interviews::cancelled_at.eq(Some(Utc::now())),
+            interviews::sequence.eq(interviews::sequence + 1),
+        ))
+        .returning(Interview::as_returning())
+        .get_result::<Interview>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_calendar_entries(
+        &self,
+        user_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(Interview, InterviewParticipants)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = interviews::table
+            .inner_join(job_applications::table.inner_join(company_posts::table.inner_join(companies::table)))
+            .filter(
+                job_applications::user_id
+                    .eq(user_id)
+                    .or(companies::user_id.eq(user_id)),
+            )
+            .filter(interviews::starts_at.ge(since))
+            .order(interviews::starts_at.asc())
+            .select(Interview::as_select())
+            .load::<Interview>(&mut conn)?;
+
+        let mut entries = Vec::with_capacity(results.len());
+        for interview in results {
+            if let Some(participants) = load_participants(&mut conn, interview.application_id)? {
+                entries.push((interview, participants));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    async fn find_feed(&self, user_id: Uuid) -> Result<Option<CalendarFeed>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = calendar_feeds::table
+            .find(user_id)
+            .select(CalendarFeed::as_select())
+            .first::<CalendarFeed>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_feed_by_token_hash(&self, feed_token_hash: &str) -> Result<Option<CalendarFeed>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = calendar_feeds::table
+            .filter(calendar_feeds::feed_token_hash.eq(feed_token_hash))
+            .select(CalendarFeed::as_select())
+            .first::<CalendarFeed>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn save_feed(&self, feed: &CalendarFeed) -> Result<CalendarFeed> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(calendar_feeds::table)
+            .values(feed)
+            .on_conflict(calendar_feeds::user_id)
+            .do_update()
+            .set((
+                calendar_feeds::feed_token_hash.eq(&feed.feed_token_hash),
+                calendar_feeds::created_at.eq(feed.created_at),
+            ))
+            .returning(CalendarFeed::as_returning())
+            .get_result::<CalendarFeed>(&mut conn)?;
+
+        Ok(result)
+    }
+}
@@ -1,11 +1,22 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use diesel::prelude::*;
 use uuid::Uuid;
+use std::collections::HashMap;
 use std::sync::Arc;
-use crate::domain::entities::job_application::{JobApplicationEntity, NewJobApplication, JobApplicationWithUser};
+use crate::domain::entities::company_post::ScreeningQuestion;
+use crate::domain::entities::job_application::{
+    ApplicationActorRole, ApplicationContacts, ApplicationScreeningAnswer, ApplicationStatus,
+    ApplicationStatusHistoryEntity, ApplyEligibility, JobApplicationEntity, JobApplicationWithUser,
+    NewApplicationScreeningAnswer, NewApplicationStatusHistory, NewJobApplication,
+};
 use crate::domain::repo::job_application::JobApplicationRepository;
-use crate::infrastructure::postgres::schema::{job_applications, user_profiles};
+use crate::domain::entities::user::{Role, UserStatusEnum};
+use crate::infrastructure::postgres::schema::{
+    application_screening_answers, application_status_history, companies, company_posts,
+    job_applications, post_screening_questions, user_profiles, users,
+};
 use crate::infrastructure::postgres::postgres_connection::DbPool;
 
 pub struct JobApplicationPostgres {
@@ -18,25 +29,109 @@ impl JobApplicationPostgres {
     }
 }
 
+/// ชื่อและอีเมลจากโปรไฟล์ (ชื่อภาษาอังกฤษก่อน) แล้วจึงใช้อีเมลของบัญชี
+fn profile_contact(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> QueryResult<(Option<String>, Option<String>, Option<String>)> {
+    let account_email = users::table
+        .find(user_id)
+        .select(users::email)
+        .first::<Option<String>>(conn)?;
+
+    let profile = user_profiles::table
+        .filter(user_profiles::user_id.eq(user_id))
+        .select((
+            user_profiles::first_name_en.nullable(),
+            user_profiles::last_name_en.nullable(),
+            user_profiles::first_name_th.nullable(),
+            user_profiles::last_name_th.nullable(),
+            user_profiles::email.nullable(),
+        ))
+        .first::<(Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(conn)
+        .optional()?;
+
+    let non_empty = |value: Option<String>| value.filter(|value| !value.trim().is_empty());
+    Ok(match profile {
+        Some((first_en, last_en, first_th, last_th, email)) => (
+            non_empty(first_en).or(non_empty(first_th)),
+            non_empty(last_en).or(non_empty(last_th)),
+            non_empty(email).or(non_empty(account_email)),
+        ),
+        None => (None, None, non_empty(account_email)),
+    })
+}
+
 #[async_trait]
 impl JobApplicationRepository for JobApplicationPostgres {
-    async fn create(&self, application: NewJobApplication) -> Result<JobApplicationEntity> {
+    async fn create(
+        &self,
+        application: NewJobApplication,
+        mut answers: Vec<NewApplicationScreeningAnswer>,
+    ) -> Result<JobApplicationEntity> {
         let mut conn = self.pool.get()?;
 
-        let result = diesel::insert_into(job_applications::table)
-            .values(&application)
-            .get_result(&mut conn)?;
+        let result = conn.transaction::<JobApplicationEntity, diesel::result::Error, _>(|conn| {
+            let created: JobApplicationEntity = diesel::insert_into(job_applications::table)
+                .values(&application)
+                .get_result(conn)?;
+
+            if !answers.is_empty() {
+                for answer in &mut answers {
+                    answer.application_id = created.id;
+                }
+                diesel::insert_into(application_screening_answers::table)
+                    .values(&answers)
+                    .execute(conn)?;
+            }
+
+            let initial_status = ApplicationStatus::parse(&created.status).unwrap_or(ApplicationStatus::Applied);
+            diesel::insert_into(application_status_history::table)
+                .values(&NewApplicationStatusHistory::new(
+                    created.id,
+                    None,
+                    initial_status,
+                    Some(created.user_id),
+                    ApplicationActorRole::Candidate,
+                    None,
+                ))
+                .execute(conn)?;
+
+            Ok(created)
+        })?;
 
         Ok(result)
     }
 
-    async fn find_by_job_id(&self, job_id: Uuid) -> Result<Vec<JobApplicationWithUser>> {
+    async fn find_by_id(&self, application_id: Uuid) -> Result<Option<JobApplicationEntity>> {
+        let mut conn = self.pool.get()?;
+
+        let result = job_applications::table
+            .find(application_id)
+            .first::<JobApplicationEntity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_by_job_id(
+        &self,
+        job_id: Uuid,
+        knocked_out: Option<bool>,
+    ) -> Result<Vec<JobApplicationWithUser>> {
         let mut conn = self.pool.get()?;
 
         // Join job_applications with user_profiles to get candidate details
-        let results = job_applications::table
+        let mut query = job_applications::table
             .filter(job_applications::job_id.eq(job_id))
             .left_join(user_profiles::table.on(user_profiles::user_id.eq(job_applications::user_id)))
+            .into_boxed();
+        if let Some(knocked_out) = knocked_out {
+            query = query.filter(job_applications::knocked_out.eq(knocked_out));
+        }
+
+        let results = query
+            .order(job_applications::created_at.asc())
             .select((
                 job_applications::all_columns,
                 user_profiles::email.nullable(),
@@ -58,6 +153,17 @@ impl JobApplicationRepository for JobApplicationPostgres {
                 Option<String>, // profile_image_url
             )>(&mut conn)?;
 
+        let application_ids: Vec<Uuid> = results.iter().map(|(app, ..)| app.id).collect();
+        let mut answers: HashMap<Uuid, Vec<ApplicationScreeningAnswer>> = HashMap::new();
+        for answer in application_screening_answers::table
+            .filter(application_screening_answers::application_id.eq_any(&application_ids))
+            .order(application_screening_answers::position.asc())
+            .select(ApplicationScreeningAnswer::as_select())
+            .load::<ApplicationScreeningAnswer>(&mut conn)?
+        {
+            answers.entry(answer.application_id).or_default().push(answer);
+        }
+
         let mapped_results = results.into_iter().map(|(app, email, fn_en, ln_en, fn_th, ln_th, phone, img)| {
             let first_name = fn_en.or(fn_th);
             let last_name = ln_en.or(ln_th);
@@ -69,6 +175,10 @@ impl JobApplicationRepository for JobApplicationPostgres {
                 status: app.status,
                 created_at: app.created_at,
                 updated_at: app.updated_at,
+                cover_letter: app.cover_letter,
+                resume_url: app.resume_url,
+                knocked_out: app.knocked_out,
+                screening_answers: answers.remove(&app.id).unwrap_or_default(),
                 first_name,
                 last_name,
                 email,
@@ -103,13 +213,127 @@ impl JobApplicationRepository for JobApplicationPostgres {
         Ok(count > 0)
     }
 
-    async fn update_status(&self, application_id: Uuid, status: String) -> Result<JobApplicationEntity> {
+    async fn find_apply_eligibility(&self, user_id: Uuid, job_id: Uuid) -> Result<Option<ApplyEligibility>> {
         let mut conn = self.pool.get()?;
 
-        let result = diesel::update(job_applications::table.find(application_id))
-            .set(job_applications::status.eq(status))
-            .get_result(&mut conn)?;
+        let Some((post_status, post_closes_at, company_user_id, company_status, company_account_status)) =
+            company_posts::table
+            .inner_join(companies::table.inner_join(users::table))
+            .filter(company_posts::id.eq(job_id))
+            .select((
+                company_posts::status,
+                company_posts::closes_at,
+                companies::user_id,
+                companies::status,
+                users::status,
+            ))
+            .first::<(String, Option<DateTime<Utc>>, Uuid, String, UserStatusEnum)>(&mut conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let applicant_role = users::table
+            .find(user_id)
+            .select(users::role)
+            .first::<Role>(&mut conn)?;
+        let (applicant_first_name, applicant_last_name, applicant_email) = profile_contact(&mut conn, user_id)?;
+
+        Ok(Some(ApplyEligibility {
+            post_status,
+            post_closes_at,
+            company_user_id,
+            company_status,
+            company_account_status,
+            applicant_role,
+            applicant_first_name,
+            applicant_last_name,
+            applicant_email,
+        }))
+    }
+
+    async fn find_contacts(&self, application_id: Uuid) -> Result<Option<ApplicationContacts>> {
+        let mut conn = self.pool.get()?;
+
+        let Some((applicant_id, job_title, company_name, company_user_id)) = job_applications::table
+            .inner_join(company_posts::table.inner_join(companies::table))
+            .filter(job_applications::id.eq(application_id))
+            .select((job_applications::user_id, company_posts::title, companies::company_name, companies::user_id))
+            .first::<(Uuid, String, String, Uuid)>(&mut conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let (first_name, last_name, applicant_email) = profile_contact(&mut conn, applicant_id)?;
+        let (_, _, company_email) = profile_contact(&mut conn, company_user_id)?;
+        let applicant_name = format!("{} {}", first_name.unwrap_or_default(), last_name.unwrap_or_default())
+            .trim()
+            .to_string();
+
+        Ok(Some(ApplicationContacts {
+            job_title,
+            company_name,
+            company_email,
+            applicant_name,
+            applicant_email,
+        }))
+    }
+
+    async fn transition_status(
+        &self,
+        from_status: &str,
+        history: NewApplicationStatusHistory,
+    ) -> Result<Option<JobApplicationEntity>> {
+        let mut conn = self.pool.get()?;
+
+        let result = conn.transaction::<Option<JobApplicationEntity>, diesel::result::Error, _>(|conn| {
+            // เงื่อนไข status เดิมกันสองคนเปลี่ยนพร้อมกันจากสถานะเดียวกัน
+            let updated = diesel::update(
+                job_applications::table
+                    .find(history.application_id)
+                    .filter(job_applications::status.eq(from_status)),
+            )
+            .set((
+                job_applications::status.eq(&history.to_status),
+                job_applications::updated_at.eq(Utc::now()),
+            ))
+            .get_result::<JobApplicationEntity>(conn)
+            .optional()?;
+
+            if updated.is_some() {
+                diesel::insert_into(application_status_history::table)
+                    .values(&history)
+                    .execute(conn)?;
+            }
+
+            Ok(updated)
+        })?;
 
         Ok(result)
     }
+
+    async fn find_screening_questions(&self, job_id: Uuid) -> Result<Vec<ScreeningQuestion>> {
+        let mut conn = self.pool.get()?;
+
+        let results = post_screening_questions::table
+            .filter(post_screening_questions::post_id.eq(job_id))
+            .order(post_screening_questions::position.asc())
+            .select(ScreeningQuestion::as_select())
+            .load::<ScreeningQuestion>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_status_history(&self, application_id: Uuid) -> Result<Vec<ApplicationStatusHistoryEntity>> {
+        let mut conn = self.pool.get()?;
+
+        let results = application_status_history::table
+            .filter(application_status_history::application_id.eq(application_id))
+            .order((application_status_history::created_at.asc(), application_status_history::id.asc()))
+            .select(ApplicationStatusHistoryEntity::as_select())
+            .load::<ApplicationStatusHistoryEntity>(&mut conn)?;
+
+        Ok(results)
+    }
 }
@@ -0,0 +1,171 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::login_throttle::{LoginLockout, LoginLockoutEvent, NewLoginLockoutEvent},
+        repo::login_throttle::LoginThrottleRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{auth_rate_limits, login_lockout_events, login_lockouts},
+    },
+};
+
+pub struct LoginThrottlePostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl LoginThrottlePostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl LoginThrottleRepository for LoginThrottlePostgres {
+    async fn hit(&self, bucket_key: &str, window: Duration) -> Result<(i32, DateTime<Utc>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now();
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::insert_into(auth_rate_limits::table)
+                .values((
+                    auth_rate_limits::bucket_key.eq(bucket_key),
+                    auth_rate_limits::window_started_at.eq(now),
+                    auth_rate_limits::hits.eq(0),
+                ))
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            // FOR UPDATE กันหลาย instance นับทับกัน
+            let (window_started_at, hits) = auth_rate_limits::table
+                .find(bucket_key)
+                .select((auth_rate_limits::window_started_at, auth_rate_limits::hits))
+                .for_update()
+                .first::<(DateTime<Utc>, i32)>(conn)?;
+
+            let (window_started_at, hits) = if window_started_at + window <= now {
+                (now, 1)
+            } else {
+                (window_started_at, hits + 1)
+            };
+
+            diesel::update(auth_rate_limits::table.find(bucket_key))
+                .set((
+                    auth_rate_limits::window_started_at.eq(window_started_at),
+                    auth_rate_limits::hits.eq(hits),
+                ))
+                .execute(conn)?;
+
+            Ok((hits, window_started_at))
+        })?;
+
+        Ok(result)
+    }
+
+    async fn find_lockout(&self, username: &str) -> Result<Option<LoginLockout>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = login_lockouts::table
+            .find(username)
+            .select(LoginLockout::as_select())
+            .first::<LoginLockout>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn record_failure(&self, username: &str, ip_address: Option<String>) -> Result<LoginLockout> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now();
+
+        let result = diesel::insert_into(login_lockouts::table)
+            .values((
+                login_lockouts::username.eq(username),
+                login_lockouts::failed_attempts.eq(1),
+                login_lockouts::last_failed_at.eq(now),
+                login_lockouts::last_ip_address.eq(&ip_address),
+            ))
+            .on_conflict(login_lockouts::username)
+            .do_update()
+            .set((
+                login_lockouts::failed_attempts.eq(login_lockouts::failed_attempts + 1),
+                login_lockouts::last_failed_at.eq(now),
+                login_lockouts::last_ip_address.eq(&ip_address),
+            ))
+            .returning(LoginLockout::as_returning())
+            .get_result::<LoginLockout>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn lock(&self, event: &NewLoginLockoutEvent) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::update(login_lockouts::table.find(&event.username))
+                .set((
+                    login_lockouts::failed_attempts.eq(0),
+                    login_lockouts::lockout_level.eq(event.lockout_level),
+                    login_lockouts::locked_until.eq(Some(event.locked_until)),
+                ))
+                .execute(conn)?;
+
+            diesel::insert_into(login_lockout_events::table)
+                .values(event)
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn clear(&self, username: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(login_lockouts::table.find(username))
+            .execute(&mut conn)?;
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn list_active_lockouts(&self) -> Result<Vec<LoginLockout>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = login_lockouts::table
+            .filter(login_lockouts::locked_until.gt(Utc::now()))
+            .order(login_lockouts::locked_until.desc())
+            .select(LoginLockout::as_select())
+            .load::<LoginLockout>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn list_events(&self, limit: i64) -> Result<Vec<LoginLockoutEvent>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = login_lockout_events::table
+            .order(login_lockout_events::created_at.desc())
+            .limit(limit)
+            .select(LoginLockoutEvent::as_select())
+            .load::<LoginLockoutEvent>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn delete_stale_buckets(&self, before: DateTime<Utc>) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            auth_rate_limits::table.filter(auth_rate_limits::window_started_at.lt(before)),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+}
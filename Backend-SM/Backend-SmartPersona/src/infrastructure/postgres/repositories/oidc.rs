@@ -0,0 +1,179 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            oidc::{NewOidcAuthRequest, NewUserIdentity, OidcAuthRequest, UserIdentity},
+            user::RegisterUserEntity,
+        },
+        repo::oidc::OidcRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        repositories::user::map_unique_violation,
+        schema::{oidc_auth_requests, user_identities, users},
+    },
+};
+
+/// Unique violation ของ user_identities -> ข้อความที่ router แปลงเป็น 409
+fn map_identity_violation(e: diesel::result::Error) -> anyhow::Error {
+    use diesel::result::{DatabaseErrorKind, Error};
+
+    match e {
+        Error::DatabaseError(DatabaseErrorKind::UniqueViolation, ref info)
+            if info
+                .constraint_name()
+                .is_some_and(|name| name.starts_with("user_identities")) =>
+        {
+            if info.constraint_name().is_some_and(|name| name.contains("subject")) {
+                anyhow::anyhow!("This identity is already linked to another account")
+            } else {
+                anyhow::anyhow!("Provider already linked to this account")
+            }
+        }
+        other => map_unique_violation(other),
+    }
+}
+
+pub struct OidcPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl OidcPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl OidcRepository for OidcPostgres {
+    async fn create_auth_request(&self, new_request: &NewOidcAuthRequest) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(oidc_auth_requests::table)
+            .values(new_request)
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn take_auth_request(&self, state_hash: &str) -> Result<Option<OidcAuthRequest>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // DELETE ... RETURNING กัน callback เดียวกันถูกใช้ซ้ำพร้อมกัน
+        let result = diesel::delete(
+            oidc_auth_requests::table.filter(oidc_auth_requests::state_hash.eq(state_hash)),
+        )
+        .returning(OidcAuthRequest::as_returning())
+        .get_result::<OidcAuthRequest>(&mut conn)
+        .optional()?;
+
+        Ok(result)
+    }
+
+    async fn find_identity(&self, provider: &str, subject: &str) -> Result<Option<UserIdentity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = user_identities::table
+            .filter(user_identities::provider.eq(provider))
+            .filter(user_identities::subject.eq(subject))
+            .select(UserIdentity::as_select())
+            .first::<UserIdentity>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn create_identity(&self, new_identity: &NewUserIdentity) -> Result<UserIdentity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(user_identities::table)
+            .values(new_identity)
+            .returning(UserIdentity::as_returning())
+            .get_result::<UserIdentity>(&mut conn)
+            .map_err(map_identity_violation)
+    }
+
+    async fn create_user_with_identity(
+        &self,
+        user: RegisterUserEntity,
+        provider: String,
+        subject: String,
+    ) -> Result<Uuid> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let email = user.email.clone();
+
+            let user_id = diesel::insert_into(users::table)
+                .values(&user)
+                .returning(users::id)
+                .get_result::<Uuid>(conn)?;
+
+            diesel::insert_into(user_identities::table)
+                .values(&NewUserIdentity {
+                    user_id,
+                    provider,
+                    subject,
+                    email,
+                    last_login_at: Some(Utc::now()),
+                })
+                .execute(conn)?;
+
+            Ok(user_id)
+        });
+
+        result.map_err(map_identity_violation)
+    }
+
+    async fn touch_identity(&self, identity_id: Uuid) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(user_identities::table.find(identity_id))
+            .set(user_identities::last_login_at.eq(Some(Utc::now())))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn list_identities(&self, user_id: Uuid) -> Result<Vec<UserIdentity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = user_identities::table
+            .filter(user_identities::user_id.eq(user_id))
+            .order(user_identities::created_at.asc())
+            .select(UserIdentity::as_select())
+            .load::<UserIdentity>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn delete_identity(&self, user_id: Uuid, provider: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            user_identities::table
+                .filter(user_identities::user_id.eq(user_id))
+                .filter(user_identities::provider.eq(provider)),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn delete_expired_auth_requests(&self) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            oidc_auth_requests::table.filter(oidc_auth_requests::expires_at.lt(Utc::now())),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+}
@@ -0,0 +1,325 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            company_post::PostStatus,
+            saved_search::{NewSavedSearch, PendingAlertPost, SavedSearch, UpdateSavedSearch},
+        },
+        repo::saved_search::SavedSearchRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        repositories::interview::contact,
+        schema::{
+            companies, company_posts, saved_search_matches, saved_search_unsubscribe_tokens,
+            saved_searches,
+        },
+    },
+};
+
+pub struct SavedSearchPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl SavedSearchPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+/// ลบประกาศที่จับคู่ไว้แต่ยังไม่ได้ส่งของการค้นหานี้
+fn discard_pending_matches(conn: &mut PgConnection, saved_search_id: Uuid) -> QueryResult<usize> {
+    diesel::delete(saved_search_matches::table)
+        .filter(saved_search_matches::saved_search_id.eq(saved_search_id))
+        .filter(saved_search_matches::sent_at.is_null())
+        .execute(conn)
+}
+
+#[async_trait]
+impl SavedSearchRepository for SavedSearchPostgres {
+    async fn create(&self, new_saved_search: &NewSavedSearch) -> Result<SavedSearch> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(saved_searches::table)
+            .values(new_saved_search)
+            .returning(SavedSearch::as_returning())
+            .get_result::<SavedSearch>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Vec<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = saved_searches::table
+            .filter(saved_searches::user_id.eq(user_id))
+            .order(saved_searches::created_at.desc())
+            .select(SavedSearch::as_select())
+            .load::<SavedSearch>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn count_by_user(&self, user_id: Uuid) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count = saved_searches::table
+            .filter(saved_searches::user_id.eq(user_id))
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn find_by_id(&self, id: Uuid, user_id: Uuid) -> Result<Option<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = saved_searches::table
+            .filter(saved_searches::id.eq(id))
+            .filter(saved_searches::user_id.eq(user_id))
+            .select(SavedSearch::as_select())
+            .first::<SavedSearch>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+        changes: &UpdateSavedSearch,
+    ) -> Result<Option<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let updated = diesel::update(saved_searches::table)
+                .filter(saved_searches::id.eq(id))
+                .filter(saved_searches::user_id.eq(user_id))
+                .set(changes)
+                .returning(SavedSearch::as_returning())
+                .get_result::<SavedSearch>(conn)
+                .optional()?;
+
+            if updated.is_some() && changes.discards_pending_matches() {
+                discard_pending_matches(conn, id)?;
+            }
+
+            Ok(updated)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn delete(&self, id: Uuid, user_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(saved_searches::table)
+            .filter(saved_searches::id.eq(id))
+            .filter(saved_searches::user_id.eq(user_id))
+            .execute(&mut conn)?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn add_unsubscribe_token(
+        &self,
+        saved_search_id: Uuid,
+        token_hash: &str,
+        expired_before: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::insert_into(saved_search_unsubscribe_tokens::table)
+            .values((
+                saved_search_unsubscribe_tokens::token_hash.eq(token_hash),
+                saved_search_unsubscribe_tokens::saved_search_id.eq(saved_search_id),
+            ))
+            .execute(&mut conn)?;
+
+        diesel::delete(saved_search_unsubscribe_tokens::table)
+            .filter(saved_search_unsubscribe_tokens::saved_search_id.eq(saved_search_id))
+            .filter(saved_search_unsubscribe_tokens::created_at.lt(expired_before))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn unsubscribe(&self, token_hash: &str) -> Result<Vec<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let saved_search_ids = saved_search_unsubscribe_tokens::table
+                .filter(saved_search_unsubscribe_tokens::token_hash.eq(token_hash))
+                .select(saved_search_unsubscribe_tokens::saved_search_id)
+                .load::<Uuid>(conn)?;
+            if saved_search_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let updated = diesel::update(saved_searches::table)
+                .filter(saved_searches::id.eq_any(&saved_search_ids))
+                .set(saved_searches::alerts_enabled.eq(false))
+                .returning(SavedSearch::as_returning())
+                .get_results::<SavedSearch>(conn)?;
+
+            for saved_search in &updated {
+                discard_pending_matches(conn, saved_search.id)?;
+            }
+
+            Ok(updated)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn find_alerting(&self) -> Result<Vec<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = saved_searches::table
+            .filter(saved_searches::alerts_enabled.eq(true))
+            .order(saved_searches::matched_until.asc())
+            .select(SavedSearch::as_select())
+            .load::<SavedSearch>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn record_matches(
+        &self,
+        saved_search_id: Uuid,
+        post_ids: &[Uuid],
+        matched_until: DateTime<Utc>,
+    ) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows: Vec<_> = post_ids
+            .iter()
+            .map(|post_id| {
+                (
+                    saved_search_matches::saved_search_id.eq(saved_search_id),
+                    saved_search_matches::post_id.eq(*post_id),
+                )
+            })
+            .collect();
+
+        let inserted = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let inserted = if rows.is_empty() {
+                0
+            } else {
+                diesel::insert_into(saved_search_matches::table)
+                    .values(&rows)
+                    .on_conflict((
+                        saved_search_matches::saved_search_id,
+                        saved_search_matches::post_id,
+                    ))
+                    .do_nothing()
+                    .execute(conn)?
+            };
+
+            diesel::update(saved_searches::table.find(saved_search_id))
+                .set(saved_searches::matched_until.eq(matched_until))
+                .execute(conn)?;
+
+            Ok(inserted)
+        })?;
+
+        Ok(inserted)
+    }
+
+    async fn find_with_pending_matches(&self) -> Result<Vec<SavedSearch>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let pending = saved_search_matches::table
+            .filter(saved_search_matches::sent_at.is_null())
+            .select(saved_search_matches::saved_search_id);
+
+        let results = saved_searches::table
+            .filter(saved_searches::alerts_enabled.eq(true))
+            .filter(saved_searches::id.eq_any(pending))
+            .order((saved_searches::user_id, saved_searches::created_at))
+            .select(SavedSearch::as_select())
+            .load::<SavedSearch>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn find_pending_posts(
+        &self,
+        user_id: Uuid,
+        saved_search_ids: &[Uuid],
+    ) -> Result<Vec<PendingAlertPost>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let pending = saved_search_matches::table
+            .inner_join(company_posts::table.inner_join(companies::table))
+            .filter(saved_search_matches::saved_search_id.eq_any(saved_search_ids))
+            .filter(saved_search_matches::sent_at.is_null())
+            .filter(company_posts::status.eq(PostStatus::Published.as_str()))
+            .filter(
+                company_posts::closes_at
+                    .is_null()
+                    .or(company_posts::closes_at.gt(Utc::now())),
+            )
+            .order((
+                company_posts::published_at.desc().nulls_last(),
+                company_posts::id,
+            ))
+            .select((
+                saved_search_matches::saved_search_id,
+                company_posts::id,
+                company_posts::title,
+                companies::company_name,
+                company_posts::location,
+                company_posts::job_type,
+            ))
+            .load::<PendingAlertPost>(&mut conn)?;
+
+        // ประกาศที่เคยส่งให้ user นี้แล้ว (ผ่านการค้นหาใดก็ตาม) ไม่ส่งซ้ำ
+        let post_ids: Vec<Uuid> = pending.iter().map(|post| post.post_id).collect();
+        let already_sent: Vec<Uuid> = saved_search_matches::table
+            .inner_join(saved_searches::table)
+            .filter(saved_searches::user_id.eq(user_id))
+            .filter(saved_search_matches::sent_at.is_not_null())
+            .filter(saved_search_matches::post_id.eq_any(&post_ids))
+            .select(saved_search_matches::post_id)
+            .load(&mut conn)?;
+
+        Ok(pending
+            .into_iter()
+            .filter(|post| !already_sent.contains(&post.post_id))
+            .collect())
+    }
+
+    async fn find_recipient(&self, user_id: Uuid) -> Result<(String, Option<String>)> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        Ok(contact(&mut conn, user_id)?)
+    }
+
+    async fn mark_sent(&self, saved_search_ids: &[Uuid], sent_at: DateTime<Utc>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::update(saved_search_matches::table)
+                .filter(saved_search_matches::saved_search_id.eq_any(saved_search_ids))
+                .filter(saved_search_matches::sent_at.is_null())
+                .set(saved_search_matches::sent_at.eq(Some(sent_at)))
+                .execute(conn)?;
+
+            diesel::update(saved_searches::table)
+                .filter(saved_searches::id.eq_any(saved_search_ids))
+                .set(saved_searches::last_sent_at.eq(Some(sent_at)))
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+}
@@ -7,12 +7,28 @@ use chrono::Utc;
 
 use crate::{
     domain::{
-        entities::user::{RegisterUserEntity, UserEntity, UserStatusEnum},
+        entities::user::{RegisterUserEntity, Role, UserEntity, UserStatusEnum},
         repo::user::UserRepository,
     },
     infrastructure::postgres::{postgres_connection::DbPool, schema::users},
 };
 
+/// แปลง unique violation ของตาราง users เป็นข้อความที่ router map เป็น 409 ได้
+pub fn map_unique_violation(e: diesel::result::Error) -> anyhow::Error {
+    use diesel::result::{DatabaseErrorKind, Error};
+
+    match e {
+        Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info) => {
+            if info.constraint_name().is_some_and(|name| name.contains("email")) {
+                anyhow::anyhow!("Email already registered")
+            } else {
+                anyhow::anyhow!("Username already taken")
+            }
+        }
+        other => other.into(),
+    }
+}
+
 pub struct UserPostgres {
     db_pool: Arc<DbPool>,
 }
@@ -24,8 +40,6 @@ impl UserPostgres {
 #[async_trait]
 impl UserRepository for UserPostgres {
     async fn register(&self, register_user_entity: RegisterUserEntity) -> Result<Uuid> {
-        use crate::domain::entities::user::{Role, UserStatusEnum};
-        
         let mut conn = Arc::clone(&self.db_pool).get()?;
         
         // If role is None, use database default (PersonaUser)
@@ -38,13 +52,15 @@ impl UserRepository for UserPostgres {
             .values((
                 users::username.eq(register_user_entity.username),
                 users::password_hash.eq(register_user_entity.password_hash),
+                users::email.eq(register_user_entity.email),
                 users::role.eq(role),
                 users::status.eq(status),
                 users::created_at.eq(register_user_entity.created_at),
                 users::updated_at.eq(register_user_entity.updated_at),
             ))
             .returning(users::id)
-            .get_result::<Uuid>(&mut conn)?;
+            .get_result::<Uuid>(&mut conn)
+            .map_err(map_unique_violation)?;
         
         Ok(result)
     }
@@ -93,4 +109,113 @@ impl UserRepository for UserPostgres {
         
         Ok(updated)
     }
+
+    async fn suspend(&self, user_id: Uuid) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        conn.transaction(|conn| suspend_user(conn, user_id))
+    }
+
+    async fn reactivate(&self, user_id: Uuid) -> Result<UserEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        conn.transaction(|conn| reactivate_user(conn, user_id))
+    }
+
+    async fn find_by_email(&self, email: String) -> Result<Option<UserEntity>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = users::table
+            .filter(users::email.eq(email.trim().to_lowercase()))
+            .select(UserEntity::as_select())
+            .first::<UserEntity>(&mut conn)
+            .optional()?;
+        Ok(result)
+    }
+
+    async fn update_password(&self, user_id: Uuid, password_hash: String) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = update(users::table.filter(users::id.eq(user_id)))
+            .set((
+                users::password_hash.eq(password_hash),
+                users::updated_at.eq(Utc::now().naive_utc()),
+            ))
+            .execute(&mut conn)?;
+
+        if rows_affected == 0 {
+            return Err(anyhow::anyhow!("User not found: {}", user_id));
+        }
+
+        Ok(())
+    }
+
+    async fn exists_with_role(&self, role: Role) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let result = diesel::select(diesel::dsl::exists(
+            users::table.filter(users::role.eq(role)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+        Ok(result)
+    }
+}
+
+/// ระงับบัญชีโดยจำสถานะเดิมไว้ - เรียกภายใน transaction (ล็อกแถวของ user)
+pub(crate) fn suspend_user(conn: &mut PgConnection, user_id: Uuid) -> Result<UserEntity> {
+    let (status, status_before_suspension) = users::table
+        .find(user_id)
+        .select((users::status, users::status_before_suspension))
+        .for_update()
+        .first::<(UserStatusEnum, Option<UserStatusEnum>)>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("User not found: {}", user_id))?;
+
+    // ระงับซ้ำไม่ทับสถานะเดิมที่จำไว้
+    let status_before_suspension = match status {
+        UserStatusEnum::Deleted => {
+            return Err(anyhow::anyhow!("Conflict: Deleted accounts cannot be suspended"));
+        }
+        UserStatusEnum::Suspended => status_before_suspension,
+        other => Some(other),
+    };
+
+    update(users::table.filter(users::id.eq(user_id)))
+        .set((
+            users::status.eq(UserStatusEnum::Suspended),
+            users::status_before_suspension.eq(status_before_suspension),
+            users::updated_at.eq(Utc::now().naive_utc()),
+        ))
+        .returning(UserEntity::as_returning())
+        .get_result::<UserEntity>(conn)
+        .map_err(Into::into)
+}
+
+/// คืนสถานะก่อนถูกระงับ - เรียกภายใน transaction (ล็อกแถวของ user)
+pub(crate) fn reactivate_user(conn: &mut PgConnection, user_id: Uuid) -> Result<UserEntity> {
+    let (status, status_before_suspension) = users::table
+        .find(user_id)
+        .select((users::status, users::status_before_suspension))
+        .for_update()
+        .first::<(UserStatusEnum, Option<UserStatusEnum>)>(conn)
+        .optional()?
+        .ok_or_else(|| anyhow::anyhow!("User not found: {}", user_id))?;
+
+    if status != UserStatusEnum::Suspended {
+        return Err(anyhow::anyhow!(
+            "Conflict: Only suspended accounts can be reactivated"
+        ));
+    }
+
+    // บัญชีที่ถูกระงับก่อนมีคอลัมน์นี้ถือว่าเคย active
+    let restored_status = match status_before_suspension {
+        Some(UserStatusEnum::Pending) => UserStatusEnum::Pending,
+        _ => UserStatusEnum::Active,
+    };
+
+    update(users::table.filter(users::id.eq(user_id)))
+        .set((
+            users::status.eq(restored_status),
+            users::status_before_suspension.eq(None::<UserStatusEnum>),
+            users::updated_at.eq(Utc::now().naive_utc()),
+        ))
+        .returning(UserEntity::as_returning())
+        .get_result::<UserEntity>(conn)
+        .map_err(Into::into)
 }
@@ -85,6 +85,8 @@ impl UserAddressRepository for UserAddressPostgres {
                 user_addresses::subdistrict.eq(&address_data.subdistrict),
                 user_addresses::postal_code.eq(&address_data.postal_code),
                 user_addresses::address_detail.eq(&address_data.address_detail),
+                user_addresses::latitude.eq(address_data.latitude),
+                user_addresses::longitude.eq(address_data.longitude),
                 user_addresses::updated_at.eq(Utc::now()),
             ))
             .returning(UserAddressEntity::as_returning())
@@ -7,10 +7,16 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::user_job_match::{NewUserJobMatch, UserJobMatchEntity},
+        entities::{
+            company_post::JobSalary,
+            user_job_match::{NewUserJobMatch, UserJobMatchEntity, UserJobMatchWithJob},
+        },
         repo::user_job_match::UserJobMatchRepository,
     },
-    infrastructure::postgres::{postgres_connection::DbPool, schema::user_job_matches},
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{company_posts, user_job_matches},
+    },
 };
 
 pub struct UserJobMatchPostgres {
@@ -64,6 +70,29 @@ impl UserJobMatchRepository for UserJobMatchPostgres {
         Ok(results)
     }
 
+    async fn get_with_jobs_by_user_id(&self, user_id: Uuid) -> Result<Vec<UserJobMatchWithJob>> {
+        let mut conn = self.pool.get()?;
+        let rows = user_job_matches::table
+            .inner_join(company_posts::table)
+            .filter(user_job_matches::user_id.eq(user_id))
+            .order(user_job_matches::match_score.desc())
+            .select((
+                UserJobMatchEntity::as_select(),
+                company_posts::title,
+                JobSalary::as_select(),
+            ))
+            .load::<(UserJobMatchEntity, String, JobSalary)>(&mut conn)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(job_match, job_title, salary)| UserJobMatchWithJob {
+                job_match,
+                job_title,
+                salary,
+            })
+            .collect())
+    }
+
     async fn delete_by_user_id(&self, user_id: Uuid) -> Result<()> {
         let mut conn = self.pool.get()?;
         diesel::delete(user_job_matches::table.filter(user_job_matches::user_id.eq(user_id)))
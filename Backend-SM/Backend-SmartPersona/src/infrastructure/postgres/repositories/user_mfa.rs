@@ -0,0 +1,182 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::user_mfa::{NewMfaRecoveryCode, UserMfa},
+        repo::user_mfa::UserMfaRepository,
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{mfa_recovery_codes, user_mfa},
+    },
+};
+
+pub struct UserMfaPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl UserMfaPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+fn insert_recovery_codes(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    recovery_code_hashes: Vec<String>,
+) -> QueryResult<()> {
+    diesel::delete(mfa_recovery_codes::table.filter(mfa_recovery_codes::user_id.eq(user_id)))
+        .execute(conn)?;
+
+    let new_codes: Vec<NewMfaRecoveryCode> = recovery_code_hashes
+        .into_iter()
+        .map(|code_hash| NewMfaRecoveryCode { user_id, code_hash })
+        .collect();
+
+    diesel::insert_into(mfa_recovery_codes::table)
+        .values(&new_codes)
+        .execute(conn)?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl UserMfaRepository for UserMfaPostgres {
+    async fn find_by_user(&self, user_id: Uuid) -> Result<Option<UserMfa>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = user_mfa::table
+            .filter(user_mfa::user_id.eq(user_id))
+            .select(UserMfa::as_select())
+            .first::<UserMfa>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn save_pending_secret(&self, user_id: Uuid, totp_secret: &str) -> Result<UserMfa> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(user_mfa::table)
+            .values((
+                user_mfa::user_id.eq(user_id),
+                user_mfa::totp_secret.eq(totp_secret),
+            ))
+            .on_conflict(user_mfa::user_id)
+            .do_update()
+            .set((
+                user_mfa::totp_secret.eq(totp_secret),
+                user_mfa::enabled_at.eq(None::<chrono::DateTime<Utc>>),
+                user_mfa::last_used_step.eq(None::<i64>),
+                user_mfa::updated_at.eq(Utc::now()),
+            ))
+            .returning(UserMfa::as_returning())
+            .get_result::<UserMfa>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn enable(&self, user_id: Uuid, used_step: i64, recovery_code_hashes: Vec<String>) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            let rows_affected = diesel::update(
+                user_mfa::table
+                    .filter(user_mfa::user_id.eq(user_id))
+                    .filter(user_mfa::enabled_at.is_null()),
+            )
+            .set((
+                user_mfa::enabled_at.eq(Some(Utc::now())),
+                user_mfa::last_used_step.eq(Some(used_step)),
+                user_mfa::updated_at.eq(Utc::now()),
+            ))
+            .execute(conn)?;
+
+            if rows_affected == 0 {
+                return Ok(false);
+            }
+
+            insert_recovery_codes(conn, user_id, recovery_code_hashes)?;
+            Ok(true)
+        })?;
+
+        Ok(result)
+    }
+
+    async fn record_used_step(&self, user_id: Uuid, step: i64) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::update(
+            user_mfa::table
+                .filter(user_mfa::user_id.eq(user_id))
+                .filter(
+                    user_mfa::last_used_step
+                        .is_null()
+                        .or(user_mfa::last_used_step.lt(step)),
+                ),
+        )
+        .set((
+            user_mfa::last_used_step.eq(Some(step)),
+            user_mfa::updated_at.eq(Utc::now()),
+        ))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: Uuid, recovery_code_hashes: Vec<String>) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            insert_recovery_codes(conn, user_id, recovery_code_hashes)
+        })?;
+
+        Ok(())
+    }
+
+    async fn use_recovery_code(&self, user_id: Uuid, code_hash: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::update(
+            mfa_recovery_codes::table
+                .filter(mfa_recovery_codes::user_id.eq(user_id))
+                .filter(mfa_recovery_codes::code_hash.eq(code_hash))
+                .filter(mfa_recovery_codes::used_at.is_null()),
+        )
+        .set(mfa_recovery_codes::used_at.eq(Some(Utc::now())))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected > 0)
+    }
+
+    async fn count_unused_recovery_codes(&self, user_id: Uuid) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = mfa_recovery_codes::table
+            .filter(mfa_recovery_codes::user_id.eq(user_id))
+            .filter(mfa_recovery_codes::used_at.is_null())
+            .count()
+            .get_result::<i64>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::delete(mfa_recovery_codes::table.filter(mfa_recovery_codes::user_id.eq(user_id)))
+                .execute(conn)?;
+            diesel::delete(user_mfa::table.filter(user_mfa::user_id.eq(user_id))).execute(conn)?;
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::prelude::*;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::user_session::{NewUserSession, UserSession},
+        repo::user_session::UserSessionRepository,
+    },
+    infrastructure::postgres::{postgres_connection::DbPool, schema::user_sessions},
+};
+
+pub struct UserSessionPostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl UserSessionPostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl UserSessionRepository for UserSessionPostgres {
+    async fn create(&self, new_session: &NewUserSession) -> Result<UserSession> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(user_sessions::table)
+            .values(new_session)
+            .returning(UserSession::as_returning())
+            .get_result::<UserSession>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn find_by_jti(&self, jti: Uuid) -> Result<Option<UserSession>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = user_sessions::table
+            .filter(user_sessions::jti.eq(jti))
+            .select(UserSession::as_select())
+            .first::<UserSession>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn rotate(&self, old_jti: Uuid, new_session: &NewUserSession) -> Result<Option<UserSession>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            // เงื่อนไข rotated_at/revoked_at IS NULL กัน refresh พร้อมกันด้วย token เดียวกัน
+            let rows_affected = diesel::update(
+                user_sessions::table
+                    .filter(user_sessions::jti.eq(old_jti))
+                    .filter(user_sessions::rotated_at.is_null())
+                    .filter(user_sessions::revoked_at.is_null()),
+            )
+            .set(user_sessions::rotated_at.eq(Some(Utc::now())))
+            .execute(conn)?;
+
+            if rows_affected == 0 {
+                return Ok(None);
+            }
+
+            let session = diesel::insert_into(user_sessions::table)
+                .values(new_session)
+                .returning(UserSession::as_returning())
+                .get_result::<UserSession>(conn)?;
+
+            Ok(Some(session))
+        })?;
+
+        Ok(result)
+    }
+
+    async fn revoke_family(&self, user_id: Uuid, family_id: Uuid, reason: &str) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::update(
+            user_sessions::table
+                .filter(user_sessions::user_id.eq(user_id))
+                .filter(user_sessions::family_id.eq(family_id))
+                .filter(user_sessions::revoked_at.is_null()),
+        )
+        .set((
+            user_sessions::revoked_at.eq(Some(Utc::now())),
+            user_sessions::revoked_reason.eq(Some(reason)),
+        ))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+
+    async fn revoke_all_for_user(
+        &self,
+        user_id: Uuid,
+        except_family_id: Option<Uuid>,
+        reason: &str,
+    ) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // Uuid::nil() ไม่ตรงกับ family ใดเลย จึง revoke ทั้งหมดเมื่อไม่ได้ระบุ family ที่ต้องเก็บไว้
+        let keep_family_id = except_family_id.unwrap_or(Uuid::nil());
+
+        let rows_affected = diesel::update(
+            user_sessions::table
+                .filter(user_sessions::user_id.eq(user_id))
+                .filter(user_sessions::family_id.ne(keep_family_id))
+                .filter(user_sessions::revoked_at.is_null()),
+        )
+        .set((
+            user_sessions::revoked_at.eq(Some(Utc::now())),
+            user_sessions::revoked_reason.eq(Some(reason)),
+        ))
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+
+    async fn is_family_active(&self, user_id: Uuid, family_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let active = diesel::select(diesel::dsl::exists(
+            user_sessions::table
+                .filter(user_sessions::user_id.eq(user_id))
+                .filter(user_sessions::family_id.eq(family_id))
+                .filter(user_sessions::revoked_at.is_null())
+                .filter(user_sessions::expires_at.gt(Utc::now())),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(active)
+    }
+
+    async fn list_active_by_user(&self, user_id: Uuid) -> Result<Vec<UserSession>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // token ที่ยังไม่ถูก rotate คือ token ล่าสุดของแต่ละ family
+        let results = user_sessions::table
+            .filter(user_sessions::user_id.eq(user_id))
+            .filter(user_sessions::rotated_at.is_null())
+            .filter(user_sessions::revoked_at.is_null())
+            .filter(user_sessions::expires_at.gt(Utc::now()))
+            .order(user_sessions::created_at.desc())
+            .select(UserSession::as_select())
+            .load::<UserSession>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn delete_expired(&self) -> Result<usize> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let rows_affected = diesel::delete(
+            user_sessions::table.filter(user_sessions::expires_at.lt(Utc::now())),
+        )
+        .execute(&mut conn)?;
+
+        Ok(rows_affected)
+    }
+}
@@ -1 +1,375 @@
+use anyhow::Result;
+use axum::async_trait;
+use chrono::Utc;
+use diesel::{
+    dsl::{self, count_star},
+    prelude::*,
+};
+use std::sync::Arc;
+use uuid::Uuid;
 
+use crate::{
+    domain::{
+        entities::user_share::{
+            NewProfileShare, ProfileShare, ShareStatistics, SharedProfileWithInfo,
+            UpdateProfileShare,
+        },
+        repo::user_share::{CleanupResult, ProfileShareRepository},
+    },
+    infrastructure::postgres::{
+        postgres_connection::DbPool,
+        schema::{profile_shares, user_profiles},
+    },
+};
+
+pub struct ProfileSharePostgres {
+    db_pool: Arc<DbPool>,
+}
+
+impl ProfileSharePostgres {
+    pub fn new(db_pool: Arc<DbPool>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ProfileShareRepository for ProfileSharePostgres {
+    async fn create(&self, new_share: &NewProfileShare) -> Result<ProfileShare> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::insert_into(profile_shares::table)
+            .values(new_share)
+            .returning(ProfileShare::as_returning())
+            .get_result::<ProfileShare>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn get_by_token(&self, token: &str) -> Result<Option<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = profile_shares::table
+            .filter(profile_shares::share_token.eq(token))
+            .select(ProfileShare::as_select())
+            .first::<ProfileShare>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_by_id(&self, share_id: Uuid) -> Result<Option<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = profile_shares::table
+            .filter(profile_shares::id.eq(share_id))
+            .select(ProfileShare::as_select())
+            .first::<ProfileShare>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .order(profile_shares::created_at.desc())
+            .select(ProfileShare::as_select())
+            .load::<ProfileShare>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(Utc::now()))
+            .order(profile_shares::created_at.desc())
+            .select(ProfileShare::as_select())
+            .load::<ProfileShare>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn update(&self, share_id: Uuid, update_data: &UpdateProfileShare) -> Result<ProfileShare> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(profile_shares::table)
+            .filter(profile_shares::id.eq(share_id))
+            .set(update_data)
+            .returning(ProfileShare::as_returning())
+            .get_result::<ProfileShare>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn delete(&self, share_id: Uuid) -> Result<()> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        diesel::delete(profile_shares::table)
+            .filter(profile_shares::id.eq(share_id))
+            .execute(&mut conn)?;
+
+        Ok(())
+    }
+
+    async fn increment_view_count(&self, share_id: Uuid) -> Result<ProfileShare> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(profile_shares::table)
+            .filter(profile_shares::id.eq(share_id))
+            .set((
+                profile_shares::view_count.eq(profile_shares::view_count + 1),
+                profile_shares::last_viewed_at.eq(Some(Utc::now())),
+            ))
+            .returning(ProfileShare::as_returning())
+            .get_result::<ProfileShare>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn deactivate(&self, share_id: Uuid) -> Result<ProfileShare> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(profile_shares::table)
+            .filter(profile_shares::id.eq(share_id))
+            .set(profile_shares::is_active.eq(false))
+            .returning(ProfileShare::as_returning())
+            .get_result::<ProfileShare>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn activate(&self, share_id: Uuid) -> Result<ProfileShare> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = diesel::update(profile_shares::table)
+            .filter(profile_shares::id.eq(share_id))
+            .set(profile_shares::is_active.eq(true))
+            .returning(ProfileShare::as_returning())
+            .get_result::<ProfileShare>(&mut conn)?;
+
+        Ok(result)
+    }
+
+    async fn is_token_accessible(&self, token: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count: i64 = profile_shares::table
+            .filter(profile_shares::share_token.eq(token))
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(Utc::now()))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok(count > 0)
+    }
+
+    async fn delete_expired_shares(&self) -> Result<u64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let deleted = diesel::delete(profile_shares::table)
+            .filter(profile_shares::expires_at.lt(Utc::now()))
+            .execute(&mut conn)?;
+
+        Ok(deleted as u64)
+    }
+
+    async fn deactivate_all_user_shares(&self, user_id: Uuid) -> Result<u64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(profile_shares::table)
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::is_active.eq(true))
+            .set(profile_shares::is_active.eq(false))
+            .execute(&mut conn)?;
+
+        Ok(updated as u64)
+    }
+
+    async fn get_user_share_statistics(&self, user_id: Uuid) -> Result<ShareStatistics> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total_shares: i64 = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .select(count_star())
+            .first(&mut conn)?;
+
+        let active_shares: i64 = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(Utc::now()))
+            .select(count_star())
+            .first(&mut conn)?;
+
+        let (total_views, latest_share_date, latest_view_date) = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .select((
+                dsl::sum(profile_shares::view_count),
+                dsl::max(profile_shares::created_at),
+                dsl::max(profile_shares::last_viewed_at),
+            ))
+            .first::<(Option<i64>, Option<chrono::DateTime<Utc>>, Option<chrono::DateTime<Utc>>)>(
+                &mut conn,
+            )?;
+
+        Ok(ShareStatistics {
+            total_shares,
+            active_shares,
+            total_views: total_views.unwrap_or(0),
+            latest_share_date,
+            latest_view_date,
+        })
+    }
+
+    async fn count_all_shares(&self) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let count: i64 = profile_shares::table.select(count_star()).first(&mut conn)?;
+        Ok(count)
+    }
+
+    async fn count_active_shares(&self) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count: i64 = profile_shares::table
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(Utc::now()))
+            .select(count_star())
+            .first(&mut conn)?;
+
+        Ok(count)
+    }
+
+    async fn get_expiring_soon(&self, hours_threshold: i64, limit: i64) -> Result<Vec<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+        let now = Utc::now();
+        let threshold = now + chrono::Duration::hours(hours_threshold);
+
+        let results = profile_shares::table
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(now))
+            .filter(profile_shares::expires_at.le(threshold))
+            .order(profile_shares::expires_at.asc())
+            .limit(limit)
+            .select(ProfileShare::as_select())
+            .load::<ProfileShare>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn get_shared_profile_info(&self, token: &str) -> Result<Option<SharedProfileWithInfo>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = profile_shares::table
+            .inner_join(user_profiles::table.on(user_profiles::user_id.eq(profile_shares::user_id)))
+            .filter(profile_shares::share_token.eq(token))
+            .select((
+                profile_shares::share_token,
+                profile_shares::view_count,
+                profile_shares::last_viewed_at,
+                profile_shares::expires_at,
+                profile_shares::user_id,
+                user_profiles::title,
+                user_profiles::first_name_th,
+                user_profiles::last_name_th,
+                user_profiles::first_name_en,
+                user_profiles::last_name_en,
+                user_profiles::gender,
+                user_profiles::birth_date,
+                user_profiles::religion,
+                user_profiles::nationality,
+            ))
+            .first::<SharedProfileWithInfo>(&mut conn)
+            .optional()?;
+
+        Ok(result)
+    }
+
+    async fn get_viewed_shares_by_user(&self, user_id: Uuid) -> Result<Vec<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::view_count.gt(0))
+            .order(profile_shares::last_viewed_at.desc())
+            .select(ProfileShare::as_select())
+            .load::<ProfileShare>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn search_by_token(
+        &self,
+        token_fragment: &str,
+        user_id: Uuid,
+    ) -> Result<Vec<ProfileShare>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::share_token.like(format!("%{}%", token_fragment)))
+            .order(profile_shares::created_at.desc())
+            .select(ProfileShare::as_select())
+            .load::<ProfileShare>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn token_exists(&self, token: &str) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count: i64 = profile_shares::table
+            .filter(profile_shares::share_token.eq(token))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok(count > 0)
+    }
+
+    async fn user_has_active_share(&self, user_id: Uuid) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let count: i64 = profile_shares::table
+            .filter(profile_shares::user_id.eq(user_id))
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.gt(Utc::now()))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok(count > 0)
+    }
+
+    async fn deactivate_multiple(&self, share_ids: &[Uuid]) -> Result<u64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(profile_shares::table)
+            .filter(profile_shares::id.eq_any(share_ids))
+            .set(profile_shares::is_active.eq(false))
+            .execute(&mut conn)?;
+
+        Ok(updated as u64)
+    }
+
+    async fn cleanup_expired_shares(&self) -> Result<CleanupResult> {
+        let start_time = std::time::Instant::now();
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        // ปิดลิงก์ที่หมดอายุแต่ยัง active อยู่ (ไม่ลบทิ้ง เพื่อเก็บสถิติไว้)
+        let deactivated = diesel::update(profile_shares::table)
+            .filter(profile_shares::is_active.eq(true))
+            .filter(profile_shares::expires_at.lt(Utc::now()))
+            .set(profile_shares::is_active.eq(false))
+            .execute(&mut conn)?;
+
+        Ok(CleanupResult {
+            deactivated_count: deactivated as u64,
+            deleted_count: 0,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            errors: Vec::new(),
+        })
+    }
+}
@@ -5,6 +5,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "job_status"))]
     pub struct JobStatus;
 
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "user_role"))]
     pub struct UserRole;
@@ -14,6 +18,50 @@ pub mod sql_types {
     pub struct UserStatus;
 }
 
+diesel::table! {
+    account_tokens (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        #[max_length = 50]
+        purpose -> Varchar,
+        expires_at -> Timestamptz,
+        used_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    account_deletions (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 20]
+        status -> Varchar,
+        reason -> Nullable<Text>,
+        requested_at -> Timestamptz,
+        scheduled_for -> Timestamptz,
+        cancelled_at -> Nullable<Timestamptz>,
+        completed_at -> Nullable<Timestamptz>,
+        storage_failures -> Array<Text>,
+    }
+}
+
+diesel::table! {
+    admin_invites (id) {
+        id -> Uuid,
+        #[max_length = 255]
+        email -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        invited_by -> Uuid,
+        expires_at -> Timestamptz,
+        accepted_at -> Nullable<Timestamptz>,
+        accepted_user_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     ads (id) {
         id -> Uuid,
@@ -35,6 +83,78 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    application_screening_answers (id) {
+        id -> Uuid,
+        application_id -> Uuid,
+        question_id -> Nullable<Uuid>,
+        position -> Int4,
+        #[max_length = 20]
+        kind -> Varchar,
+        prompt -> Text,
+        answer -> Nullable<Text>,
+        knocked_out -> Bool,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    application_status_history (id) {
+        id -> Uuid,
+        application_id -> Uuid,
+        #[max_length = 20]
+        from_status -> Nullable<Varchar>,
+        #[max_length = 20]
+        to_status -> Varchar,
+        actor_id -> Nullable<Uuid>,
+        #[max_length = 20]
+        actor_role -> Varchar,
+        note -> Nullable<Text>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    audit_events (id) {
+        id -> Uuid,
+        actor_id -> Nullable<Uuid>,
+        #[max_length = 50]
+        actor_role -> Nullable<Varchar>,
+        #[max_length = 100]
+        action -> Varchar,
+        #[max_length = 50]
+        target_type -> Varchar,
+        #[max_length = 255]
+        target_id -> Varchar,
+        reason -> Nullable<Text>,
+        before_state -> Nullable<Jsonb>,
+        after_state -> Nullable<Jsonb>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        #[max_length = 512]
+        user_agent -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    auth_rate_limits (bucket_key) {
+        #[max_length = 320]
+        bucket_key -> Varchar,
+        window_started_at -> Timestamptz,
+        hits -> Int4,
+    }
+}
+
+diesel::table! {
+    calendar_feeds (user_id) {
+        user_id -> Uuid,
+        #[max_length = 64]
+        feed_token_hash -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     companies (id) {
         id -> Uuid,
@@ -72,6 +192,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    company_api_keys (id) {
+        id -> Uuid,
+        company_id -> Uuid,
+        created_by -> Nullable<Uuid>,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 16]
+        key_prefix -> Varchar,
+        #[max_length = 64]
+        key_hash -> Varchar,
+        scopes -> Array<Text>,
+        expires_at -> Nullable<Timestamptz>,
+        last_used_at -> Nullable<Timestamptz>,
+        #[max_length = 64]
+        last_used_ip -> Nullable<Varchar>,
+        revoked_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     company_galleries (id) {
         id -> Uuid,
@@ -81,6 +222,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
+    company_post_search (post_id) {
+        post_id -> Uuid,
+        search_vector -> Tsvector,
+        indexed_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     company_posts (id) {
         id -> Uuid,
@@ -104,6 +256,39 @@ diesel::table! {
         benefits -> Nullable<Text>,
         latitude -> Nullable<Float8>,
         longitude -> Nullable<Float8>,
+        salary_min -> Nullable<Int8>,
+        salary_max -> Nullable<Int8>,
+        #[max_length = 3]
+        salary_currency -> Varchar,
+        #[max_length = 10]
+        salary_period -> Varchar,
+        salary_negotiable -> Bool,
+        publish_at -> Nullable<Timestamptz>,
+        closes_at -> Nullable<Timestamptz>,
+        published_at -> Nullable<Timestamptz>,
+        closed_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    data_exports (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 20]
+        status -> Varchar,
+        archive -> Nullable<Bytea>,
+        archive_size -> Nullable<Int8>,
+        #[max_length = 64]
+        archive_sha256 -> Nullable<Varchar>,
+        #[max_length = 64]
+        download_token_hash -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        requested_at -> Timestamptz,
+        started_at -> Nullable<Timestamptz>,
+        completed_at -> Nullable<Timestamptz>,
+        expires_at -> Nullable<Timestamptz>,
+        downloaded_at -> Nullable<Timestamptz>,
+        download_count -> Int4,
     }
 }
 
@@ -122,6 +307,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    interview_slots (id) {
+        id -> Uuid,
+        interview_id -> Uuid,
+        starts_at -> Timestamptz,
+        ends_at -> Timestamptz,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    interviews (id) {
+        id -> Uuid,
+        application_id -> Uuid,
+        #[max_length = 20]
+        status -> Varchar,
+        location -> Nullable<Text>,
+        video_url -> Nullable<Text>,
+        note -> Nullable<Text>,
+        starts_at -> Nullable<Timestamptz>,
+        ends_at -> Nullable<Timestamptz>,
+        sequence -> Int4,
+        cancel_reason -> Nullable<Text>,
+        cancelled_at -> Nullable<Timestamptz>,
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     job_applications (id) {
         id -> Uuid,
@@ -131,6 +346,81 @@ diesel::table! {
         status -> Varchar,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        cover_letter -> Nullable<Text>,
+        resume_url -> Nullable<Text>,
+        knocked_out -> Bool,
+    }
+}
+
+diesel::table! {
+    login_lockout_events (id) {
+        id -> Uuid,
+        #[max_length = 255]
+        username -> Varchar,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        lockout_level -> Int4,
+        failed_attempts -> Int4,
+        locked_until -> Timestamptz,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    login_lockouts (username) {
+        #[max_length = 255]
+        username -> Varchar,
+        failed_attempts -> Int4,
+        lockout_level -> Int4,
+        locked_until -> Nullable<Timestamptz>,
+        last_failed_at -> Timestamptz,
+        #[max_length = 64]
+        last_ip_address -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    mfa_recovery_codes (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        code_hash -> Varchar,
+        used_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    oidc_auth_requests (id) {
+        id -> Uuid,
+        #[max_length = 64]
+        state_hash -> Varchar,
+        #[max_length = 50]
+        provider -> Varchar,
+        #[max_length = 64]
+        nonce -> Varchar,
+        #[max_length = 128]
+        code_verifier -> Varchar,
+        link_user_id -> Nullable<Uuid>,
+        #[max_length = 512]
+        return_to -> Nullable<Varchar>,
+        expires_at -> Timestamptz,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    post_screening_questions (id) {
+        id -> Uuid,
+        post_id -> Uuid,
+        position -> Int4,
+        #[max_length = 20]
+        kind -> Varchar,
+        prompt -> Text,
+        required -> Bool,
+        options -> Array<Text>,
+        knockout_answers -> Array<Text>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -141,11 +431,11 @@ diesel::table! {
         #[max_length = 128]
         share_token -> Varchar,
         expires_at -> Timestamptz,
-        view_count -> Nullable<Int4>,
+        view_count -> Int4,
         last_viewed_at -> Nullable<Timestamptz>,
-        is_active -> Nullable<Bool>,
-        created_at -> Nullable<Timestamptz>,
-        updated_at -> Nullable<Timestamptz>,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -169,6 +459,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    saved_search_matches (saved_search_id, post_id) {
+        saved_search_id -> Uuid,
+        post_id -> Uuid,
+        matched_at -> Timestamptz,
+        sent_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    saved_search_unsubscribe_tokens (token_hash, saved_search_id) {
+        #[max_length = 64]
+        token_hash -> Varchar,
+        saved_search_id -> Uuid,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    saved_searches (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        criteria -> Jsonb,
+        #[max_length = 10]
+        frequency -> Varchar,
+        alerts_enabled -> Bool,
+        matched_until -> Timestamptz,
+        last_sent_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     social_analysis (id) {
         id -> Uuid,
@@ -230,6 +555,8 @@ diesel::table! {
         address_detail -> Nullable<Text>,
         created_at -> Nullable<Timestamptz>,
         updated_at -> Nullable<Timestamptz>,
+        latitude -> Nullable<Float8>,
+        longitude -> Nullable<Float8>,
     }
 }
 
@@ -285,6 +612,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_identities (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 50]
+        provider -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        last_login_at -> Nullable<Timestamptz>,
+    }
+}
+
 diesel::table! {
     user_job_matches (id) {
         id -> Uuid,
@@ -311,6 +653,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_mfa (user_id) {
+        user_id -> Uuid,
+        #[max_length = 64]
+        totp_secret -> Varchar,
+        enabled_at -> Nullable<Timestamptz>,
+        last_used_step -> Nullable<Int8>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     user_portfolios (id) {
         id -> Uuid,
@@ -393,6 +747,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_sessions (jti) {
+        jti -> Uuid,
+        family_id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 20]
+        scope -> Varchar,
+        user_agent -> Nullable<Text>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        session_started_at -> Timestamptz,
+        created_at -> Timestamptz,
+        expires_at -> Timestamptz,
+        rotated_at -> Nullable<Timestamptz>,
+        revoked_at -> Nullable<Timestamptz>,
+        #[max_length = 50]
+        revoked_reason -> Nullable<Varchar>,
+    }
+}
+
 diesel::table! {
     user_skills (id) {
         id -> Uuid,
@@ -422,17 +796,41 @@ diesel::table! {
         max_profiles -> Int4,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        status_before_suspension -> Nullable<UserStatus>,
     }
 }
 
+diesel::joinable!(account_deletions -> users (user_id));
+diesel::joinable!(account_tokens -> users (user_id));
+diesel::joinable!(application_screening_answers -> job_applications (application_id));
+diesel::joinable!(application_screening_answers -> post_screening_questions (question_id));
+diesel::joinable!(application_status_history -> job_applications (application_id));
+diesel::joinable!(application_status_history -> users (actor_id));
+diesel::joinable!(calendar_feeds -> users (user_id));
 diesel::joinable!(companies -> users (user_id));
+diesel::joinable!(company_api_keys -> companies (company_id));
+diesel::joinable!(company_api_keys -> users (created_by));
 diesel::joinable!(company_galleries -> companies (company_id));
+diesel::joinable!(company_post_search -> company_posts (post_id));
 diesel::joinable!(company_posts -> companies (company_id));
+diesel::joinable!(data_exports -> users (user_id));
+diesel::joinable!(interview_slots -> interviews (interview_id));
+diesel::joinable!(interviews -> job_applications (application_id));
+diesel::joinable!(interviews -> users (created_by));
 diesel::joinable!(job_applications -> company_posts (job_id));
 diesel::joinable!(job_applications -> users (user_id));
+diesel::joinable!(mfa_recovery_codes -> users (user_id));
+diesel::joinable!(oidc_auth_requests -> users (link_user_id));
+diesel::joinable!(post_screening_questions -> company_posts (post_id));
 diesel::joinable!(profile_shares -> users (user_id));
 diesel::joinable!(saved_jobs -> company_posts (post_id));
 diesel::joinable!(saved_jobs -> users (user_id));
+diesel::joinable!(saved_search_matches -> company_posts (post_id));
+diesel::joinable!(saved_search_matches -> saved_searches (saved_search_id));
+diesel::joinable!(saved_search_unsubscribe_tokens -> saved_searches (saved_search_id));
+diesel::joinable!(saved_searches -> users (user_id));
 diesel::joinable!(social_analysis -> social_connections (social_connection_id));
 diesel::joinable!(social_analysis -> users (user_id));
 diesel::joinable!(social_posts -> social_connections (social_connection_id));
@@ -440,24 +838,48 @@ diesel::joinable!(user_addresses -> users (user_id));
 diesel::joinable!(user_ai_scores -> users (user_id));
 diesel::joinable!(user_educations -> users (user_id));
 diesel::joinable!(user_experiences -> users (user_id));
+diesel::joinable!(user_identities -> users (user_id));
 diesel::joinable!(user_job_matches -> company_posts (job_id));
 diesel::joinable!(user_job_matches -> users (user_id));
 diesel::joinable!(user_job_preferences -> users (user_id));
+diesel::joinable!(user_mfa -> users (user_id));
 diesel::joinable!(user_portfolios -> users (user_id));
 diesel::joinable!(user_privacy_settings -> users (user_id));
 diesel::joinable!(user_profiles -> users (user_id));
+diesel::joinable!(user_sessions -> users (user_id));
 diesel::joinable!(user_skills -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_deletions,
+    account_tokens,
+    admin_invites,
     ads,
+    application_screening_answers,
+    application_status_history,
+    audit_events,
+    auth_rate_limits,
+    calendar_feeds,
     companies,
+    company_api_keys,
     company_galleries,
+    company_post_search,
     company_posts,
+    data_exports,
     generation_jobs,
+    interview_slots,
+    interviews,
     job_applications,
+    login_lockout_events,
+    login_lockouts,
+    mfa_recovery_codes,
+    oidc_auth_requests,
+    post_screening_questions,
     profile_shares,
     prompt_templates,
     saved_jobs,
+    saved_search_matches,
+    saved_search_unsubscribe_tokens,
+    saved_searches,
     social_analysis,
     social_connections,
     social_posts,
@@ -465,11 +887,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     user_ai_scores,
     user_educations,
     user_experiences,
+    user_identities,
     user_job_matches,
     user_job_preferences,
+    user_mfa,
     user_portfolios,
     user_privacy_settings,
     user_profiles,
+    user_sessions,
     user_skills,
     users,
 );
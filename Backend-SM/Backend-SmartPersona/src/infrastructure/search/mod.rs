@@ -0,0 +1,66 @@
+//! ตัดคำสำหรับ full-text search
+//!
+//! Postgres ไม่มี parser ภาษาไทย (ไม่มีช่องว่างระหว่างคำ) และ parser ปกติจะทิ้งตัวอักษรไทย
+//! เมื่อ database ใช้ locale `C` จึงตัดคำที่นี่แล้วส่งเป็น `tsvector` / `tsquery` literal
+//! (`'คำ':1A` / `'คำ' & 'word'`) ซึ่ง Postgres อ่านเหมือนกันทุก locale
+//! ทั้งฝั่ง index และฝั่ง query ต้องผ่าน `tokenize` เดียวกันเพื่อให้ได้คำชุดเดียวกัน
+
+use std::sync::LazyLock;
+
+use icu_segmenter::WordSegmenter;
+
+/// แบบ dictionary ตัดคำประสมไทย (เช่น "ซอฟต์แวร์", "กรุงเทพมหานคร") ได้ตรงกว่าแบบ LSTM
+static WORD_SEGMENTER: LazyLock<WordSegmenter> = LazyLock::new(WordSegmenter::new_dictionary);
+
+/// lexeme ของ Postgres ยาวได้ไม่เกิน 2046 bytes - คำที่ยาวกว่านี้ไม่ใช่คำที่คนค้นอยู่แล้ว
+const MAX_LEXEME_BYTES: usize = 2046;
+
+/// แยกคำ (ไทย / อังกฤษ / ตัวเลข) เป็นตัวพิมพ์เล็ก ตัดช่องว่างและเครื่องหมายวรรคตอนออก
+pub fn tokenize(text: &str) -> Vec<String> {
+    let breakpoints: Vec<usize> = WORD_SEGMENTER.segment_str(text).collect();
+
+    breakpoints
+        .windows(2)
+        .map(|bounds| &text[bounds[0]..bounds[1]])
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .filter(|word| word.len() <= MAX_LEXEME_BYTES)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// ครอบ lexeme ด้วย quote ตามรูปแบบ input ของ tsvector / tsquery
+fn quote_lexeme(word: &str) -> String {
+    format!("'{}'", word.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// tsvector ของหลายส่วนพร้อมน้ำหนัก (`'A'`-`'D'`) - position นับต่อเนื่องข้ามทุกส่วน
+pub fn tsvector_literal<'a>(weighted_parts: impl IntoIterator<Item = (char, &'a str)>) -> String {
+    let mut position = 0;
+    let mut lexemes = Vec::new();
+
+    for (weight, text) in weighted_parts {
+        for word in tokenize(text) {
+            // position เกิน 16383 จะถูกปัดลงโดย Postgres เอง
+            position += 1;
+            lexemes.push(format!("{}:{}{}", quote_lexeme(&word), position, weight));
+        }
+    }
+
+    lexemes.join(" ")
+}
+
+/// tsquery ที่ต้องพบทุกคำ - None ถ้าไม่เหลือคำที่ค้นได้ (เช่นมีแต่เครื่องหมาย)
+pub fn tsquery_literal(query: &str) -> Option<String> {
+    let terms = tokenize(query);
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(
+        terms
+            .iter()
+            .map(|term| quote_lexeme(term))
+            .collect::<Vec<_>>()
+            .join(" & "),
+    )
+}
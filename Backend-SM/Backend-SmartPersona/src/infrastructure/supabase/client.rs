@@ -28,6 +28,17 @@ impl SupabaseClient {
         format!("{}/storage/v1", self.url)
     }
 
+    /// Public URL of an object in the bucket
+    /// Supabase public URL format: https://{project_ref}.supabase.co/storage/v1/object/public/{bucket}/{path}
+    pub fn public_object_url(&self, file_path: &str) -> String {
+        format!(
+            "{}/storage/v1/object/public/{}/{}",
+            self.url.trim_end_matches('/'),
+            self.storage_bucket,
+            file_path
+        )
+    }
+
     pub fn get_authorization_header(&self) -> String {
         format!("Bearer {}", self.service_role_key)
     }
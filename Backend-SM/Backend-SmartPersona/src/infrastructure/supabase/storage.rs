@@ -7,6 +7,10 @@ use uuid::Uuid;
 
 use super::client::SupabaseClient;
 
+/// โฟลเดอร์ของไฟล์ resume ที่แนบกับใบสมัครงาน
+pub const RESUME_FOLDER: &str = "resumes";
+pub const RESUME_EXTENSIONS: [&str; 3] = ["pdf", "doc", "docx"];
+
 pub struct StorageService {
     client: Arc<SupabaseClient>,
 }
@@ -66,22 +70,7 @@ impl StorageService {
         }
 
         // For public buckets, use public URL directly (more reliable and doesn't expire)
-        // Supabase public URL format: https://{project_ref}.supabase.co/storage/v1/object/public/{bucket}/{path}
-        let public_url = if self.client.url.ends_with('/') {
-            format!(
-                "{}storage/v1/object/public/{}/{}",
-                self.client.url,
-                self.client.storage_bucket,
-                file_path
-            )
-        } else {
-            format!(
-                "{}/storage/v1/object/public/{}/{}",
-                self.client.url,
-                self.client.storage_bucket,
-                file_path
-            )
-        };
+        let public_url = self.client.public_object_url(&file_path);
 
         tracing::info!("Using public URL for public bucket: {}", public_url);
         Ok(public_url)
@@ -236,5 +225,20 @@ impl StorageService {
         self.upload_file(file_data, file_name, user_id, Some("covers".to_string()))
             .await
     }
-}
 
+    /// Upload a resume for job applications (PDF / Word)
+    pub async fn upload_resume(
+        &self,
+        file_data: Bytes,
+        file_name: String,
+        user_id: Uuid,
+    ) -> Result<String> {
+        self.upload_file(file_data, file_name, user_id, Some(RESUME_FOLDER.to_string()))
+            .await
+    }
+
+    /// Public URL prefix of uploaded resumes - used to check `resume_url` on applications
+    pub fn resume_url_prefix(&self) -> String {
+        self.client.public_object_url(&format!("{}/", RESUME_FOLDER))
+    }
+}
@@ -27,6 +27,7 @@ const socialProviders = [
 const fieldSets = {
   [AUTH_MODES.SIGN_UP]: [
     { id: "username", label: "Username", type: "text", placeholder: "Enter your username", autoComplete: "username" },
+    { id: "email", label: "Email", type: "email", placeholder: "Enter your email address", autoComplete: "email" },
     { id: "password", label: "Password", type: "password", placeholder: "Enter a secure password", autoComplete: "new-password" },
     { id: "confirmPassword", label: "Confirm Password", type: "password", placeholder: "Confirm your password", autoComplete: "new-password" },
   ],
@@ -57,6 +58,7 @@ const contentMap = {
 
 const ERROR_MESSAGES = {
   INVALID_CREDENTIALS: "Invalid username or password.",
+  EMAIL_NOT_VERIFIED: "Please verify your email before signing in. Check your inbox for the verification link.",
   REGISTRATION_FAILED: "Registration failed. Please try again.",
   GENERIC_ERROR: "Something went wrong. Please try again.",
 };
@@ -81,6 +83,9 @@ type AuthFormProps = {
 export default function AuthForm({ mode, onModeChange }: AuthFormProps) {
   const [isLoading, setIsLoading] = useState(false);
   const [submitError, setSubmitError] = useState<string | null>(null);
+  // Set after sign up - the account stays pending until the email link is opened
+  const [verificationEmail, setVerificationEmail] = useState<string | null>(null);
+  const [resendStatus, setResendStatus] = useState<"idle" | "sent" | "failed">("idle");
   const router = useRouter();
   const searchParams = useSearchParams();
 
@@ -121,6 +126,8 @@ export default function AuthForm({ mode, onModeChange }: AuthFormProps) {
       resetForms();
       const redirectUrl = searchParams.get('redirect') || REDIRECT_URLS.PROFILE;
       router.push(redirectUrl);
+    } else if (response.status === 403 && typeof response.data === "string" && response.data.includes("Email not verified")) {
+      setSubmitError(ERROR_MESSAGES.EMAIL_NOT_VERIFIED);
     } else {
       setSubmitError(ERROR_MESSAGES.INVALID_CREDENTIALS);
     }
@@ -129,11 +136,13 @@ export default function AuthForm({ mode, onModeChange }: AuthFormProps) {
   const performSignUp = async () => {
     if (!validateRegisterForm()) return;
 
-    const registerResponse = await userAPI.register(registerForm.username, registerForm.password);
+    const registerResponse = await userAPI.register(registerForm.username, registerForm.email, registerForm.password);
     if (!registerResponse.ok) {
       // Check if it's a duplicate username error
       const errorMsg = typeof registerResponse.data === 'string' ? registerResponse.data.toLowerCase() : '';
-      if (errorMsg.includes('duplicate') || errorMsg.includes('unique') || errorMsg.includes('already exists')) {
+      if (errorMsg.includes('email already registered')) {
+        setSubmitError("อีเมลนี้ถูกใช้สมัครแล้ว กรุณาเข้าสู่ระบบหรือใช้อีเมลอื่น");
+      } else if (errorMsg.includes('duplicate') || errorMsg.includes('unique') || errorMsg.includes('already exists') || errorMsg.includes('already taken')) {
         setSubmitError("ชื่อผู้ใช้นี้มีคนใช้งานแล้ว กรุณาเลือกชื่อผู้ใช้อื่น");
       } else {
         setSubmitError(ERROR_MESSAGES.REGISTRATION_FAILED);
@@ -141,24 +150,31 @@ export default function AuthForm({ mode, onModeChange }: AuthFormProps) {
       return;
     }
 
-    // Auto-login after successful registration
-    const loginResponse = await authAPI.login(registerForm.username, registerForm.password);
-    if (loginResponse.ok) {
-      console.log("Registration and login successful:", loginResponse.data);
-      
-      // Set user_role cookie for middleware (new users are PersonaUser by default)
-      document.cookie = `user_role=PersonaUser; path=/; max-age=2592000; SameSite=Lax`;
-      
-      resetForms();
-      router.push(REDIRECT_URLS.ONBOARDING);
-    } else {
-      // This case is unlikely but handled: registration worked but immediate login failed.
-      // We can redirect to login page with a success message.
-      console.log("Registration successful. Please log in.");
-      onModeChange(AUTH_MODES.SIGN_IN);
+    // The account is pending until the user opens the verification link in their inbox
+    setVerificationEmail(registerForm.email.trim());
+    setResendStatus("idle");
+    resetForms();
+  };
+
+  const handleResendVerification = async () => {
+    if (!verificationEmail) return;
+    setIsLoading(true);
+    try {
+      const response = await authAPI.requestEmailVerification(verificationEmail);
+      setResendStatus(response.ok ? "sent" : "failed");
+    } catch (error) {
+      console.error("Resend verification error:", error);
+      setResendStatus("failed");
+    } finally {
+      setIsLoading(false);
     }
   };
 
+  const handleBackToSignIn = () => {
+    setVerificationEmail(null);
+    onModeChange(AUTH_MODES.SIGN_IN);
+  };
+
   const handleSubmit = async (event: FormEvent<HTMLFormElement>) => {
     event.preventDefault();
     setSubmitError(null);
@@ -201,6 +217,44 @@ export default function AuthForm({ mode, onModeChange }: AuthFormProps) {
   const content = contentMap[mode];
   const nextMode: AuthMode = mode === AUTH_MODES.SIGN_UP ? AUTH_MODES.SIGN_IN : AUTH_MODES.SIGN_UP;
 
+  if (verificationEmail) {
+    return (
+      <div className="w-full max-w-md font-['Plus Jakarta Sans',_Inter,_'Helvetica Neue',_sans-serif]">
+        <motion.div initial={{ opacity: 0, y: 24 }} animate={{ opacity: 1, y: 0 }} transition={{ duration: 0.45, ease: [0.16, 1, 0.3, 1] }} className="space-y-6 rounded-[32px]">
+          <div className="space-y-3">
+            <h1 className="text-4xl font-semibold text-white sm:text-[2.75rem]">Check your inbox</h1>
+            <p className="text-base text-gray-500">
+              We sent a verification link to <span className="font-semibold text-[#ff6a45]">{verificationEmail}</span>.
+              Open it to activate your account, then sign in.
+            </p>
+          </div>
+
+          {resendStatus === "sent" && (
+            <div className="rounded-lg bg-green-50 p-3">
+              <p className="text-sm text-green-700">A new verification link is on its way.</p>
+            </div>
+          )}
+          {resendStatus === "failed" && (
+            <div className="rounded-lg bg-red-50 p-3">
+              <p className="text-sm text-red-600">{ERROR_MESSAGES.GENERIC_ERROR}</p>
+            </div>
+          )}
+
+          <button type="button" onClick={handleBackToSignIn} className="inline-flex w-full items-center justify-center rounded-full bg-gradient-to-r from-[#ffb067] via-[#ff8364] to-[#ff5d6b] px-6 py-4 text-sm font-semibold text-white shadow-[0_25px_45px_rgba(255,131,100,0.45)] transition hover:opacity-90">
+            Back to sign in
+          </button>
+
+          <div className="text-center text-sm text-gray-500">
+            <span>Didn&apos;t get the email? </span>
+            <button type="button" onClick={handleResendVerification} className="font-semibold text-[#ff6a45] hover:opacity-80" disabled={isLoading}>
+              {isLoading ? "Sending..." : "Resend link"}
+            </button>
+          </div>
+        </motion.div>
+      </div>
+    );
+  }
+
   return (
     <div className="w-full max-w-md font-['Plus Jakarta Sans',_Inter,_'Helvetica Neue',_sans-serif]">
       <motion.div initial={{ opacity: 0, y: -12 }} animate={{ opacity: 1, y: 0 }} transition={{ duration: 0.6, ease: [0.16, 1, 0.3, 1] }} className="mb-10 flex items-center gap-3 text-[#ff6a45]">
@@ -55,7 +55,7 @@ export default function CreateJobPostDialog({
         description: "",
         salary_range: "",
         tags: [],
-        status: "active",
+        status: "published",
         responsibilities: "",
         qualifications: "",
         benefits: "",
@@ -81,7 +81,7 @@ export default function CreateJobPostDialog({
                 description: post.description || "",
                 salary_range: post.salary_range || "",
                 tags: post.tags || [],
-                status: post.status || "active",
+                status: post.status || "published",
                 responsibilities: post.responsibilities || "",
                 qualifications: post.qualifications || "",
                 benefits: post.benefits || "",
@@ -97,7 +97,7 @@ export default function CreateJobPostDialog({
                 description: "",
                 salary_range: "",
                 tags: [],
-                status: "active",
+                status: "published",
                 responsibilities: "",
                 qualifications: "",
                 benefits: "",
@@ -253,7 +253,7 @@ export default function CreateJobPostDialog({
                     description: "",
                     salary_range: "",
                     tags: [],
-                    status: "active",
+                    status: "published",
                     responsibilities: "",
                     qualifications: "",
                     benefits: "",
@@ -556,7 +556,7 @@ export default function CreateJobPostDialog({
                             label="Status"
                             onChange={(e) => setFormData({ ...formData, status: e.target.value })}
                         >
-                            <MenuItem value="active">Active (Published)</MenuItem>
+                            <MenuItem value="published">Published</MenuItem>
                             <MenuItem value="draft">Draft (Not Published)</MenuItem>
                             <MenuItem value="closed">Closed</MenuItem>
                         </Select>
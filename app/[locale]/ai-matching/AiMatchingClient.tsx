@@ -128,7 +128,7 @@ export default function AiMatchingClient({ isLoggedIn }: AiMatchingClientProps)
                             // Found saved matches, now we need job details
                             const jobsResponse = await companyAPI.getAllPosts();
                             const jobsData = jobsResponse.ok && jobsResponse.data ? jobsResponse.data : [];
-                            const activeJobs = jobsData.filter((j) => j.status === 'active');
+                            const activeJobs = jobsData.filter((j) => j.status === 'published');
 
                             const savedMatches = matchesRes.data;
 
@@ -326,7 +326,7 @@ export default function AiMatchingClient({ isLoggedIn }: AiMatchingClientProps)
             // Fetch jobs using companyAPI
             const jobsResponse = await companyAPI.getAllPosts();
             const jobsData = jobsResponse.ok && jobsResponse.data ? jobsResponse.data : [];
-            const activeJobs = jobsData.filter((j) => j.status === 'active');
+            const activeJobs = jobsData.filter((j) => j.status === 'published');
 
             const profile = profileRes.ok ? profileRes.data : null;
             const address = addressRes.ok ? addressRes.data : null;
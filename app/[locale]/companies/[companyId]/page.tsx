@@ -118,7 +118,7 @@ export default function PublicCompanyProfilePage() {
 
                     if (postsRes.ok && postsRes.data) {
                         // Filter only active posts
-                        const activePosts = postsRes.data.filter((post) => post.status === 'active');
+                        const activePosts = postsRes.data.filter((post) => post.status === 'published');
                         setPosts(activePosts);
                     }
                 }
@@ -458,7 +458,7 @@ export default function PublicCompanyProfilePage() {
                                                     <Box>
                                                         <Box sx={{ display: "flex", justifyContent: "space-between", alignItems: "flex-start", mb: 1 }}>
                                                             <Typography variant="subtitle1" fontWeight={600}>{post.title}</Typography>
-                                                            <Chip label={post.status} color={post.status === "active" ? "success" : "default"} size="small" />
+                                                            <Chip label={post.status} color={post.status === "published" ? "success" : "default"} size="small" />
                                                         </Box>
                                                         <Stack direction="row" spacing={2} sx={{ mb: 2 }}>
                                                             <Box sx={{ display: "flex", alignItems: "center", gap: 0.5 }}>
@@ -3,7 +3,7 @@
 import { FormEvent, useState, useEffect } from "react";
 import { motion } from "framer-motion";
 import { useAuthForms } from "@/app/lib/hooks/use-auth-forms";
-import { authAPI, userAPI, companyAPI } from "@/app/lib/api";
+import { authAPI, userAPI, companyAPI, PENDING_COMPANY_REGISTRATION_KEY } from "@/app/lib/api";
 import { useRouter } from "next/navigation";
 import { useAuth } from "@/app/lib/auth/auth-context";
 import Link from "next/link";
@@ -91,6 +91,18 @@ export default function CompanyLoginPage() {
 
             // Check company status
             try {
+              // First login after verifying the email - submit the company info saved at sign up
+              const pendingCompany = localStorage.getItem(PENDING_COMPANY_REGISTRATION_KEY);
+              if (pendingCompany) {
+                console.log("🏢 [Company Login] Submitting company info from registration...");
+                const upsertResponse = await companyAPI.upsertCompany(JSON.parse(pendingCompany));
+                if (upsertResponse.ok) {
+                  localStorage.removeItem(PENDING_COMPANY_REGISTRATION_KEY);
+                } else {
+                  console.error("❌ [Company Login] Failed to submit company info:", upsertResponse.data);
+                }
+              }
+
               const companyResponse = await companyAPI.getCompany();
               console.log("📥 [Company Login] Company response:", {
                 ok: companyResponse.ok,
@@ -201,7 +213,11 @@ export default function CompanyLoginPage() {
         const errorMessage = typeof response.data === 'string'
           ? response.data
           : 'ชื่อผู้ใช้หรือรหัสผ่านไม่ถูกต้อง';
-        setSubmitError(errorMessage);
+        if (response.status === 403 && errorMessage.includes("Email not verified")) {
+          setSubmitError("กรุณายืนยันอีเมลก่อนเข้าสู่ระบบ ตรวจสอบลิงก์ยืนยันในกล่องอีเมลของคุณ");
+        } else {
+          setSubmitError(errorMessage);
+        }
       }
     } catch (error) {
       console.error("❌ [Company Login] Unexpected error:", error);
@@ -462,7 +462,7 @@ function CompanyProfilePageContent() {
                         <Grid container spacing={2}>
                             {[
                                 { label: "Total Job Posts", value: posts.length.toString(), color: "#3b82f6", gradient: "linear-gradient(135deg, #3b82f6 0%, #2563eb 100%)" },
-                                { label: "Open Positions", value: posts.filter(p => p.status === "active").length.toString(), color: "#ef4444", gradient: "linear-gradient(135deg, #ef4444 0%, #dc2626 100%)" },
+                                { label: "Open Positions", value: posts.filter(p => p.status === "published").length.toString(), color: "#ef4444", gradient: "linear-gradient(135deg, #ef4444 0%, #dc2626 100%)" },
                                 { label: "Employees", value: company.company_size || "N/A", color: "#22c55e", gradient: "linear-gradient(135deg, #22c55e 0%, #16a34a 100%)" },
                                 { label: "Avg Hiring Time", value: "14d", color: "#a855f7", gradient: "linear-gradient(135deg, #a855f7 0%, #9333ea 100%)" },
                             ].map((stat, index) => (
@@ -666,7 +666,7 @@ function CompanyProfilePageContent() {
                                     <Box>
                                         <Box sx={{ display: "flex", justifyContent: "space-between", alignItems: "flex-start", mb: 1 }}>
                                             <Typography variant="subtitle1" fontWeight={600}>{post.title}</Typography>
-                                            <Chip label={post.status} color={post.status === "active" ? "success" : "default"} size="small" />
+                                            <Chip label={post.status} color={post.status === "published" ? "success" : "default"} size="small" />
                                         </Box>
                                         <Stack direction="row" spacing={2} sx={{ mb: 2 }}>
                                             <Box sx={{ display: "flex", alignItems: "center", gap: 0.5 }}>
@@ -752,7 +752,7 @@ function CompanyProfilePageContent() {
                     <Typography variant="h6" gutterBottom>Job Post Performance</Typography>
                     <Grid container spacing={2}>
                         {[
-                            { label: "Active Posts", value: safePosts.filter((p) => p.status === "active").length.toString() || "0", color: "primary.main" },
+                            { label: "Active Posts", value: safePosts.filter((p) => p.status === "published").length.toString() || "0", color: "primary.main" },
                             { label: "Drafts", value: safePosts.filter((p) => p.status === "draft").length.toString() || "0", color: "warning.main" },
                             { label: "Closed", value: safePosts.filter((p) => p.status === "closed").length.toString() || "0", color: "text.secondary" },
                             // { label: "Applicants", value: "248", color: "success.main" },
@@ -790,7 +790,7 @@ function CompanyProfilePageContent() {
                                                 <Typography variant="subtitle1" fontWeight={600}>{post.title}</Typography>
                                                 <Typography variant="body2" color="text.secondary">{post.location} • {post.job_type}</Typography>
                                             </Box>
-                                            <Chip label={post.status} color={post.status === "active" ? "success" : "default"} size="small" />
+                                            <Chip label={post.status} color={post.status === "published" ? "success" : "default"} size="small" />
                                         </Stack>
                                         {post.description && (
                                             <>
@@ -1009,8 +1009,8 @@ function CompanyProfilePageContent() {
                                         <Typography variant="caption" color="text.secondary">
                                             Status
                                         </Typography>
-                                        <Typography variant="body2" fontWeight={600} color={post.status === 'active' ? 'success.main' : 'text.secondary'}>
-                                            {post.status === 'active' ? 'Active' : 'Inactive'}
+                                        <Typography variant="body2" fontWeight={600} color={post.status === 'published' ? 'success.main' : 'text.secondary'}>
+                                            {post.status === 'published' ? 'Active' : 'Inactive'}
                                         </Typography>
                                     </Box>
                                     <Box>
@@ -1421,7 +1421,7 @@ function CompanyProfilePageContent() {
                                                         <Chip
                                                             size="small"
                                                             label={applicant.status}
-                                                            color={applicant.status === 'pending' ? 'warning' : applicant.status === 'accepted' ? 'success' : 'error'}
+                                                            color={applicant.status === 'applied' ? 'warning' : applicant.status === 'hired' ? 'success' : ['rejected', 'withdrawn'].includes(applicant.status) ? 'error' : 'info'}
                                                         />
                                                     </Stack>
                                                 </Box>
@@ -1442,12 +1442,22 @@ function CompanyProfilePageContent() {
                                                         size="small"
                                                         color="success"
                                                         fullWidth
-                                                        disabled={applicant.status === 'accepted'}
+                                                        disabled={['hired', 'rejected', 'withdrawn'].includes(applicant.status)}
                                                         onClick={async () => {
                                                             try {
-                                                                const response = await jobApplicationAPI.updateApplicationStatus(applicant.id, 'accepted');
+                                                                // Move to the next pipeline stage the backend allows from the current status
+                                                                const timelineResponse = await jobApplicationAPI.getApplicationHistory(applicant.id);
+                                                                const nextStatus = timelineResponse.ok && timelineResponse.data
+                                                                    ? timelineResponse.data.next_statuses.find((status) => status !== 'rejected')
+                                                                    : undefined;
+                                                                if (!nextStatus) {
+                                                                    setSnackbar({ open: true, message: "Failed to accept candidate", severity: 'error' });
+                                                                    return;
+                                                                }
+
+                                                                const response = await jobApplicationAPI.updateApplicationStatus(applicant.id, nextStatus);
                                                                 if (response.ok) {
-                                                                    setSnackbar({ open: true, message: "Candidate accepted", severity: 'success' });
+                                                                    setSnackbar({ open: true, message: `Candidate moved to ${nextStatus}`, severity: 'success' });
                                                                     // Refresh applicants list
                                                                     if (selectedJobForApplicants) {
                                                                         const refreshResponse = await jobApplicationAPI.getJobCandidates(selectedJobForApplicants.id);
@@ -1471,7 +1481,7 @@ function CompanyProfilePageContent() {
                                                         size="small"
                                                         color="error"
                                                         fullWidth
-                                                        disabled={applicant.status === 'rejected'}
+                                                        disabled={['hired', 'rejected', 'withdrawn'].includes(applicant.status)}
                                                         onClick={async () => {
                                                             try {
                                                                 const response = await jobApplicationAPI.updateApplicationStatus(applicant.id, 'rejected');
@@ -3,9 +3,7 @@
 import { FormEvent, useState, useMemo } from "react";
 import { AnimatePresence, motion } from "framer-motion";
 import { useAuthForms } from "@/app/lib/hooks/use-auth-forms";
-import { authAPI, userAPI, companyAPI } from "@/app/lib/api";
-import { useAuth } from "@/app/lib/auth/auth-context";
-import { useRouter } from "next/navigation";
+import { authAPI, userAPI, PENDING_COMPANY_REGISTRATION_KEY } from "@/app/lib/api";
 import Link from "next/link";
 import type { CompanyPayload } from "@/app/lib/api";
 import {
@@ -28,14 +26,15 @@ const formVariants = {
   exit: { opacity: 0, y: -24 },
 };
 
-type Step = "register" | "company";
+type Step = "register" | "company" | "verify";
 
 export default function CompanyRegisterPage() {
   const [step, setStep] = useState<Step>("register");
   const [isLoading, setIsLoading] = useState(false);
   const [submitError, setSubmitError] = useState<string | null>(null);
-  const router = useRouter();
-  const { refreshAuth } = useAuth();
+  // อีเมลที่ส่งลิงก์ยืนยันไป - บัญชียัง pending จนกว่าจะกดลิงก์
+  const [verificationEmail, setVerificationEmail] = useState<string | null>(null);
+  const [resendStatus, setResendStatus] = useState<"idle" | "sent" | "failed">("idle");
 
   const {
     registerForm,
@@ -164,6 +163,7 @@ export default function CompanyRegisterPage() {
       console.log("Creating user account...");
       const registerResponse = await userAPI.register(
         registerForm.username,
+        registerForm.email,
         registerForm.password,
         "CompanyUser",
       );
@@ -174,7 +174,9 @@ export default function CompanyRegisterPage() {
           ? registerResponse.data.toLowerCase()
           : '';
 
-        if (errorMsg.includes('duplicate') || errorMsg.includes('unique') || errorMsg.includes('already exists')) {
+        if (errorMsg.includes('email already registered')) {
+          setSubmitError("อีเมลนี้ถูกใช้สมัครแล้ว กรุณากลับไปเปลี่ยนอีเมล");
+        } else if (errorMsg.includes('duplicate') || errorMsg.includes('unique') || errorMsg.includes('already exists') || errorMsg.includes('already taken')) {
           setSubmitError("ชื่อผู้ใช้นี้มีคนใช้งานแล้ว กรุณากลับไปเปลี่ยนชื่อผู้ใช้");
         } else {
           setSubmitError("การสร้างบัญชีล้มเหลว กรุณาลองใหม่อีกครั้ง");
@@ -183,43 +185,14 @@ export default function CompanyRegisterPage() {
         return;
       }
 
-      // Step 2: Auto-login
-      console.log("Logging in...");
-      const loginResponse = await authAPI.login(
-        registerForm.username,
-        registerForm.password,
-      );
-
-      if (!loginResponse.ok) {
-        setSubmitError("สร้างบัญชีสำเร็จ แต่ไม่สามารถเข้าสู่ระบบได้ กรุณาเข้าสู่ระบบด้วยตนเอง");
-        setIsLoading(false);
-        return;
-      }
+      // Step 2: Keep the company info until the first login -
+      // the account cannot sign in before the email is verified
+      localStorage.setItem(PENDING_COMPANY_REGISTRATION_KEY, JSON.stringify(companyForm));
 
-      console.log("Login successful");
-
-      // Set user_role cookie for middleware to read
-      document.cookie = `user_role=CompanyUser; path=/; max-age=2592000; SameSite=Lax`;
-
-      // Sync AuthProvider state
-      await refreshAuth();
-
-      // Step 3: Create company record
-      console.log("Creating company record...");
-      const companyResponse = await companyAPI.upsertCompany(companyForm);
-
-      if (companyResponse.ok) {
-        console.log("Company registration successful:", companyResponse.data);
-        resetForms();
-        // Redirect to pending approval page
-        router.push("/company-pending-approval");
-      } else {
-        setSubmitError("บัญชีถูกสร้างแล้ว แต่การบันทึกข้อมูลบริษัทล้มเหลว คุณสามารถกรอกข้อมูลบริษัทภายหลังได้");
-        // Still redirect after a delay since account was created
-        setTimeout(() => {
-          router.push("/company-pending-approval");
-        }, 3000);
-      }
+      setVerificationEmail(registerForm.email.trim());
+      setResendStatus("idle");
+      resetForms();
+      setStep("verify");
     } catch (error) {
       console.error("Company registration error:", error);
       setSubmitError("เกิดข้อผิดพลาด กรุณาลองใหม่อีกครั้ง");
@@ -228,6 +201,20 @@ export default function CompanyRegisterPage() {
     }
   };
 
+  const handleResendVerification = async () => {
+    if (!verificationEmail) return;
+    setIsLoading(true);
+    try {
+      const response = await authAPI.requestEmailVerification(verificationEmail);
+      setResendStatus(response.ok ? "sent" : "failed");
+    } catch (error) {
+      console.error("Resend verification error:", error);
+      setResendStatus("failed");
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
   // Memoized address options
   const provinceOptions = useMemo(() => {
     return getProvinces();
@@ -308,6 +295,33 @@ export default function CompanyRegisterPage() {
                   )}
                 </div>
 
+                <div className="space-y-2">
+                  <label
+                    htmlFor="accountEmail"
+                    className="text-sm font-medium text-gray-500"
+                  >
+                    Email
+                  </label>
+                  <input
+                    id="accountEmail"
+                    type="email"
+                    placeholder="Enter your email address"
+                    autoComplete="email"
+                    className={capsuleInput}
+                    required
+                    value={registerForm.email || ""}
+                    onChange={(e) =>
+                      handleRegisterChange("email", e.target.value)
+                    }
+                    disabled={isLoading}
+                  />
+                  {registerErrors.email && (
+                    <p className="text-sm text-red-500">
+                      {registerErrors.email}
+                    </p>
+                  )}
+                </div>
+
                 <div className="space-y-2">
                   <label
                     htmlFor="password"
@@ -379,7 +393,7 @@ export default function CompanyRegisterPage() {
                 </button>
               </form>
             </motion.div>
-          ) : (
+          ) : step === "company" ? (
             <motion.div
               key="company"
               variants={formVariants}
@@ -881,6 +895,58 @@ export default function CompanyRegisterPage() {
                 </div>
               </form>
             </motion.div>
+          ) : (
+            <motion.div
+              key="verify"
+              variants={formVariants}
+              initial="initial"
+              animate="animate"
+              exit="exit"
+              transition={{ duration: 0.45, ease: [0.16, 1, 0.3, 1] }}
+              className="space-y-8 rounded-[32px]"
+            >
+              <div className="space-y-3">
+                <h1 className="text-4xl font-semibold text-white sm:text-[2.75rem]">
+                  ตรวจสอบอีเมลของคุณ
+                </h1>
+                <p className="text-base text-gray-500">
+                  เราส่งลิงก์ยืนยันไปที่{" "}
+                  <span className="font-semibold text-[#ff6a45]">{verificationEmail}</span>{" "}
+                  กรุณากดลิงก์เพื่อเปิดใช้งานบัญชี แล้วเข้าสู่ระบบ
+                  ข้อมูลบริษัทจะถูกส่งให้ผู้ดูแลตรวจสอบเมื่อคุณเข้าสู่ระบบครั้งแรก
+                </p>
+              </div>
+
+              {resendStatus === "sent" && (
+                <div className="rounded-lg bg-green-50 p-3">
+                  <p className="text-sm text-green-700">ส่งลิงก์ยืนยันใหม่แล้ว</p>
+                </div>
+              )}
+              {resendStatus === "failed" && (
+                <div className="rounded-lg bg-red-50 p-3">
+                  <p className="text-sm text-red-600">เกิดข้อผิดพลาด กรุณาลองใหม่อีกครั้ง</p>
+                </div>
+              )}
+
+              <Link
+                href="/company-login"
+                className="group relative inline-flex w-full items-center justify-center overflow-hidden rounded-full bg-gradient-to-r from-[#ffb067] via-[#ff8364] to-[#ff5d6b] px-6 py-4 text-sm font-semibold text-white shadow-[0_25px_45px_rgba(255,131,100,0.45)] transition focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-[#ff8364]"
+              >
+                <span className="tracking-wide">ไปหน้าเข้าสู่ระบบ</span>
+              </Link>
+
+              <p className="text-center text-sm text-gray-400">
+                ไม่ได้รับอีเมล?{" "}
+                <button
+                  type="button"
+                  onClick={handleResendVerification}
+                  disabled={isLoading}
+                  className="text-[#ff6a45] hover:text-[#ff8364] transition font-medium disabled:opacity-50"
+                >
+                  {isLoading ? "กำลังส่ง..." : "ส่งลิงก์อีกครั้ง"}
+                </button>
+              </p>
+            </motion.div>
           )}
         </AnimatePresence>
 
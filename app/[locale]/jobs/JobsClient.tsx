@@ -737,7 +737,7 @@ function JobsPageContent({ isLoggedIn }: { isLoggedIn: boolean }): React.ReactEl
 
                 if (response.ok && response.data) {
                     // Filter only active posts
-                    const activePosts = response.data.filter((post) => post.status === 'active');
+                    const activePosts = response.data.filter((post) => post.status === 'published');
 
                     // Fetch company names
                     const companyIds = Array.from(new Set(activePosts.map(p => p.company_id)));
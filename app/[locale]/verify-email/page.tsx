@@ -0,0 +1,161 @@
+"use client";
+
+import { FormEvent, Suspense, useEffect, useRef, useState } from "react";
+import { motion } from "framer-motion";
+import { useSearchParams } from "next/navigation";
+import Link from "next/link";
+import { authAPI } from "@/app/lib/api";
+
+const capsuleInput =
+  "w-full rounded-full border border-transparent bg-white px-6 py-4 text-sm text-gray-900 shadow-[0_15px_45px_rgba(15,23,42,0.08)] placeholder:text-gray-400 outline-none transition focus:border-[#ffbb9b] focus:ring-2 focus:ring-[#ff8364]/50";
+
+type VerifyState = "verifying" | "verified" | "failed";
+
+function VerifyEmailContent() {
+  const searchParams = useSearchParams();
+  const token = searchParams.get("token");
+  const [state, setState] = useState<VerifyState>(token ? "verifying" : "failed");
+  const [email, setEmail] = useState("");
+  const [isSending, setIsSending] = useState(false);
+  const [resendStatus, setResendStatus] = useState<"idle" | "sent" | "failed">("idle");
+  // Tokens are single use - don't confirm twice when the effect re-runs in dev mode
+  const confirmed = useRef(false);
+
+  useEffect(() => {
+    if (!token || confirmed.current) return;
+    confirmed.current = true;
+
+    authAPI
+      .confirmEmail(token)
+      .then((response) => setState(response.ok ? "verified" : "failed"))
+      .catch((error) => {
+        console.error("Email verification error:", error);
+        setState("failed");
+      });
+  }, [token]);
+
+  const handleResend = async (event: FormEvent<HTMLFormElement>) => {
+    event.preventDefault();
+    if (!email.trim()) return;
+
+    setIsSending(true);
+    try {
+      const response = await authAPI.requestEmailVerification(email);
+      setResendStatus(response.ok ? "sent" : "failed");
+    } catch (error) {
+      console.error("Resend verification error:", error);
+      setResendStatus("failed");
+    } finally {
+      setIsSending(false);
+    }
+  };
+
+  if (state === "verifying") {
+    return (
+      <div className="flex justify-center">
+        <div className="animate-spin rounded-full h-12 w-12 border-b-2 border-[#ff6a45]"></div>
+      </div>
+    );
+  }
+
+  if (state === "verified") {
+    return (
+      <div className="space-y-8">
+        <div className="space-y-3">
+          <h1 className="text-4xl font-semibold text-white sm:text-[2.75rem]">
+            Email verified
+          </h1>
+          <p className="text-base text-gray-500">
+            Your account is active. Sign in to continue.
+          </p>
+        </div>
+
+        <div className="flex flex-col gap-4 sm:flex-row">
+          <Link
+            href="/auth?redirect=/onboarding"
+            className="inline-flex flex-1 items-center justify-center rounded-full bg-gradient-to-r from-[#ffb067] via-[#ff8364] to-[#ff5d6b] px-6 py-4 text-sm font-semibold text-white shadow-[0_25px_45px_rgba(255,131,100,0.45)] transition hover:opacity-90"
+          >
+            Sign in
+          </Link>
+          <Link
+            href="/company-login"
+            className="inline-flex flex-1 items-center justify-center rounded-full border border-gray-300 px-6 py-4 text-sm font-semibold text-white transition hover:bg-white/10"
+          >
+            เข้าสู่ระบบสำหรับบริษัท
+          </Link>
+        </div>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-8">
+      <div className="space-y-3">
+        <h1 className="text-4xl font-semibold text-white sm:text-[2.75rem]">
+          Link expired
+        </h1>
+        <p className="text-base text-gray-500">
+          This verification link is invalid or has expired. Enter your email to get a new one.
+        </p>
+      </div>
+
+      <form className="space-y-4" onSubmit={handleResend}>
+        <input
+          type="email"
+          value={email}
+          onChange={(event) => setEmail(event.target.value)}
+          placeholder="Email"
+          className={capsuleInput}
+          required
+        />
+
+        {resendStatus === "sent" && (
+          <div className="rounded-lg bg-green-50 p-3">
+            <p className="text-sm text-green-700">
+              If an account is waiting for verification, a new link is on its way.
+            </p>
+          </div>
+        )}
+        {resendStatus === "failed" && (
+          <div className="rounded-lg bg-red-50 p-3">
+            <p className="text-sm text-red-600">Something went wrong. Please try again.</p>
+          </div>
+        )}
+
+        <button
+          type="submit"
+          disabled={isSending}
+          className="inline-flex w-full items-center justify-center rounded-full bg-gradient-to-r from-[#ffb067] via-[#ff8364] to-[#ff5d6b] px-6 py-4 text-sm font-semibold text-white shadow-[0_25px_45px_rgba(255,131,100,0.45)] transition hover:opacity-90 disabled:opacity-50 disabled:cursor-not-allowed"
+        >
+          {isSending ? "Sending..." : "Send new link"}
+        </button>
+      </form>
+    </div>
+  );
+}
+
+export default function VerifyEmailPage() {
+  return (
+    <main className="min-h-screen bg-[#111] text-white flex items-center justify-center px-4 py-12">
+      <div className="w-full max-w-md font-['Plus Jakarta Sans',_Inter,_'Helvetica Neue',_sans-serif]">
+        <motion.div
+          initial={{ opacity: 0, y: -12 }}
+          animate={{ opacity: 1, y: 0 }}
+          transition={{ duration: 0.6, ease: [0.16, 1, 0.3, 1] }}
+          className="mb-10 flex items-center gap-3 text-[#ff6a45]"
+        >
+          <div className="flex h-12 w-12 items-center justify-center rounded-2xl bg-[#ffece4] text-lg font-semibold tracking-tight text-[#ff6a45] shadow-inner">
+            SP
+          </div>
+          <span className="text-xs uppercase tracking-[0.45em] text-gray-400">
+            smart persona
+          </span>
+        </motion.div>
+
+        <Suspense fallback={<div className="flex justify-center">Loading...</div>}>
+          <VerifyEmailContent />
+        </Suspense>
+      </div>
+    </main>
+  );
+}
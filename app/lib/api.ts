@@ -36,8 +36,12 @@ export const API_ENDPOINTS = {
   REFRESH_TOKEN: "/authentication/refresh-token",
   LOGOUT: "/authentication/logout",
   ADMIN_LOGIN: "/authentication/admin/login",
+  ADMIN_MFA_ENROLL: "/authentication/admin/mfa/enroll",
+  ADMIN_MFA_VERIFY: "/authentication/admin/mfa/verify",
   ADMIN_REFRESH_TOKEN: "/authentication/admin/refresh-token",
   ADMIN_LOGOUT: "/authentication/admin/logout",
+  VERIFY_EMAIL_REQUEST: "/authentication/verify-email/request",
+  VERIFY_EMAIL_CONFIRM: "/authentication/verify-email/confirm",
 
   // User
   REGISTER: "/api/user/register",
@@ -116,6 +120,7 @@ export interface LoginRequest {
 
 export interface RegisterRequest {
   username: string;
+  email: string;
   password: string;
 }
 
@@ -248,6 +253,9 @@ export interface CompanyPayload {
   logo_url?: string | null;
 }
 
+// Company info filled in at sign up, submitted on the first login after the email is verified
+export const PENDING_COMPANY_REGISTRATION_KEY = "pending_company_registration";
+
 export interface CompanyResponse extends CompanyPayload {
   id: string;
   user_id: string;
@@ -571,6 +579,21 @@ export const authAPI = {
     });
   },
 
+  // Resend the verification link (always 202 - does not reveal whether the email exists)
+  requestEmailVerification: async (email: string): Promise<ApiResponse<any>> => {
+    return apiCall(API_ENDPOINTS.VERIFY_EMAIL_REQUEST, {
+      method: "POST",
+      body: JSON.stringify({ email: email.trim() }),
+    });
+  },
+
+  confirmEmail: async (token: string): Promise<ApiResponse<any>> => {
+    return apiCall(API_ENDPOINTS.VERIFY_EMAIL_CONFIRM, {
+      method: "POST",
+      body: JSON.stringify({ token }),
+    });
+  },
+
   refreshToken: async (): Promise<ApiResponse<any>> => {
     return apiCall(API_ENDPOINTS.REFRESH_TOKEN, {
       method: "POST",
@@ -583,6 +606,7 @@ export const authAPI = {
     });
   },
 
+  // 202 with { mfa_required, enrollment_required, mfa_token } - finish with adminVerifyMfa
   adminLogin: async (
     username: string,
     password: string,
@@ -593,6 +617,20 @@ export const authAPI = {
     });
   },
 
+  adminEnrollMfa: async (mfaToken: string): Promise<ApiResponse<any>> => {
+    return apiCall(API_ENDPOINTS.ADMIN_MFA_ENROLL, {
+      method: "POST",
+      body: JSON.stringify({ mfa_token: mfaToken }),
+    });
+  },
+
+  adminVerifyMfa: async (mfaToken: string, code: string): Promise<ApiResponse<any>> => {
+    return apiCall(API_ENDPOINTS.ADMIN_MFA_VERIFY, {
+      method: "POST",
+      body: JSON.stringify({ mfa_token: mfaToken, code }),
+    });
+  },
+
   adminLogout: async (): Promise<ApiResponse<any>> => {
     return apiCall(API_ENDPOINTS.ADMIN_LOGOUT, {
       method: "POST",
@@ -616,6 +654,7 @@ export const userAPI = {
 
   register: async (
     username: string,
+    email: string,
     password: string,
     role?: "PersonaUser" | "CompanyUser" | "Admin",
   ): Promise<ApiResponse<any>> => {
@@ -623,6 +662,7 @@ export const userAPI = {
       method: "POST",
       body: JSON.stringify({
         username,
+        email: email.trim(),
         password,
         ...(role && { role }),
       }),
@@ -1420,6 +1460,23 @@ export interface JobApplicationResponse {
   updated_at: string;
 }
 
+export type ApplicationStatus =
+  | 'applied'
+  | 'screening'
+  | 'interview'
+  | 'offer'
+  | 'hired'
+  | 'rejected'
+  | 'withdrawn';
+
+export interface ApplicationTimelineResponse {
+  application_id: string;
+  status: ApplicationStatus;
+  // Statuses the caller may move the application to next
+  next_statuses: ApplicationStatus[];
+  history: any[];
+}
+
 export interface JobApplicationWithUserResponse extends JobApplicationResponse {
   first_name?: string | null;
   last_name?: string | null;
@@ -1448,10 +1505,16 @@ export const jobApplicationAPI = {
     });
   },
 
-  updateApplicationStatus: async (applicationId: string, status: 'pending' | 'accepted' | 'rejected'): Promise<ApiResponse<any>> => {
+  getApplicationHistory: async (applicationId: string): Promise<ApiResponse<ApplicationTimelineResponse>> => {
+    return apiCall(`/api/user/applications/${applicationId}/history`, {
+      method: 'GET',
+    });
+  },
+
+  updateApplicationStatus: async (applicationId: string, status: ApplicationStatus, note?: string): Promise<ApiResponse<any>> => {
     return apiCall(`/api/user/applications/${applicationId}/status`, {
       method: 'PATCH',
-      body: JSON.stringify({ status }),
+      body: JSON.stringify({ status, note }),
     });
   },
 };
@@ -24,6 +24,7 @@ interface AuthContextType {
   ) => Promise<{ success: boolean; error?: string }>;
   register: (
     username: string,
+    email: string,
     password: string,
   ) => Promise<{ success: boolean; error?: string }>;
   logout: () => Promise<void>;
@@ -170,41 +171,24 @@ export const AuthProvider: React.FC<AuthProviderProps> = ({ children }) => {
 
   const register = async (
     username: string,
+    email: string,
     password: string,
   ): Promise<{ success: boolean; error?: string }> => {
     try {
       setIsLoading(true);
       // Step 1: Register the user
-      const registerResponse = await apiClient.register({ username, password });
+      const registerResponse = await apiClient.register({ username, email, password });
 
       if (!registerResponse.success) {
         return { success: false, error: registerResponse.message };
       }
 
-      // Step 2: Auto-login after successful registration
-      const loginResponse = await apiClient.login({ username, password });
-
-      if (loginResponse.success) {
-        setIsAuthenticated(true);
-        setUser({ username });
-        localStorage.setItem("username", username);
-        // Clear old profile images when registering a new user
-        localStorage.removeItem("profile_image_url");
-        localStorage.removeItem("cover_image_url");
-        localStorage.removeItem("user_about_me");
-        
-        // Fetch user role
-        await fetchUserInfo();
-        
-        router.push("/onboarding");
-        return { success: true };
-      }
-
-      return {
-        success: false,
-        error:
-          "Registration successful but login failed. Please login manually.",
-      };
+      // The account stays pending until the email link is opened, so there is no auto-login here.
+      // Clear old profile images from a previous user on this device
+      localStorage.removeItem("profile_image_url");
+      localStorage.removeItem("cover_image_url");
+      localStorage.removeItem("user_about_me");
+      return { success: true };
     } catch (error) {
       console.error("Registration failed:", error);
       return {
@@ -5,7 +5,7 @@ export interface LoginRequest {
 
 export interface RegisterRequest {
   username: string;
-
+  email: string;
   password: string;
 }
 
@@ -20,6 +20,26 @@ export interface AuthResponse {
   data?: unknown;
 }
 
+// Admin login is always two-step: /admin/login answers 202 with this challenge,
+// cookies are only set by /admin/mfa/verify
+export interface MfaChallenge {
+  mfa_required: boolean;
+  enrollment_required: boolean;
+  mfa_token: string;
+  expires_in: number;
+}
+
+export interface MfaEnrollment {
+  secret: string;
+  provisioning_uri: string;
+}
+
+export interface AdminMfaVerifyResponse {
+  message: string;
+  // Only returned when this verification just enabled MFA - show them once
+  recovery_codes?: string[];
+}
+
 export interface UserProfile {
   username: string;
   email?: string;
@@ -100,14 +120,31 @@ class ApiClient {
     });
   }
 
-  async adminLogin(data: LoginRequest): Promise<AuthResponse> {
-    return this.request<AuthResponse>("/admin/login", {
+  async adminLogin(data: LoginRequest): Promise<MfaChallenge> {
+    return this.request<MfaChallenge>("/authentication/admin/login", {
       method: "POST",
       body: JSON.stringify(data),
       credentials: "include",
     });
   }
 
+  // Admins without MFA get a TOTP secret to add to their authenticator app first
+  async adminEnrollMfa(mfaToken: string): Promise<MfaEnrollment> {
+    return this.request<MfaEnrollment>("/authentication/admin/mfa/enroll", {
+      method: "POST",
+      body: JSON.stringify({ mfa_token: mfaToken }),
+      credentials: "include",
+    });
+  }
+
+  async adminVerifyMfa(mfaToken: string, code: string): Promise<AdminMfaVerifyResponse> {
+    return this.request<AdminMfaVerifyResponse>("/authentication/admin/mfa/verify", {
+      method: "POST",
+      body: JSON.stringify({ mfa_token: mfaToken, code }),
+      credentials: "include", // Cookies are set here
+    });
+  }
+
   async getUserProfile(): Promise<UserProfile> {
     try {
       return await this.request<UserProfile>("/api/user/", {
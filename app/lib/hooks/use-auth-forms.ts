@@ -7,6 +7,7 @@ export interface LoginFormData {
 
 export interface RegisterFormData {
   username: string;
+  email: string;
   password: string;
   confirmPassword: string;
 }
@@ -21,6 +22,7 @@ export const useAuthForms = () => {
 
   const [registerForm, setRegisterForm] = useState<RegisterFormData>({
     username: "",
+    email: "",
     password: "",
     confirmPassword: "",
   });
@@ -58,6 +60,12 @@ export const useAuthForms = () => {
       errors.username = "Username must be at least 3 characters";
     }
 
+    if (!registerForm.email.trim()) {
+      errors.email = "Email is required";
+    } else if (!/^[^\s@]+@[^\s@]+\.[^\s@]+$/.test(registerForm.email.trim())) {
+      errors.email = "Please enter a valid email address";
+    }
+
     if (!registerForm.password.trim()) {
       errors.password = "Password is required";
     } else if (registerForm.password.length < 8) {
@@ -97,6 +105,7 @@ export const useAuthForms = () => {
     setLoginForm({ username: "", password: "" });
     setRegisterForm({
       username: "",
+      email: "",
       password: "",
       confirmPassword: "",
     });
@@ -1,18 +1,46 @@
 "use client";
 
 import React, { useState } from "react";
-import Link from "next/link";
 import { useRouter } from "next/navigation";
 
 const API_BASE_URL = process.env.NEXT_PUBLIC_API_BASE_URL || "http://localhost:8000";
 
+const inputClassName =
+    "w-full px-4 py-3 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-800 dark:text-white disabled:opacity-50 disabled:cursor-not-allowed transition";
+
+const buttonClassName =
+    "w-full py-3 px-4 bg-blue-600 hover:bg-blue-700 text-white font-medium rounded-lg transition disabled:opacity-50 disabled:cursor-not-allowed focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2";
+
+// Admin login is two-step: password first (202 + mfa_token), then a TOTP code
+type Step = "password" | "mfa" | "recovery";
+
+interface MfaEnrollment {
+    secret: string;
+    provisioning_uri: string;
+}
+
 export default function SigninPage() {
+    const [step, setStep] = useState<Step>("password");
     const [username, setUsername] = useState("");
     const [password, setPassword] = useState("");
+    const [mfaToken, setMfaToken] = useState("");
+    const [enrollment, setEnrollment] = useState<MfaEnrollment | null>(null);
+    const [code, setCode] = useState("");
+    const [recoveryCodes, setRecoveryCodes] = useState<string[]>([]);
     const [isLoading, setIsLoading] = useState(false);
     const [error, setError] = useState("");
     const router = useRouter();
 
+    const postJson = (path: string, body: unknown) =>
+        fetch(`${API_BASE_URL}${path}`, {
+            method: "POST",
+            headers: {
+                "Content-Type": "application/json",
+            },
+            credentials: "include",
+            body: JSON.stringify(body),
+        });
+
     const handleSubmit = async (e: React.FormEvent) => {
         e.preventDefault();
         setError("");
@@ -21,28 +49,91 @@ export default function SigninPage() {
         console.log("🔐 [Admin Login] Starting login process...");
 
         try {
-            const response = await fetch(`${API_BASE_URL}/authentication/admin/login`, {
-                method: "POST",
-                headers: {
-                    "Content-Type": "application/json",
-                },
-                credentials: "include",
-                body: JSON.stringify({ username, password }),
-            });
+            const response = await postJson("/authentication/admin/login", { username, password });
 
             console.log("📥 [Admin Login] Response:", {
                 ok: response.ok,
                 status: response.status,
             });
 
-            if (response.ok) {
-                console.log("✅ [Admin Login] Login successful, redirecting...");
-                // Redirect to admin dashboard
-                router.push("/");
-            } else {
+            if (response.status !== 202) {
                 console.log("❌ [Admin Login] Login failed");
-                setError("ชื่อผู้ใช้หรือรหัสผ่านไม่ถูกต้อง");
+                setError(
+                    response.status === 429
+                        ? "พยายามเข้าสู่ระบบหลายครั้งเกินไป กรุณารอสักครู่"
+                        : "ชื่อผู้ใช้หรือรหัสผ่านไม่ถูกต้อง"
+                );
+                return;
+            }
+
+            const challenge = await response.json();
+            setMfaToken(challenge.mfa_token);
+
+            // First sign-in: the admin has to add the secret to an authenticator app before verifying
+            if (challenge.enrollment_required) {
+                const enrollResponse = await postJson("/authentication/admin/mfa/enroll", {
+                    mfa_token: challenge.mfa_token,
+                });
+                if (!enrollResponse.ok) {
+                    setError("ไม่สามารถตั้งค่าการยืนยันตัวตนสองขั้นตอนได้ กรุณาลองใหม่อีกครั้ง");
+                    return;
+                }
+                setEnrollment(await enrollResponse.json());
+            }
+
+            console.log("🔑 [Admin Login] Password accepted, waiting for MFA code...");
+            setStep("mfa");
+        } catch (err) {
+            console.error("❌ [Admin Login] Error:", err);
+            setError("เกิดข้อผิดพลาด กรุณาลองใหม่อีกครั้ง");
+        } finally {
+            setIsLoading(false);
+        }
+    };
+
+    const handleVerify = async (e: React.FormEvent) => {
+        e.preventDefault();
+        setError("");
+        setIsLoading(true);
+
+        try {
+            const response = await postJson("/authentication/admin/mfa/verify", {
+                mfa_token: mfaToken,
+                code: code.trim(),
+            });
+
+            if (response.status === 401 && (await response.text()).includes("expired MFA token")) {
+                // The mfa_token expired - start over from the password step
+                setStep("password");
+                setEnrollment(null);
+                setCode("");
+                setError("หมดเวลายืนยันตัวตน กรุณาเข้าสู่ระบบใหม่อีกครั้ง");
+                return;
+            }
+
+            if (!response.ok) {
+                setError(
+                    response.status === 429
+                        ? "พยายามยืนยันรหัสหลายครั้งเกินไป กรุณารอสักครู่"
+                        : "รหัสยืนยันไม่ถูกต้อง"
+                );
+                return;
             }
+
+            console.log("✅ [Admin Login] Login successful");
+
+            // Enrollment returns recovery codes once - show them before leaving the page
+            const contentType = response.headers.get("content-type") || "";
+            if (contentType.includes("application/json")) {
+                const data = await response.json();
+                if (Array.isArray(data.recovery_codes) && data.recovery_codes.length > 0) {
+                    setRecoveryCodes(data.recovery_codes);
+                    setStep("recovery");
+                    return;
+                }
+            }
+
+            router.push("/");
         } catch (err) {
             console.error("❌ [Admin Login] Error:", err);
             setError("เกิดข้อผิดพลาด กรุณาลองใหม่อีกครั้ง");
@@ -51,6 +142,12 @@ export default function SigninPage() {
         }
     };
 
+    const errorBox = error && (
+        <div className="p-4 bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 rounded-lg">
+            <p className="text-sm text-red-600 dark:text-red-400">{error}</p>
+        </div>
+    );
+
     return (
         <div className="lg:w-1/2 w-full bg-white dark:bg-gray-900 flex items-center justify-center">
             <div className="w-full sm:max-w-md p-8">
@@ -59,64 +156,123 @@ export default function SigninPage() {
                         Admin Login
                     </h1>
                     <p className="text-gray-600 dark:text-gray-400">
-                        เข้าสู่ระบบเพื่อจัดการระบบ Smart Persona
+                        {step === "password"
+                            ? "เข้าสู่ระบบเพื่อจัดการระบบ Smart Persona"
+                            : step === "mfa"
+                                ? "กรอกรหัส 6 หลักจากแอปยืนยันตัวตน หรือรหัสกู้คืน"
+                                : "เก็บรหัสกู้คืนเหล่านี้ไว้ในที่ปลอดภัย รหัสจะแสดงเพียงครั้งเดียว"}
                     </p>
                 </div>
 
-                <form onSubmit={handleSubmit} className="space-y-6">
-                    <div>
-                        <label
-                            htmlFor="username"
-                            className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
-                        >
-                            Username
-                        </label>
-                        <input
-                            id="username"
-                            type="text"
-                            value={username}
-                            onChange={(e) => setUsername(e.target.value)}
-                            required
-                            disabled={isLoading}
-                            className="w-full px-4 py-3 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-800 dark:text-white disabled:opacity-50 disabled:cursor-not-allowed transition"
-                            placeholder="Enter your username"
-                        />
-                    </div>
+                {step === "password" && (
+                    <form onSubmit={handleSubmit} className="space-y-6">
+                        <div>
+                            <label
+                                htmlFor="username"
+                                className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
+                            >
+                                Username
+                            </label>
+                            <input
+                                id="username"
+                                type="text"
+                                value={username}
+                                onChange={(e) => setUsername(e.target.value)}
+                                required
+                                disabled={isLoading}
+                                className={inputClassName}
+                                placeholder="Enter your username"
+                            />
+                        </div>
 
-                    <div>
-                        <label
-                            htmlFor="password"
-                            className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
-                        >
-                            Password
-                        </label>
-                        <input
-                            id="password"
-                            type="password"
-                            value={password}
-                            onChange={(e) => setPassword(e.target.value)}
-                            required
-                            disabled={isLoading}
-                            className="w-full px-4 py-3 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-800 dark:text-white disabled:opacity-50 disabled:cursor-not-allowed transition"
-                            placeholder="Enter your password"
-                        />
-                    </div>
+                        <div>
+                            <label
+                                htmlFor="password"
+                                className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
+                            >
+                                Password
+                            </label>
+                            <input
+                                id="password"
+                                type="password"
+                                value={password}
+                                onChange={(e) => setPassword(e.target.value)}
+                                required
+                                disabled={isLoading}
+                                className={inputClassName}
+                                placeholder="Enter your password"
+                            />
+                        </div>
+
+                        {errorBox}
 
-                    {error && (
-                        <div className="p-4 bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 rounded-lg">
-                            <p className="text-sm text-red-600 dark:text-red-400">{error}</p>
+                        <button type="submit" disabled={isLoading} className={buttonClassName}>
+                            {isLoading ? "กำลังเข้าสู่ระบบ..." : "เข้าสู่ระบบ"}
+                        </button>
+                    </form>
+                )}
+
+                {step === "mfa" && (
+                    <form onSubmit={handleVerify} className="space-y-6">
+                        {enrollment && (
+                            <div className="p-4 bg-blue-50 dark:bg-blue-900/20 border border-blue-200 dark:border-blue-800 rounded-lg space-y-2">
+                                <p className="text-sm text-gray-700 dark:text-gray-300">
+                                    เพิ่มบัญชีนี้ในแอปยืนยันตัวตน (เช่น Google Authenticator) ด้วย secret ด้านล่าง
+                                </p>
+                                <p className="font-mono text-sm break-all text-gray-900 dark:text-white">
+                                    {enrollment.secret}
+                                </p>
+                                <a
+                                    href={enrollment.provisioning_uri}
+                                    className="text-sm text-blue-600 hover:text-blue-700 dark:text-blue-400"
+                                >
+                                    เปิดในแอปยืนยันตัวตน
+                                </a>
+                            </div>
+                        )}
+
+                        <div>
+                            <label
+                                htmlFor="code"
+                                className="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
+                            >
+                                Verification code
+                            </label>
+                            <input
+                                id="code"
+                                type="text"
+                                inputMode="numeric"
+                                autoComplete="one-time-code"
+                                value={code}
+                                onChange={(e) => setCode(e.target.value)}
+                                required
+                                disabled={isLoading}
+                                className={inputClassName}
+                                placeholder="123456"
+                            />
                         </div>
-                    )}
 
-                    <button
-                        type="submit"
-                        disabled={isLoading}
-                        className="w-full py-3 px-4 bg-blue-600 hover:bg-blue-700 text-white font-medium rounded-lg transition disabled:opacity-50 disabled:cursor-not-allowed focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2"
-                    >
-                        {isLoading ? "กำลังเข้าสู่ระบบ..." : "เข้าสู่ระบบ"}
-                    </button>
-                </form>
+                        {errorBox}
+
+                        <button type="submit" disabled={isLoading} className={buttonClassName}>
+                            {isLoading ? "กำลังยืนยัน..." : "ยืนยัน"}
+                        </button>
+                    </form>
+                )}
 
+                {step === "recovery" && (
+                    <div className="space-y-6">
+                        <ul className="grid grid-cols-2 gap-2 p-4 bg-gray-50 dark:bg-gray-800 rounded-lg font-mono text-sm text-gray-900 dark:text-white">
+                            {recoveryCodes.map((recoveryCode) => (
+                                <li key={recoveryCode}>{recoveryCode}</li>
+                            ))}
+                        </ul>
+
+                        <button type="button" onClick={() => router.push("/")} className={buttonClassName}>
+                            บันทึกแล้ว ไปที่แดชบอร์ด
+                        </button>
+                    </div>
+                )}
 
             </div>
         </div>
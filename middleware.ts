@@ -14,6 +14,7 @@ const publicPaths = [
   "/company-login", // Company login page
   "/company-register", // Company register page
   "/company-pending-approval", // Company pending approval page
+  "/verify-email", // Email verification link landing page
   "/about", // About page
   "/contact", // Contact page
 ];